@@ -0,0 +1,137 @@
+//! The composition root: turns [`Settings`] into a running service.
+//!
+//! ```no_run
+//! # async fn run(settings: shared::config::Settings) -> Result<(), Box<dyn std::error::Error>> {
+//! api::app::AppBuilder::new(settings).build().await?.serve().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+mod ports;
+mod shutdown;
+mod state;
+mod workers;
+
+pub use ports::Ports;
+
+use crate::http;
+use crate::http::audit::{RequestAuditLog, RequestUnitOfWork};
+use crate::http::state::AppState;
+use application::ports::AuditLog;
+use axum::Router;
+use sea_orm::{DatabaseConnection, DbErr};
+use shared::config::Settings;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use workers::Workers;
+
+type PortsOverride = Box<dyn FnOnce(&mut Ports) + Send>;
+
+/// Assembles the service from its settings. By default every port gets the
+/// adapter [`Ports::from_settings`] picks; [`AppBuilder::with_ports`] replaces
+/// individual ones, e.g. with in-memory adapters in tests.
+pub struct AppBuilder {
+    settings: Settings,
+    database: Option<DatabaseConnection>,
+    overrides: Vec<PortsOverride>,
+}
+
+impl AppBuilder {
+    pub fn new(settings: Settings) -> Self {
+        Self {
+            settings,
+            database: None,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Uses `db` instead of connecting to `settings.database.url`.
+    pub fn with_database(mut self, db: DatabaseConnection) -> Self {
+        self.database = Some(db);
+        self
+    }
+
+    /// Changes the adapters after the defaults are in place. Overrides apply in
+    /// the order they were added.
+    pub fn with_ports(mut self, change: impl FnOnce(&mut Ports) + Send + 'static) -> Self {
+        self.overrides.push(Box::new(change));
+        self
+    }
+
+    pub async fn build(self) -> Result<App, DbErr> {
+        let db = match self.database {
+            Some(db) => db,
+            None => infrastructure::db::init_db(&self.settings.database).await?,
+        };
+        let mut ports = Ports::from_settings(&self.settings, &db);
+        for change in self.overrides {
+            change(&mut ports);
+        }
+
+        let audit_log: Arc<dyn AuditLog> = Arc::new(RequestAuditLog::new(ports.audit_log.clone()));
+        ports.unit_of_work = Arc::new(RequestUnitOfWork::new(ports.unit_of_work.clone()));
+        let state = state::app_state(&self.settings, db, &ports, &audit_log);
+        let workers = Workers::new(&self.settings, &ports, &audit_log);
+        Ok(App {
+            settings: self.settings,
+            state,
+            workers,
+        })
+    }
+}
+
+/// A fully wired service. Background workers only run while it is served.
+pub struct App {
+    settings: Settings,
+    state: AppState,
+    workers: Workers,
+}
+
+impl App {
+    pub fn state(&self) -> &AppState {
+        &self.state
+    }
+
+    pub fn build_router(&self) -> Router {
+        http::router(self.state.clone(), &self.settings)
+    }
+
+    /// Serves the API on the configured address with the background workers
+    /// running, until SIGTERM or SIGINT.
+    pub async fn serve(self) -> std::io::Result<()> {
+        let bind_addr = format!(
+            "{}:{}",
+            self.settings.server.host, self.settings.server.port
+        );
+        let listener = tokio::net::TcpListener::bind(&bind_addr).await?;
+
+        println!("listening on http://{bind_addr}");
+        self.serve_with_shutdown(listener, shutdown::signal()).await
+    }
+
+    /// Serves the API on `listener` until `shutdown` completes. The server then
+    /// stops accepting connections, finishes the requests in flight and stops the
+    /// background workers before this returns.
+    pub async fn serve_with_shutdown(
+        self,
+        listener: tokio::net::TcpListener,
+        shutdown: impl Future<Output = ()> + Send + 'static,
+    ) -> std::io::Result<()> {
+        let router = self.build_router();
+        let workers = self.workers.spawn();
+
+        // Connect info feeds the client IP used for login throttling.
+        let served = axum::serve(
+            listener,
+            router.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(shutdown)
+        .await;
+
+        tracing::info!("requests drained, stopping background workers");
+        workers.stop().await;
+        tracing::info!("shutdown complete");
+        served
+    }
+}
@@ -0,0 +1,125 @@
+use application::ports::{
+    AccessTokenService, AccountRestoreTokenRepository, ApiKeyRepository, AuditLog,
+    DataExportArchive, DataExportRepository, EmailService, EmailVerificationTokenRepository,
+    EventOutbox, EventPublisher, LinkedIdentityRepository, LoginAttemptStore,
+    MagicLinkTokenRepository, OidcClient, OidcFlowStore, OneTimeTokenService, PasskeyCeremonyStore,
+    PasskeyRepository, PasswordHasher, PasswordResetTokenRepository, SessionRepository,
+    TotpService, TwoFactorRepository, UnitOfWork, UserErasureRepository, UserRepository,
+    WebauthnService, WebhookRepository, WebhookSender,
+};
+use application::use_cases::EventBus;
+use infrastructure::db::repos;
+use sea_orm::DatabaseConnection;
+use shared::config::{LoginAttemptStoreKind, Settings};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// One adapter per application port. [`Ports::from_settings`] picks the production
+/// adapters; any field can be replaced before the use cases are assembled.
+#[derive(Clone)]
+pub struct Ports {
+    pub users: Arc<dyn UserRepository>,
+    pub email_verification_tokens: Arc<dyn EmailVerificationTokenRepository>,
+    /// Must write to the same storage as `users` and `email_verification_tokens`.
+    pub unit_of_work: Arc<dyn UnitOfWork>,
+    pub password_reset_tokens: Arc<dyn PasswordResetTokenRepository>,
+    pub magic_link_tokens: Arc<dyn MagicLinkTokenRepository>,
+    pub account_restore_tokens: Arc<dyn AccountRestoreTokenRepository>,
+    pub one_time_tokens: Arc<dyn OneTimeTokenService>,
+    pub access_tokens: Arc<dyn AccessTokenService>,
+    pub password_hasher: Arc<dyn PasswordHasher>,
+    pub login_attempts: Arc<dyn LoginAttemptStore>,
+    pub email: Arc<dyn EmailService>,
+    /// Requests attribute entries to the calling client on top of this.
+    pub audit_log: Arc<dyn AuditLog>,
+    pub two_factor: Arc<dyn TwoFactorRepository>,
+    pub totp: Arc<dyn TotpService>,
+    pub passkeys: Arc<dyn PasskeyRepository>,
+    pub passkey_ceremonies: Arc<dyn PasskeyCeremonyStore>,
+    pub webauthn: Arc<dyn WebauthnService>,
+    pub linked_identities: Arc<dyn LinkedIdentityRepository>,
+    pub oidc_flows: Arc<dyn OidcFlowStore>,
+    pub oidc_client: Arc<dyn OidcClient>,
+    pub sessions: Arc<dyn SessionRepository>,
+    pub api_keys: Arc<dyn ApiKeyRepository>,
+    pub data_exports: Arc<dyn DataExportRepository>,
+    pub data_export_archive: Arc<dyn DataExportArchive>,
+    pub user_erasure: Arc<dyn UserErasureRepository>,
+    /// Read by the webhook worker; the default `event_publisher` writes to it.
+    pub event_outbox: Arc<dyn EventOutbox>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+    pub webhooks: Arc<dyn WebhookRepository>,
+    pub webhook_sender: Arc<dyn WebhookSender>,
+}
+
+impl Ports {
+    /// The adapters the service runs with: Postgres for storage, Mailtrap for
+    /// email. Nothing connects until it is first used.
+    pub fn from_settings(settings: &Settings, db: &DatabaseConnection) -> Self {
+        let login_attempts: Arc<dyn LoginAttemptStore> = match settings.auth.login_throttle.store {
+            LoginAttemptStoreKind::Postgres => {
+                Arc::new(repos::PostgresLoginAttemptStore::new(db.clone()))
+            }
+            LoginAttemptStoreKind::Memory => {
+                Arc::new(infrastructure::memory::InMemoryLoginAttemptStore::new())
+            }
+        };
+        let event_outbox = Arc::new(repos::PostgresEventOutbox::new(db.clone()));
+        let event_publisher = Arc::new(
+            EventBus::new().with_handler(Arc::new(infrastructure::events::TracingEventHandler)),
+        );
+
+        Self {
+            users: Arc::new(repos::PostgresUserRepository::new(db.clone())),
+            email_verification_tokens: Arc::new(
+                repos::PostgresEmailVerificationTokenRepository::new(db.clone()),
+            ),
+            unit_of_work: Arc::new(infrastructure::db::PostgresUnitOfWork::new(db.clone())),
+            password_reset_tokens: Arc::new(repos::PostgresPasswordResetTokenRepository::new(
+                db.clone(),
+            )),
+            magic_link_tokens: Arc::new(repos::PostgresMagicLinkTokenRepository::new(db.clone())),
+            account_restore_tokens: Arc::new(repos::PostgresAccountRestoreTokenRepository::new(
+                db.clone(),
+            )),
+            one_time_tokens: Arc::new(infrastructure::security::Sha256OneTimeTokenService),
+            access_tokens: Arc::new(infrastructure::security::JwtAccessTokenService::new(
+                &settings.auth,
+            )),
+            password_hasher: Arc::new(infrastructure::security::Argon2Hasher),
+            login_attempts,
+            email: Arc::new(infrastructure::email::MailtrapEmailService::new(
+                settings.mailtrap.clone(),
+            )),
+            audit_log: Arc::new(repos::PostgresAuditLog::new(db.clone())),
+            two_factor: Arc::new(repos::PostgresTwoFactorRepository::new(db.clone())),
+            totp: Arc::new(infrastructure::security::TotpRsService::new(
+                settings.auth.totp_issuer.clone(),
+            )),
+            passkeys: Arc::new(repos::PostgresPasskeyRepository::new(db.clone())),
+            passkey_ceremonies: Arc::new(repos::PostgresPasskeyCeremonyStore::new(db.clone())),
+            webauthn: Arc::new(infrastructure::security::WebauthnRelyingParty::new(
+                &settings.passkeys.rp_id,
+                settings.passkeys.origins.clone(),
+            )),
+            linked_identities: Arc::new(repos::PostgresLinkedIdentityRepository::new(db.clone())),
+            oidc_flows: Arc::new(repos::PostgresOidcFlowStore::new(db.clone())),
+            oidc_client: Arc::new(infrastructure::oidc::HttpOidcClient::new(&settings.oidc)),
+            sessions: Arc::new(repos::PostgresSessionRepository::new(db.clone())),
+            api_keys: Arc::new(repos::PostgresApiKeyRepository::new(db.clone())),
+            data_exports: Arc::new(repos::PostgresDataExportRepository::new(db.clone())),
+            data_export_archive: Arc::new(
+                infrastructure::storage::FilesystemDataExportArchive::new(
+                    &settings.data_export.storage_dir,
+                ),
+            ),
+            user_erasure: Arc::new(repos::PostgresUserErasureRepository::new(db.clone())),
+            event_outbox,
+            event_publisher,
+            webhooks: Arc::new(repos::PostgresWebhookRepository::new(db.clone())),
+            webhook_sender: Arc::new(infrastructure::webhooks::HttpWebhookSender::new(
+                Duration::from_secs(settings.webhooks.timeout_seconds.max(1)),
+            )),
+        }
+    }
+}
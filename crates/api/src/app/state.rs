@@ -0,0 +1,294 @@
+use super::Ports;
+use crate::http::healthcheck::Readiness;
+use crate::http::state::AppState;
+use application::ports::AuditLog;
+use application::use_cases::{
+    AuthenticateApiKeyUseCase, ChangeUserRoleUseCase, CheckSessionUseCase,
+    ClearLoginLockoutUseCase, CompleteOidcLoginUseCase, CompleteTotpLoginUseCase,
+    ConfirmIdentityLinkUseCase, ConfirmTotpUseCase, CreateApiKeyUseCase, CreateUserUseCase,
+    CreateWebhookSubscriptionUseCase, DeleteApiKeyUseCase, DeletePasskeyUseCase, DeleteUserUseCase,
+    DeleteWebhookSubscriptionUseCase, DownloadDataExportUseCase, EnrollTotpUseCase,
+    FinishPasskeyLoginUseCase, FinishPasskeyRegistrationUseCase, GetUserUseCase,
+    ListApiKeysUseCase, ListAuditLogUseCase, ListLinkedIdentitiesUseCase, ListLoginLockoutsUseCase,
+    ListPasskeysUseCase, ListSessionsUseCase, ListUsersUseCase, ListWebhookDeliveriesUseCase,
+    ListWebhookSubscriptionsUseCase, LoginThrottle, LoginThrottlePolicy, LoginUseCase,
+    MagicLinkLoginUseCase, PasskeyOptions, RedeliverWebhookUseCase, RequestDataExportUseCase,
+    RequestMagicLinkUseCase, RequestPasswordResetUseCase, ResetPasswordUseCase,
+    RestoreAccountUseCase, RestoreUserUseCase, RevokeAllSessionsUseCase, RevokeSessionUseCase,
+    SessionTokenIssuer, SignupUseCase, StartOidcLoginUseCase, StartPasskeyLoginUseCase,
+    StartPasskeyRegistrationUseCase, UnlockAccountUseCase, UpdateUserProfileUseCase,
+    VerifyEmailUseCase,
+};
+use chrono::Duration;
+use sea_orm::DatabaseConnection;
+use shared::config::{LoginThrottleSettings, PasskeySettings, Settings};
+use std::sync::Arc;
+
+fn login_throttle_policy(settings: &LoginThrottleSettings) -> LoginThrottlePolicy {
+    LoginThrottlePolicy {
+        max_account_failures: settings.max_account_failures,
+        max_ip_failures: settings.max_ip_failures,
+        failure_window: Duration::minutes(settings.failure_window_minutes as i64),
+        base_delay: Duration::seconds(settings.base_delay_seconds as i64),
+        max_delay: Duration::seconds(settings.max_delay_seconds as i64),
+        lockout_duration: Duration::minutes(settings.lockout_minutes as i64),
+    }
+}
+
+fn passkey_options(settings: &PasskeySettings) -> PasskeyOptions {
+    PasskeyOptions {
+        rp_id: settings.rp_id.clone(),
+        rp_name: settings.rp_name.clone(),
+        timeout: Duration::seconds(settings.ceremony_timeout_seconds as i64),
+    }
+}
+
+/// The use cases behind the HTTP handlers, wired to `ports`.
+pub(super) fn app_state(
+    settings: &Settings,
+    db: DatabaseConnection,
+    ports: &Ports,
+    audit_log: &Arc<dyn AuditLog>,
+) -> AppState {
+    let p = ports;
+    let session_tokens = Arc::new(SessionTokenIssuer::new(
+        p.sessions.clone(),
+        p.access_tokens.clone(),
+    ));
+    let login_throttle = Arc::new(LoginThrottle::new(
+        p.login_attempts.clone(),
+        p.users.clone(),
+        p.email.clone(),
+        p.one_time_tokens.clone(),
+        login_throttle_policy(&settings.auth.login_throttle),
+    ));
+    let account_grace_period = Duration::days(settings.account_deletion.grace_period_days.into());
+
+    AppState {
+        readiness: Arc::new(Readiness::new(db.clone(), settings)),
+        db,
+        token_service: p.access_tokens.clone(),
+        create_user_use_case: Arc::new(CreateUserUseCase::new(
+            p.users.clone(),
+            p.unit_of_work.clone(),
+            p.password_hasher.clone(),
+            p.event_publisher.clone(),
+        )),
+        get_user_use_case: Arc::new(GetUserUseCase::new(p.users.clone())),
+        delete_user_use_case: Arc::new(DeleteUserUseCase::new(
+            p.users.clone(),
+            p.account_restore_tokens.clone(),
+            p.one_time_tokens.clone(),
+            p.email.clone(),
+            p.unit_of_work.clone(),
+            account_grace_period,
+            p.event_publisher.clone(),
+        )),
+        list_users_use_case: Arc::new(ListUsersUseCase::new(p.users.clone())),
+        update_user_profile_use_case: Arc::new(UpdateUserProfileUseCase::new(
+            p.users.clone(),
+            p.unit_of_work.clone(),
+            p.event_publisher.clone(),
+        )),
+        signup_use_case: Arc::new(SignupUseCase::new(
+            p.users.clone(),
+            p.unit_of_work.clone(),
+            p.password_hasher.clone(),
+            p.email.clone(),
+            p.event_publisher.clone(),
+        )),
+        verify_email_use_case: Arc::new(VerifyEmailUseCase::new(
+            p.users.clone(),
+            p.email_verification_tokens.clone(),
+            p.unit_of_work.clone(),
+            p.event_publisher.clone(),
+        )),
+        request_password_reset_use_case: Arc::new(RequestPasswordResetUseCase::new(
+            p.users.clone(),
+            p.password_reset_tokens.clone(),
+            p.email.clone(),
+        )),
+        reset_password_use_case: Arc::new(ResetPasswordUseCase::new(
+            p.users.clone(),
+            p.password_reset_tokens.clone(),
+            p.password_hasher.clone(),
+            p.unit_of_work.clone(),
+            settings.auth.password_reset_token_expiry_hours,
+            p.event_publisher.clone(),
+        )),
+        login_use_case: Arc::new(LoginUseCase::new(
+            p.users.clone(),
+            p.password_hasher.clone(),
+            session_tokens.clone(),
+            p.two_factor.clone(),
+            login_throttle.clone(),
+        )),
+        complete_totp_login_use_case: Arc::new(CompleteTotpLoginUseCase::new(
+            p.users.clone(),
+            p.two_factor.clone(),
+            p.totp.clone(),
+            session_tokens.clone(),
+            login_throttle,
+        )),
+        request_magic_link_use_case: Arc::new(RequestMagicLinkUseCase::new(
+            p.users.clone(),
+            p.magic_link_tokens.clone(),
+            p.one_time_tokens.clone(),
+            p.email.clone(),
+            Duration::minutes(settings.auth.magic_link_ttl_minutes as i64),
+        )),
+        magic_link_login_use_case: Arc::new(MagicLinkLoginUseCase::new(
+            p.users.clone(),
+            p.magic_link_tokens.clone(),
+            p.one_time_tokens.clone(),
+            p.two_factor.clone(),
+            session_tokens.clone(),
+        )),
+        enroll_totp_use_case: Arc::new(EnrollTotpUseCase::new(
+            p.users.clone(),
+            p.two_factor.clone(),
+            p.totp.clone(),
+        )),
+        confirm_totp_use_case: Arc::new(ConfirmTotpUseCase::new(
+            p.users.clone(),
+            p.two_factor.clone(),
+            p.totp.clone(),
+            session_tokens.clone(),
+        )),
+        start_passkey_registration_use_case: Arc::new(StartPasskeyRegistrationUseCase::new(
+            p.users.clone(),
+            p.passkeys.clone(),
+            p.passkey_ceremonies.clone(),
+            p.webauthn.clone(),
+            passkey_options(&settings.passkeys),
+        )),
+        finish_passkey_registration_use_case: Arc::new(FinishPasskeyRegistrationUseCase::new(
+            p.passkeys.clone(),
+            p.passkey_ceremonies.clone(),
+            p.webauthn.clone(),
+        )),
+        list_passkeys_use_case: Arc::new(ListPasskeysUseCase::new(p.passkeys.clone())),
+        delete_passkey_use_case: Arc::new(DeletePasskeyUseCase::new(p.passkeys.clone())),
+        start_passkey_login_use_case: Arc::new(StartPasskeyLoginUseCase::new(
+            p.passkey_ceremonies.clone(),
+            p.webauthn.clone(),
+            passkey_options(&settings.passkeys),
+        )),
+        finish_passkey_login_use_case: Arc::new(FinishPasskeyLoginUseCase::new(
+            p.users.clone(),
+            p.passkeys.clone(),
+            p.passkey_ceremonies.clone(),
+            p.webauthn.clone(),
+            session_tokens.clone(),
+        )),
+        start_oidc_login_use_case: Arc::new(StartOidcLoginUseCase::new(
+            p.oidc_client.clone(),
+            p.oidc_flows.clone(),
+            Duration::seconds(settings.oidc.login_timeout_seconds as i64),
+        )),
+        complete_oidc_login_use_case: Arc::new(CompleteOidcLoginUseCase::new(
+            p.users.clone(),
+            p.unit_of_work.clone(),
+            p.linked_identities.clone(),
+            p.oidc_flows.clone(),
+            p.oidc_client.clone(),
+            p.one_time_tokens.clone(),
+            p.password_hasher.clone(),
+            p.two_factor.clone(),
+            session_tokens,
+            Duration::minutes(settings.oidc.link_ttl_minutes as i64),
+            p.event_publisher.clone(),
+        )),
+        confirm_identity_link_use_case: Arc::new(ConfirmIdentityLinkUseCase::new(
+            p.linked_identities.clone(),
+            p.oidc_flows.clone(),
+            p.one_time_tokens.clone(),
+        )),
+        list_linked_identities_use_case: Arc::new(ListLinkedIdentitiesUseCase::new(
+            p.linked_identities.clone(),
+        )),
+        check_session_use_case: Arc::new(CheckSessionUseCase::new(p.sessions.clone())),
+        list_sessions_use_case: Arc::new(ListSessionsUseCase::new(p.sessions.clone())),
+        revoke_session_use_case: Arc::new(RevokeSessionUseCase::new(
+            p.sessions.clone(),
+            audit_log.clone(),
+        )),
+        revoke_all_sessions_use_case: Arc::new(RevokeAllSessionsUseCase::new(p.sessions.clone())),
+        create_api_key_use_case: Arc::new(CreateApiKeyUseCase::new(
+            p.users.clone(),
+            p.api_keys.clone(),
+            p.one_time_tokens.clone(),
+            audit_log.clone(),
+        )),
+        authenticate_api_key_use_case: Arc::new(AuthenticateApiKeyUseCase::new(
+            p.api_keys.clone(),
+            p.users.clone(),
+            p.one_time_tokens.clone(),
+        )),
+        list_api_keys_use_case: Arc::new(ListApiKeysUseCase::new(p.api_keys.clone())),
+        delete_api_key_use_case: Arc::new(DeleteApiKeyUseCase::new(
+            p.api_keys.clone(),
+            audit_log.clone(),
+        )),
+        unlock_account_use_case: Arc::new(UnlockAccountUseCase::new(
+            p.login_attempts.clone(),
+            p.one_time_tokens.clone(),
+        )),
+        list_login_lockouts_use_case: Arc::new(ListLoginLockoutsUseCase::new(
+            p.login_attempts.clone(),
+        )),
+        clear_login_lockout_use_case: Arc::new(ClearLoginLockoutUseCase::new(
+            p.login_attempts.clone(),
+            audit_log.clone(),
+        )),
+        list_audit_log_use_case: Arc::new(ListAuditLogUseCase::new(audit_log.clone())),
+        change_user_role_use_case: Arc::new(ChangeUserRoleUseCase::new(
+            p.users.clone(),
+            p.unit_of_work.clone(),
+            p.event_publisher.clone(),
+        )),
+        request_data_export_use_case: Arc::new(RequestDataExportUseCase::new(
+            p.users.clone(),
+            p.data_exports.clone(),
+            audit_log.clone(),
+        )),
+        download_data_export_use_case: Arc::new(DownloadDataExportUseCase::new(
+            p.data_exports.clone(),
+            p.data_export_archive.clone(),
+            p.one_time_tokens.clone(),
+        )),
+        restore_account_use_case: Arc::new(RestoreAccountUseCase::new(
+            p.users.clone(),
+            p.account_restore_tokens.clone(),
+            p.one_time_tokens.clone(),
+            p.unit_of_work.clone(),
+            p.event_publisher.clone(),
+        )),
+        restore_user_use_case: Arc::new(RestoreUserUseCase::new(
+            p.users.clone(),
+            p.account_restore_tokens.clone(),
+            p.unit_of_work.clone(),
+            p.event_publisher.clone(),
+        )),
+        create_webhook_subscription_use_case: Arc::new(CreateWebhookSubscriptionUseCase::new(
+            p.webhooks.clone(),
+            p.one_time_tokens.clone(),
+            audit_log.clone(),
+            settings.webhooks.allow_insecure_urls,
+        )),
+        list_webhook_subscriptions_use_case: Arc::new(ListWebhookSubscriptionsUseCase::new(
+            p.webhooks.clone(),
+        )),
+        delete_webhook_subscription_use_case: Arc::new(DeleteWebhookSubscriptionUseCase::new(
+            p.webhooks.clone(),
+            audit_log.clone(),
+        )),
+        list_webhook_deliveries_use_case: Arc::new(ListWebhookDeliveriesUseCase::new(
+            p.webhooks.clone(),
+        )),
+        redeliver_webhook_use_case: Arc::new(RedeliverWebhookUseCase::new(
+            p.webhooks.clone(),
+            audit_log.clone(),
+        )),
+    }
+}
@@ -0,0 +1,211 @@
+use super::Ports;
+use application::ports::AuditLog;
+use application::use_cases::{
+    DeliverWebhooksUseCase, EraseDeletedUsersUseCase, ProcessDataExportUseCase,
+    PurgeAuditLogUseCase, RelayOutboxEventsUseCase, UserDataCollector, WebhookFanOut,
+    WebhookRetryPolicy,
+};
+use shared::config::Settings;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio::time::Interval;
+
+/// The jobs that run next to the HTTP server, built with the same ports as the
+/// handlers.
+pub(super) struct Workers {
+    data_export: ProcessDataExportUseCase,
+    data_export_every: Duration,
+    erasure: EraseDeletedUsersUseCase,
+    erasure_every: Duration,
+    webhook_fan_out: RelayOutboxEventsUseCase,
+    webhook_delivery: DeliverWebhooksUseCase,
+    webhooks_every: Duration,
+    audit_log_purge: PurgeAuditLogUseCase,
+    audit_log_purge_every: Duration,
+}
+
+impl Workers {
+    pub(super) fn new(settings: &Settings, ports: &Ports, audit_log: &Arc<dyn AuditLog>) -> Self {
+        let p = ports;
+        Self {
+            data_export: ProcessDataExportUseCase::new(
+                Arc::new(UserDataCollector::new(
+                    p.users.clone(),
+                    p.sessions.clone(),
+                    p.passkeys.clone(),
+                    p.linked_identities.clone(),
+                    p.api_keys.clone(),
+                    audit_log.clone(),
+                )),
+                p.data_exports.clone(),
+                p.data_export_archive.clone(),
+                p.one_time_tokens.clone(),
+                p.email.clone(),
+                chrono::Duration::hours(settings.data_export.download_ttl_hours as i64),
+            ),
+            data_export_every: Duration::from_secs(
+                settings.data_export.poll_interval_seconds.max(1),
+            ),
+            erasure: EraseDeletedUsersUseCase::new(
+                p.user_erasure.clone(),
+                p.data_exports.clone(),
+                p.data_export_archive.clone(),
+                audit_log.clone(),
+                chrono::Duration::days(settings.account_deletion.grace_period_days.into()),
+            ),
+            erasure_every: Duration::from_secs(
+                settings.account_deletion.erase_interval_minutes.max(1) * 60,
+            ),
+            webhook_fan_out: RelayOutboxEventsUseCase::new(
+                p.event_outbox.clone(),
+                Arc::new(WebhookFanOut::new(p.webhooks.clone())),
+            ),
+            webhook_delivery: DeliverWebhooksUseCase::new(
+                p.webhooks.clone(),
+                p.webhook_sender.clone(),
+                WebhookRetryPolicy {
+                    max_attempts: settings.webhooks.max_attempts.max(1),
+                    base_delay: chrono::Duration::seconds(
+                        settings.webhooks.retry_base_delay_seconds as i64,
+                    ),
+                    max_delay: chrono::Duration::minutes(
+                        settings.webhooks.retry_max_delay_minutes as i64,
+                    ),
+                },
+            ),
+            webhooks_every: Duration::from_secs(settings.webhooks.poll_interval_seconds.max(1)),
+            audit_log_purge: PurgeAuditLogUseCase::new(
+                audit_log.clone(),
+                chrono::Duration::days(settings.audit_log.retention_days.into()),
+            ),
+            audit_log_purge_every: Duration::from_secs(
+                settings.audit_log.purge_interval_minutes.max(1) * 60,
+            ),
+        }
+    }
+
+    pub(super) fn spawn(self) -> RunningWorkers {
+        let (stop, stopped) = watch::channel(false);
+        let tasks = vec![
+            spawn_data_export_worker(self.data_export, self.data_export_every, stopped.clone()),
+            spawn_deleted_user_erasure(self.erasure, self.erasure_every, stopped.clone()),
+            spawn_webhook_worker(
+                self.webhook_fan_out,
+                self.webhook_delivery,
+                self.webhooks_every,
+                stopped.clone(),
+            ),
+            spawn_audit_log_purge(self.audit_log_purge, self.audit_log_purge_every, stopped),
+        ];
+        RunningWorkers { stop, tasks }
+    }
+}
+
+pub(super) struct RunningWorkers {
+    stop: watch::Sender<bool>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl RunningWorkers {
+    /// Lets every worker finish the run it is in, then waits for all of them to exit.
+    pub(super) async fn stop(self) {
+        self.stop.send_replace(true);
+        for task in self.tasks {
+            if let Err(err) = task.await {
+                tracing::error!(error = %err, "background worker failed");
+            }
+        }
+    }
+}
+
+/// Waits for the next run; `false` once the workers are told to stop.
+async fn next_tick(interval: &mut Interval, stop: &mut watch::Receiver<bool>) -> bool {
+    tokio::select! {
+        _ = interval.tick() => !*stop.borrow(),
+        _ = stop.changed() => false,
+    }
+}
+
+/// Removes expired audit log entries in the background until the server stops.
+fn spawn_audit_log_purge(
+    use_case: PurgeAuditLogUseCase,
+    every: Duration,
+    mut stop: watch::Receiver<bool>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(every);
+        while next_tick(&mut interval, &mut stop).await {
+            if let Err(err) = use_case.execute().await {
+                tracing::error!(error = %err, "audit log purge failed");
+            }
+        }
+    })
+}
+
+/// Works through queued data exports and removes expired ones until the server
+/// stops.
+fn spawn_data_export_worker(
+    use_case: ProcessDataExportUseCase,
+    every: Duration,
+    mut stop: watch::Receiver<bool>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(every);
+        while next_tick(&mut interval, &mut stop).await {
+            while !*stop.borrow() {
+                match use_case.execute().await {
+                    Ok(Some(export_id)) => {
+                        tracing::info!(%export_id, "data export ready");
+                    }
+                    Ok(None) => break,
+                    Err(err) => {
+                        tracing::error!(error = %err, "data export failed");
+                        break;
+                    }
+                }
+            }
+            if let Err(err) = use_case.remove_expired().await {
+                tracing::error!(error = %err, "removing expired data exports failed");
+            }
+        }
+    })
+}
+
+/// Erases accounts whose grace period has ended until the server stops.
+fn spawn_deleted_user_erasure(
+    use_case: EraseDeletedUsersUseCase,
+    every: Duration,
+    mut stop: watch::Receiver<bool>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(every);
+        while next_tick(&mut interval, &mut stop).await {
+            if let Err(err) = use_case.execute().await {
+                tracing::error!(error = %err, "erasing deleted users failed");
+            }
+        }
+    })
+}
+
+/// Queues deliveries for new events and sends the due ones until the server
+/// stops.
+fn spawn_webhook_worker(
+    fan_out: RelayOutboxEventsUseCase,
+    deliver: DeliverWebhooksUseCase,
+    every: Duration,
+    mut stop: watch::Receiver<bool>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(every);
+        while next_tick(&mut interval, &mut stop).await {
+            if let Err(err) = fan_out.execute().await {
+                tracing::error!(error = %err, "queueing webhook deliveries failed");
+            }
+            if let Err(err) = deliver.execute().await {
+                tracing::error!(error = %err, "sending webhooks failed");
+            }
+        }
+    })
+}
@@ -0,0 +1,156 @@
+use application::ports::{AttemptSubject, AuditEntry, AuditLogFilter, LoginAttempts};
+use chrono::{DateTime, Utc};
+use domain_users::models::user::UserRole;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LoginLockoutResponse {
+    /// `account` or `ip`.
+    #[schema(example = "account")]
+    pub subject_type: String,
+    /// Email address or IP address the failures were counted against.
+    #[schema(example = "john@example.com")]
+    pub subject: String,
+    pub failed_attempts: u32,
+    pub last_failed_at: DateTime<Utc>,
+    pub blocked_until: Option<DateTime<Utc>>,
+    pub locked_until: Option<DateTime<Utc>>,
+}
+
+impl From<LoginAttempts> for LoginLockoutResponse {
+    fn from(attempts: LoginAttempts) -> Self {
+        let (subject_type, subject) = match attempts.subject {
+            AttemptSubject::Account(email) => ("account", email),
+            AttemptSubject::Ip(ip) => ("ip", ip.to_string()),
+        };
+        Self {
+            subject_type: subject_type.to_string(),
+            subject,
+            failed_attempts: attempts.failed_attempts,
+            last_failed_at: attempts.last_failed_at,
+            blocked_until: attempts.blocked_until,
+            locked_until: attempts.locked_until,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams, Validate)]
+#[into_params(parameter_in = Query)]
+pub struct AuditLogQuery {
+    /// Only entries of actions taken by this user.
+    pub actor_id: Option<Uuid>,
+    /// Only entries about this user, API key etc.
+    pub target_id: Option<Uuid>,
+    /// e.g. `user.deleted`.
+    #[validate(length(min = 1, max = 100))]
+    pub action: Option<String>,
+    /// Only entries at or after this time.
+    pub from: Option<DateTime<Utc>>,
+    /// Only entries before this time.
+    pub to: Option<DateTime<Utc>>,
+    #[validate(range(min = 0))]
+    pub page: Option<u64>,
+    #[validate(range(min = 1, max = 100))]
+    pub page_size: Option<u64>,
+}
+
+impl AuditLogQuery {
+    pub fn filter(&self) -> AuditLogFilter {
+        AuditLogFilter {
+            actor_id: self.actor_id,
+            target_id: self.target_id,
+            action: self.action.clone(),
+            from: self.from,
+            to: self.to,
+        }
+    }
+
+    pub fn page(&self) -> u64 {
+        self.page.unwrap_or(0)
+    }
+
+    pub fn page_size(&self) -> u64 {
+        self.page_size.unwrap_or(20)
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FieldChangeResponse {
+    #[schema(example = "role")]
+    pub field: String,
+    #[schema(example = "user")]
+    pub old: Option<String>,
+    #[schema(example = "creator")]
+    pub new: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuditEntryResponse {
+    pub id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+    /// `null` for anonymous requests.
+    pub actor_id: Option<Uuid>,
+    #[schema(example = "user.role_changed")]
+    pub action: String,
+    #[schema(example = "user")]
+    pub target_type: String,
+    pub target_id: Option<Uuid>,
+    pub changes: Vec<FieldChangeResponse>,
+    pub correlation_id: Option<String>,
+    #[schema(example = "203.0.113.7")]
+    pub ip_address: Option<String>,
+}
+
+impl From<AuditEntry> for AuditEntryResponse {
+    fn from(entry: AuditEntry) -> Self {
+        Self {
+            id: entry.id,
+            occurred_at: entry.occurred_at,
+            actor_id: entry.actor_id,
+            action: entry.action,
+            target_type: entry.target_type,
+            target_id: entry.target_id,
+            changes: entry
+                .changes
+                .into_iter()
+                .map(|change| FieldChangeResponse {
+                    field: change.field,
+                    old: change.old_value,
+                    new: change.new_value,
+                })
+                .collect(),
+            correlation_id: entry.correlation_id,
+            ip_address: entry.ip_address.map(|ip| ip.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UserRoleDto {
+    User,
+    VerifiedUser,
+    Creator,
+    Moderator,
+    Admin,
+}
+
+impl From<UserRoleDto> for UserRole {
+    fn from(role: UserRoleDto) -> Self {
+        match role {
+            UserRoleDto::User => UserRole::User,
+            UserRoleDto::VerifiedUser => UserRole::VerifiedUser,
+            UserRoleDto::Creator => UserRole::Creator,
+            UserRoleDto::Moderator => UserRole::Moderator,
+            UserRoleDto::Admin => UserRole::Admin,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct ChangeUserRoleRequest {
+    pub role: UserRoleDto,
+}
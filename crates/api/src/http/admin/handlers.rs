@@ -0,0 +1,216 @@
+use crate::http::admin::dtos::{
+    AuditEntryResponse, AuditLogQuery, ChangeUserRoleRequest, LoginLockoutResponse,
+};
+use crate::http::auth::AdminUser;
+use crate::http::extract::{ValidatedJson, ValidatedQuery};
+use crate::http::state::AppState;
+use crate::http::users::dtos::UserResponse;
+use crate::http::{ApiResponse, AppError, PaginatedResponse};
+use application::ports::AttemptSubject;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::net::IpAddr;
+use uuid::Uuid;
+
+#[utoipa::path(
+    get,
+    path = "/admin/lockouts",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Accounts and IPs currently throttled or locked", body = ApiResponse<Vec<LoginLockoutResponse>>),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn list_lockouts(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+) -> Result<impl IntoResponse, AppError> {
+    let lockouts = state.list_login_lockouts_use_case.execute().await?;
+
+    let response: Vec<LoginLockoutResponse> = lockouts
+        .into_iter()
+        .map(LoginLockoutResponse::from)
+        .collect();
+
+    Ok((StatusCode::OK, Json(ApiResponse::success(response))))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/lockouts/accounts/{email}",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    params(
+        ("email" = String, Path, description = "Email address the failures were counted against")
+    ),
+    responses(
+        (status = 204, description = "Lockout cleared"),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::http::ApiErrorResponse),
+        (status = 404, description = "No failed attempts recorded", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn clear_account_lockout(
+    State(state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(email): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let subject = AttemptSubject::account(&email);
+    state
+        .clear_login_lockout_use_case
+        .execute(admin.user_id, &subject)
+        .await?;
+    tracing::info!(admin_id = %admin.user_id, subject = %subject.key(), "login lockout cleared");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/lockouts/ips/{ip}",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    params(
+        ("ip" = String, Path, description = "IPv4 or IPv6 address")
+    ),
+    responses(
+        (status = 204, description = "Lockout cleared"),
+        (status = 400, description = "Not an IP address", body = crate::http::ApiErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::http::ApiErrorResponse),
+        (status = 404, description = "No failed attempts recorded", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn clear_ip_lockout(
+    State(state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(ip): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let ip: IpAddr = ip
+        .parse()
+        .map_err(|_| AppError::BadRequest(format!("{} is not an IP address", ip)))?;
+
+    let subject = AttemptSubject::Ip(ip);
+    state
+        .clear_login_lockout_use_case
+        .execute(admin.user_id, &subject)
+        .await?;
+    tracing::info!(admin_id = %admin.user_id, subject = %subject.key(), "login lockout cleared");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/audit-log",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    params(AuditLogQuery),
+    responses(
+        (status = 200, description = "Matching audit log entries, newest first", body = PaginatedResponse<AuditEntryResponse>),
+        (status = 400, description = "Invalid filter", body = crate::http::ApiErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn list_audit_log(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    ValidatedQuery(query): ValidatedQuery<AuditLogQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let (entries, total) = state
+        .list_audit_log_use_case
+        .execute(&query.filter(), query.page(), query.page_size())
+        .await?;
+
+    let response: Vec<AuditEntryResponse> = entries.into_iter().map(Into::into).collect();
+
+    Ok((
+        StatusCode::OK,
+        Json(PaginatedResponse::success(
+            response,
+            query.page(),
+            query.page_size(),
+            total,
+        )),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin/users/{id}/role",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    request_body = ChangeUserRoleRequest,
+    responses(
+        (status = 200, description = "Role changed; the user is signed out everywhere", body = crate::http::ApiResponseUser),
+        (status = 400, description = "Invalid payload", body = crate::http::ApiErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Caller is not an admin or tried to change their own role", body = crate::http::ApiErrorResponse),
+        (status = 404, description = "User not found", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn change_user_role(
+    State(state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(id): Path<Uuid>,
+    ValidatedJson(payload): ValidatedJson<ChangeUserRoleRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state
+        .change_user_role_use_case
+        .execute(admin.user_id, id, payload.role.into())
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success(UserResponse::from(user))),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/users/{id}/restore",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    description = "Undoes the deletion of an account that has not been erased yet.",
+    responses(
+        (status = 200, description = "User restored, or was not deleted", body = crate::http::ApiResponseUser),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::http::ApiErrorResponse),
+        (status = 404, description = "User not found or already erased", body = crate::http::ApiErrorResponse),
+        (status = 409, description = "Another account now uses the user's email", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn restore_user(
+    State(state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state
+        .restore_user_use_case
+        .execute(admin.user_id, id)
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success(UserResponse::from(user))),
+    ))
+}
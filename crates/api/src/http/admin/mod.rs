@@ -0,0 +1,24 @@
+pub mod dtos;
+pub mod handlers;
+
+use crate::http::state::AppState;
+use axum::{
+    Router,
+    routing::{delete, get, post, put},
+};
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/admin/lockouts", get(handlers::list_lockouts))
+        .route("/admin/audit-log", get(handlers::list_audit_log))
+        .route("/admin/users/{id}/role", put(handlers::change_user_role))
+        .route("/admin/users/{id}/restore", post(handlers::restore_user))
+        .route(
+            "/admin/lockouts/accounts/{email}",
+            delete(handlers::clear_account_lockout),
+        )
+        .route(
+            "/admin/lockouts/ips/{ip}",
+            delete(handlers::clear_ip_lockout),
+        )
+}
@@ -0,0 +1,94 @@
+use application::ports::{ApiKey, ApiKeyScope};
+use application::use_cases::CreatedApiKey;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+pub enum ApiKeyScopeDto {
+    /// `GET /me`
+    #[serde(rename = "profile:read")]
+    ProfileRead,
+    /// `PATCH /me`
+    #[serde(rename = "profile:write")]
+    ProfileWrite,
+}
+
+impl From<ApiKeyScopeDto> for ApiKeyScope {
+    fn from(scope: ApiKeyScopeDto) -> Self {
+        match scope {
+            ApiKeyScopeDto::ProfileRead => ApiKeyScope::ProfileRead,
+            ApiKeyScopeDto::ProfileWrite => ApiKeyScope::ProfileWrite,
+        }
+    }
+}
+
+impl From<ApiKeyScope> for ApiKeyScopeDto {
+    fn from(scope: ApiKeyScope) -> Self {
+        match scope {
+            ApiKeyScope::ProfileRead => ApiKeyScopeDto::ProfileRead,
+            ApiKeyScope::ProfileWrite => ApiKeyScopeDto::ProfileWrite,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct CreateApiKeyRequest {
+    #[validate(length(min = 1, max = 100))]
+    #[schema(example = "Moodle sync")]
+    pub name: String,
+    #[validate(length(min = 1))]
+    pub scopes: Vec<ApiKeyScopeDto>,
+    #[validate(range(min = 1, max = 365))]
+    #[schema(example = 90)]
+    pub expires_in_days: u32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ApiKeyResponse {
+    pub id: Uuid,
+    #[schema(example = "Moodle sync")]
+    pub name: String,
+    /// The start of the key, to recognise it.
+    #[schema(example = "mak_Xq3v9LpA")]
+    pub prefix: String,
+    pub scopes: Vec<ApiKeyScopeDto>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<ApiKey> for ApiKeyResponse {
+    fn from(api_key: ApiKey) -> Self {
+        Self {
+            id: api_key.id,
+            name: api_key.name,
+            prefix: api_key.prefix,
+            scopes: api_key.scopes.into_iter().map(Into::into).collect(),
+            created_at: api_key.created_at,
+            expires_at: api_key.expires_at,
+            last_used_at: api_key.last_used_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreatedApiKeyResponse {
+    /// Send as `Authorization: ApiKey <key>`. It is shown only once and cannot be
+    /// retrieved later.
+    #[schema(example = "mak_Xq3v9LpA2bR7kT0sWmYc1uEoHn5gJd8fZiVl4xQ6yNw")]
+    pub key: String,
+    #[serde(flatten)]
+    pub api_key: ApiKeyResponse,
+}
+
+impl From<CreatedApiKey> for CreatedApiKeyResponse {
+    fn from(created: CreatedApiKey) -> Self {
+        Self {
+            key: created.key,
+            api_key: created.api_key.into(),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::http::ApiResponse;
+use crate::http::AppError;
+use crate::http::api_keys::dtos::{ApiKeyResponse, CreateApiKeyRequest, CreatedApiKeyResponse};
+use crate::http::auth::AuthenticatedUser;
+use crate::http::extract::ValidatedJson;
+use crate::http::state::AppState;
+use application::use_cases::CreateApiKeyInput;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "/me/api-keys",
+    tag = "api-keys",
+    security(("bearer_auth" = [])),
+    request_body = CreateApiKeyRequest,
+    responses(
+        (status = 201, description = "API key created; the key is only returned this once", body = ApiResponse<CreatedApiKeyResponse>),
+        (status = 400, description = "Invalid payload", body = crate::http::ApiErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Only creators and admins may create API keys", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn create_api_key(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    ValidatedJson(payload): ValidatedJson<CreateApiKeyRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let created = state
+        .create_api_key_use_case
+        .execute(CreateApiKeyInput {
+            user_id: user.user_id,
+            name: payload.name,
+            scopes: payload.scopes.into_iter().map(Into::into).collect(),
+            expires_in: chrono::Duration::days(payload.expires_in_days.into()),
+        })
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(CreatedApiKeyResponse::from(created))),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/me/api-keys",
+    tag = "api-keys",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "API keys of the current user, newest first", body = ApiResponse<Vec<ApiKeyResponse>>),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn list_api_keys(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+) -> Result<impl IntoResponse, AppError> {
+    let api_keys = state.list_api_keys_use_case.execute(user.user_id).await?;
+
+    let response: Vec<ApiKeyResponse> = api_keys.into_iter().map(Into::into).collect();
+
+    Ok((StatusCode::OK, Json(ApiResponse::success(response))))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/me/api-keys/{id}",
+    tag = "api-keys",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "API key ID")
+    ),
+    responses(
+        (status = 204, description = "API key revoked"),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 404, description = "API key not found", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn delete_api_key(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .delete_api_key_use_case
+        .execute(user.user_id, id)
+        .await?;
+
+    tracing::info!(user_id = %user.user_id, api_key_id = %id, "api key revoked");
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,17 @@
+pub mod dtos;
+pub mod handlers;
+
+use crate::http::state::AppState;
+use axum::{
+    Router,
+    routing::{delete, get},
+};
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/me/api-keys",
+            get(handlers::list_api_keys).post(handlers::create_api_key),
+        )
+        .route("/me/api-keys/{id}", delete(handlers::delete_api_key))
+}
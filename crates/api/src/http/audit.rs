@@ -0,0 +1,183 @@
+use crate::http::client_ip::current_client_ip;
+use crate::http::middleware::get_correlation_id;
+use application::ports::email_verification_token_repository::EmailVerificationTokenRepository;
+use application::ports::unit_of_work::{Transaction, UnitOfWork, UnitOfWorkError};
+use application::ports::{
+    AuditEntry, AuditLog, AuditLogError, AuditLogFilter, EventOutbox, SessionRepository,
+    UserRepository,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+fn stamped(entry: &AuditEntry) -> AuditEntry {
+    let mut entry = entry.clone();
+    entry.correlation_id = entry.correlation_id.or_else(get_correlation_id);
+    entry.ip_address = entry.ip_address.or_else(current_client_ip);
+    entry
+}
+
+/// Stamps audit entries with the correlation id and client IP of the request they are
+/// recorded in, so use cases need not pass either along.
+pub struct RequestAuditLog {
+    inner: Arc<dyn AuditLog>,
+}
+
+impl RequestAuditLog {
+    pub fn new(inner: Arc<dyn AuditLog>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl AuditLog for RequestAuditLog {
+    async fn record(&self, entry: &AuditEntry) -> Result<(), AuditLogError> {
+        self.inner.record(&stamped(entry)).await
+    }
+
+    async fn find(
+        &self,
+        filter: &AuditLogFilter,
+        page: u64,
+        page_size: u64,
+    ) -> Result<(Vec<AuditEntry>, u64), AuditLogError> {
+        self.inner.find(filter, page, page_size).await
+    }
+
+    async fn delete_older_than(&self, cutoff: DateTime<Utc>) -> Result<u64, AuditLogError> {
+        self.inner.delete_older_than(cutoff).await
+    }
+}
+
+/// Like [`RequestAuditLog`], for the audit entries written inside a transaction.
+pub struct RequestUnitOfWork {
+    inner: Arc<dyn UnitOfWork>,
+}
+
+impl RequestUnitOfWork {
+    pub fn new(inner: Arc<dyn UnitOfWork>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for RequestUnitOfWork {
+    async fn begin(&self) -> Result<Box<dyn Transaction>, UnitOfWorkError> {
+        Ok(Box::new(RequestTransaction {
+            inner: self.inner.begin().await?,
+        }))
+    }
+}
+
+/// Serves as its own audit log, stamping entries before they reach the inner one.
+struct RequestTransaction {
+    inner: Box<dyn Transaction>,
+}
+
+#[async_trait]
+impl AuditLog for RequestTransaction {
+    async fn record(&self, entry: &AuditEntry) -> Result<(), AuditLogError> {
+        self.inner.audit_log().record(&stamped(entry)).await
+    }
+
+    async fn find(
+        &self,
+        filter: &AuditLogFilter,
+        page: u64,
+        page_size: u64,
+    ) -> Result<(Vec<AuditEntry>, u64), AuditLogError> {
+        self.inner.audit_log().find(filter, page, page_size).await
+    }
+
+    async fn delete_older_than(&self, cutoff: DateTime<Utc>) -> Result<u64, AuditLogError> {
+        self.inner.audit_log().delete_older_than(cutoff).await
+    }
+}
+
+#[async_trait]
+impl Transaction for RequestTransaction {
+    fn users(&self) -> &dyn UserRepository {
+        self.inner.users()
+    }
+
+    fn email_verification_tokens(&self) -> &dyn EmailVerificationTokenRepository {
+        self.inner.email_verification_tokens()
+    }
+
+    fn audit_log(&self) -> &dyn AuditLog {
+        self
+    }
+
+    fn sessions(&self) -> &dyn SessionRepository {
+        self.inner.sessions()
+    }
+
+    fn event_outbox(&self) -> &dyn EventOutbox {
+        self.inner.event_outbox()
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), UnitOfWorkError> {
+        self.inner.commit().await
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), UnitOfWorkError> {
+        self.inner.rollback().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::client_ip::CLIENT_IP;
+    use crate::http::middleware::CORRELATION_ID;
+    use test_support::{
+        InMemoryAuditLog, InMemoryEmailVerificationTokenRepository, InMemoryUnitOfWork,
+        InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn test_entries_carry_the_request_context() {
+        let inner = Arc::new(InMemoryAuditLog::new());
+        let audit_log = RequestAuditLog::new(inner.clone());
+        let entry = AuditEntry::new(None, "user.deleted", "user", None, Vec::new());
+
+        let record = audit_log.record(&entry);
+        let ip = Some("203.0.113.7".parse().unwrap());
+        CORRELATION_ID
+            .scope("req-1".to_string(), CLIENT_IP.scope(ip, record))
+            .await
+            .unwrap();
+        // Outside of a request there is nothing to stamp.
+        audit_log.record(&entry).await.unwrap();
+
+        let entries = inner.entries();
+        assert_eq!(entries[0].correlation_id.as_deref(), Some("req-1"));
+        assert_eq!(entries[0].ip_address, ip);
+        assert_eq!(entries[1].correlation_id, None);
+        assert_eq!(entries[1].ip_address, None);
+    }
+
+    #[tokio::test]
+    async fn test_transaction_entries_carry_the_request_context() {
+        let inner = Arc::new(InMemoryAuditLog::new());
+        let unit_of_work = RequestUnitOfWork::new(Arc::new(InMemoryUnitOfWork::new(
+            Arc::new(InMemoryUserRepository::new()),
+            Arc::new(InMemoryEmailVerificationTokenRepository::new()),
+            inner.clone(),
+            Arc::default(),
+            Arc::default(),
+        )));
+        let entry = AuditEntry::new(None, "user.profile_updated", "user", None, Vec::new());
+
+        let transaction = unit_of_work.begin().await.unwrap();
+        let record = transaction.audit_log().record(&entry);
+        CORRELATION_ID
+            .scope("req-2".to_string(), CLIENT_IP.scope(None, record))
+            .await
+            .unwrap();
+        assert!(inner.entries().is_empty());
+        transaction.commit().await.unwrap();
+
+        assert_eq!(inner.entries()[0].correlation_id.as_deref(), Some("req-2"));
+    }
+}
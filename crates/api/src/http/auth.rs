@@ -0,0 +1,245 @@
+use crate::http::AppError;
+use crate::http::state::AppState;
+use application::ports::{AccessTokenScope, ApiKeyScope};
+use application::use_cases::ApiKeyPrincipal;
+use axum::extract::{FromRequestParts, MatchedPath, OptionalFromRequestParts};
+use axum::http::{Method, header, request::Parts};
+use domain_users::models::user::UserRole;
+use uuid::Uuid;
+
+/// Routes that accept API keys and the scope each requires. API keys are refused
+/// everywhere else, so new endpoints stay closed to keys until listed here.
+const API_KEY_ROUTES: &[(Method, &str, ApiKeyScope)] = &[
+    (Method::GET, "/me", ApiKeyScope::ProfileRead),
+    (Method::PATCH, "/me", ApiKeyScope::ProfileWrite),
+];
+
+/// The caller identified by a valid `Authorization: Bearer <token>` header, or by an
+/// `Authorization: ApiKey <key>` header on the routes listed in [`API_KEY_ROUTES`].
+#[derive(Debug, Clone)]
+pub struct AuthenticatedUser {
+    pub user_id: Uuid,
+    pub role: UserRole,
+    /// The session the token belongs to; `None` for partial tokens and API keys.
+    pub session_id: Option<Uuid>,
+}
+
+/// An [`AuthenticatedUser`] whose access token carries the admin role.
+#[derive(Debug, Clone)]
+pub struct AdminUser(pub AuthenticatedUser);
+
+/// The caller of the TOTP login step, identified by the partial token from the password step.
+#[derive(Debug, Clone)]
+pub struct TotpChallengeUser(pub AuthenticatedUser);
+
+/// A fully signed in user, or staff signed in with a password only in order to enroll TOTP.
+#[derive(Debug, Clone)]
+pub struct TotpEnrollmentUser(pub AuthenticatedUser);
+
+enum Credentials<'a> {
+    Bearer(&'a str),
+    ApiKey(&'a str),
+}
+
+fn credentials(parts: &Parts) -> Result<Option<Credentials<'_>>, AppError> {
+    let Some(value) = parts.headers.get(header::AUTHORIZATION) else {
+        return Ok(None);
+    };
+    let value = value
+        .to_str()
+        .map_err(|_| AppError::Unauthorized("Malformed Authorization header".to_string()))?;
+
+    if let Some(token) = value.strip_prefix("Bearer ") {
+        Ok(Some(Credentials::Bearer(token.trim())))
+    } else if let Some(key) = value.strip_prefix("ApiKey ") {
+        Ok(Some(Credentials::ApiKey(key.trim())))
+    } else {
+        Err(AppError::Unauthorized(
+            "Malformed Authorization header".to_string(),
+        ))
+    }
+}
+
+/// The access token of an endpoint that only signed in users may call.
+fn bearer_token(parts: &Parts) -> Result<Option<&str>, AppError> {
+    match credentials(parts)? {
+        Some(Credentials::Bearer(token)) => Ok(Some(token)),
+        Some(Credentials::ApiKey(_)) => Err(api_key_refused()),
+        None => Ok(None),
+    }
+}
+
+fn api_key_refused() -> AppError {
+    AppError::Forbidden("API keys cannot be used for this endpoint".to_string())
+}
+
+/// The scope an API key needs for the route, `None` when the route refuses keys.
+pub(crate) fn api_key_scope(method: &Method, route: &str) -> Option<ApiKeyScope> {
+    API_KEY_ROUTES
+        .iter()
+        .find(|(allowed, path, _)| allowed == method && *path == route)
+        .map(|(_, _, scope)| *scope)
+}
+
+fn required_api_key_scope(parts: &Parts) -> Option<ApiKeyScope> {
+    let route = parts.extensions.get::<MatchedPath>()?.as_str();
+    api_key_scope(&parts.method, route)
+}
+
+async fn authenticate_api_key(
+    key: &str,
+    parts: &Parts,
+    state: &AppState,
+) -> Result<AuthenticatedUser, AppError> {
+    // The rate limiter has already resolved the key when it throttled the request.
+    let principal = match parts.extensions.get::<ApiKeyPrincipal>() {
+        Some(principal) => principal.clone(),
+        None => state.authenticate_api_key_use_case.execute(key).await?,
+    };
+
+    let scope = required_api_key_scope(parts).ok_or_else(api_key_refused)?;
+    if !principal.scopes.contains(&scope) {
+        return Err(AppError::Forbidden(format!(
+            "API key lacks the {} scope",
+            scope.as_str()
+        )));
+    }
+
+    Ok(AuthenticatedUser {
+        user_id: principal.user_id,
+        role: principal.role,
+        session_id: None,
+    })
+}
+
+/// A fully signed in user or a valid API key; `None` without an Authorization header.
+async fn authenticate_user(
+    parts: &Parts,
+    state: &AppState,
+) -> Result<Option<AuthenticatedUser>, AppError> {
+    match credentials(parts)? {
+        Some(Credentials::Bearer(token)) => authenticate(token, state, &[AccessTokenScope::Full])
+            .await
+            .map(Some),
+        Some(Credentials::ApiKey(key)) => authenticate_api_key(key, parts, state).await.map(Some),
+        None => Ok(None),
+    }
+}
+
+async fn authenticate(
+    token: &str,
+    state: &AppState,
+    allowed: &[AccessTokenScope],
+) -> Result<AuthenticatedUser, AppError> {
+    let claims = state
+        .token_service
+        .verify(token)
+        .map_err(|_| AppError::Unauthorized("Invalid or expired access token".to_string()))?;
+
+    if !allowed.contains(&claims.scope) {
+        return Err(AppError::Unauthorized(match claims.scope {
+            AccessTokenScope::Full => "Access token cannot be used here".to_string(),
+            AccessTokenScope::TotpChallenge => {
+                "Two-factor authentication required, submit the code to /auth/login/totp"
+                    .to_string()
+            }
+            AccessTokenScope::TotpEnrollment => {
+                "Two-factor enrollment required, enroll via /me/totp".to_string()
+            }
+        }));
+    }
+
+    // Every full token is issued with a session; one without could never be revoked.
+    if claims.scope == AccessTokenScope::Full && claims.session_id.is_none() {
+        return Err(AppError::Unauthorized(
+            "Invalid or expired access token".to_string(),
+        ));
+    }
+    if let Some(session_id) = claims.session_id {
+        state
+            .check_session_use_case
+            .execute(claims.user_id, session_id)
+            .await?;
+    }
+
+    Ok(AuthenticatedUser {
+        user_id: claims.user_id,
+        role: claims.role,
+        session_id: claims.session_id,
+    })
+}
+
+impl FromRequestParts<AppState> for AuthenticatedUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        authenticate_user(parts, state)
+            .await?
+            .ok_or_else(|| AppError::Unauthorized("Missing bearer token".to_string()))
+    }
+}
+
+impl OptionalFromRequestParts<AppState> for AuthenticatedUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        authenticate_user(parts, state).await
+    }
+}
+
+impl FromRequestParts<AppState> for AdminUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let user =
+            <AuthenticatedUser as FromRequestParts<AppState>>::from_request_parts(parts, state)
+                .await?;
+        if user.role != UserRole::Admin {
+            return Err(AppError::Forbidden("Admin privileges required".to_string()));
+        }
+        Ok(AdminUser(user))
+    }
+}
+
+impl FromRequestParts<AppState> for TotpChallengeUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let token = bearer_token(parts)?
+            .ok_or_else(|| AppError::Unauthorized("Missing bearer token".to_string()))?;
+        authenticate(token, state, &[AccessTokenScope::TotpChallenge])
+            .await
+            .map(TotpChallengeUser)
+    }
+}
+
+impl FromRequestParts<AppState> for TotpEnrollmentUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let token = bearer_token(parts)?
+            .ok_or_else(|| AppError::Unauthorized("Missing bearer token".to_string()))?;
+        authenticate(
+            token,
+            state,
+            &[AccessTokenScope::Full, AccessTokenScope::TotpEnrollment],
+        )
+        .await
+        .map(TotpEnrollmentUser)
+    }
+}
@@ -0,0 +1,219 @@
+use application::use_cases::ClientInfo;
+use axum::{
+    body::Body,
+    extract::{ConnectInfo, FromRequestParts, State},
+    http::{HeaderMap, Request, header, request::Parts},
+    middleware::Next,
+    response::Response,
+};
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+tokio::task_local! {
+    pub(crate) static CLIENT_IP: Option<IpAddr>;
+}
+
+/// Client IP of the request being handled, for code that has no access to the request.
+pub fn current_client_ip() -> Option<IpAddr> {
+    CLIENT_IP.try_with(|ip| *ip).ok().flatten()
+}
+
+/// Address of the client that sent the request, resolved by [`client_ip_middleware`].
+/// `None` when the server runs without connect info, e.g. in router tests.
+#[derive(Debug, Clone, Copy)]
+pub struct ClientIp(pub Option<IpAddr>);
+
+/// An IP address or CIDR range such as `10.0.0.0/8`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpRange {
+    network: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpRange {
+    pub fn parse(value: &str) -> Option<Self> {
+        let (addr, prefix_len) = match value.trim().split_once('/') {
+            Some((addr, prefix)) => (addr.parse::<IpAddr>().ok()?, prefix.parse::<u8>().ok()?),
+            None => {
+                let addr = value.trim().parse::<IpAddr>().ok()?;
+                (addr, if addr.is_ipv4() { 32 } else { 128 })
+            }
+        };
+        let max_len = if addr.is_ipv4() { 32 } else { 128 };
+        (prefix_len <= max_len).then_some(Self {
+            network: addr,
+            prefix_len,
+        })
+    }
+
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match (self.network, ip) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                u32::from(network) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                u128::from(network) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TrustedProxies(Vec<IpRange>);
+
+impl TrustedProxies {
+    /// Entries that are neither an IP nor a CIDR range are skipped with a warning.
+    pub fn from_config(entries: &[String]) -> Self {
+        let ranges = entries
+            .iter()
+            .filter_map(|entry| {
+                let range = IpRange::parse(entry);
+                if range.is_none() {
+                    tracing::warn!(entry = %entry, "ignoring invalid trusted proxy");
+                }
+                range
+            })
+            .collect();
+        Self(ranges)
+    }
+
+    fn is_trusted(&self, ip: IpAddr) -> bool {
+        self.0.iter().any(|range| range.contains(ip))
+    }
+
+    /// Only a trusted peer may speak for the client. `X-Forwarded-For` is read right to
+    /// left and the first hop that is not a trusted proxy is the client; entries left of
+    /// it were supplied by the client and can be forged.
+    pub fn resolve(&self, peer: IpAddr, headers: &HeaderMap) -> IpAddr {
+        if !self.is_trusted(peer) {
+            return peer;
+        }
+
+        let hops: Vec<&str> = headers
+            .get_all(X_FORWARDED_FOR)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .collect();
+
+        for hop in hops.into_iter().rev() {
+            match hop.trim().parse::<IpAddr>() {
+                Ok(hop) if self.is_trusted(hop) => continue,
+                Ok(hop) => return hop,
+                Err(_) => break,
+            }
+        }
+        peer
+    }
+}
+
+pub async fn client_ip_middleware(
+    State(trusted_proxies): State<Arc<TrustedProxies>>,
+    mut request: Request<Body>,
+    next: Next,
+) -> Response {
+    let ip = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| trusted_proxies.resolve(addr.ip(), request.headers()));
+
+    request.extensions_mut().insert(ClientIp(ip));
+    CLIENT_IP.scope(ip, next.run(request)).await
+}
+
+impl<S> FromRequestParts<S> for ClientIp
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .extensions
+            .get::<ClientIp>()
+            .copied()
+            .unwrap_or(ClientIp(None)))
+    }
+}
+
+/// IP and user agent of the client, recorded on the session a login opens.
+#[derive(Debug, Clone)]
+pub struct ClientDetails(pub ClientInfo);
+
+impl<S> FromRequestParts<S> for ClientDetails
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let ClientIp(ip) = ClientIp::from_request_parts(parts, state).await?;
+        let user_agent = parts
+            .headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string);
+
+        Ok(ClientDetails(ClientInfo { ip, user_agent }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_ip_range_contains() {
+        let range = IpRange::parse("10.0.0.0/8").unwrap();
+        assert!(range.contains(ip("10.1.2.3")));
+        assert!(!range.contains(ip("11.0.0.1")));
+
+        let single = IpRange::parse("2001:db8::1").unwrap();
+        assert!(single.contains(ip("2001:db8::1")));
+        assert!(!single.contains(ip("2001:db8::2")));
+
+        assert!(IpRange::parse("10.0.0.0/33").is_none());
+        assert!(IpRange::parse("proxy.local").is_none());
+    }
+
+    #[test]
+    fn test_forwarded_for_is_ignored_from_untrusted_peer() {
+        let proxies = TrustedProxies::from_config(&["10.0.0.0/8".to_string()]);
+        let mut headers = HeaderMap::new();
+        headers.insert(X_FORWARDED_FOR, HeaderValue::from_static("198.51.100.1"));
+
+        assert_eq!(
+            proxies.resolve(ip("203.0.113.7"), &headers),
+            ip("203.0.113.7")
+        );
+    }
+
+    #[test]
+    fn test_forwarded_for_skips_trusted_hops() {
+        let proxies = TrustedProxies::from_config(&["10.0.0.0/8".to_string()]);
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            X_FORWARDED_FOR,
+            HeaderValue::from_static("1.1.1.1, 203.0.113.7, 10.0.0.2"),
+        );
+
+        assert_eq!(proxies.resolve(ip("10.0.0.1"), &headers), ip("203.0.113.7"));
+    }
+}
@@ -0,0 +1,54 @@
+use application::ports::{DataExport, DataExportStatus};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Clone, Copy, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DataExportStatusDto {
+    Pending,
+    Processing,
+    Ready,
+    Failed,
+    Expired,
+}
+
+impl From<DataExportStatus> for DataExportStatusDto {
+    fn from(status: DataExportStatus) -> Self {
+        match status {
+            DataExportStatus::Pending => Self::Pending,
+            DataExportStatus::Processing => Self::Processing,
+            DataExportStatus::Ready => Self::Ready,
+            DataExportStatus::Failed => Self::Failed,
+            DataExportStatus::Expired => Self::Expired,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DataExportResponse {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub status: DataExportStatusDto,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<DataExport> for DataExportResponse {
+    fn from(export: DataExport) -> Self {
+        Self {
+            id: export.id,
+            user_id: export.user_id,
+            status: export.status.into(),
+            created_at: export.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams, Validate)]
+pub struct DataExportDownloadQuery {
+    /// Token from the emailed download link.
+    #[validate(length(min = 1))]
+    pub token: String,
+}
@@ -0,0 +1,115 @@
+use crate::http::ApiResponse;
+use crate::http::AppError;
+use crate::http::auth::{AdminUser, AuthenticatedUser};
+use crate::http::data_exports::dtos::{DataExportDownloadQuery, DataExportResponse};
+use crate::http::extract::ValidatedQuery;
+use crate::http::state::AppState;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "/me/data-export",
+    tag = "data-export",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 202, description = "Export queued; a download link is emailed once it is ready", body = ApiResponse<DataExportResponse>),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = crate::http::ApiErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds until the next request is allowed"))),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn request_own_data_export(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+) -> Result<impl IntoResponse, AppError> {
+    let export = state
+        .request_data_export_use_case
+        .execute(user.user_id, user.user_id)
+        .await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(ApiResponse::success(DataExportResponse::from(export))),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/users/{id}/data-export",
+    tag = "data-export",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 202, description = "Export queued; the download link is emailed to the user, not the admin", body = ApiResponse<DataExportResponse>),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::http::ApiErrorResponse),
+        (status = 404, description = "User not found", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn request_data_export(
+    State(state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let export = state
+        .request_data_export_use_case
+        .execute(id, admin.user_id)
+        .await?;
+    tracing::info!(admin_id = %admin.user_id, user_id = %id, "data export requested");
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(ApiResponse::success(DataExportResponse::from(export))),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/data-export/download",
+    tag = "data-export",
+    params(DataExportDownloadQuery),
+    responses(
+        (status = 200, description = "ZIP archive of JSON files", content_type = "application/zip", body = Vec<u8>),
+        (status = 400, description = "Missing, invalid or expired token", body = crate::http::ApiErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = crate::http::ApiErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds until the next request is allowed"))),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn download_data_export(
+    State(state): State<AppState>,
+    ValidatedQuery(query): ValidatedQuery<DataExportDownloadQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let download = state
+        .download_data_export_use_case
+        .execute(&query.token)
+        .await?;
+
+    let filename = format!(
+        "data-export-{}.zip",
+        download.export.created_at.format("%Y-%m-%d")
+    );
+
+    Ok((
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, "application/zip".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            ),
+            (header::CACHE_CONTROL, "no-store".to_string()),
+        ],
+        download.archive,
+    ))
+}
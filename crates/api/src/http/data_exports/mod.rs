@@ -0,0 +1,18 @@
+pub mod dtos;
+pub mod handlers;
+
+use crate::http::state::AppState;
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/me/data-export", post(handlers::request_own_data_export))
+        .route(
+            "/admin/users/{id}/data-export",
+            post(handlers::request_data_export),
+        )
+        .route("/data-export/download", get(handlers::download_data_export))
+}
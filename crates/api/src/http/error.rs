@@ -1,7 +1,11 @@
-use crate::http::middleware::get_correlation_id;
+use crate::http::middleware::{
+    ErrorFormat, Locale, PROBLEM_JSON, get_correlation_id, get_error_format, get_request_locale,
+};
+use crate::http::validation::FieldViolation;
+use application::ErrorCode;
 use axum::{
     Json,
-    http::StatusCode,
+    http::{HeaderValue, StatusCode, header},
     response::{IntoResponse, Response},
 };
 use serde::Serialize;
@@ -14,51 +18,242 @@ pub struct ApiErrorResponse {
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ApiErrorDetail {
+    /// Stable machine-readable error code, see [`ErrorCode`].
+    #[schema(schema_with = error_code_schema)]
+    pub code: String,
+    pub message: String,
+    /// Present for validation failures: one entry per violated rule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<Vec<ApiFieldError>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ApiFieldError {
+    /// Dotted path of the offending field, e.g. `email` or `children[0].first_name`.
+    #[schema(example = "email")]
+    pub field: String,
+    /// Machine-readable rule identifier, e.g. `required`, `length`, `email`.
+    #[schema(example = "email")]
     pub code: String,
+    /// Human-readable message in the language negotiated via `Accept-Language`.
+    #[schema(example = "Must be a valid email address.")]
     pub message: String,
+}
+
+/// RFC 9457 problem details, sent instead of [`ApiErrorResponse`] when the client
+/// asks for `application/problem+json`.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProblemDetails {
+    /// Always `about:blank`: the status code carries the semantics, `code` the specifics.
+    #[serde(rename = "type")]
+    #[schema(example = "about:blank")]
+    pub problem_type: String,
+    #[schema(example = "Not Found")]
+    pub title: String,
+    #[schema(example = 404)]
+    pub status: u16,
+    pub detail: String,
+    /// Path of the request that failed.
+    #[schema(example = "/users/0f9b2c4e-8d1a-4c7e-9f3b-2a6d5e8c1b7f")]
+    pub instance: String,
+    /// Extension member: the same machine-readable code as in the envelope.
+    #[schema(schema_with = error_code_schema)]
+    pub code: String,
+    /// Extension member: field-level violations for validation failures.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<ApiFieldError>>,
+    /// Extension member: correlation id of the request, also sent as `x-correlation-id`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub correlation_id: Option<String>,
 }
 
+/// Documents the closed set of codes so clients can generate exhaustive matches.
+fn error_code_schema() -> utoipa::openapi::Object {
+    utoipa::openapi::ObjectBuilder::new()
+        .schema_type(utoipa::openapi::schema::Type::String)
+        .enum_values(Some(ErrorCode::ALL.iter().map(ErrorCode::as_str)))
+        .examples([serde_json::json!("USER_NOT_FOUND")])
+        .build()
+}
+
+/// HTTP status for each error code; the code carries the specifics.
+pub fn status_for(code: ErrorCode) -> StatusCode {
+    match code {
+        ErrorCode::BadRequest
+        | ErrorCode::ValidationFailed
+        | ErrorCode::InvalidToken
+        | ErrorCode::ExpiredToken
+        | ErrorCode::PasskeyChallengeInvalid
+        | ErrorCode::PasskeyRejected
+        | ErrorCode::OidcStateInvalid => StatusCode::BAD_REQUEST,
+        ErrorCode::Unauthorized
+        | ErrorCode::InvalidCredentials
+        | ErrorCode::InvalidTwoFactorCode
+        | ErrorCode::OidcIdentityRejected => StatusCode::UNAUTHORIZED,
+        ErrorCode::Forbidden => StatusCode::FORBIDDEN,
+        ErrorCode::NotFound | ErrorCode::UserNotFound => StatusCode::NOT_FOUND,
+        ErrorCode::EmailTaken | ErrorCode::Conflict | ErrorCode::ConcurrentModification => {
+            StatusCode::CONFLICT
+        }
+        ErrorCode::PreconditionFailed => StatusCode::PRECONDITION_FAILED,
+        ErrorCode::UnsupportedMediaType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        ErrorCode::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
+        ErrorCode::AccountLocked => StatusCode::LOCKED,
+        ErrorCode::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR,
+        ErrorCode::UpstreamUnavailable => StatusCode::BAD_GATEWAY,
+        ErrorCode::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+    }
+}
+
 #[derive(Debug)]
 #[allow(dead_code)]
 pub enum AppError {
+    /// An application error with its specific code, e.g. `EMAIL_TAKEN`.
+    Application(ErrorCode, String),
     BadRequest(String),
+    Validation(Vec<FieldViolation>),
+    Unauthorized(String),
+    Forbidden(String),
+    /// Rejected until `retry_after_seconds` have passed; sent as `Retry-After`.
+    Throttled {
+        code: ErrorCode,
+        message: String,
+        retry_after_seconds: u64,
+    },
     NotFound(String),
     Conflict(String),
+    PreconditionFailed(String),
+    UnsupportedMediaType(String),
+    PayloadTooLarge(String),
     Internal(String),
 }
 
+fn validation_message(locale: Locale) -> String {
+    match locale {
+        Locale::En => "Request validation failed".to_string(),
+        Locale::De => "Die Anfrage ist ungültig".to_string(),
+    }
+}
+
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        let (status, code, message) = match self {
-            AppError::BadRequest(msg) => (StatusCode::BAD_REQUEST, "BAD_REQUEST", msg),
-            AppError::NotFound(msg) => (StatusCode::NOT_FOUND, "NOT_FOUND", msg),
-            AppError::Conflict(msg) => (StatusCode::CONFLICT, "CONFLICT", msg),
-            AppError::Internal(msg) => (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "INTERNAL_SERVER_ERROR",
-                msg,
-            ),
+        let locale = get_request_locale();
+        let mut details = None;
+        let mut retry_after = None;
+        let (code, message) = match self {
+            AppError::Application(code, msg) => (code, msg),
+            AppError::BadRequest(msg) => (ErrorCode::BadRequest, msg),
+            AppError::Validation(violations) => {
+                details = Some(
+                    violations
+                        .iter()
+                        .map(|v| ApiFieldError {
+                            field: v.field.clone(),
+                            code: v.code.clone(),
+                            message: v.message(locale),
+                        })
+                        .collect(),
+                );
+                (ErrorCode::ValidationFailed, validation_message(locale))
+            }
+            AppError::Unauthorized(msg) => (ErrorCode::Unauthorized, msg),
+            AppError::Forbidden(msg) => (ErrorCode::Forbidden, msg),
+            AppError::Throttled {
+                code,
+                message,
+                retry_after_seconds,
+            } => {
+                retry_after = Some(retry_after_seconds);
+                (code, message)
+            }
+            AppError::NotFound(msg) => (ErrorCode::NotFound, msg),
+            AppError::Conflict(msg) => (ErrorCode::Conflict, msg),
+            AppError::PreconditionFailed(msg) => (ErrorCode::PreconditionFailed, msg),
+            AppError::UnsupportedMediaType(msg) => (ErrorCode::UnsupportedMediaType, msg),
+            AppError::PayloadTooLarge(msg) => (ErrorCode::PayloadTooLarge, msg),
+            AppError::Internal(msg) => (ErrorCode::InternalServerError, msg),
         };
+        let status = status_for(code);
 
-        let body = Json(ApiErrorResponse {
-            error: ApiErrorDetail {
-                code: code.to_string(),
-                message,
-                correlation_id: get_correlation_id(),
-            },
-        });
+        let correlation_id = get_correlation_id();
+        let mut response = match get_error_format() {
+            ErrorFormat::Envelope => (
+                status,
+                Json(ApiErrorResponse {
+                    error: ApiErrorDetail {
+                        code: code.to_string(),
+                        message,
+                        details,
+                        correlation_id,
+                    },
+                }),
+            )
+                .into_response(),
+            ErrorFormat::Problem { instance } => {
+                let problem = ProblemDetails {
+                    problem_type: "about:blank".to_string(),
+                    title: status.canonical_reason().unwrap_or("Error").to_string(),
+                    status: status.as_u16(),
+                    detail: message,
+                    instance,
+                    code: code.to_string(),
+                    errors: details,
+                    correlation_id,
+                };
+                (
+                    status,
+                    [(header::CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON))],
+                    Json(problem),
+                )
+                    .into_response()
+            }
+        };
 
-        (status, body).into_response()
+        if status == StatusCode::UNAUTHORIZED {
+            response
+                .headers_mut()
+                .insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+        }
+        if let Some(seconds) = retry_after {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(seconds));
+        }
+        response
     }
 }
 
+impl From<validator::ValidationErrors> for AppError {
+    fn from(errors: validator::ValidationErrors) -> Self {
+        Self::Validation(crate::http::validation::violations_from(&errors))
+    }
+}
+
+fn field_violations(errors: Vec<application::use_cases::FieldError>) -> Vec<FieldViolation> {
+    errors
+        .into_iter()
+        .map(|e| {
+            let mut violation =
+                FieldViolation::new(e.field, e.code).with_fallback_message(e.message);
+            for (name, value) in e.params {
+                violation = violation.with_param(&name, value);
+            }
+            violation
+        })
+        .collect()
+}
+
 impl From<application::use_cases::CreateUserError> for AppError {
     fn from(err: application::use_cases::CreateUserError) -> Self {
+        let code = err.code();
         match err {
+            application::use_cases::CreateUserError::Validation(errors) => {
+                Self::Validation(field_violations(errors))
+            }
             application::use_cases::CreateUserError::AlreadyExists(email) => {
-                Self::Conflict(format!("User with email {} already exists", email))
+                Self::Application(code, format!("User with email {} already exists", email))
             }
             application::use_cases::CreateUserError::RepositoryError(msg) => {
                 tracing::error!(error = %msg, "Repository error");
@@ -85,9 +280,10 @@ impl From<application::use_cases::GetUserError> for AppError {
 
 impl From<application::use_cases::DeleteUserError> for AppError {
     fn from(err: application::use_cases::DeleteUserError) -> Self {
+        let code = err.code();
         match err {
             application::use_cases::DeleteUserError::NotFound(id) => {
-                Self::NotFound(format!("User with ID {} not found", id))
+                Self::Application(code, format!("User with ID {} not found", id))
             }
             application::use_cases::DeleteUserError::RepositoryError(msg) => {
                 tracing::error!(error = %msg, "Repository error");
@@ -110,9 +306,13 @@ impl From<application::use_cases::ListUsersError> for AppError {
 
 impl From<application::use_cases::SignupError> for AppError {
     fn from(err: application::use_cases::SignupError) -> Self {
+        let code = err.code();
         match err {
+            application::use_cases::SignupError::Validation(errors) => {
+                Self::Validation(field_violations(errors))
+            }
             application::use_cases::SignupError::AlreadyExists(email) => {
-                Self::Conflict(format!("User with email {} already exists", email))
+                Self::Application(code, format!("User with email {} already exists", email))
             }
             application::use_cases::SignupError::RepositoryError(msg) => {
                 tracing::error!(error = %msg, "Repository error");
@@ -132,12 +332,13 @@ impl From<application::use_cases::SignupError> for AppError {
 
 impl From<application::use_cases::VerifyEmailError> for AppError {
     fn from(err: application::use_cases::VerifyEmailError) -> Self {
+        let code = err.code();
         match err {
             application::use_cases::VerifyEmailError::InvalidToken => {
-                Self::BadRequest("Invalid or expired token".to_string())
+                Self::Application(code, "Invalid or expired token".to_string())
             }
             application::use_cases::VerifyEmailError::UserNotFound => {
-                Self::NotFound("User not found".to_string())
+                Self::Application(code, "User not found".to_string())
             }
             application::use_cases::VerifyEmailError::RepositoryError(msg) => {
                 tracing::error!(error = %msg, "Repository error");
@@ -149,9 +350,10 @@ impl From<application::use_cases::VerifyEmailError> for AppError {
 
 impl From<application::use_cases::RequestPasswordResetError> for AppError {
     fn from(err: application::use_cases::RequestPasswordResetError) -> Self {
+        let code = err.code();
         match err {
             application::use_cases::RequestPasswordResetError::UserNotFound => {
-                Self::NotFound("User not found".to_string())
+                Self::Application(code, "User not found".to_string())
             }
             application::use_cases::RequestPasswordResetError::RepositoryError(msg) => {
                 tracing::error!(error = %msg, "Repository error");
@@ -167,15 +369,16 @@ impl From<application::use_cases::RequestPasswordResetError> for AppError {
 
 impl From<application::use_cases::ResetPasswordError> for AppError {
     fn from(err: application::use_cases::ResetPasswordError) -> Self {
+        let code = err.code();
         match err {
             application::use_cases::ResetPasswordError::InvalidToken => {
-                Self::BadRequest("Invalid token".to_string())
+                Self::Application(code, "Invalid token".to_string())
             }
             application::use_cases::ResetPasswordError::ExpiredToken => {
-                Self::BadRequest("Expired token".to_string())
+                Self::Application(code, "Expired token".to_string())
             }
             application::use_cases::ResetPasswordError::UserNotFound => {
-                Self::NotFound("User not found".to_string())
+                Self::Application(code, "User not found".to_string())
             }
             application::use_cases::ResetPasswordError::RepositoryError(msg) => {
                 tracing::error!(error = %msg, "Repository error");
@@ -188,3 +391,689 @@ impl From<application::use_cases::ResetPasswordError> for AppError {
         }
     }
 }
+
+impl From<application::use_cases::UpdateUserProfileError> for AppError {
+    fn from(err: application::use_cases::UpdateUserProfileError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::UpdateUserProfileError::NotFound(id) => {
+                Self::Application(code, format!("User with ID {} not found", id))
+            }
+            application::use_cases::UpdateUserProfileError::Validation(errors) => {
+                Self::Validation(field_violations(errors))
+            }
+            application::use_cases::UpdateUserProfileError::PreconditionFailed {
+                current_version,
+            } => Self::Application(
+                code,
+                format!(
+                    "User has been modified, current ETag is \"{}\"",
+                    current_version
+                ),
+            ),
+            application::use_cases::UpdateUserProfileError::Conflict => Self::Application(
+                code,
+                "User was modified concurrently, please retry".to_string(),
+            ),
+            application::use_cases::UpdateUserProfileError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::LoginError> for AppError {
+    fn from(err: application::use_cases::LoginError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::LoginError::InvalidCredentials => {
+                Self::Application(code, "Invalid email or password".to_string())
+            }
+            application::use_cases::LoginError::InvalidTwoFactorCode => {
+                Self::Application(code, "Invalid authentication code".to_string())
+            }
+            application::use_cases::LoginError::TooManyAttempts {
+                retry_after_seconds,
+            }
+            | application::use_cases::LoginError::AccountLocked {
+                retry_after_seconds,
+            } => Self::Throttled {
+                code,
+                message: err.to_string(),
+                retry_after_seconds,
+            },
+            application::use_cases::LoginError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+            application::use_cases::LoginError::InternalError(msg) => {
+                tracing::error!(error = %msg, "Internal error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::EnrollTotpError> for AppError {
+    fn from(err: application::use_cases::EnrollTotpError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::EnrollTotpError::UserNotFound
+            | application::use_cases::EnrollTotpError::AlreadyEnrolled => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::EnrollTotpError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+            application::use_cases::EnrollTotpError::InternalError(msg) => {
+                tracing::error!(error = %msg, "Internal error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::ConfirmTotpError> for AppError {
+    fn from(err: application::use_cases::ConfirmTotpError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::ConfirmTotpError::UserNotFound
+            | application::use_cases::ConfirmTotpError::NoPendingEnrollment
+            | application::use_cases::ConfirmTotpError::AlreadyEnrolled
+            | application::use_cases::ConfirmTotpError::InvalidCode => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::ConfirmTotpError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+            application::use_cases::ConfirmTotpError::InternalError(msg) => {
+                tracing::error!(error = %msg, "Internal error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::StartPasskeyRegistrationError> for AppError {
+    fn from(err: application::use_cases::StartPasskeyRegistrationError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::StartPasskeyRegistrationError::UserNotFound => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::StartPasskeyRegistrationError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::FinishPasskeyRegistrationError> for AppError {
+    fn from(err: application::use_cases::FinishPasskeyRegistrationError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::FinishPasskeyRegistrationError::InvalidCeremony
+            | application::use_cases::FinishPasskeyRegistrationError::AlreadyRegistered => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::FinishPasskeyRegistrationError::Rejected(reason) => {
+                tracing::warn!(reason = %reason, "passkey registration rejected");
+                Self::Application(code, "Passkey could not be verified".to_string())
+            }
+            application::use_cases::FinishPasskeyRegistrationError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::ListPasskeysError> for AppError {
+    fn from(err: application::use_cases::ListPasskeysError) -> Self {
+        match err {
+            application::use_cases::ListPasskeysError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::DeletePasskeyError> for AppError {
+    fn from(err: application::use_cases::DeletePasskeyError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::DeletePasskeyError::NotFound => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::DeletePasskeyError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::CheckSessionError> for AppError {
+    fn from(err: application::use_cases::CheckSessionError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::CheckSessionError::Revoked => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::CheckSessionError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::ListSessionsError> for AppError {
+    fn from(err: application::use_cases::ListSessionsError) -> Self {
+        match err {
+            application::use_cases::ListSessionsError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::RevokeSessionError> for AppError {
+    fn from(err: application::use_cases::RevokeSessionError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::RevokeSessionError::NotFound => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::RevokeSessionError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::RevokeAllSessionsError> for AppError {
+    fn from(err: application::use_cases::RevokeAllSessionsError) -> Self {
+        match err {
+            application::use_cases::RevokeAllSessionsError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::CreateApiKeyError> for AppError {
+    fn from(err: application::use_cases::CreateApiKeyError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::CreateApiKeyError::UserNotFound
+            | application::use_cases::CreateApiKeyError::NotAllowed
+            | application::use_cases::CreateApiKeyError::NoScopes => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::CreateApiKeyError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::AuthenticateApiKeyError> for AppError {
+    fn from(err: application::use_cases::AuthenticateApiKeyError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::AuthenticateApiKeyError::InvalidKey => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::AuthenticateApiKeyError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::ListApiKeysError> for AppError {
+    fn from(err: application::use_cases::ListApiKeysError) -> Self {
+        match err {
+            application::use_cases::ListApiKeysError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::DeleteApiKeyError> for AppError {
+    fn from(err: application::use_cases::DeleteApiKeyError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::DeleteApiKeyError::NotFound => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::DeleteApiKeyError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::ListAuditLogError> for AppError {
+    fn from(err: application::use_cases::ListAuditLogError) -> Self {
+        match err {
+            application::use_cases::ListAuditLogError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::ChangeUserRoleError> for AppError {
+    fn from(err: application::use_cases::ChangeUserRoleError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::ChangeUserRoleError::NotFound(_)
+            | application::use_cases::ChangeUserRoleError::OwnRole => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::ChangeUserRoleError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::RequestDataExportError> for AppError {
+    fn from(err: application::use_cases::RequestDataExportError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::RequestDataExportError::NotFound(_) => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::RequestDataExportError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::DownloadDataExportError> for AppError {
+    fn from(err: application::use_cases::DownloadDataExportError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::DownloadDataExportError::InvalidToken
+            | application::use_cases::DownloadDataExportError::ExpiredToken => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::DownloadDataExportError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::RestoreAccountError> for AppError {
+    fn from(err: application::use_cases::RestoreAccountError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::RestoreAccountError::InvalidToken
+            | application::use_cases::RestoreAccountError::ExpiredToken
+            | application::use_cases::RestoreAccountError::EmailTaken(_) => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::RestoreAccountError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::RestoreUserError> for AppError {
+    fn from(err: application::use_cases::RestoreUserError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::RestoreUserError::NotFound(_)
+            | application::use_cases::RestoreUserError::EmailTaken(_) => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::RestoreUserError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::CreateWebhookSubscriptionError> for AppError {
+    fn from(err: application::use_cases::CreateWebhookSubscriptionError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::CreateWebhookSubscriptionError::InvalidUrl
+            | application::use_cases::CreateWebhookSubscriptionError::NoEvents
+            | application::use_cases::CreateWebhookSubscriptionError::UnknownEvent(_) => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::CreateWebhookSubscriptionError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::ListWebhookSubscriptionsError> for AppError {
+    fn from(err: application::use_cases::ListWebhookSubscriptionsError) -> Self {
+        match err {
+            application::use_cases::ListWebhookSubscriptionsError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::DeleteWebhookSubscriptionError> for AppError {
+    fn from(err: application::use_cases::DeleteWebhookSubscriptionError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::DeleteWebhookSubscriptionError::NotFound => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::DeleteWebhookSubscriptionError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::ListWebhookDeliveriesError> for AppError {
+    fn from(err: application::use_cases::ListWebhookDeliveriesError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::ListWebhookDeliveriesError::NotFound => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::ListWebhookDeliveriesError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::RedeliverWebhookError> for AppError {
+    fn from(err: application::use_cases::RedeliverWebhookError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::RedeliverWebhookError::NotFound => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::RedeliverWebhookError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::StartPasskeyLoginError> for AppError {
+    fn from(err: application::use_cases::StartPasskeyLoginError) -> Self {
+        match err {
+            application::use_cases::StartPasskeyLoginError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::PasskeyLoginError> for AppError {
+    fn from(err: application::use_cases::PasskeyLoginError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::PasskeyLoginError::InvalidCeremony
+            | application::use_cases::PasskeyLoginError::InvalidCredentials => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::PasskeyLoginError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+            application::use_cases::PasskeyLoginError::InternalError(msg) => {
+                tracing::error!(error = %msg, "Internal error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::RequestMagicLinkError> for AppError {
+    fn from(err: application::use_cases::RequestMagicLinkError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::RequestMagicLinkError::UserNotFound
+            | application::use_cases::RequestMagicLinkError::EmailNotVerified => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::RequestMagicLinkError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+            application::use_cases::RequestMagicLinkError::EmailError(msg) => {
+                tracing::error!(error = %msg, "Email error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::MagicLinkLoginError> for AppError {
+    fn from(err: application::use_cases::MagicLinkLoginError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::MagicLinkLoginError::InvalidToken
+            | application::use_cases::MagicLinkLoginError::ExpiredToken => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::MagicLinkLoginError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+            application::use_cases::MagicLinkLoginError::InternalError(msg) => {
+                tracing::error!(error = %msg, "Internal error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::StartOidcLoginError> for AppError {
+    fn from(err: application::use_cases::StartOidcLoginError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::StartOidcLoginError::UnknownProvider => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::StartOidcLoginError::ProviderUnavailable(msg) => {
+                tracing::error!(error = %msg, "Identity provider unavailable");
+                Self::Application(code, "Identity provider unavailable".to_string())
+            }
+            application::use_cases::StartOidcLoginError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::CompleteOidcLoginError> for AppError {
+    fn from(err: application::use_cases::CompleteOidcLoginError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::CompleteOidcLoginError::InvalidState
+            | application::use_cases::CompleteOidcLoginError::UnknownProvider
+            | application::use_cases::CompleteOidcLoginError::IdentityRejected
+            | application::use_cases::CompleteOidcLoginError::EmailNotVerified => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::CompleteOidcLoginError::ProviderUnavailable(msg) => {
+                tracing::error!(error = %msg, "Identity provider unavailable");
+                Self::Application(code, "Identity provider unavailable".to_string())
+            }
+            application::use_cases::CompleteOidcLoginError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+            application::use_cases::CompleteOidcLoginError::InternalError(msg) => {
+                tracing::error!(error = %msg, "Internal error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::ConfirmIdentityLinkError> for AppError {
+    fn from(err: application::use_cases::ConfirmIdentityLinkError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::ConfirmIdentityLinkError::InvalidToken
+            | application::use_cases::ConfirmIdentityLinkError::AlreadyLinked => {
+                Self::Application(code, err.to_string())
+            }
+            application::use_cases::ConfirmIdentityLinkError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::ListLinkedIdentitiesError> for AppError {
+    fn from(err: application::use_cases::ListLinkedIdentitiesError) -> Self {
+        match err {
+            application::use_cases::ListLinkedIdentitiesError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::UnlockAccountError> for AppError {
+    fn from(err: application::use_cases::UnlockAccountError) -> Self {
+        let code = err.code();
+        match err {
+            application::use_cases::UnlockAccountError::InvalidToken => {
+                Self::Application(code, "Invalid or already used unlock token".to_string())
+            }
+            application::use_cases::UnlockAccountError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::ListLoginLockoutsError> for AppError {
+    fn from(err: application::use_cases::ListLoginLockoutsError) -> Self {
+        match err {
+            application::use_cases::ListLoginLockoutsError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<application::use_cases::ClearLoginLockoutError> for AppError {
+    fn from(err: application::use_cases::ClearLoginLockoutError) -> Self {
+        match err {
+            application::use_cases::ClearLoginLockoutError::NotFound => {
+                Self::NotFound("No failed login attempts recorded".to_string())
+            }
+            application::use_cases::ClearLoginLockoutError::RepositoryError(msg) => {
+                tracing::error!(error = %msg, "Repository error");
+                Self::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::middleware::ERROR_FORMAT;
+    use axum::body::to_bytes;
+
+    async fn body_json(response: Response) -> serde_json::Value {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_envelope_is_the_default() {
+        let response = AppError::NotFound("User not found".to_string()).into_response();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let body = body_json(response).await;
+        assert_eq!(body["error"]["code"], "NOT_FOUND");
+        assert_eq!(body["error"]["message"], "User not found");
+    }
+
+    #[tokio::test]
+    async fn test_problem_details_when_requested() {
+        let format = ErrorFormat::Problem {
+            instance: "/users/42".to_string(),
+        };
+        let response = ERROR_FORMAT.sync_scope(format, || {
+            AppError::NotFound("User not found".to_string()).into_response()
+        });
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], PROBLEM_JSON);
+        let body = body_json(response).await;
+        assert_eq!(body["type"], "about:blank");
+        assert_eq!(body["title"], "Not Found");
+        assert_eq!(body["status"], 404);
+        assert_eq!(body["detail"], "User not found");
+        assert_eq!(body["instance"], "/users/42");
+        assert_eq!(body["code"], "NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn test_application_error_keeps_specific_code() {
+        let err: AppError = application::use_cases::ResetPasswordError::ExpiredToken.into();
+        let response = err.into_response();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = body_json(response).await;
+        assert_eq!(body["error"]["code"], "EXPIRED_TOKEN");
+    }
+
+    #[test]
+    fn test_openapi_documents_every_error_code() {
+        let doc =
+            serde_json::to_value(<crate::http::ApiDoc as utoipa::OpenApi>::openapi()).unwrap();
+
+        let documented =
+            &doc["components"]["schemas"]["ApiErrorDetail"]["properties"]["code"]["enum"];
+        let expected: Vec<&str> = ErrorCode::ALL.iter().map(ErrorCode::as_str).collect();
+        assert_eq!(documented, &serde_json::json!(expected));
+    }
+}
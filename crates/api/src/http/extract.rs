@@ -0,0 +1,243 @@
+use crate::http::AppError;
+use crate::http::validation::FieldViolation;
+use axum::body::Bytes;
+use axum::extract::{FromRequest, FromRequestParts, Request};
+use axum::http::{HeaderMap, StatusCode, header, request::Parts};
+use serde::de::DeserializeOwned;
+use validator::Validate;
+
+/// Drop-in replacement for `axum::Json` whose rejections use our error envelope
+/// instead of axum's plain-text bodies.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Json<T>(pub T);
+
+/// Like [`Json`], additionally running `validator` rules on the payload.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedJson<T>(pub T);
+
+/// Query string extractor with envelope rejections that runs `validator` rules.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedQuery<T>(pub T);
+
+fn is_json_content_type(headers: &HeaderMap) -> bool {
+    let Some(content_type) = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+    else {
+        return false;
+    };
+
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    mime == "application/json" || (mime.starts_with("application/") && mime.ends_with("+json"))
+}
+
+/// serde_json appends " at line X column Y" to its messages, which is noise for API clients.
+fn strip_position(message: &str) -> &str {
+    message
+        .rfind(" at line ")
+        .map_or(message, |index| &message[..index])
+}
+
+/// Extracts the field name from serde messages such as "missing field `email`".
+fn quoted_name(message: &str) -> Option<&str> {
+    let start = message.find('`')? + 1;
+    let len = message[start..].find('`')?;
+    Some(&message[start..start + len])
+}
+
+/// Maps a serde message to a violation code; the rejected value is never echoed back.
+fn classify(path: &str, message: &str) -> FieldViolation {
+    let fallback = strip_position(message).to_string();
+    let violation = if message.starts_with("missing field") {
+        let field = quoted_name(message).unwrap_or_default();
+        FieldViolation::new(crate::http::validation::join_path(path, field), "required")
+    } else if message.starts_with("unknown field") {
+        // The path already ends at the offending key here, unlike for missing fields.
+        FieldViolation::new(path, "unknown_field")
+    } else if message.starts_with("invalid type") {
+        FieldViolation::new(path, "invalid_type")
+    } else {
+        FieldViolation::new(path, "invalid_value")
+    };
+    violation.with_fallback_message(fallback)
+}
+
+/// `serde_path_to_error` renders the root as ".", which we present as an empty path.
+fn field_path(path: &serde_path_to_error::Path) -> String {
+    let path = path.to_string();
+    if path == "." { String::new() } else { path }
+}
+
+fn parse_json<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, AppError> {
+    let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+    let value = serde_path_to_error::deserialize(&mut deserializer).map_err(|err| {
+        let path = field_path(err.path());
+        let inner = err.into_inner();
+        let violation = match inner.classify() {
+            serde_json::error::Category::Data => classify(&path, &inner.to_string()),
+            _ => FieldViolation::new(path, "json_syntax")
+                .with_fallback_message(strip_position(&inner.to_string())),
+        };
+        AppError::Validation(vec![violation])
+    })?;
+
+    deserializer.end().map_err(|err| {
+        AppError::Validation(vec![
+            FieldViolation::new("", "json_syntax")
+                .with_fallback_message(strip_position(&err.to_string())),
+        ])
+    })?;
+
+    Ok(value)
+}
+
+fn parse_query<T: DeserializeOwned>(query: &str) -> Result<T, AppError> {
+    let deserializer =
+        serde_urlencoded::Deserializer::new(form_urlencoded::parse(query.as_bytes()));
+    serde_path_to_error::deserialize(deserializer).map_err(|err| {
+        let path = field_path(err.path());
+        AppError::Validation(vec![classify(&path, &err.into_inner().to_string())])
+    })
+}
+
+impl<T, S> FromRequest<S> for Json<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        if !is_json_content_type(req.headers()) {
+            return Err(AppError::UnsupportedMediaType(
+                "Expected request with `Content-Type: application/json`".to_string(),
+            ));
+        }
+
+        let bytes = Bytes::from_request(req, state)
+            .await
+            .map_err(|e| match e.status() {
+                StatusCode::PAYLOAD_TOO_LARGE => AppError::PayloadTooLarge(e.body_text()),
+                _ => AppError::BadRequest(e.body_text()),
+            })?;
+
+        parse_json(&bytes).map(Json)
+    }
+}
+
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, state).await?;
+        value.validate()?;
+        Ok(ValidatedJson(value))
+    }
+}
+
+impl<T, S> FromRequestParts<S> for ValidatedQuery<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let value: T = parse_query(parts.uri.query().unwrap_or_default())?;
+        value.validate()?;
+        Ok(ValidatedQuery(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[allow(dead_code)]
+    struct Payload {
+        email: String,
+        age: Option<u32>,
+    }
+
+    fn violations(err: AppError) -> Vec<FieldViolation> {
+        match err {
+            AppError::Validation(violations) => violations,
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_missing_field_is_reported_as_required() {
+        let err = parse_json::<Payload>(br#"{"age": 3}"#).unwrap_err();
+
+        let violations = violations(err);
+        assert_eq!(violations[0].field, "email");
+        assert_eq!(violations[0].code, "required");
+    }
+
+    #[test]
+    fn test_wrong_type_reports_field_path() {
+        let err = parse_json::<Payload>(br#"{"email": "a@b.c", "age": "three"}"#).unwrap_err();
+
+        let violations = violations(err);
+        assert_eq!(violations[0].field, "age");
+        assert_eq!(violations[0].code, "invalid_type");
+    }
+
+    #[test]
+    fn test_unknown_field_and_syntax_errors() {
+        let unknown = violations(parse_json::<Payload>(br#"{"email": "a", "x": 1}"#).unwrap_err());
+        assert_eq!(unknown[0].field, "x");
+        assert_eq!(unknown[0].code, "unknown_field");
+
+        let syntax = violations(parse_json::<Payload>(br#"{"email": "#).unwrap_err());
+        assert_eq!(syntax[0].code, "json_syntax");
+    }
+
+    #[tokio::test]
+    async fn test_oversized_body_is_rejected_in_the_envelope() {
+        use axum::extract::DefaultBodyLimit;
+        use axum::{Router, body::Body, routing::post};
+        use tower::ServiceExt;
+
+        let app = Router::new()
+            .route("/", post(|Json(_): Json<serde_json::Value>| async { "ok" }))
+            .layer(DefaultBodyLimit::max(16));
+        let request = axum::http::Request::builder()
+            .method("POST")
+            .uri("/")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"email": "someone@example.com"}"#))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["error"]["code"], "PAYLOAD_TOO_LARGE");
+    }
+
+    #[test]
+    fn test_query_type_errors() {
+        let err = parse_query::<Payload>("email=a%40b.c&age=abc").unwrap_err();
+
+        let violations = violations(err);
+        assert_eq!(violations[0].field, "age");
+        assert_eq!(violations[0].code, "invalid_value");
+    }
+}
@@ -1,5 +1,10 @@
+mod readiness;
+
+pub use readiness::{CheckResult, CheckStatus, Readiness, ReadinessResponse, ReadinessStatus};
+
 use crate::http::ApiResponse;
 use crate::http::state::AppState;
+use axum::http::StatusCode;
 use axum::routing::get;
 use axum::{Json, Router, extract::State};
 use sea_orm::ConnectionTrait;
@@ -39,6 +44,55 @@ async fn health(State(state): State<AppState>) -> Json<ApiResponse<HealthRespons
     }))
 }
 
+#[derive(Serialize, ToSchema)]
+pub struct LivenessResponse {
+    status: &'static str,
+}
+
+/// Answers as long as the process can serve requests; restarting it is the only
+/// remedy when this fails, so it checks no dependencies.
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "health",
+    responses(
+        (status = 200, description = "The process is running", body = ApiResponse<LivenessResponse>)
+    )
+)]
+async fn live() -> Json<ApiResponse<LivenessResponse>> {
+    Json(ApiResponse::success(LivenessResponse { status: "ok" }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "All dependencies are available", body = ApiResponse<ReadinessResponse>),
+        (status = 503, description = "At least one check failed; `success` is false", body = ApiResponse<ReadinessResponse>)
+    )
+)]
+async fn ready(
+    State(state): State<AppState>,
+) -> (StatusCode, Json<ApiResponse<ReadinessResponse>>) {
+    let report = state.readiness.check().await;
+    let status = if report.is_ready() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (
+        status,
+        Json(ApiResponse {
+            success: report.is_ready(),
+            data: report,
+        }),
+    )
+}
+
 pub fn router() -> Router<AppState> {
-    Router::new().route("/health", get(health))
+    Router::new()
+        .route("/health", get(health))
+        .route("/health/live", get(live))
+        .route("/health/ready", get(ready))
 }
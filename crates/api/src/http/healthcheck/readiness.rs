@@ -0,0 +1,281 @@
+use migration::{Alias, Migrator, MigratorTrait, Query};
+use sea_orm::{ConnectionTrait, DatabaseConnection, Statement};
+use serde::Serialize;
+use shared::config::{MailtrapSettings, Settings};
+use std::collections::HashSet;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A check that takes longer than this counts as failed, so a hanging dependency
+/// cannot stall the probe.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub latency_ms: u64,
+    /// Why the check failed; causes that may reveal internals are only logged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadinessStatus {
+    Ready,
+    NotReady,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    pub status: ReadinessStatus,
+    pub checks: Vec<CheckResult>,
+}
+
+impl ReadinessResponse {
+    fn from_checks(checks: Vec<CheckResult>) -> Self {
+        let status = if checks.iter().all(|check| check.status == CheckStatus::Up) {
+            ReadinessStatus::Ready
+        } else {
+            ReadinessStatus::NotReady
+        };
+        Self { status, checks }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.status == ReadinessStatus::Ready
+    }
+}
+
+/// What an instance needs before it should receive traffic: a reachable, fully
+/// migrated database, writable export storage and a usable mail configuration.
+pub struct Readiness {
+    db: DatabaseConnection,
+    storage_dir: PathBuf,
+    mail: MailtrapSettings,
+}
+
+impl Readiness {
+    pub fn new(db: DatabaseConnection, settings: &Settings) -> Self {
+        Self {
+            db,
+            storage_dir: PathBuf::from(&settings.data_export.storage_dir),
+            mail: settings.mailtrap.clone(),
+        }
+    }
+
+    /// Runs all checks concurrently.
+    pub async fn check(&self) -> ReadinessResponse {
+        let (database, migrations, storage, mail) = tokio::join!(
+            timed("database", check_database(&self.db)),
+            timed("migrations", check_migrations(&self.db)),
+            timed("storage", check_storage(&self.storage_dir)),
+            timed("mail", async { check_mail(&self.mail) }),
+        );
+        ReadinessResponse::from_checks(vec![database, migrations, storage, mail])
+    }
+}
+
+async fn timed(name: &'static str, check: impl Future<Output = Result<(), String>>) -> CheckResult {
+    let started = Instant::now();
+    let outcome = match tokio::time::timeout(CHECK_TIMEOUT, check).await {
+        Ok(outcome) => outcome,
+        Err(_) => Err(format!("timed out after {}s", CHECK_TIMEOUT.as_secs())),
+    };
+    let latency_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+    match outcome {
+        Ok(()) => CheckResult {
+            name,
+            status: CheckStatus::Up,
+            latency_ms,
+            detail: None,
+        },
+        Err(detail) => {
+            tracing::warn!(check = name, %detail, "readiness check failed");
+            CheckResult {
+                name,
+                status: CheckStatus::Down,
+                latency_ms,
+                detail: Some(detail),
+            }
+        }
+    }
+}
+
+async fn check_database(db: &DatabaseConnection) -> Result<(), String> {
+    db.execute(Statement::from_string(
+        db.get_database_backend(),
+        "SELECT 1",
+    ))
+    .await
+    .map(|_| ())
+    .map_err(|err| {
+        tracing::warn!(error = %err, "database is not reachable");
+        "database is not reachable".to_string()
+    })
+}
+
+/// Compares the applied versions with the migrations this build knows about.
+/// Unlike `Migrator::get_pending_migrations` this only reads, so the probe works
+/// with a read-only role and never creates the migrations table.
+async fn check_migrations(db: &DatabaseConnection) -> Result<(), String> {
+    let query = Query::select()
+        .column(Alias::new("version"))
+        .from(Migrator::migration_table_name())
+        .to_owned();
+    let rows = db
+        .query_all(db.get_database_backend().build(&query))
+        .await
+        .map_err(|err| {
+            tracing::warn!(error = %err, "reading applied migrations failed");
+            "applied migrations could not be read".to_string()
+        })?;
+    let applied: HashSet<String> = rows
+        .iter()
+        .filter_map(|row| row.try_get::<String>("", "version").ok())
+        .collect();
+
+    let pending = Migrator::migrations()
+        .iter()
+        .filter(|migration| !applied.contains(migration.name()))
+        .count();
+    if pending == 0 {
+        Ok(())
+    } else {
+        Err(format!("{pending} pending migrations"))
+    }
+}
+
+/// Data exports are written here, so the directory must exist or be creatable,
+/// and accept new files.
+async fn check_storage(dir: &Path) -> Result<(), String> {
+    let not_writable = |err: std::io::Error| {
+        tracing::warn!(error = %err, dir = %dir.display(), "export storage is not writable");
+        "export storage is not writable".to_string()
+    };
+    tokio::fs::create_dir_all(dir).await.map_err(not_writable)?;
+    let probe = dir.join(format!(".readiness-{}", Uuid::new_v4()));
+    tokio::fs::write(&probe, b"").await.map_err(not_writable)?;
+    tokio::fs::remove_file(&probe).await.map_err(not_writable)
+}
+
+/// The API token `MailtrapSettings::default` ships with; mail cannot be sent with it.
+const PLACEHOLDER_API_TOKEN: &str = "YOUR_API_TOKEN";
+
+/// Only the settings every signup needs; optional templates may stay empty.
+fn check_mail(settings: &MailtrapSettings) -> Result<(), String> {
+    let required = [
+        ("api_token", &settings.api_token),
+        ("sender_email", &settings.sender_email),
+        (
+            "verification_template_uuid",
+            &settings.verification_template_uuid,
+        ),
+        (
+            "password_reset_template_uuid",
+            &settings.password_reset_template_uuid,
+        ),
+    ];
+    let missing: Vec<&str> = required
+        .iter()
+        .filter(|(_, value)| value.trim().is_empty() || value.as_str() == PLACEHOLDER_API_TOKEN)
+        .map(|(name, _)| *name)
+        .collect();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("missing mail settings: {}", missing.join(", ")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(name: &'static str, status: CheckStatus) -> CheckResult {
+        CheckResult {
+            name,
+            status,
+            latency_ms: 0,
+            detail: None,
+        }
+    }
+
+    #[test]
+    fn test_ready_only_when_every_check_is_up() {
+        let ready = ReadinessResponse::from_checks(vec![
+            result("database", CheckStatus::Up),
+            result("mail", CheckStatus::Up),
+        ]);
+        assert!(ready.is_ready());
+
+        let not_ready = ReadinessResponse::from_checks(vec![
+            result("database", CheckStatus::Up),
+            result("mail", CheckStatus::Down),
+        ]);
+        assert!(!not_ready.is_ready());
+    }
+
+    #[test]
+    fn test_mail_check_names_missing_settings() {
+        let mut settings = MailtrapSettings {
+            api_token: "token".to_string(),
+            sender_email: "hello@example.com".to_string(),
+            verification_template_uuid: "verification".to_string(),
+            password_reset_template_uuid: "reset".to_string(),
+            ..MailtrapSettings::default()
+        };
+        assert_eq!(check_mail(&settings), Ok(()));
+
+        settings.api_token = PLACEHOLDER_API_TOKEN.to_string();
+        assert!(check_mail(&settings).is_err());
+
+        settings.api_token = " ".to_string();
+        settings.password_reset_template_uuid.clear();
+        assert_eq!(
+            check_mail(&settings),
+            Err("missing mail settings: api_token, password_reset_template_uuid".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_storage_check_creates_the_directory() {
+        let dir = std::env::temp_dir().join(format!("readiness-{}", Uuid::new_v4()));
+
+        assert_eq!(check_storage(&dir.join("exports")).await, Ok(()));
+        assert!(dir.join("exports").is_dir());
+        assert_eq!(std::fs::read_dir(dir.join("exports")).unwrap().count(), 0);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_storage_check_fails_when_the_path_is_a_file() {
+        let file = std::env::temp_dir().join(format!("readiness-{}", Uuid::new_v4()));
+        std::fs::write(&file, b"").unwrap();
+
+        assert!(check_storage(&file).await.is_err());
+
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_slow_checks_time_out() {
+        tokio::time::pause();
+        let result = timed("slow", std::future::pending()).await;
+
+        assert_eq!(result.status, CheckStatus::Down);
+        assert_eq!(result.detail.as_deref(), Some("timed out after 2s"));
+    }
+}
@@ -1,6 +1,6 @@
 use axum::{
     body::Body,
-    http::{HeaderName, HeaderValue, Request},
+    http::{HeaderName, HeaderValue, Request, header},
     middleware::Next,
     response::Response,
 };
@@ -11,12 +11,123 @@ pub const CORRELATION_ID_HEADER: &str = "x-correlation-id";
 
 tokio::task_local! {
     pub static CORRELATION_ID: String;
+    pub static REQUEST_LOCALE: Locale;
+    pub static ERROR_FORMAT: ErrorFormat;
 }
 
 pub fn get_correlation_id() -> Option<String> {
     CORRELATION_ID.try_with(|id| id.clone()).ok()
 }
 
+/// Languages we have translated client-facing messages for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    De,
+}
+
+impl Locale {
+    /// Picks the highest weighted supported language from an `Accept-Language` value.
+    pub fn from_accept_language(value: &str) -> Self {
+        let mut best: Option<(f32, Locale)> = None;
+        for entry in value.split(',') {
+            let mut parts = entry.trim().split(';');
+            let tag = parts.next().unwrap_or_default().trim().to_ascii_lowercase();
+            let quality = parts
+                .find_map(|p| p.trim().strip_prefix("q="))
+                .and_then(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            let locale = match tag.split('-').next() {
+                Some("de") => Locale::De,
+                Some("en") => Locale::En,
+                _ => continue,
+            };
+            if best.is_none_or(|(q, _)| quality > q) {
+                best = Some((quality, locale));
+            }
+        }
+        best.map(|(_, locale)| locale).unwrap_or_default()
+    }
+}
+
+pub fn get_request_locale() -> Locale {
+    REQUEST_LOCALE
+        .try_with(|locale| *locale)
+        .unwrap_or_default()
+}
+
+pub async fn locale_middleware(request: Request<Body>, next: Next) -> Response {
+    let locale = request
+        .headers()
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .map(Locale::from_accept_language)
+        .unwrap_or_default();
+
+    REQUEST_LOCALE.scope(locale, next.run(request)).await
+}
+
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// Body format for error responses, negotiated from the `Accept` header.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ErrorFormat {
+    /// Our `{"error": {...}}` envelope.
+    #[default]
+    Envelope,
+    /// RFC 9457 problem details; `instance` is the path of the failed request.
+    Problem { instance: String },
+}
+
+impl ErrorFormat {
+    /// Problem details are only used when the client ranks `application/problem+json`
+    /// at least as high as `application/json`.
+    pub fn from_accept(value: &str, instance: &str) -> Self {
+        let mut problem_quality: Option<f32> = None;
+        let mut json_quality: Option<f32> = None;
+        for entry in value.split(',') {
+            let mut parts = entry.trim().split(';');
+            let media_type = parts.next().unwrap_or_default().trim().to_ascii_lowercase();
+            let quality = parts
+                .find_map(|p| p.trim().strip_prefix("q="))
+                .and_then(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            match media_type.as_str() {
+                PROBLEM_JSON => problem_quality = Some(quality),
+                "application/json" => json_quality = Some(quality),
+                _ => {}
+            }
+        }
+
+        match problem_quality {
+            Some(problem) if problem > 0.0 && problem >= json_quality.unwrap_or(0.0) => {
+                ErrorFormat::Problem {
+                    instance: instance.to_string(),
+                }
+            }
+            _ => ErrorFormat::Envelope,
+        }
+    }
+}
+
+pub fn get_error_format() -> ErrorFormat {
+    ERROR_FORMAT
+        .try_with(|format| format.clone())
+        .unwrap_or_default()
+}
+
+pub async fn error_format_middleware(request: Request<Body>, next: Next) -> Response {
+    let format = request
+        .headers()
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| ErrorFormat::from_accept(value, request.uri().path()))
+        .unwrap_or_default();
+
+    ERROR_FORMAT.scope(format, next.run(request)).await
+}
+
 pub async fn correlation_id_middleware(mut request: Request<Body>, next: Next) -> Response {
     let header_name = HeaderName::from_static(CORRELATION_ID_HEADER);
 
@@ -38,7 +149,8 @@ pub async fn correlation_id_middleware(mut request: Request<Body>, next: Next) -
         "http_request",
         correlation_id = %correlation_id,
         method = %request.method(),
-        uri = %request.uri(),
+        // Query strings carry credentials, like the data export token or the OIDC code.
+        path = %request.uri().path(),
     );
 
     let mut response = CORRELATION_ID
@@ -49,3 +161,44 @@ pub async fn correlation_id_middleware(mut request: Request<Body>, next: Next) -
 
     response
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locale_from_accept_language() {
+        assert_eq!(Locale::from_accept_language("de-DE,de;q=0.9"), Locale::De);
+        assert_eq!(
+            Locale::from_accept_language("fr, en;q=0.5, de;q=0.8"),
+            Locale::De
+        );
+        assert_eq!(Locale::from_accept_language("en-GB"), Locale::En);
+        assert_eq!(Locale::from_accept_language("fr"), Locale::En);
+        assert_eq!(Locale::from_accept_language(""), Locale::En);
+    }
+
+    #[test]
+    fn test_error_format_from_accept() {
+        let problem = ErrorFormat::Problem {
+            instance: "/users/1".to_string(),
+        };
+        assert_eq!(
+            ErrorFormat::from_accept("application/problem+json", "/users/1"),
+            problem
+        );
+        assert_eq!(
+            ErrorFormat::from_accept("application/json, application/problem+json", "/users/1"),
+            problem
+        );
+        assert_eq!(
+            ErrorFormat::from_accept("application/json, application/problem+json;q=0.5", "/"),
+            ErrorFormat::Envelope
+        );
+        assert_eq!(
+            ErrorFormat::from_accept("application/problem+json;q=0", "/"),
+            ErrorFormat::Envelope
+        );
+        assert_eq!(ErrorFormat::from_accept("*/*", "/"), ErrorFormat::Envelope);
+    }
+}
@@ -1,62 +1,306 @@
+mod admin;
+mod api_keys;
+pub mod audit;
+mod auth;
+mod client_ip;
+mod data_exports;
 mod error;
-mod healthcheck;
+mod extract;
+pub mod healthcheck;
 mod middleware;
+mod oidc;
+mod passkeys;
+mod rate_limit;
 mod response;
+mod sessions;
 pub mod state;
 mod users;
+mod validation;
+mod webhooks;
 
-pub use error::{ApiErrorDetail, ApiErrorResponse, AppError};
+pub use error::{ApiErrorDetail, ApiErrorResponse, ApiFieldError, AppError, ProblemDetails};
 pub use response::{ApiResponse, ApiResponseUser, PaginatedResponse};
 
+use self::client_ip::TrustedProxies;
+use self::rate_limit::RateLimitState;
 use self::state::AppState;
-use crate::http::healthcheck::HealthResponse;
+use crate::http::admin::dtos::{
+    AuditEntryResponse, ChangeUserRoleRequest, FieldChangeResponse, LoginLockoutResponse,
+    UserRoleDto,
+};
+use crate::http::api_keys::dtos::{
+    ApiKeyResponse, ApiKeyScopeDto, CreateApiKeyRequest, CreatedApiKeyResponse,
+};
+use crate::http::data_exports::dtos::{DataExportResponse, DataExportStatusDto};
+use crate::http::healthcheck::{
+    CheckResult, CheckStatus, HealthResponse, LivenessResponse, ReadinessResponse, ReadinessStatus,
+};
+use crate::http::oidc::dtos::{
+    ConfirmIdentityLinkRequest, IdentityLinkRequiredResponse, LinkedIdentityResponse,
+    OidcAuthorizationResponse, OidcCallbackRequest, OidcCallbackResponse,
+};
+use crate::http::passkeys::dtos::{
+    AssertionResponse, AttestationResponse, AuthenticationCredential, AuthenticatorSelection,
+    CredentialDescriptor, CredentialParameters, PasskeyCreationOptions,
+    PasskeyLoginOptionsResponse, PasskeyLoginRequest, PasskeyRegistrationOptionsResponse,
+    PasskeyRequestOptions, PasskeyResponse, PasskeyUserEntity, RegisterPasskeyRequest,
+    RegistrationCredential, RelyingPartyEntity,
+};
+use crate::http::sessions::dtos::SessionResponse;
 use crate::http::users::dtos::{
-    CreateUserRequest, ForgotPasswordRequest, PaginationParams, ResetPasswordRequest, UserResponse,
-    VerifyEmailRequest,
+    CreateUserRequest, ForgotPasswordRequest, LoginNextStep, LoginRequest, LoginResponse,
+    MagicLinkLoginRequest, MagicLinkRequest, PaginationParams, ResetPasswordRequest,
+    RestoreAccountRequest, TotpCodeRequest, TotpConfirmationResponse, TotpEnrollmentResponse,
+    UnlockAccountRequest, UpdateUserProfileRequest, UserResponse, VerifyEmailRequest,
+};
+use crate::http::webhooks::dtos::{
+    CreateWebhookRequest, CreatedWebhookResponse, WebhookDeliveryResponse,
+    WebhookDeliveryStatusDto, WebhookResponse,
 };
 use axum::Router;
-use utoipa::OpenApi;
+use shared::config::Settings;
+use std::sync::Arc;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::openapi::{Content, Ref, RefOr};
+use utoipa::{Modify, OpenApi};
 use utoipa_swagger_ui::SwaggerUi;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         healthcheck::health,
+        healthcheck::live,
+        healthcheck::ready,
         users::handlers::create_user,
         users::handlers::get_user,
         users::handlers::list_users,
         users::handlers::delete_user,
+        users::handlers::update_user,
+        users::handlers::get_me,
+        users::handlers::update_me,
         users::handlers::signup,
         users::handlers::verify_email,
+        users::handlers::restore_account,
         users::handlers::forgot_password,
         users::handlers::reset_password,
+        users::handlers::login,
+        users::handlers::login_totp,
+        users::handlers::request_magic_link,
+        users::handlers::login_magic_link,
+        users::handlers::enroll_totp,
+        users::handlers::confirm_totp,
+        users::handlers::unlock_account,
+        passkeys::handlers::passkey_registration_options,
+        passkeys::handlers::register_passkey,
+        passkeys::handlers::list_passkeys,
+        passkeys::handlers::delete_passkey,
+        passkeys::handlers::passkey_login_options,
+        passkeys::handlers::login_passkey,
+        oidc::handlers::authorize,
+        oidc::handlers::callback,
+        oidc::handlers::list_identities,
+        oidc::handlers::confirm_identity_link,
+        sessions::handlers::list_sessions,
+        sessions::handlers::revoke_session,
+        sessions::handlers::revoke_all_sessions,
+        api_keys::handlers::create_api_key,
+        api_keys::handlers::list_api_keys,
+        api_keys::handlers::delete_api_key,
+        admin::handlers::list_lockouts,
+        admin::handlers::clear_account_lockout,
+        admin::handlers::clear_ip_lockout,
+        admin::handlers::list_audit_log,
+        admin::handlers::change_user_role,
+        admin::handlers::restore_user,
+        webhooks::handlers::create_webhook,
+        webhooks::handlers::list_webhooks,
+        webhooks::handlers::delete_webhook,
+        webhooks::handlers::list_webhook_deliveries,
+        webhooks::handlers::redeliver_webhook,
+        data_exports::handlers::request_own_data_export,
+        data_exports::handlers::request_data_export,
+        data_exports::handlers::download_data_export,
     ),
     components(
         schemas(
             HealthResponse,
+            LivenessResponse,
+            ReadinessResponse,
+            ReadinessStatus,
+            CheckResult,
+            CheckStatus,
             CreateUserRequest,
+            UpdateUserProfileRequest,
             UserResponse,
             VerifyEmailRequest,
+            RestoreAccountRequest,
             ForgotPasswordRequest,
             ResetPasswordRequest,
+            LoginRequest,
+            LoginResponse,
+            LoginNextStep,
+            MagicLinkRequest,
+            MagicLinkLoginRequest,
+            TotpCodeRequest,
+            TotpEnrollmentResponse,
+            TotpConfirmationResponse,
+            UnlockAccountRequest,
+            LoginLockoutResponse,
+            AuditEntryResponse,
+            FieldChangeResponse,
+            UserRoleDto,
+            ChangeUserRoleRequest,
+            CreateWebhookRequest,
+            WebhookResponse,
+            CreatedWebhookResponse,
+            WebhookDeliveryStatusDto,
+            WebhookDeliveryResponse,
+            PasskeyRegistrationOptionsResponse,
+            PasskeyCreationOptions,
+            RelyingPartyEntity,
+            PasskeyUserEntity,
+            CredentialParameters,
+            CredentialDescriptor,
+            AuthenticatorSelection,
+            RegisterPasskeyRequest,
+            RegistrationCredential,
+            AttestationResponse,
+            PasskeyLoginOptionsResponse,
+            PasskeyRequestOptions,
+            PasskeyLoginRequest,
+            AuthenticationCredential,
+            AssertionResponse,
+            PasskeyResponse,
+            OidcAuthorizationResponse,
+            OidcCallbackRequest,
+            OidcCallbackResponse,
+            IdentityLinkRequiredResponse,
+            ConfirmIdentityLinkRequest,
+            LinkedIdentityResponse,
+            SessionResponse,
+            ApiKeyScopeDto,
+            CreateApiKeyRequest,
+            ApiKeyResponse,
+            CreatedApiKeyResponse,
+            DataExportStatusDto,
+            DataExportResponse,
             PaginationParams,
             ApiResponseUser,
             ApiErrorResponse,
-            ApiErrorDetail
+            ApiErrorDetail,
+            ApiFieldError,
+            ProblemDetails
         )
     ),
+    modifiers(&SecurityAddon, &ProblemDetailsAddon),
     tags(
-        (name = "users", description = "User management endpoints")
+        (name = "users", description = "User management endpoints"),
+        (name = "passkeys", description = "Passkey (WebAuthn) registration and login"),
+        (name = "oidc", description = "Login with external OpenID Connect providers"),
+        (name = "sessions", description = "Signed in devices of the current user"),
+        (name = "api-keys", description = "Personal API keys for programmatic access"),
+        (name = "data-export", description = "Copies of a user's personal data (GDPR Art. 15/20)"),
+        (name = "admin", description = "Administrative endpoints, require the admin role")
     )
 )]
 pub struct ApiDoc;
 
-pub fn router(state: AppState) -> Router {
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::with_description(
+                "Authorization",
+                "`ApiKey <key>`, accepted only where listed with the required scope",
+            ))),
+        );
+    }
+}
+
+/// Every error response can also be rendered as RFC 9457 problem details, so each
+/// documented `ApiErrorResponse` gets an `application/problem+json` alternative.
+struct ProblemDetailsAddon;
+
+impl Modify for ProblemDetailsAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let error_ref = Ref::from_schema_name("ApiErrorResponse").ref_location;
+        for path_item in openapi.paths.paths.values_mut() {
+            let operations = [
+                &mut path_item.get,
+                &mut path_item.put,
+                &mut path_item.post,
+                &mut path_item.delete,
+                &mut path_item.patch,
+            ];
+            for operation in operations.into_iter().flatten() {
+                for response in operation.responses.responses.values_mut() {
+                    let RefOr::T(response) = response else {
+                        continue;
+                    };
+                    let is_error = response.content.get("application/json").is_some_and(
+                        |c| matches!(&c.schema, Some(RefOr::Ref(r)) if r.ref_location == error_ref),
+                    );
+                    if is_error {
+                        response.content.insert(
+                            middleware::PROBLEM_JSON.to_string(),
+                            Content::new(Some(Ref::from_schema_name("ProblemDetails"))),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn router(state: AppState, settings: &Settings) -> Router {
     log_routes();
-    Router::new()
+    let mut router = Router::new()
         .merge(healthcheck::router())
         .merge(users::router())
-        .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
+        .merge(passkeys::router())
+        .merge(oidc::router())
+        .merge(sessions::router())
+        .merge(api_keys::router())
+        .merge(data_exports::router())
+        .merge(admin::router())
+        .merge(webhooks::router())
+        .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()));
+
+    // Runs after routing, so the limiter sees the matched route pattern.
+    if let Some(rate_limit) = RateLimitState::from_settings(
+        &settings.rate_limit,
+        state.token_service.clone(),
+        state.authenticate_api_key_use_case.clone(),
+    ) {
+        router = router.layer(axum::middleware::from_fn_with_state(
+            Arc::new(rate_limit),
+            rate_limit::rate_limit_middleware,
+        ));
+    }
+
+    router
+        .layer(axum::middleware::from_fn_with_state(
+            Arc::new(TrustedProxies::from_config(
+                &settings.server.trusted_proxies,
+            )),
+            client_ip::client_ip_middleware,
+        ))
+        .layer(axum::middleware::from_fn(middleware::locale_middleware))
+        .layer(axum::middleware::from_fn(
+            middleware::error_format_middleware,
+        ))
         .layer(axum::middleware::from_fn(
             middleware::correlation_id_middleware,
         ))
@@ -98,3 +342,22 @@ fn log_routes() {
     );
     tracing::info!(method = "GET", path = "/swagger-ui", "route registered");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_responses_document_problem_details() {
+        let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+        let content = &doc["paths"]["/users/{id}"]["get"]["responses"]["404"]["content"];
+        assert!(content["application/json"].is_object());
+        assert_eq!(
+            content["application/problem+json"]["schema"]["$ref"],
+            "#/components/schemas/ProblemDetails"
+        );
+        let ok = &doc["paths"]["/users/{id}"]["get"]["responses"]["200"]["content"];
+        assert!(ok["application/problem+json"].is_null());
+    }
+}
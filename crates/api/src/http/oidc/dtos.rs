@@ -0,0 +1,78 @@
+use crate::http::users::dtos::LoginResponse;
+use application::ports::LinkedIdentity;
+use application::use_cases::IdentityLinkRequired;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OidcAuthorizationResponse {
+    /// Where to send the browser; the provider redirects back with `code` and `state`.
+    pub authorization_url: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct OidcCallbackRequest {
+    #[validate(length(min = 1))]
+    pub code: String,
+    #[validate(length(min = 1))]
+    pub state: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IdentityLinkRequiredResponse {
+    /// Confirm with `POST /me/identities` after logging in to the existing account.
+    pub link_token: String,
+    #[schema(example = "google")]
+    pub provider: String,
+    pub email: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<IdentityLinkRequired> for IdentityLinkRequiredResponse {
+    fn from(link: IdentityLinkRequired) -> Self {
+        Self {
+            link_token: link.link_token,
+            provider: link.provider,
+            email: link.email,
+            expires_at: link.expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum OidcCallbackResponse {
+    /// Logged in, or provider login accepted and `next_step` pending.
+    LoggedIn(LoginResponse),
+    /// The email belongs to an existing account, which has to confirm the link.
+    LinkRequired(IdentityLinkRequiredResponse),
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct ConfirmIdentityLinkRequest {
+    #[validate(length(min = 1))]
+    pub link_token: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LinkedIdentityResponse {
+    pub id: Uuid,
+    #[schema(example = "google")]
+    pub provider: String,
+    pub email: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<LinkedIdentity> for LinkedIdentityResponse {
+    fn from(identity: LinkedIdentity) -> Self {
+        Self {
+            id: identity.id,
+            provider: identity.provider,
+            email: identity.email,
+            created_at: identity.created_at,
+        }
+    }
+}
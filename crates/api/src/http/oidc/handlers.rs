@@ -0,0 +1,181 @@
+use crate::http::ApiResponse;
+use crate::http::AppError;
+use crate::http::auth::AuthenticatedUser;
+use crate::http::client_ip::ClientDetails;
+use crate::http::extract::ValidatedJson;
+use crate::http::oidc::dtos::{
+    ConfirmIdentityLinkRequest, LinkedIdentityResponse, OidcAuthorizationResponse,
+    OidcCallbackRequest, OidcCallbackResponse,
+};
+use crate::http::state::AppState;
+use crate::http::users::handlers::login_body;
+use application::use_cases::{CompleteOidcLoginError, OidcLoginOutcome};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::IntoResponse,
+};
+use chrono::Utc;
+
+/// Holds the `state` of the login this browser started. Without it, an attacker could
+/// send a victim's browser to the callback with the attacker's own `code` and `state`,
+/// and log the victim into the attacker's account.
+const STATE_COOKIE: &str = "oidc_state";
+
+fn state_cookie(state: &str, max_age_seconds: i64) -> Result<HeaderValue, AppError> {
+    HeaderValue::from_str(&format!(
+        "{STATE_COOKIE}={state}; Path=/auth/oidc; Max-Age={max_age_seconds}; HttpOnly; Secure; SameSite=Lax"
+    ))
+    .map_err(|e| AppError::Internal(e.to_string()))
+}
+
+fn cookie<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/oidc/{provider}/authorize",
+    tag = "oidc",
+    params(
+        ("provider" = String, Path, description = "Configured provider, e.g. `google`")
+    ),
+    responses(
+        (status = 200, description = "Provider URL to send the browser to; sets the `oidc_state` cookie the callback requires", body = ApiResponse<OidcAuthorizationResponse>,
+            headers(("Set-Cookie" = String, description = "`oidc_state`, HttpOnly and limited to `/auth/oidc`"))),
+        (status = 404, description = "Unknown provider", body = crate::http::ApiErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = crate::http::ApiErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds until the next request is allowed"))),
+        (status = 502, description = "Provider unavailable", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn authorize(
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let started = state.start_oidc_login_use_case.execute(&provider).await?;
+    let max_age = (started.expires_at - Utc::now()).num_seconds().max(0);
+
+    Ok((
+        StatusCode::OK,
+        [(header::SET_COOKIE, state_cookie(&started.state, max_age)?)],
+        Json(ApiResponse::success(OidcAuthorizationResponse {
+            authorization_url: started.authorization_url,
+        })),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/oidc/{provider}/callback",
+    tag = "oidc",
+    params(
+        ("provider" = String, Path, description = "Configured provider, e.g. `google`")
+    ),
+    request_body = OidcCallbackRequest,
+    responses(
+        (status = 200, description = "Logged in, or the identity has to be linked to an existing account first", body = ApiResponse<OidcCallbackResponse>),
+        (status = 400, description = "Invalid payload, or unknown or expired state, or a state this browser did not start", body = crate::http::ApiErrorResponse),
+        (status = 401, description = "Provider login was not accepted", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Provider did not verify the email", body = crate::http::ApiErrorResponse),
+        (status = 404, description = "Unknown provider", body = crate::http::ApiErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = crate::http::ApiErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds until the next request is allowed"))),
+        (status = 502, description = "Provider unavailable", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn callback(
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+    headers: HeaderMap,
+    ClientDetails(client): ClientDetails,
+    ValidatedJson(payload): ValidatedJson<OidcCallbackRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if cookie(&headers, STATE_COOKIE) != Some(payload.state.as_str()) {
+        return Err(CompleteOidcLoginError::InvalidState.into());
+    }
+
+    let outcome = state
+        .complete_oidc_login_use_case
+        .execute(&provider, &payload.code, &payload.state, &client)
+        .await?;
+
+    let response = match outcome {
+        OidcLoginOutcome::LoggedIn(output) => OidcCallbackResponse::LoggedIn(login_body(output)),
+        OidcLoginOutcome::LinkRequired(link) => OidcCallbackResponse::LinkRequired(link.into()),
+    };
+
+    Ok((
+        StatusCode::OK,
+        [(header::SET_COOKIE, state_cookie("", 0)?)],
+        Json(ApiResponse::success(response)),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/me/identities",
+    tag = "oidc",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Provider accounts linked to the current user", body = ApiResponse<Vec<LinkedIdentityResponse>>),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn list_identities(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+) -> Result<impl IntoResponse, AppError> {
+    let identities = state
+        .list_linked_identities_use_case
+        .execute(user.user_id)
+        .await?;
+
+    let response: Vec<LinkedIdentityResponse> = identities
+        .into_iter()
+        .map(LinkedIdentityResponse::from)
+        .collect();
+
+    Ok((StatusCode::OK, Json(ApiResponse::success(response))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/me/identities",
+    tag = "oidc",
+    security(("bearer_auth" = [])),
+    request_body = ConfirmIdentityLinkRequest,
+    responses(
+        (status = 201, description = "Identity linked", body = ApiResponse<LinkedIdentityResponse>),
+        (status = 400, description = "Invalid, expired or foreign link token", body = crate::http::ApiErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 409, description = "Identity is already linked", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn confirm_identity_link(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    ValidatedJson(payload): ValidatedJson<ConfirmIdentityLinkRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let identity = state
+        .confirm_identity_link_use_case
+        .execute(user.user_id, &payload.link_token)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(LinkedIdentityResponse::from(identity))),
+    ))
+}
@@ -0,0 +1,18 @@
+pub mod dtos;
+pub mod handlers;
+
+use crate::http::state::AppState;
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/auth/oidc/{provider}/authorize", post(handlers::authorize))
+        .route("/auth/oidc/{provider}/callback", post(handlers::callback))
+        .route(
+            "/me/identities",
+            get(handlers::list_identities).post(handlers::confirm_identity_link),
+        )
+}
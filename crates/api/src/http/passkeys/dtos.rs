@@ -0,0 +1,302 @@
+//! Request and response bodies in the WebAuthn JSON encoding, so browsers can pass them
+//! to `PublicKeyCredential.parseCreationOptionsFromJSON()` and friends, and send the
+//! result of `credential.toJSON()` back as is. Binary values are base64url encoded.
+
+use application::ports::{Passkey, PasskeyAssertionResponse, PasskeyRegistrationResponse};
+use application::use_cases::{PasskeyLoginOptions, PasskeyRegistrationOptions};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+const PUBLIC_KEY: &str = "public-key";
+
+mod base64url {
+    use base64::Engine;
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&URL_SAFE_NO_PAD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        URL_SAFE_NO_PAD
+            .decode(value.trim_end_matches('='))
+            .map_err(|_| serde::de::Error::custom("expected a base64url encoded value"))
+    }
+
+    pub mod option {
+        use super::*;
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Vec<u8>>, D::Error> {
+            match Option::<String>::deserialize(deserializer)? {
+                Some(value) => URL_SAFE_NO_PAD
+                    .decode(value.trim_end_matches('='))
+                    .map(Some)
+                    .map_err(|_| serde::de::Error::custom("expected a base64url encoded value")),
+                None => Ok(None),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RelyingPartyEntity {
+    #[schema(example = "mein-ausmalbild.de")]
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyUserEntity {
+    /// The user handle, base64url encoded.
+    #[serde(with = "base64url")]
+    #[schema(value_type = String)]
+    pub id: Vec<u8>,
+    pub name: String,
+    pub display_name: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CredentialParameters {
+    #[serde(rename = "type")]
+    #[schema(example = "public-key")]
+    pub kind: String,
+    /// COSE algorithm identifier, `-7` for ES256.
+    #[schema(example = -7)]
+    pub alg: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CredentialDescriptor {
+    #[serde(rename = "type")]
+    #[schema(example = "public-key")]
+    pub kind: String,
+    #[serde(with = "base64url")]
+    #[schema(value_type = String)]
+    pub id: Vec<u8>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticatorSelection {
+    #[schema(example = "required")]
+    pub resident_key: String,
+    #[schema(example = "required")]
+    pub user_verification: String,
+}
+
+/// `PublicKeyCredentialCreationOptionsJSON`.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyCreationOptions {
+    #[serde(with = "base64url")]
+    #[schema(value_type = String)]
+    pub challenge: Vec<u8>,
+    pub rp: RelyingPartyEntity,
+    pub user: PasskeyUserEntity,
+    pub pub_key_cred_params: Vec<CredentialParameters>,
+    /// Milliseconds.
+    pub timeout: u64,
+    pub exclude_credentials: Vec<CredentialDescriptor>,
+    pub authenticator_selection: AuthenticatorSelection,
+    #[schema(example = "none")]
+    pub attestation: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PasskeyRegistrationOptionsResponse {
+    /// Send back with the created credential.
+    pub ceremony_id: Uuid,
+    pub public_key: PasskeyCreationOptions,
+}
+
+impl From<PasskeyRegistrationOptions> for PasskeyRegistrationOptionsResponse {
+    fn from(options: PasskeyRegistrationOptions) -> Self {
+        Self {
+            ceremony_id: options.ceremony_id,
+            public_key: PasskeyCreationOptions {
+                challenge: options.challenge,
+                rp: RelyingPartyEntity {
+                    id: options.rp_id,
+                    name: options.rp_name,
+                },
+                user: PasskeyUserEntity {
+                    id: options.user_handle,
+                    name: options.user_name,
+                    display_name: options.user_display_name,
+                },
+                pub_key_cred_params: options
+                    .algorithms
+                    .into_iter()
+                    .map(|alg| CredentialParameters {
+                        kind: PUBLIC_KEY.to_string(),
+                        alg,
+                    })
+                    .collect(),
+                timeout: options.timeout.num_milliseconds().max(0) as u64,
+                exclude_credentials: options
+                    .exclude_credentials
+                    .into_iter()
+                    .map(|id| CredentialDescriptor {
+                        kind: PUBLIC_KEY.to_string(),
+                        id,
+                    })
+                    .collect(),
+                authenticator_selection: AuthenticatorSelection {
+                    resident_key: "required".to_string(),
+                    user_verification: "required".to_string(),
+                },
+                attestation: "none".to_string(),
+            },
+        }
+    }
+}
+
+/// `AuthenticatorAttestationResponseJSON`; further fields are ignored.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AttestationResponse {
+    #[serde(rename = "clientDataJSON", with = "base64url")]
+    #[schema(value_type = String)]
+    pub client_data_json: Vec<u8>,
+    #[serde(with = "base64url")]
+    #[schema(value_type = String)]
+    pub attestation_object: Vec<u8>,
+}
+
+/// `RegistrationResponseJSON`, the result of `credential.toJSON()`.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RegistrationCredential {
+    #[serde(with = "base64url")]
+    #[schema(value_type = String)]
+    pub id: Vec<u8>,
+    pub response: AttestationResponse,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct RegisterPasskeyRequest {
+    pub ceremony_id: Uuid,
+    /// Label to tell passkeys apart, defaults to "Passkey".
+    #[validate(length(max = 64))]
+    #[schema(example = "MacBook")]
+    pub name: Option<String>,
+    pub credential: RegistrationCredential,
+}
+
+impl From<RegistrationCredential> for PasskeyRegistrationResponse {
+    fn from(credential: RegistrationCredential) -> Self {
+        Self {
+            credential_id: credential.id,
+            client_data_json: credential.response.client_data_json,
+            attestation_object: credential.response.attestation_object,
+        }
+    }
+}
+
+/// `PublicKeyCredentialRequestOptionsJSON`. `allowCredentials` is left empty, so the
+/// browser offers every passkey it holds for this site.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyRequestOptions {
+    #[serde(with = "base64url")]
+    #[schema(value_type = String)]
+    pub challenge: Vec<u8>,
+    pub rp_id: String,
+    /// Milliseconds.
+    pub timeout: u64,
+    #[schema(example = "required")]
+    pub user_verification: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PasskeyLoginOptionsResponse {
+    /// Send back with the assertion.
+    pub ceremony_id: Uuid,
+    pub public_key: PasskeyRequestOptions,
+}
+
+impl From<PasskeyLoginOptions> for PasskeyLoginOptionsResponse {
+    fn from(options: PasskeyLoginOptions) -> Self {
+        Self {
+            ceremony_id: options.ceremony_id,
+            public_key: PasskeyRequestOptions {
+                challenge: options.challenge,
+                rp_id: options.rp_id,
+                timeout: options.timeout.num_milliseconds().max(0) as u64,
+                user_verification: "required".to_string(),
+            },
+        }
+    }
+}
+
+/// `AuthenticatorAssertionResponseJSON`.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AssertionResponse {
+    #[serde(rename = "clientDataJSON", with = "base64url")]
+    #[schema(value_type = String)]
+    pub client_data_json: Vec<u8>,
+    #[serde(with = "base64url")]
+    #[schema(value_type = String)]
+    pub authenticator_data: Vec<u8>,
+    #[serde(with = "base64url")]
+    #[schema(value_type = String)]
+    pub signature: Vec<u8>,
+    #[serde(default, deserialize_with = "base64url::option::deserialize")]
+    #[schema(value_type = Option<String>)]
+    pub user_handle: Option<Vec<u8>>,
+}
+
+/// `AuthenticationResponseJSON`, the result of `credential.toJSON()`.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AuthenticationCredential {
+    #[serde(with = "base64url")]
+    #[schema(value_type = String)]
+    pub id: Vec<u8>,
+    pub response: AssertionResponse,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct PasskeyLoginRequest {
+    pub ceremony_id: Uuid,
+    pub credential: AuthenticationCredential,
+}
+
+impl From<AuthenticationCredential> for PasskeyAssertionResponse {
+    fn from(credential: AuthenticationCredential) -> Self {
+        Self {
+            credential_id: credential.id,
+            client_data_json: credential.response.client_data_json,
+            authenticator_data: credential.response.authenticator_data,
+            signature: credential.response.signature,
+            user_handle: credential.response.user_handle,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PasskeyResponse {
+    pub id: Uuid,
+    #[schema(example = "MacBook")]
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<Passkey> for PasskeyResponse {
+    fn from(passkey: Passkey) -> Self {
+        Self {
+            id: passkey.id,
+            name: passkey.name,
+            created_at: passkey.created_at,
+            last_used_at: passkey.last_used_at,
+        }
+    }
+}
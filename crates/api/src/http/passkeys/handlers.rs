@@ -0,0 +1,185 @@
+use crate::http::AppError;
+use crate::http::auth::AuthenticatedUser;
+use crate::http::client_ip::ClientDetails;
+use crate::http::extract::ValidatedJson;
+use crate::http::passkeys::dtos::{
+    PasskeyLoginOptionsResponse, PasskeyLoginRequest, PasskeyRegistrationOptionsResponse,
+    PasskeyResponse, RegisterPasskeyRequest,
+};
+use crate::http::state::AppState;
+use crate::http::users::handlers::login_response;
+use crate::http::{ApiResponse, users::dtos::LoginResponse};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "/me/passkeys/registration/options",
+    tag = "passkeys",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Options for `navigator.credentials.create()`", body = ApiResponse<PasskeyRegistrationOptionsResponse>),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 404, description = "User not found", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn passkey_registration_options(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+) -> Result<impl IntoResponse, AppError> {
+    let options = state
+        .start_passkey_registration_use_case
+        .execute(user.user_id)
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            PasskeyRegistrationOptionsResponse::from(options),
+        )),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/me/passkeys/registration",
+    tag = "passkeys",
+    security(("bearer_auth" = [])),
+    request_body = RegisterPasskeyRequest,
+    responses(
+        (status = 201, description = "Passkey registered", body = ApiResponse<PasskeyResponse>),
+        (status = 400, description = "Invalid payload, expired ceremony or rejected credential", body = crate::http::ApiErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 409, description = "Passkey is already registered", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn register_passkey(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    ValidatedJson(payload): ValidatedJson<RegisterPasskeyRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let passkey = state
+        .finish_passkey_registration_use_case
+        .execute(
+            user.user_id,
+            payload.ceremony_id,
+            payload.name.as_deref().unwrap_or_default(),
+            &payload.credential.into(),
+        )
+        .await?;
+
+    tracing::info!(user_id = %user.user_id, passkey_id = %passkey.id, "passkey registered");
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(PasskeyResponse::from(passkey))),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/me/passkeys",
+    tag = "passkeys",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Passkeys of the current user", body = ApiResponse<Vec<PasskeyResponse>>),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn list_passkeys(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+) -> Result<impl IntoResponse, AppError> {
+    let passkeys = state.list_passkeys_use_case.execute(user.user_id).await?;
+
+    let response: Vec<PasskeyResponse> = passkeys.into_iter().map(PasskeyResponse::from).collect();
+
+    Ok((StatusCode::OK, Json(ApiResponse::success(response))))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/me/passkeys/{id}",
+    tag = "passkeys",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Passkey ID")
+    ),
+    responses(
+        (status = 204, description = "Passkey removed"),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 404, description = "Passkey not found", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn delete_passkey(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .delete_passkey_use_case
+        .execute(user.user_id, id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/passkeys/options",
+    tag = "passkeys",
+    responses(
+        (status = 200, description = "Options for `navigator.credentials.get()`", body = ApiResponse<PasskeyLoginOptionsResponse>),
+        (status = 429, description = "Rate limit exceeded", body = crate::http::ApiErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds until the next request is allowed"))),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn passkey_login_options(
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, AppError> {
+    let options = state.start_passkey_login_use_case.execute().await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success(PasskeyLoginOptionsResponse::from(
+            options,
+        ))),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/passkeys/login",
+    tag = "passkeys",
+    request_body = PasskeyLoginRequest,
+    responses(
+        (status = 200, description = "Logged in", body = ApiResponse<LoginResponse>),
+        (status = 400, description = "Invalid payload, or expired ceremony", body = crate::http::ApiErrorResponse),
+        (status = 401, description = "Passkey was not accepted", body = crate::http::ApiErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = crate::http::ApiErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds until the next request is allowed"))),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn login_passkey(
+    State(state): State<AppState>,
+    ClientDetails(client): ClientDetails,
+    ValidatedJson(payload): ValidatedJson<PasskeyLoginRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let output = state
+        .finish_passkey_login_use_case
+        .execute(payload.ceremony_id, &payload.credential.into(), &client)
+        .await?;
+
+    Ok(login_response(output))
+}
@@ -0,0 +1,27 @@
+pub mod dtos;
+pub mod handlers;
+
+use crate::http::state::AppState;
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/me/passkeys", get(handlers::list_passkeys))
+        .route("/me/passkeys/{id}", delete(handlers::delete_passkey))
+        .route(
+            "/me/passkeys/registration/options",
+            post(handlers::passkey_registration_options),
+        )
+        .route(
+            "/me/passkeys/registration",
+            post(handlers::register_passkey),
+        )
+        .route(
+            "/auth/passkeys/options",
+            post(handlers::passkey_login_options),
+        )
+        .route("/auth/passkeys/login", post(handlers::login_passkey))
+}
@@ -0,0 +1,642 @@
+use crate::http::AppError;
+use crate::http::auth::api_key_scope;
+use crate::http::client_ip::ClientIp;
+use application::ErrorCode;
+use application::ports::AccessTokenService;
+use application::use_cases::AuthenticateApiKeyUseCase;
+use axum::{
+    body::Body,
+    extract::{MatchedPath, State},
+    http::{Request, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use shared::config::{
+    ApiKeyRateLimitSettings, RateLimitKey, RateLimitPolicySettings, RateLimitSettings,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::net::{IpAddr, Ipv6Addr};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// The most keys a policy tracks. Beyond it the least recently seen keys are dropped,
+/// which hands them a full bucket again.
+const MAX_BUCKETS: usize = 100_000;
+const CATCH_ALL_ROUTE: &str = "*";
+
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+    /// Position in [`Buckets::by_use`].
+    seq: u64,
+}
+
+/// The buckets of one policy, in the order they were last used.
+#[derive(Debug, Default)]
+struct Buckets {
+    by_key: HashMap<String, TokenBucket>,
+    by_use: BTreeMap<u64, String>,
+    next_seq: u64,
+}
+
+impl Buckets {
+    fn take(&mut self, key: &str) -> Option<TokenBucket> {
+        let bucket = self.by_key.remove(key)?;
+        self.by_use.remove(&bucket.seq);
+        Some(bucket)
+    }
+
+    fn put(&mut self, key: String, tokens: f64, updated_at: Instant) {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.by_use.insert(seq, key.clone());
+        self.by_key.insert(
+            key,
+            TokenBucket {
+                tokens,
+                updated_at,
+                seq,
+            },
+        );
+    }
+
+    /// Drops the buckets idle for `full_after`, which are full again and no different
+    /// from having none, then the least recently used ones beyond `max`. Only looks at
+    /// the buckets it drops, plus one.
+    fn prune(&mut self, now: Instant, full_after: Duration, max: usize) {
+        while let Some(oldest) = self.by_use.first_entry() {
+            let idle = self.by_key.get(oldest.get()).is_none_or(|bucket| {
+                now.saturating_duration_since(bucket.updated_at) >= full_after
+            });
+            if !idle && self.by_key.len() <= max {
+                break;
+            }
+            let key = oldest.remove();
+            self.by_key.remove(&key);
+        }
+    }
+}
+
+/// A token bucket per key: bursts of up to `capacity` requests, refilled at one
+/// token per `refill_every`.
+#[derive(Debug)]
+pub struct RateLimiter {
+    name: String,
+    capacity: f64,
+    refill_every: Duration,
+    key: RateLimitKey,
+    max_buckets: usize,
+    buckets: Mutex<Buckets>,
+}
+
+impl RateLimiter {
+    pub fn new(settings: &RateLimitPolicySettings) -> Self {
+        Self {
+            name: settings.name.clone(),
+            capacity: settings.capacity.max(1) as f64,
+            refill_every: Duration::from_secs(settings.refill_every_seconds.max(1)),
+            key: settings.key,
+            max_buckets: MAX_BUCKETS,
+            buckets: Mutex::default(),
+        }
+    }
+
+    /// How long an untouched bucket takes to fill up from empty.
+    fn full_after(&self) -> Duration {
+        self.refill_every.mul_f64(self.capacity)
+    }
+
+    fn refilled(&self, bucket: TokenBucket, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(bucket.updated_at);
+        let refill = elapsed.as_secs_f64() / self.refill_every.as_secs_f64();
+        (bucket.tokens + refill).min(self.capacity)
+    }
+
+    /// Takes a token for `key`. When the bucket is empty, returns the seconds until
+    /// the next token is available.
+    pub fn check(&self, key: &str, now: Instant) -> Result<(), u64> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let tokens = buckets
+            .take(key)
+            .map_or(self.capacity, |bucket| self.refilled(bucket, now));
+        buckets.prune(now, self.full_after(), self.max_buckets - 1);
+
+        if tokens >= 1.0 {
+            buckets.put(key.to_string(), tokens - 1.0, now);
+            Ok(())
+        } else {
+            buckets.put(key.to_string(), tokens, now);
+            let wait = (1.0 - tokens) * self.refill_every.as_secs_f64();
+            Err((wait.ceil() as u64).max(1))
+        }
+    }
+}
+
+/// All configured policies, resolved by the route a request matched. Requests made
+/// with a valid API key on a route that accepts keys are limited per key instead.
+pub struct RateLimitState {
+    limiters: Vec<RateLimiter>,
+    by_route: HashMap<String, usize>,
+    catch_all: Option<usize>,
+    api_keys: RateLimiter,
+    token_service: Arc<dyn AccessTokenService>,
+    authenticate_api_key: Arc<AuthenticateApiKeyUseCase>,
+}
+
+impl RateLimitState {
+    /// Returns `None` when rate limiting is disabled.
+    pub fn from_settings(
+        settings: &RateLimitSettings,
+        token_service: Arc<dyn AccessTokenService>,
+        authenticate_api_key: Arc<AuthenticateApiKeyUseCase>,
+    ) -> Option<Self> {
+        if !settings.enabled {
+            return None;
+        }
+
+        let mut by_route = HashMap::new();
+        let mut catch_all = None;
+        for (index, policy) in settings.policies.iter().enumerate() {
+            for route in &policy.routes {
+                if route == CATCH_ALL_ROUTE {
+                    catch_all.get_or_insert(index);
+                } else {
+                    by_route.entry(route.clone()).or_insert(index);
+                }
+            }
+        }
+
+        Some(Self {
+            limiters: settings.policies.iter().map(RateLimiter::new).collect(),
+            by_route,
+            catch_all,
+            api_keys: RateLimiter::new(&api_key_policy(&settings.api_keys)),
+            token_service,
+            authenticate_api_key,
+        })
+    }
+
+    fn limiter_for(&self, route: Option<&str>) -> Option<&RateLimiter> {
+        route
+            .and_then(|route| self.by_route.get(route))
+            .or(self.catch_all.as_ref())
+            .map(|index| &self.limiters[*index])
+    }
+
+    /// The API key of a request to a route that accepts keys. Keys sent anywhere else
+    /// are left to the route policy, like invalid ones, so a made up key cannot dodge it.
+    fn api_key(route: Option<&str>, request: &Request<Body>) -> Option<String> {
+        api_key_scope(request.method(), route?)?;
+        let key = request
+            .headers()
+            .get(header::AUTHORIZATION)?
+            .to_str()
+            .ok()?
+            .strip_prefix("ApiKey ")?;
+        Some(key.to_string())
+    }
+
+    fn user_key(&self, request: &Request<Body>) -> Option<String> {
+        let token = request
+            .headers()
+            .get(header::AUTHORIZATION)?
+            .to_str()
+            .ok()?
+            .strip_prefix("Bearer ")?;
+        let claims = self.token_service.verify(token.trim()).ok()?;
+        Some(format!("user:{}", claims.user_id))
+    }
+
+    /// Anonymous and invalid-token requests of a user keyed policy share the IP bucket.
+    /// `None` when not even the client IP is known.
+    fn key_for(&self, limiter: &RateLimiter, request: &Request<Body>) -> Option<String> {
+        let user_key = match limiter.key {
+            RateLimitKey::User => self.user_key(request),
+            RateLimitKey::Ip => None,
+        };
+        user_key.or_else(|| {
+            let ClientIp(ip) = request.extensions().get::<ClientIp>().copied()?;
+            ip.map(|ip| format!("ip:{}", client_network(ip)))
+        })
+    }
+
+    async fn limiter_and_key(
+        &self,
+        route: Option<&str>,
+        request: &mut Request<Body>,
+    ) -> Option<(&RateLimiter, String)> {
+        let api_key = Self::api_key(route, request);
+        let principal = match api_key {
+            Some(key) => self.authenticate_api_key.execute(&key).await.ok(),
+            None => None,
+        };
+        if let Some(principal) = principal {
+            let key = format!("api_key:{}", principal.api_key_id);
+            request.extensions_mut().insert(principal);
+            return Some((&self.api_keys, key));
+        }
+        let limiter = self.limiter_for(route)?;
+        Some((limiter, self.key_for(limiter, request)?))
+    }
+}
+
+/// IPv6 clients usually get a whole /64, so they share one bucket; otherwise each of
+/// their addresses would get its own.
+fn client_network(ip: IpAddr) -> String {
+    match ip.to_canonical() {
+        IpAddr::V6(ip) => {
+            let network = Ipv6Addr::from(u128::from(ip) & !u128::from(u64::MAX));
+            format!("{}/64", network)
+        }
+        ip => ip.to_string(),
+    }
+}
+
+fn api_key_policy(settings: &ApiKeyRateLimitSettings) -> RateLimitPolicySettings {
+    RateLimitPolicySettings {
+        name: "api_key".to_string(),
+        routes: Vec::new(),
+        capacity: settings.capacity,
+        refill_every_seconds: settings.refill_every_seconds,
+        key: RateLimitKey::User,
+    }
+}
+
+pub async fn rate_limit_middleware(
+    State(state): State<Arc<RateLimitState>>,
+    mut request: Request<Body>,
+    next: Next,
+) -> Response {
+    let route = request.extensions().get::<MatchedPath>().cloned();
+
+    if let Some((limiter, key)) = state
+        .limiter_and_key(route.as_ref().map(MatchedPath::as_str), &mut request)
+        .await
+        && let Err(retry_after_seconds) = limiter.check(&key, Instant::now())
+    {
+        tracing::warn!(policy = %limiter.name, key = %key, retry_after_seconds, "rate limit exceeded");
+        return AppError::Throttled {
+            code: ErrorCode::TooManyRequests,
+            message: format!(
+                "Too many requests. Try again in {} seconds",
+                retry_after_seconds
+            ),
+            retry_after_seconds,
+        }
+        .into_response();
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use application::ports::{AccessTokenClaims, AccessTokenScope, ApiKey, ApiKeyScope};
+    use axum::{Router, http::StatusCode, routing::get};
+    use chrono::Utc;
+    use domain_users::models::user::UserRole;
+    use test_support::contract::user_with_email;
+    use test_support::{
+        FakeAccessTokenService, FakeOneTimeTokenService, InMemoryApiKeyRepository,
+        InMemoryUserRepository,
+    };
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    const FIRST_KEY: &str = "mak_AAAAAAAA-first";
+    const SECOND_KEY: &str = "mak_BBBBBBBB-second";
+
+    fn policy(
+        name: &str,
+        routes: &[&str],
+        capacity: u32,
+        key: RateLimitKey,
+    ) -> RateLimitPolicySettings {
+        RateLimitPolicySettings {
+            name: name.to_string(),
+            routes: routes.iter().map(|r| r.to_string()).collect(),
+            capacity,
+            refill_every_seconds: 10,
+            key,
+        }
+    }
+
+    fn api_key(user_id: Uuid, key: &str) -> ApiKey {
+        ApiKey {
+            id: Uuid::new_v4(),
+            user_id,
+            name: "Moodle sync".to_string(),
+            prefix: key.chars().take(12).collect(),
+            key_hash: FakeOneTimeTokenService::hash_of(key),
+            scopes: vec![ApiKeyScope::ProfileRead],
+            created_at: Utc::now(),
+            expires_at: Utc::now() + chrono::Duration::days(30),
+            last_used_at: None,
+        }
+    }
+
+    /// Resolves [`FIRST_KEY`] and [`SECOND_KEY`], both owned by a creator.
+    fn authenticate_api_key() -> Arc<AuthenticateApiKeyUseCase> {
+        let mut owner = user_with_email("creator@example.com");
+        owner.change_role(UserRole::Creator);
+        let keys = [
+            api_key(owner.id(), FIRST_KEY),
+            api_key(owner.id(), SECOND_KEY),
+        ];
+        Arc::new(AuthenticateApiKeyUseCase::new(
+            Arc::new(InMemoryApiKeyRepository::with_keys(keys)),
+            Arc::new(InMemoryUserRepository::with_users([owner])),
+            Arc::new(FakeOneTimeTokenService),
+        ))
+    }
+
+    fn access_token(tokens: &FakeAccessTokenService) -> String {
+        tokens
+            .issue(&AccessTokenClaims {
+                user_id: Uuid::new_v4(),
+                role: UserRole::User,
+                scope: AccessTokenScope::Full,
+                session_id: Some(Uuid::new_v4()),
+            })
+            .unwrap()
+            .token
+    }
+
+    fn app(policies: Vec<RateLimitPolicySettings>) -> Router {
+        app_with_tokens(policies, Arc::new(FakeAccessTokenService::new()))
+    }
+
+    fn app_with_tokens(
+        policies: Vec<RateLimitPolicySettings>,
+        tokens: Arc<FakeAccessTokenService>,
+    ) -> Router {
+        let state = RateLimitState::from_settings(
+            &RateLimitSettings {
+                enabled: true,
+                policies,
+                api_keys: ApiKeyRateLimitSettings {
+                    capacity: 2,
+                    refill_every_seconds: 10,
+                },
+            },
+            tokens,
+            authenticate_api_key(),
+        )
+        .unwrap();
+
+        Router::new()
+            .route("/auth/login", get(|| async { "ok" }))
+            .route("/auth/signup", get(|| async { "ok" }))
+            .route("/me", get(|| async { "ok" }))
+            .route("/users/{id}", get(|| async { "ok" }))
+            .layer(axum::middleware::from_fn_with_state(
+                Arc::new(state),
+                rate_limit_middleware,
+            ))
+            .layer(axum::middleware::from_fn(
+                |mut request: Request<Body>, next: Next| async move {
+                    let ip = request
+                        .headers()
+                        .get("x-test-ip")
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(|v| v.parse::<IpAddr>().ok());
+                    request.extensions_mut().insert(ClientIp(ip));
+                    next.run(request).await
+                },
+            ))
+    }
+
+    async fn send(app: &Router, uri: &str, ip: &str, token: Option<&str>) -> Response {
+        send_authorized(app, uri, ip, token.map(|token| format!("Bearer {}", token))).await
+    }
+
+    async fn send_authorized(
+        app: &Router,
+        uri: &str,
+        ip: &str,
+        authorization: Option<String>,
+    ) -> Response {
+        let mut request = Request::builder().uri(uri).header("x-test-ip", ip);
+        if let Some(authorization) = authorization {
+            request = request.header(header::AUTHORIZATION, authorization);
+        }
+        app.clone()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn test_bucket_refills_over_time() {
+        let limiter = RateLimiter::new(&policy("auth", &["*"], 2, RateLimitKey::Ip));
+        let start = Instant::now();
+
+        assert!(limiter.check("ip:1.1.1.1", start).is_ok());
+        assert!(limiter.check("ip:1.1.1.1", start).is_ok());
+        assert_eq!(limiter.check("ip:1.1.1.1", start), Err(10));
+        assert_eq!(
+            limiter.check("ip:1.1.1.1", start + Duration::from_secs(4)),
+            Err(6)
+        );
+        assert!(
+            limiter
+                .check("ip:1.1.1.1", start + Duration::from_secs(10))
+                .is_ok()
+        );
+        assert!(limiter.check("ip:2.2.2.2", start).is_ok());
+    }
+
+    #[test]
+    fn test_buckets_idle_until_full_are_dropped() {
+        let limiter = RateLimiter::new(&policy("auth", &["*"], 2, RateLimitKey::Ip));
+        let start = Instant::now();
+
+        assert!(limiter.check("ip:1.1.1.1", start).is_ok());
+        assert!(
+            limiter
+                .check("ip:2.2.2.2", start + Duration::from_secs(5))
+                .is_ok()
+        );
+        assert!(
+            limiter
+                .check("ip:3.3.3.3", start + Duration::from_secs(20))
+                .is_ok()
+        );
+
+        let buckets = limiter.buckets.lock().unwrap();
+        assert_eq!(buckets.by_key.len(), 2);
+        assert!(!buckets.by_key.contains_key("ip:1.1.1.1"));
+    }
+
+    #[test]
+    fn test_bucket_count_is_capped_by_dropping_the_least_recently_used() {
+        let mut limiter = RateLimiter::new(&policy("auth", &["*"], 1, RateLimitKey::Ip));
+        limiter.max_buckets = 2;
+        let start = Instant::now();
+
+        assert!(limiter.check("ip:1.1.1.1", start).is_ok());
+        assert!(limiter.check("ip:2.2.2.2", start).is_ok());
+        assert!(limiter.check("ip:1.1.1.1", start).is_err());
+        assert!(limiter.check("ip:3.3.3.3", start).is_ok());
+
+        let buckets = limiter.buckets.lock().unwrap();
+        assert_eq!(buckets.by_key.len(), 2);
+        assert!(buckets.by_key.contains_key("ip:1.1.1.1"));
+        assert!(!buckets.by_key.contains_key("ip:2.2.2.2"));
+    }
+
+    #[test]
+    fn test_ipv6_clients_are_keyed_by_their_64() {
+        assert_eq!(
+            client_network("2001:db8:1:2:aaaa::1".parse().unwrap()),
+            "2001:db8:1:2::/64"
+        );
+        assert_eq!(
+            client_network("::ffff:192.0.2.1".parse().unwrap()),
+            "192.0.2.1"
+        );
+        assert_eq!(client_network("192.0.2.1".parse().unwrap()), "192.0.2.1");
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_route_group_returns_429_with_retry_after() {
+        let app = app(vec![
+            policy("auth", &["/auth/login"], 1, RateLimitKey::Ip),
+            policy("api", &["*"], 5, RateLimitKey::User),
+        ]);
+
+        assert_eq!(
+            send(&app, "/auth/login", "1.1.1.1", None).await.status(),
+            StatusCode::OK
+        );
+        let response = send(&app, "/auth/login", "1.1.1.1", None).await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "10");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["error"]["code"], "TOO_MANY_REQUESTS");
+
+        // Other clients and other route groups have their own buckets.
+        assert_eq!(
+            send(&app, "/auth/login", "2.2.2.2", None).await.status(),
+            StatusCode::OK
+        );
+        assert_eq!(
+            send(&app, "/users/1", "1.1.1.1", None).await.status(),
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    async fn test_user_keyed_policy_limits_per_user() {
+        let tokens = Arc::new(FakeAccessTokenService::new());
+        let app = app_with_tokens(
+            vec![policy("api", &["*"], 1, RateLimitKey::User)],
+            tokens.clone(),
+        );
+        let alice = access_token(&tokens);
+        let bob = access_token(&tokens);
+
+        assert_eq!(
+            send(&app, "/users/1", "1.1.1.1", Some(&alice))
+                .await
+                .status(),
+            StatusCode::OK
+        );
+        assert_eq!(
+            send(&app, "/users/1", "2.2.2.2", Some(&alice))
+                .await
+                .status(),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        assert_eq!(
+            send(&app, "/users/1", "1.1.1.1", Some(&bob)).await.status(),
+            StatusCode::OK
+        );
+        // Anonymous requests fall back to the client IP.
+        assert_eq!(
+            send(&app, "/users/1", "1.1.1.1", None).await.status(),
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    async fn test_api_keys_have_their_own_bucket_per_key() {
+        let app = app(vec![policy("api", &["*"], 100, RateLimitKey::User)]);
+        let first = Some(format!("ApiKey {}", FIRST_KEY));
+        let second = Some(format!("ApiKey {}", SECOND_KEY));
+
+        for _ in 0..2 {
+            assert_eq!(
+                send_authorized(&app, "/me", "1.1.1.1", first.clone())
+                    .await
+                    .status(),
+                StatusCode::OK
+            );
+        }
+        assert_eq!(
+            send_authorized(&app, "/me", "2.2.2.2", first)
+                .await
+                .status(),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        assert_eq!(
+            send_authorized(&app, "/me", "1.1.1.1", second)
+                .await
+                .status(),
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unknown_api_key_does_not_bypass_route_policy() {
+        let app = app(vec![
+            policy("signup", &["/auth/signup"], 1, RateLimitKey::Ip),
+            policy("api", &["*"], 100, RateLimitKey::User),
+        ]);
+        let fake = Some("ApiKey mak_made-up".to_string());
+
+        assert_eq!(
+            send_authorized(&app, "/auth/signup", "1.1.1.1", fake.clone())
+                .await
+                .status(),
+            StatusCode::OK
+        );
+        assert_eq!(
+            send_authorized(&app, "/auth/signup", "1.1.1.1", fake)
+                .await
+                .status(),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+    }
+
+    #[tokio::test]
+    async fn test_valid_api_key_on_other_routes_uses_route_policy() {
+        let app = app(vec![policy(
+            "signup",
+            &["/auth/signup"],
+            1,
+            RateLimitKey::Ip,
+        )]);
+        let first = Some(format!("ApiKey {}", FIRST_KEY));
+
+        assert_eq!(
+            send_authorized(&app, "/auth/signup", "1.1.1.1", first.clone())
+                .await
+                .status(),
+            StatusCode::OK
+        );
+        assert_eq!(
+            send_authorized(&app, "/auth/signup", "1.1.1.1", first)
+                .await
+                .status(),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+    }
+}
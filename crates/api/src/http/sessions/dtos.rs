@@ -0,0 +1,33 @@
+use application::ports::Session;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SessionResponse {
+    pub id: Uuid,
+    #[schema(example = "Mozilla/5.0 (Macintosh; Intel Mac OS X 14_5) Firefox/128.0")]
+    pub user_agent: Option<String>,
+    #[schema(example = "203.0.113.7")]
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    /// Whether this is the session of the access token used for the request.
+    pub current: bool,
+}
+
+impl SessionResponse {
+    pub fn new(session: Session, current_session_id: Option<Uuid>) -> Self {
+        Self {
+            current: current_session_id == Some(session.id),
+            id: session.id,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address.map(|ip| ip.to_string()),
+            created_at: session.created_at,
+            last_seen_at: session.last_seen_at,
+            expires_at: session.expires_at,
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::http::ApiResponse;
+use crate::http::AppError;
+use crate::http::auth::AuthenticatedUser;
+use crate::http::sessions::dtos::SessionResponse;
+use crate::http::state::AppState;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    get,
+    path = "/me/sessions",
+    tag = "sessions",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Devices the current user is signed in on", body = ApiResponse<Vec<SessionResponse>>),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn list_sessions(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+) -> Result<impl IntoResponse, AppError> {
+    let sessions = state.list_sessions_use_case.execute(user.user_id).await?;
+
+    let response: Vec<SessionResponse> = sessions
+        .into_iter()
+        .map(|session| SessionResponse::new(session, user.session_id))
+        .collect();
+
+    Ok((StatusCode::OK, Json(ApiResponse::success(response))))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/me/sessions/{id}",
+    tag = "sessions",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Session ID")
+    ),
+    responses(
+        (status = 204, description = "Session signed out"),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 404, description = "Session not found", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn revoke_session(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .revoke_session_use_case
+        .execute(user.user_id, id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/me/sessions",
+    tag = "sessions",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 204, description = "Signed out everywhere, including this session"),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn revoke_all_sessions(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .revoke_all_sessions_use_case
+        .execute(user.user_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,17 @@
+pub mod dtos;
+pub mod handlers;
+
+use crate::http::state::AppState;
+use axum::{
+    Router,
+    routing::{delete, get},
+};
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/me/sessions",
+            get(handlers::list_sessions).delete(handlers::revoke_all_sessions),
+        )
+        .route("/me/sessions/{id}", delete(handlers::revoke_session))
+}
@@ -1,6 +1,20 @@
+use crate::http::healthcheck::Readiness;
+use application::ports::AccessTokenService;
 use application::use_cases::{
-    CreateUserUseCase, DeleteUserUseCase, GetUserUseCase, ListUsersUseCase,
-    RequestPasswordResetUseCase, ResetPasswordUseCase, SignupUseCase, VerifyEmailUseCase,
+    AuthenticateApiKeyUseCase, ChangeUserRoleUseCase, CheckSessionUseCase,
+    ClearLoginLockoutUseCase, CompleteOidcLoginUseCase, CompleteTotpLoginUseCase,
+    ConfirmIdentityLinkUseCase, ConfirmTotpUseCase, CreateApiKeyUseCase, CreateUserUseCase,
+    CreateWebhookSubscriptionUseCase, DeleteApiKeyUseCase, DeletePasskeyUseCase, DeleteUserUseCase,
+    DeleteWebhookSubscriptionUseCase, DownloadDataExportUseCase, EnrollTotpUseCase,
+    FinishPasskeyLoginUseCase, FinishPasskeyRegistrationUseCase, GetUserUseCase,
+    ListApiKeysUseCase, ListAuditLogUseCase, ListLinkedIdentitiesUseCase, ListLoginLockoutsUseCase,
+    ListPasskeysUseCase, ListSessionsUseCase, ListUsersUseCase, ListWebhookDeliveriesUseCase,
+    ListWebhookSubscriptionsUseCase, LoginUseCase, MagicLinkLoginUseCase, RedeliverWebhookUseCase,
+    RequestDataExportUseCase, RequestMagicLinkUseCase, RequestPasswordResetUseCase,
+    ResetPasswordUseCase, RestoreAccountUseCase, RestoreUserUseCase, RevokeAllSessionsUseCase,
+    RevokeSessionUseCase, SignupUseCase, StartOidcLoginUseCase, StartPasskeyLoginUseCase,
+    StartPasskeyRegistrationUseCase, UnlockAccountUseCase, UpdateUserProfileUseCase,
+    VerifyEmailUseCase,
 };
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
@@ -8,39 +22,53 @@ use std::sync::Arc;
 #[derive(Clone)]
 pub struct AppState {
     pub db: DatabaseConnection,
+    pub token_service: Arc<dyn AccessTokenService>,
+    pub readiness: Arc<Readiness>,
     pub create_user_use_case: Arc<CreateUserUseCase>,
     pub get_user_use_case: Arc<GetUserUseCase>,
     pub delete_user_use_case: Arc<DeleteUserUseCase>,
     pub list_users_use_case: Arc<ListUsersUseCase>,
+    pub update_user_profile_use_case: Arc<UpdateUserProfileUseCase>,
     pub signup_use_case: Arc<SignupUseCase>,
     pub verify_email_use_case: Arc<VerifyEmailUseCase>,
     pub request_password_reset_use_case: Arc<RequestPasswordResetUseCase>,
     pub reset_password_use_case: Arc<ResetPasswordUseCase>,
-}
-
-impl AppState {
-    #[allow(clippy::too_many_arguments)]
-    pub fn new(
-        db: DatabaseConnection,
-        create_user_use_case: CreateUserUseCase,
-        get_user_use_case: GetUserUseCase,
-        delete_user_use_case: DeleteUserUseCase,
-        list_users_use_case: ListUsersUseCase,
-        signup_use_case: SignupUseCase,
-        verify_email_use_case: VerifyEmailUseCase,
-        request_password_reset_use_case: RequestPasswordResetUseCase,
-        reset_password_use_case: ResetPasswordUseCase,
-    ) -> Self {
-        Self {
-            db,
-            create_user_use_case: Arc::new(create_user_use_case),
-            get_user_use_case: Arc::new(get_user_use_case),
-            delete_user_use_case: Arc::new(delete_user_use_case),
-            list_users_use_case: Arc::new(list_users_use_case),
-            signup_use_case: Arc::new(signup_use_case),
-            verify_email_use_case: Arc::new(verify_email_use_case),
-            request_password_reset_use_case: Arc::new(request_password_reset_use_case),
-            reset_password_use_case: Arc::new(reset_password_use_case),
-        }
-    }
+    pub login_use_case: Arc<LoginUseCase>,
+    pub complete_totp_login_use_case: Arc<CompleteTotpLoginUseCase>,
+    pub request_magic_link_use_case: Arc<RequestMagicLinkUseCase>,
+    pub magic_link_login_use_case: Arc<MagicLinkLoginUseCase>,
+    pub enroll_totp_use_case: Arc<EnrollTotpUseCase>,
+    pub confirm_totp_use_case: Arc<ConfirmTotpUseCase>,
+    pub start_passkey_registration_use_case: Arc<StartPasskeyRegistrationUseCase>,
+    pub finish_passkey_registration_use_case: Arc<FinishPasskeyRegistrationUseCase>,
+    pub list_passkeys_use_case: Arc<ListPasskeysUseCase>,
+    pub delete_passkey_use_case: Arc<DeletePasskeyUseCase>,
+    pub start_passkey_login_use_case: Arc<StartPasskeyLoginUseCase>,
+    pub finish_passkey_login_use_case: Arc<FinishPasskeyLoginUseCase>,
+    pub start_oidc_login_use_case: Arc<StartOidcLoginUseCase>,
+    pub complete_oidc_login_use_case: Arc<CompleteOidcLoginUseCase>,
+    pub confirm_identity_link_use_case: Arc<ConfirmIdentityLinkUseCase>,
+    pub list_linked_identities_use_case: Arc<ListLinkedIdentitiesUseCase>,
+    pub check_session_use_case: Arc<CheckSessionUseCase>,
+    pub list_sessions_use_case: Arc<ListSessionsUseCase>,
+    pub revoke_session_use_case: Arc<RevokeSessionUseCase>,
+    pub revoke_all_sessions_use_case: Arc<RevokeAllSessionsUseCase>,
+    pub create_api_key_use_case: Arc<CreateApiKeyUseCase>,
+    pub authenticate_api_key_use_case: Arc<AuthenticateApiKeyUseCase>,
+    pub list_api_keys_use_case: Arc<ListApiKeysUseCase>,
+    pub delete_api_key_use_case: Arc<DeleteApiKeyUseCase>,
+    pub unlock_account_use_case: Arc<UnlockAccountUseCase>,
+    pub list_login_lockouts_use_case: Arc<ListLoginLockoutsUseCase>,
+    pub clear_login_lockout_use_case: Arc<ClearLoginLockoutUseCase>,
+    pub list_audit_log_use_case: Arc<ListAuditLogUseCase>,
+    pub change_user_role_use_case: Arc<ChangeUserRoleUseCase>,
+    pub request_data_export_use_case: Arc<RequestDataExportUseCase>,
+    pub download_data_export_use_case: Arc<DownloadDataExportUseCase>,
+    pub restore_account_use_case: Arc<RestoreAccountUseCase>,
+    pub restore_user_use_case: Arc<RestoreUserUseCase>,
+    pub create_webhook_subscription_use_case: Arc<CreateWebhookSubscriptionUseCase>,
+    pub list_webhook_subscriptions_use_case: Arc<ListWebhookSubscriptionsUseCase>,
+    pub delete_webhook_subscription_use_case: Arc<DeleteWebhookSubscriptionUseCase>,
+    pub list_webhook_deliveries_use_case: Arc<ListWebhookDeliveriesUseCase>,
+    pub redeliver_webhook_use_case: Arc<RedeliverWebhookUseCase>,
 }
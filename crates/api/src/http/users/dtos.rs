@@ -1,5 +1,5 @@
 use chrono::NaiveDate;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 use validator::Validate;
@@ -28,25 +28,119 @@ pub struct UserResponse {
     pub birth_date: Option<NaiveDate>,
     pub is_email_verified: bool,
     pub role: String,
+    pub locale: Option<String>,
 }
 
 impl From<domain_users::User> for UserResponse {
     fn from(user: domain_users::User) -> Self {
         Self {
-            id: user.id,
-            first_name: user.first_name,
-            last_name: user.last_name,
-            email: user.email,
-            phone_number: user.phone_number,
-            birth_date: user.birth_date,
-            is_email_verified: user.is_email_verified,
-            role: user.role.to_string(),
+            id: user.id(),
+            first_name: user.first_name().to_string(),
+            last_name: user.last_name().to_string(),
+            email: user.email().to_string(),
+            phone_number: user.phone_number().map(ToString::to_string),
+            birth_date: user.birth_date(),
+            is_email_verified: user.is_email_verified(),
+            role: user.role().to_string(),
+            locale: user.locale().map(str::to_string),
         }
     }
 }
 
-#[derive(Debug, Deserialize, ToSchema)]
+/// Distinguishes an absent field (`None`) from an explicit `null` (`Some(None)`).
+fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// Partial profile update: omitted fields are left unchanged, `null` clears
+/// nullable fields.
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateUserProfileRequest {
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    #[schema(value_type = Option<String>, example = "+49 30 1234567")]
+    pub phone_number: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    #[schema(value_type = Option<String>, format = Date)]
+    pub birth_date: Option<Option<NaiveDate>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    #[schema(value_type = Option<String>, example = "de-DE")]
+    pub locale: Option<Option<String>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct LoginRequest {
+    #[validate(email)]
+    pub email: String,
+    #[validate(length(min = 1))]
+    pub password: String,
+}
+
+/// What the client has to do before `access_token` grants full access.
+#[derive(Debug, Clone, Copy, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LoginNextStep {
+    /// Submit an authenticator or recovery code to `/auth/login/totp`.
+    Totp,
+    /// Enroll an authenticator via `/me/totp` and confirm it.
+    TotpEnrollment,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LoginResponse {
+    pub access_token: String,
+    #[schema(example = "Bearer")]
+    pub token_type: String,
+    pub expires_in: u64,
+    /// Present when the token is only good for the next login step.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_step: Option<LoginNextStep>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct TotpCodeRequest {
+    /// Six digit authenticator code, or a recovery code where accepted.
+    #[validate(length(min = 6, max = 32))]
+    #[schema(example = "123456")]
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TotpEnrollmentResponse {
+    /// Base32 secret for manual entry.
+    pub secret: String,
+    /// `otpauth://` URI to render as a QR code.
+    #[schema(
+        example = "otpauth://totp/mein-ausmalbild.de:jane%40example.com?secret=JBSWY3DPEHPK3PXP&issuer=mein-ausmalbild.de"
+    )]
+    pub provisioning_uri: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TotpConfirmationResponse {
+    /// One-time recovery codes. They are shown only once.
+    pub recovery_codes: Vec<String>,
+    pub access_token: String,
+    #[schema(example = "Bearer")]
+    pub token_type: String,
+    pub expires_in: u64,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct VerifyEmailRequest {
+    #[validate(length(min = 1))]
+    pub token: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct RestoreAccountRequest {
+    #[validate(length(min = 1))]
     pub token: String,
 }
 
@@ -58,11 +152,31 @@ pub struct ForgotPasswordRequest {
 
 #[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct ResetPasswordRequest {
+    #[validate(length(min = 1))]
     pub token: String,
     #[validate(length(min = 8))]
     pub new_password: String,
 }
 
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct MagicLinkRequest {
+    #[validate(email)]
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct MagicLinkLoginRequest {
+    /// Token from the `token` query parameter of the emailed link.
+    #[validate(length(min = 1))]
+    pub token: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct UnlockAccountRequest {
+    #[validate(length(min = 1))]
+    pub token: String,
+}
+
 #[derive(Debug, Deserialize, ToSchema, IntoParams, Validate)]
 pub struct PaginationParams {
     #[validate(range(min = 0))]
@@ -1,19 +1,76 @@
 use crate::http::AppError;
+use crate::http::auth::{AuthenticatedUser, TotpChallengeUser, TotpEnrollmentUser};
+use crate::http::client_ip::ClientDetails;
+use crate::http::extract::{ValidatedJson, ValidatedQuery};
 use crate::http::state::AppState;
 use crate::http::users::dtos::{
-    CreateUserRequest, ForgotPasswordRequest, PaginationParams, ResetPasswordRequest, UserResponse,
-    VerifyEmailRequest,
+    CreateUserRequest, ForgotPasswordRequest, LoginNextStep, LoginRequest, LoginResponse,
+    MagicLinkLoginRequest, MagicLinkRequest, PaginationParams, ResetPasswordRequest,
+    RestoreAccountRequest, TotpCodeRequest, TotpConfirmationResponse, TotpEnrollmentResponse,
+    UnlockAccountRequest, UpdateUserProfileRequest, UserResponse, VerifyEmailRequest,
 };
 use crate::http::{ApiResponse, PaginatedResponse};
-use application::use_cases::{CreateUserInput, SignupInput};
+use application::ports::AccessTokenScope;
+use application::use_cases::{CreateUserInput, LoginOutput, SignupInput, UpdateUserProfileInput};
 use axum::{
     Json,
-    extract::{Path, Query, State},
-    http::{HeaderMap, StatusCode, header},
+    extract::{Path, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
     response::IntoResponse,
 };
+use domain_users::User;
+use domain_users::models::user::UserRole;
 use uuid::Uuid;
-use validator::Validate;
+
+fn etag(user: &User) -> HeaderValue {
+    HeaderValue::from_str(&format!("\"{}\"", user.version()))
+        .unwrap_or_else(|_| HeaderValue::from_static("\"0\""))
+}
+
+/// Reads the expected version from `If-Match`. `*` or a missing header skip the check;
+/// anything that is not one of our ETags can never match.
+fn expected_version(headers: &HeaderMap) -> Result<Option<i32>, AppError> {
+    let Some(value) = headers.get(header::IF_MATCH) else {
+        return Ok(None);
+    };
+
+    let value = value.to_str().unwrap_or_default().trim();
+    if value == "*" {
+        return Ok(None);
+    }
+
+    value
+        .trim_matches('"')
+        .parse::<i32>()
+        .map(Some)
+        .map_err(|_| AppError::PreconditionFailed("If-Match does not match".to_string()))
+}
+
+fn user_response(user: User) -> impl IntoResponse {
+    (
+        StatusCode::OK,
+        [(header::ETAG, etag(&user))],
+        Json(ApiResponse::success(UserResponse::from(user))),
+    )
+}
+
+fn update_input(
+    user_id: Uuid,
+    actor_id: Option<Uuid>,
+    headers: &HeaderMap,
+    payload: UpdateUserProfileRequest,
+) -> Result<UpdateUserProfileInput, AppError> {
+    Ok(UpdateUserProfileInput {
+        user_id,
+        actor_id,
+        expected_version: expected_version(headers)?,
+        first_name: payload.first_name,
+        last_name: payload.last_name,
+        phone_number: payload.phone_number,
+        birth_date: payload.birth_date,
+        locale: payload.locale,
+    })
+}
 
 #[utoipa::path(
     post,
@@ -29,12 +86,8 @@ use validator::Validate;
 )]
 pub async fn create_user(
     State(state): State<AppState>,
-    Json(payload): Json<CreateUserRequest>,
+    ValidatedJson(payload): ValidatedJson<CreateUserRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    payload
-        .validate()
-        .map_err(|e| AppError::BadRequest(e.to_string()))?;
-
     let input = CreateUserInput {
         first_name: payload.first_name,
         last_name: payload.last_name,
@@ -49,7 +102,7 @@ pub async fn create_user(
     let mut headers = HeaderMap::new();
     headers.insert(
         header::LOCATION,
-        format!("/users/{}", user.id).parse().unwrap(),
+        format!("/users/{}", user.id()).parse().unwrap(),
     );
 
     Ok((
@@ -79,14 +132,107 @@ pub async fn get_user(
     let user = state.get_user_use_case.execute(id).await?;
 
     match user {
-        Some(user) => Ok((
-            StatusCode::OK,
-            Json(ApiResponse::success(UserResponse::from(user))),
-        )),
+        Some(user) => Ok(user_response(user)),
         None => Err(AppError::NotFound(format!("User with ID {} not found", id))),
     }
 }
 
+#[utoipa::path(
+    patch,
+    path = "/users/{id}",
+    tag = "users",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "User ID"),
+        ("If-Match" = Option<String>, Header, description = "ETag from a previous read; the update is rejected if the user changed since")
+    ),
+    request_body = UpdateUserProfileRequest,
+    responses(
+        (status = 200, description = "User updated", body = crate::http::ApiResponseUser),
+        (status = 400, description = "Invalid request payload", body = crate::http::ApiErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Not the user and not an admin", body = crate::http::ApiErrorResponse),
+        (status = 404, description = "User not found", body = crate::http::ApiErrorResponse),
+        (status = 409, description = "Concurrent modification", body = crate::http::ApiErrorResponse),
+        (status = 412, description = "ETag does not match", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn update_user(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    actor: AuthenticatedUser,
+    headers: HeaderMap,
+    ValidatedJson(payload): ValidatedJson<UpdateUserProfileRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if actor.user_id != id && actor.role != UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only the user or an admin may update this profile".to_string(),
+        ));
+    }
+
+    let input = update_input(id, Some(actor.user_id), &headers, payload)?;
+    let user = state.update_user_profile_use_case.execute(input).await?;
+
+    Ok(user_response(user))
+}
+
+#[utoipa::path(
+    get,
+    path = "/me",
+    tag = "users",
+    security(("bearer_auth" = []), ("api_key" = ["profile:read"])),
+    responses(
+        (status = 200, description = "The authenticated user", body = crate::http::ApiResponseUser),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 404, description = "User no longer exists", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn get_me(
+    State(state): State<AppState>,
+    auth: AuthenticatedUser,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state
+        .get_user_use_case
+        .execute(auth.user_id)
+        .await?
+        .filter(|u| !u.is_deleted())
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+    Ok(user_response(user))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/me",
+    tag = "users",
+    security(("bearer_auth" = []), ("api_key" = ["profile:write"])),
+    params(
+        ("If-Match" = Option<String>, Header, description = "ETag from a previous read; the update is rejected if the user changed since")
+    ),
+    request_body = UpdateUserProfileRequest,
+    responses(
+        (status = 200, description = "Profile updated", body = crate::http::ApiResponseUser),
+        (status = 400, description = "Invalid request payload", body = crate::http::ApiErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 409, description = "Concurrent modification", body = crate::http::ApiErrorResponse),
+        (status = 412, description = "ETag does not match", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn update_me(
+    State(state): State<AppState>,
+    auth: AuthenticatedUser,
+    headers: HeaderMap,
+    ValidatedJson(payload): ValidatedJson<UpdateUserProfileRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let input = update_input(auth.user_id, Some(auth.user_id), &headers, payload)?;
+    let user = state.update_user_profile_use_case.execute(input).await?;
+
+    Ok(user_response(user))
+}
+
 #[utoipa::path(
     get,
     path = "/users",
@@ -101,12 +247,8 @@ pub async fn get_user(
 )]
 pub async fn list_users(
     State(state): State<AppState>,
-    Query(pagination): Query<PaginationParams>,
+    ValidatedQuery(pagination): ValidatedQuery<PaginationParams>,
 ) -> Result<impl IntoResponse, AppError> {
-    pagination
-        .validate()
-        .map_err(|e| AppError::BadRequest(e.to_string()))?;
-
     let (users, total) = state
         .list_users_use_case
         .execute(pagination.page(), pagination.page_size())
@@ -129,20 +271,35 @@ pub async fn list_users(
     delete,
     path = "/users/{id}",
     tag = "users",
+    security(("bearer_auth" = [])),
     params(
         ("id" = Uuid, Path, description = "User ID")
     ),
+    description = "Deletes the account and mails the user a link that restores it until \
+        the grace period ends. After that the account is erased for good.",
     responses(
         (status = 204, description = "User deleted successfully"),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Not the user and not an admin", body = crate::http::ApiErrorResponse),
         (status = 404, description = "User not found", body = crate::http::ApiErrorResponse),
         (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
     )
 )]
 pub async fn delete_user(
     State(state): State<AppState>,
+    actor: AuthenticatedUser,
     Path(id): Path<Uuid>,
 ) -> Result<impl IntoResponse, AppError> {
-    state.delete_user_use_case.execute(id).await?;
+    if actor.user_id != id && actor.role != UserRole::Admin {
+        return Err(AppError::Forbidden(
+            "Only the user or an admin may delete this account".to_string(),
+        ));
+    }
+
+    state
+        .delete_user_use_case
+        .execute(id, Some(actor.user_id))
+        .await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -156,17 +313,15 @@ pub async fn delete_user(
         (status = 201, description = "User signed up successfully", body = crate::http::ApiResponseUser),
         (status = 400, description = "Invalid request payload", body = crate::http::ApiErrorResponse),
         (status = 409, description = "User already exists", body = crate::http::ApiErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = crate::http::ApiErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds until the next request is allowed"))),
         (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
     )
 )]
 pub async fn signup(
     State(state): State<AppState>,
-    Json(payload): Json<CreateUserRequest>,
+    ValidatedJson(payload): ValidatedJson<CreateUserRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    payload
-        .validate()
-        .map_err(|e| AppError::BadRequest(e.to_string()))?;
-
     let input = SignupInput {
         first_name: payload.first_name,
         last_name: payload.last_name,
@@ -181,7 +336,7 @@ pub async fn signup(
     let mut headers = HeaderMap::new();
     headers.insert(
         header::LOCATION,
-        format!("/users/{}", user.id).parse().unwrap(),
+        format!("/users/{}", user.id()).parse().unwrap(),
     );
 
     Ok((
@@ -204,13 +359,42 @@ pub async fn signup(
 )]
 pub async fn verify_email(
     State(state): State<AppState>,
-    Json(payload): Json<VerifyEmailRequest>,
+    ValidatedJson(payload): ValidatedJson<VerifyEmailRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     state.verify_email_use_case.execute(&payload.token).await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/auth/restore-account",
+    tag = "users",
+    request_body = RestoreAccountRequest,
+    responses(
+        (status = 200, description = "Account restored", body = crate::http::ApiResponseUser),
+        (status = 400, description = "Invalid or expired token", body = crate::http::ApiErrorResponse),
+        (status = 409, description = "Another account now uses the email", body = crate::http::ApiErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = crate::http::ApiErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds until the next request is allowed"))),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn restore_account(
+    State(state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<RestoreAccountRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state
+        .restore_account_use_case
+        .execute(&payload.token)
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success(UserResponse::from(user))),
+    ))
+}
+
 #[utoipa::path(
     post,
     path = "/auth/forgot-password",
@@ -219,17 +403,15 @@ pub async fn verify_email(
     responses(
         (status = 202, description = "Password reset email sent (accepted)"),
         (status = 400, description = "Invalid request payload", body = crate::http::ApiErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = crate::http::ApiErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds until the next request is allowed"))),
         (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
     )
 )]
 pub async fn forgot_password(
     State(state): State<AppState>,
-    Json(payload): Json<ForgotPasswordRequest>,
+    ValidatedJson(payload): ValidatedJson<ForgotPasswordRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    payload
-        .validate()
-        .map_err(|e| AppError::BadRequest(e.to_string()))?;
-
     // We use 202 Accepted to avoid user enumeration if we want,
     // but here we just follow the use case.
     // If user is not found, we still return 202 or handled error.
@@ -260,12 +442,8 @@ pub async fn forgot_password(
 )]
 pub async fn reset_password(
     State(state): State<AppState>,
-    Json(payload): Json<ResetPasswordRequest>,
+    ValidatedJson(payload): ValidatedJson<ResetPasswordRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    payload
-        .validate()
-        .map_err(|e| AppError::BadRequest(e.to_string()))?;
-
     state
         .reset_password_use_case
         .execute(&payload.token, &payload.new_password)
@@ -273,3 +451,232 @@ pub async fn reset_password(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[utoipa::path(
+    post,
+    path = "/auth/magic-link",
+    tag = "users",
+    request_body = MagicLinkRequest,
+    responses(
+        (status = 202, description = "Login link sent if the email belongs to a verified account"),
+        (status = 400, description = "Invalid request payload", body = crate::http::ApiErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = crate::http::ApiErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds until the next request is allowed"))),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn request_magic_link(
+    State(state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<MagicLinkRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Unknown and unverified addresses get the same answer, so the endpoint
+    // cannot be used to probe for accounts.
+    match state
+        .request_magic_link_use_case
+        .execute(&payload.email)
+        .await
+    {
+        Ok(())
+        | Err(application::use_cases::RequestMagicLinkError::UserNotFound)
+        | Err(application::use_cases::RequestMagicLinkError::EmailNotVerified) => {
+            Ok(StatusCode::ACCEPTED)
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/magic-link/login",
+    tag = "users",
+    request_body = MagicLinkLoginRequest,
+    responses(
+        (status = 200, description = "Logged in, or link accepted and `next_step` pending", body = ApiResponse<LoginResponse>),
+        (status = 400, description = "Invalid, used or expired link", body = crate::http::ApiErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = crate::http::ApiErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds until the next request is allowed"))),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn login_magic_link(
+    State(state): State<AppState>,
+    ClientDetails(client): ClientDetails,
+    ValidatedJson(payload): ValidatedJson<MagicLinkLoginRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let output = state
+        .magic_link_login_use_case
+        .execute(&payload.token, &client)
+        .await?;
+
+    Ok(login_response(output))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/login",
+    tag = "users",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "Logged in, or password accepted and `next_step` pending", body = ApiResponse<LoginResponse>),
+        (status = 400, description = "Invalid request payload", body = crate::http::ApiErrorResponse),
+        (status = 401, description = "Invalid email or password", body = crate::http::ApiErrorResponse),
+        (status = 423, description = "Account locked after too many failed attempts", body = crate::http::ApiErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds until the lockout expires"))),
+        (status = 429, description = "Too many failed attempts, retry later", body = crate::http::ApiErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds until the next attempt is allowed"))),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn login(
+    State(state): State<AppState>,
+    ClientDetails(client): ClientDetails,
+    ValidatedJson(payload): ValidatedJson<LoginRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let output = state
+        .login_use_case
+        .execute(&payload.email, &payload.password, &client)
+        .await?;
+
+    Ok(login_response(output))
+}
+
+pub(crate) fn login_body(output: LoginOutput) -> LoginResponse {
+    let next_step = match output.scope {
+        AccessTokenScope::Full => None,
+        AccessTokenScope::TotpChallenge => Some(LoginNextStep::Totp),
+        AccessTokenScope::TotpEnrollment => Some(LoginNextStep::TotpEnrollment),
+    };
+
+    LoginResponse {
+        access_token: output.access_token.token,
+        token_type: "Bearer".to_string(),
+        expires_in: output.access_token.expires_in_seconds,
+        next_step,
+    }
+}
+
+pub(crate) fn login_response(output: LoginOutput) -> impl IntoResponse {
+    (
+        StatusCode::OK,
+        Json(ApiResponse::success(login_body(output))),
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/login/totp",
+    tag = "users",
+    security(("bearer_auth" = [])),
+    request_body = TotpCodeRequest,
+    responses(
+        (status = 200, description = "Logged in", body = ApiResponse<LoginResponse>),
+        (status = 400, description = "Invalid request payload", body = crate::http::ApiErrorResponse),
+        (status = 401, description = "Missing or invalid challenge token, or wrong code", body = crate::http::ApiErrorResponse),
+        (status = 423, description = "Account locked after too many failed attempts", body = crate::http::ApiErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds until the lockout expires"))),
+        (status = 429, description = "Too many failed attempts, retry later", body = crate::http::ApiErrorResponse,
+            headers(("Retry-After" = u64, description = "Seconds until the next attempt is allowed"))),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn login_totp(
+    State(state): State<AppState>,
+    TotpChallengeUser(user): TotpChallengeUser,
+    ClientDetails(client): ClientDetails,
+    ValidatedJson(payload): ValidatedJson<TotpCodeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let output = state
+        .complete_totp_login_use_case
+        .execute(user.user_id, &payload.code, &client)
+        .await?;
+
+    Ok(login_response(output))
+}
+
+#[utoipa::path(
+    post,
+    path = "/me/totp",
+    tag = "users",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Pending enrollment with a new secret", body = ApiResponse<TotpEnrollmentResponse>),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 409, description = "Two-factor authentication is already enabled", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn enroll_totp(
+    State(state): State<AppState>,
+    TotpEnrollmentUser(user): TotpEnrollmentUser,
+) -> Result<impl IntoResponse, AppError> {
+    let setup = state.enroll_totp_use_case.execute(user.user_id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success(TotpEnrollmentResponse {
+            secret: setup.secret,
+            provisioning_uri: setup.provisioning_uri,
+        })),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/me/totp/confirm",
+    tag = "users",
+    security(("bearer_auth" = [])),
+    request_body = TotpCodeRequest,
+    responses(
+        (status = 200, description = "Two-factor authentication enabled", body = ApiResponse<TotpConfirmationResponse>),
+        (status = 400, description = "Invalid request payload", body = crate::http::ApiErrorResponse),
+        (status = 401, description = "Missing or invalid access token, or wrong code", body = crate::http::ApiErrorResponse),
+        (status = 409, description = "No pending enrollment, or already enabled", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn confirm_totp(
+    State(state): State<AppState>,
+    TotpEnrollmentUser(user): TotpEnrollmentUser,
+    ClientDetails(client): ClientDetails,
+    ValidatedJson(payload): ValidatedJson<TotpCodeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let output = state
+        .confirm_totp_use_case
+        .execute(user.user_id, &payload.code, &client)
+        .await?;
+
+    tracing::info!(user_id = %user.user_id, "two-factor authentication enabled");
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success(TotpConfirmationResponse {
+            recovery_codes: output.recovery_codes,
+            access_token: output.access_token.token,
+            token_type: "Bearer".to_string(),
+            expires_in: output.access_token.expires_in_seconds,
+        })),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/unlock",
+    tag = "users",
+    request_body = UnlockAccountRequest,
+    responses(
+        (status = 204, description = "Account unlocked"),
+        (status = 400, description = "Invalid or already used token", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn unlock_account(
+    State(state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<UnlockAccountRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .unlock_account_use_case
+        .execute(&payload.token)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -15,10 +15,21 @@ pub fn router() -> Router<AppState> {
         )
         .route(
             "/users/{id}",
-            get(handlers::get_user).delete(handlers::delete_user),
+            get(handlers::get_user)
+                .patch(handlers::update_user)
+                .delete(handlers::delete_user),
         )
+        .route("/me", get(handlers::get_me).patch(handlers::update_me))
         .route("/auth/signup", post(handlers::signup))
         .route("/auth/verify-email", post(handlers::verify_email))
+        .route("/auth/restore-account", post(handlers::restore_account))
         .route("/auth/forgot-password", post(handlers::forgot_password))
         .route("/auth/reset-password", post(handlers::reset_password))
+        .route("/me/totp", post(handlers::enroll_totp))
+        .route("/me/totp/confirm", post(handlers::confirm_totp))
+        .route("/auth/login", post(handlers::login))
+        .route("/auth/login/totp", post(handlers::login_totp))
+        .route("/auth/magic-link", post(handlers::request_magic_link))
+        .route("/auth/magic-link/login", post(handlers::login_magic_link))
+        .route("/auth/unlock", post(handlers::unlock_account))
 }
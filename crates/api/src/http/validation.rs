@@ -0,0 +1,235 @@
+use crate::http::middleware::Locale;
+use validator::{ValidationErrors, ValidationErrorsKind};
+
+/// Parameters of a validation rule that may be echoed back to clients. The rejected
+/// value itself is deliberately never included, as it may be a password.
+const EXPOSED_PARAMS: [&str; 3] = ["min", "max", "equal"];
+
+/// A single rule violation on a request field, independent of the response language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldViolation {
+    pub field: String,
+    pub code: String,
+    pub params: Vec<(String, String)>,
+    pub fallback_message: Option<String>,
+}
+
+impl FieldViolation {
+    pub fn new(field: impl Into<String>, code: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            code: code.into(),
+            params: Vec::new(),
+            fallback_message: None,
+        }
+    }
+
+    pub fn with_param(mut self, name: &str, value: impl ToString) -> Self {
+        self.params.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn with_fallback_message(mut self, message: impl Into<String>) -> Self {
+        self.fallback_message = Some(message.into());
+        self
+    }
+
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn message(&self, locale: Locale) -> String {
+        catalog_message(self, locale)
+            .or_else(|| self.fallback_message.clone())
+            .unwrap_or_else(|| match locale {
+                Locale::En => "Invalid value.".to_string(),
+                Locale::De => "Ungültiger Wert.".to_string(),
+            })
+    }
+}
+
+/// Flattens `validator` errors into violations with dotted field paths, e.g.
+/// `address.street` or `children[2].first_name`, sorted by path.
+pub fn violations_from(errors: &ValidationErrors) -> Vec<FieldViolation> {
+    let mut violations = Vec::new();
+    collect(errors, "", &mut violations);
+    violations.sort_by(|a, b| a.field.cmp(&b.field));
+    violations
+}
+
+fn collect(errors: &ValidationErrors, prefix: &str, out: &mut Vec<FieldViolation>) {
+    for (field, kind) in errors.errors() {
+        let path = join_path(prefix, field);
+        match kind {
+            ValidationErrorsKind::Field(field_errors) => {
+                for error in field_errors {
+                    let mut violation = FieldViolation::new(path.clone(), error.code.as_ref());
+                    for name in EXPOSED_PARAMS {
+                        if let Some(value) = error.params.get(name) {
+                            violation = violation.with_param(name, value);
+                        }
+                    }
+                    if let Some(message) = &error.message {
+                        violation = violation.with_fallback_message(message.as_ref());
+                    }
+                    out.push(violation);
+                }
+            }
+            ValidationErrorsKind::Struct(nested) => collect(nested, &path, out),
+            ValidationErrorsKind::List(items) => {
+                for (index, nested) in items {
+                    collect(nested, &format!("{}[{}]", path, index), out);
+                }
+            }
+        }
+    }
+}
+
+pub fn join_path(prefix: &str, field: &str) -> String {
+    if prefix.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", prefix, field)
+    }
+}
+
+fn catalog_message(violation: &FieldViolation, locale: Locale) -> Option<String> {
+    let min = violation.param("min");
+    let max = violation.param("max");
+    let equal = violation.param("equal");
+
+    let message = match (violation.code.as_str(), locale) {
+        ("required", Locale::En) => "This field is required.".to_string(),
+        ("required", Locale::De) => "Dieses Feld ist erforderlich.".to_string(),
+        ("email", Locale::En) => "Must be a valid email address.".to_string(),
+        ("email", Locale::De) => "Muss eine gültige E-Mail-Adresse sein.".to_string(),
+        ("length", Locale::En) => match (min, max, equal) {
+            (_, _, Some(n)) => format!("Must be exactly {} characters long.", n),
+            (Some(min), Some(max), _) => {
+                format!("Must be between {} and {} characters long.", min, max)
+            }
+            (Some(min), None, _) => format!("Must be at least {} characters long.", min),
+            (None, Some(max), _) => format!("Must be at most {} characters long.", max),
+            (None, None, None) => return None,
+        },
+        ("length", Locale::De) => match (min, max, equal) {
+            (_, _, Some(n)) => format!("Muss genau {} Zeichen lang sein.", n),
+            (Some(min), Some(max), _) => {
+                format!("Muss zwischen {} und {} Zeichen lang sein.", min, max)
+            }
+            (Some(min), None, _) => format!("Muss mindestens {} Zeichen lang sein.", min),
+            (None, Some(max), _) => format!("Darf höchstens {} Zeichen lang sein.", max),
+            (None, None, None) => return None,
+        },
+        ("range", Locale::En) => match (min, max) {
+            (Some(min), Some(max)) => format!("Must be between {} and {}.", min, max),
+            (Some(min), None) => format!("Must be at least {}.", min),
+            (None, Some(max)) => format!("Must be at most {}.", max),
+            (None, None) => return None,
+        },
+        ("range", Locale::De) => match (min, max) {
+            (Some(min), Some(max)) => format!("Muss zwischen {} und {} liegen.", min, max),
+            (Some(min), None) => format!("Muss mindestens {} sein.", min),
+            (None, Some(max)) => format!("Darf höchstens {} sein.", max),
+            (None, None) => return None,
+        },
+        ("future_date", Locale::En) => "Must not be in the future.".to_string(),
+        ("future_date", Locale::De) => "Darf nicht in der Zukunft liegen.".to_string(),
+        ("phone_number", Locale::En) => {
+            "Must be a valid phone number, e.g. +49 30 1234567.".to_string()
+        }
+        ("phone_number", Locale::De) => {
+            "Muss eine gültige Telefonnummer sein, z. B. +49 30 1234567.".to_string()
+        }
+        ("locale", Locale::En) => "Must be a language tag like \"de\" or \"de-DE\".".to_string(),
+        ("locale", Locale::De) => "Muss ein Sprachcode wie \"de\" oder \"de-DE\" sein.".to_string(),
+        ("invalid_type", Locale::En) => "Has the wrong type.".to_string(),
+        ("invalid_type", Locale::De) => "Hat den falschen Typ.".to_string(),
+        ("unknown_field", Locale::En) => "Is not a known field.".to_string(),
+        ("unknown_field", Locale::De) => "Ist kein bekanntes Feld.".to_string(),
+        ("json_syntax", Locale::En) => "The request body is not valid JSON.".to_string(),
+        ("json_syntax", Locale::De) => "Der Request-Body ist kein gültiges JSON.".to_string(),
+        _ => return None,
+    };
+
+    Some(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use validator::Validate;
+
+    #[derive(Validate)]
+    struct Child {
+        #[validate(length(min = 1))]
+        first_name: String,
+    }
+
+    #[derive(Validate)]
+    struct Payload {
+        #[validate(email)]
+        email: String,
+        #[validate(length(min = 8))]
+        password: String,
+        #[validate(nested)]
+        children: Vec<Child>,
+    }
+
+    #[test]
+    fn test_violations_are_flattened_with_paths() {
+        let payload = Payload {
+            email: "not-an-email".to_string(),
+            password: "short".to_string(),
+            children: vec![
+                Child {
+                    first_name: "Anna".to_string(),
+                },
+                Child {
+                    first_name: String::new(),
+                },
+            ],
+        };
+
+        let violations = violations_from(&payload.validate().unwrap_err());
+
+        let fields: Vec<(&str, &str)> = violations
+            .iter()
+            .map(|v| (v.field.as_str(), v.code.as_str()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("children[1].first_name", "length"),
+                ("email", "email"),
+                ("password", "length"),
+            ]
+        );
+        let password = &violations[2];
+        assert_eq!(password.params, vec![("min".to_string(), "8".to_string())]);
+    }
+
+    #[test]
+    fn test_messages_are_localized() {
+        let violation = FieldViolation::new("password", "length").with_param("min", 8);
+
+        assert_eq!(
+            violation.message(Locale::En),
+            "Must be at least 8 characters long."
+        );
+        assert_eq!(
+            violation.message(Locale::De),
+            "Muss mindestens 8 Zeichen lang sein."
+        );
+    }
+
+    #[test]
+    fn test_unknown_code_uses_fallback_message() {
+        let violation = FieldViolation::new("iban", "iban").with_fallback_message("bad iban");
+
+        assert_eq!(violation.message(Locale::De), "bad iban");
+    }
+}
@@ -0,0 +1,123 @@
+use application::ports::{WebhookDelivery, WebhookDeliveryStatus, WebhookSubscription};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct CreateWebhookRequest {
+    /// Receives a signed `POST` per event. Must use `https`.
+    #[validate(url, length(max = 2048))]
+    #[schema(example = "https://partner.example.com/hooks/accounts")]
+    pub url: String,
+    /// Names of the events to send, e.g. `user.signed_up`, `user.email_verified`,
+    /// `user.email_changed`, `user.password_changed`, `user.profile_updated`,
+    /// `user.role_changed`, `user.deleted` or `user.restored`.
+    #[validate(length(min = 1))]
+    #[schema(example = json!(["user.deleted"]))]
+    pub events: Vec<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookResponse {
+    pub id: Uuid,
+    #[schema(example = "https://partner.example.com/hooks/accounts")]
+    pub url: String,
+    #[schema(example = json!(["user.deleted"]))]
+    pub events: Vec<String>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<WebhookSubscription> for WebhookResponse {
+    fn from(subscription: WebhookSubscription) -> Self {
+        Self {
+            id: subscription.id,
+            url: subscription.url,
+            events: subscription.events,
+            created_by: subscription.created_by,
+            created_at: subscription.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreatedWebhookResponse {
+    /// Key of the `Webhook-Signature` header, `v1=` followed by the hex HMAC-SHA256
+    /// of `{Webhook-Timestamp}.{body}`. It is shown only once and cannot be
+    /// retrieved later.
+    #[schema(example = "whsec_Xq3v9LpA2bR7kT0sWmYc1uEoHn5gJd8fZiVl4xQ6yNw")]
+    pub secret: String,
+    #[serde(flatten)]
+    pub webhook: WebhookResponse,
+}
+
+impl From<WebhookSubscription> for CreatedWebhookResponse {
+    fn from(subscription: WebhookSubscription) -> Self {
+        Self {
+            secret: subscription.secret.clone(),
+            webhook: subscription.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatusDto {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+impl From<WebhookDeliveryStatus> for WebhookDeliveryStatusDto {
+    fn from(status: WebhookDeliveryStatus) -> Self {
+        match status {
+            WebhookDeliveryStatus::Pending => Self::Pending,
+            WebhookDeliveryStatus::Succeeded => Self::Succeeded,
+            WebhookDeliveryStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryResponse {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    /// Sent as `Webhook-Id`; the same for every attempt.
+    pub event_id: Uuid,
+    #[schema(example = "user.deleted")]
+    pub event_type: String,
+    /// The `data` of the request body.
+    #[schema(value_type = Object)]
+    pub payload: serde_json::Value,
+    pub status: WebhookDeliveryStatusDto,
+    pub attempts: u32,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    /// HTTP status of the last response; `null` if the endpoint did not answer.
+    #[schema(example = 503)]
+    pub response_status: Option<u16>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryResponse {
+    fn from(delivery: WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id,
+            webhook_id: delivery.subscription_id,
+            event_id: delivery.event.id,
+            event_type: delivery.event.name,
+            payload: serde_json::from_str(&delivery.event.payload)
+                .unwrap_or(serde_json::Value::Null),
+            status: delivery.status.into(),
+            attempts: delivery.attempts,
+            next_attempt_at: delivery.next_attempt_at,
+            last_attempt_at: delivery.last_attempt_at,
+            response_status: delivery.response_status,
+            last_error: delivery.last_error,
+            created_at: delivery.created_at,
+        }
+    }
+}
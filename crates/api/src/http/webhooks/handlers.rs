@@ -0,0 +1,184 @@
+use crate::http::auth::AdminUser;
+use crate::http::extract::{ValidatedJson, ValidatedQuery};
+use crate::http::state::AppState;
+use crate::http::users::dtos::PaginationParams;
+use crate::http::webhooks::dtos::{
+    CreateWebhookRequest, CreatedWebhookResponse, WebhookDeliveryResponse, WebhookResponse,
+};
+use crate::http::{ApiResponse, AppError, PaginatedResponse};
+use application::use_cases::CreateWebhookSubscriptionInput;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "/admin/webhooks",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    request_body = CreateWebhookRequest,
+    description = "Subscribes an endpoint to events. Each delivery is signed with the \
+        returned secret and retried with exponential backoff until the endpoint answers \
+        with a 2xx status.",
+    responses(
+        (status = 201, description = "Webhook created; the secret is only returned this once", body = ApiResponse<CreatedWebhookResponse>),
+        (status = 400, description = "Invalid payload or unknown event", body = crate::http::ApiErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn create_webhook(
+    State(state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    ValidatedJson(payload): ValidatedJson<CreateWebhookRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let subscription = state
+        .create_webhook_subscription_use_case
+        .execute(CreateWebhookSubscriptionInput {
+            admin_id: admin.user_id,
+            url: payload.url,
+            events: payload.events,
+        })
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(CreatedWebhookResponse::from(
+            subscription,
+        ))),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/webhooks",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "All webhooks, oldest first", body = ApiResponse<Vec<WebhookResponse>>),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn list_webhooks(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+) -> Result<impl IntoResponse, AppError> {
+    let subscriptions = state.list_webhook_subscriptions_use_case.execute().await?;
+
+    let response: Vec<WebhookResponse> = subscriptions.into_iter().map(Into::into).collect();
+
+    Ok((StatusCode::OK, Json(ApiResponse::success(response))))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/webhooks/{id}",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Webhook ID")
+    ),
+    responses(
+        (status = 204, description = "Webhook deleted with its delivery log"),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::http::ApiErrorResponse),
+        (status = 404, description = "Webhook not found", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn delete_webhook(
+    State(state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    state
+        .delete_webhook_subscription_use_case
+        .execute(admin.user_id, id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/webhooks/{id}/deliveries",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Webhook ID"),
+        PaginationParams
+    ),
+    responses(
+        (status = 200, description = "Delivery log of the webhook, newest first", body = PaginatedResponse<WebhookDeliveryResponse>),
+        (status = 400, description = "Invalid pagination", body = crate::http::ApiErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::http::ApiErrorResponse),
+        (status = 404, description = "Webhook not found", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn list_webhook_deliveries(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    Path(id): Path<Uuid>,
+    ValidatedQuery(pagination): ValidatedQuery<PaginationParams>,
+) -> Result<impl IntoResponse, AppError> {
+    let (deliveries, total) = state
+        .list_webhook_deliveries_use_case
+        .execute(id, pagination.page(), pagination.page_size())
+        .await?;
+
+    let response: Vec<WebhookDeliveryResponse> = deliveries.into_iter().map(Into::into).collect();
+
+    Ok((
+        StatusCode::OK,
+        Json(PaginatedResponse::success(
+            response,
+            pagination.page(),
+            pagination.page_size(),
+            total,
+        )),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/webhooks/deliveries/{id}/redeliver",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Delivery ID")
+    ),
+    description = "Sends the event again with the next worker run, with a fresh set of attempts.",
+    responses(
+        (status = 202, description = "Delivery queued", body = ApiResponse<WebhookDeliveryResponse>),
+        (status = 401, description = "Missing or invalid access token", body = crate::http::ApiErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = crate::http::ApiErrorResponse),
+        (status = 404, description = "Delivery not found", body = crate::http::ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::http::ApiErrorResponse)
+    )
+)]
+pub async fn redeliver_webhook(
+    State(state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let delivery = state
+        .redeliver_webhook_use_case
+        .execute(admin.user_id, id)
+        .await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(ApiResponse::success(WebhookDeliveryResponse::from(
+            delivery,
+        ))),
+    ))
+}
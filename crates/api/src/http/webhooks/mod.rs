@@ -0,0 +1,25 @@
+pub mod dtos;
+pub mod handlers;
+
+use crate::http::state::AppState;
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/admin/webhooks",
+            get(handlers::list_webhooks).post(handlers::create_webhook),
+        )
+        .route("/admin/webhooks/{id}", delete(handlers::delete_webhook))
+        .route(
+            "/admin/webhooks/{id}/deliveries",
+            get(handlers::list_webhook_deliveries),
+        )
+        .route(
+            "/admin/webhooks/deliveries/{id}/redeliver",
+            post(handlers::redeliver_webhook),
+        )
+}
@@ -0,0 +1,6 @@
+//! The HTTP layer of the service and the composition root that wires it to its
+//! adapters. The binary in `main.rs` loads the settings and serves an [`app::App`];
+//! tests build the same router with some ports replaced.
+
+pub mod app;
+pub mod http;
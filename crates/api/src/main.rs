@@ -1,10 +1,8 @@
-use crate::http::state::AppState;
+use api::app::AppBuilder;
 use shared::config::Settings;
 use std::process::ExitCode;
 use tracing_subscriber::{EnvFilter, fmt};
 
-mod http;
-
 #[tokio::main]
 async fn main() -> ExitCode {
     if let Err(err) = run().await {
@@ -44,70 +42,10 @@ async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     tracing::debug!(?settings, "detailed configuration");
 
     tracing::info!("initializing database");
-    let db = infrastructure::db::init_db(&settings.database).await?;
-
-    // Initialize dependencies
-    let user_repo = std::sync::Arc::new(infrastructure::db::repos::PostgresUserRepository::new(
-        db.clone(),
-    ));
-    let token_repo = std::sync::Arc::new(
-        infrastructure::db::repos::PostgresEmailVerificationTokenRepository::new(db.clone()),
-    );
-    let password_reset_token_repo = std::sync::Arc::new(
-        infrastructure::db::repos::PostgresPasswordResetTokenRepository::new(db.clone()),
-    );
-    let password_hasher = std::sync::Arc::new(infrastructure::security::Argon2Hasher);
-    let email_service: std::sync::Arc<dyn application::ports::email_service::EmailService> =
-        std::sync::Arc::new(infrastructure::email::MailtrapEmailService::new(
-            settings.mailtrap.clone(),
-        ));
-
-    // Initialize use cases
-    let create_user_use_case =
-        application::use_cases::CreateUserUseCase::new(user_repo.clone(), password_hasher.clone());
-    let get_user_use_case = application::use_cases::GetUserUseCase::new(user_repo.clone());
-    let delete_user_use_case = application::use_cases::DeleteUserUseCase::new(user_repo.clone());
-    let list_users_use_case = application::use_cases::ListUsersUseCase::new(user_repo.clone());
-    let signup_use_case = application::use_cases::SignupUseCase::new(
-        user_repo.clone(),
-        token_repo.clone(),
-        password_hasher.clone(),
-        email_service.clone(),
-    );
-    let verify_email_use_case =
-        application::use_cases::VerifyEmailUseCase::new(user_repo.clone(), token_repo);
-    let request_password_reset_use_case = application::use_cases::RequestPasswordResetUseCase::new(
-        user_repo.clone(),
-        password_reset_token_repo.clone(),
-        email_service,
-    );
-    let reset_password_use_case = application::use_cases::ResetPasswordUseCase::new(
-        user_repo.clone(),
-        password_reset_token_repo,
-        password_hasher.clone(),
-        settings.auth.password_reset_token_expiry_hours,
-    );
-
-    let state = AppState::new(
-        db,
-        create_user_use_case,
-        get_user_use_case,
-        delete_user_use_case,
-        list_users_use_case,
-        signup_use_case,
-        verify_email_use_case,
-        request_password_reset_use_case,
-        reset_password_use_case,
-    );
-    let app = http::router(state);
+    let app = AppBuilder::new(settings).build().await?;
 
     tracing::info!("starting api");
-
-    let bind_addr = format!("{}:{}", settings.server.host, settings.server.port);
-    let listener = tokio::net::TcpListener::bind(&bind_addr).await?;
-
-    println!("listening on http://{bind_addr}");
-    axum::serve(listener, app).await?;
+    app.serve().await?;
 
     Ok(())
 }
@@ -0,0 +1,81 @@
+mod common;
+
+use axum::body::Body;
+use axum::http::{Method, Request, StatusCode, header};
+use common::{TestApp, TestResponse};
+use domain_users::User;
+use domain_users::models::user::UserRole;
+use test_support::contract::user_with_email;
+
+async fn delete(app: &TestApp, user: &User, token: Option<&str>) -> TestResponse {
+    let mut request = Request::builder()
+        .method(Method::DELETE)
+        .uri(format!("/users/{}", user.id()));
+    if let Some(token) = token {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {token}"));
+    }
+    app.request(request.body(Body::empty()).unwrap()).await
+}
+
+#[tokio::test]
+async fn test_anonymous_deletion_is_unauthorized() {
+    let app = TestApp::new().await;
+    let anna = user_with_email("anna@example.com");
+    app.sign_in(&anna).await;
+
+    let response = delete(&app, &anna, None).await;
+
+    assert_eq!(response.status, StatusCode::UNAUTHORIZED);
+    assert!(!app.users.users()[0].is_deleted());
+    assert!(app.audit_log.entries().is_empty());
+}
+
+#[tokio::test]
+async fn test_deleting_another_users_account_is_forbidden() {
+    let app = TestApp::new().await;
+    let anna = user_with_email("anna@example.com");
+    app.sign_in(&anna).await;
+    let token = app.sign_in(&user_with_email("mallory@example.com")).await;
+
+    let response = delete(&app, &anna, Some(&token)).await;
+
+    assert_eq!(response.status, StatusCode::FORBIDDEN);
+    assert_eq!(response.body["error"]["code"], "FORBIDDEN");
+    assert!(app.users.users().iter().all(|user| !user.is_deleted()));
+    assert!(app.audit_log.entries().is_empty());
+}
+
+#[tokio::test]
+async fn test_users_delete_their_own_account() {
+    let app = TestApp::new().await;
+    let anna = user_with_email("anna@example.com");
+    let token = app.sign_in(&anna).await;
+
+    let response = delete(&app, &anna, Some(&token)).await;
+
+    assert_eq!(response.status, StatusCode::NO_CONTENT);
+    assert!(app.users.users()[0].is_deleted());
+    assert_eq!(
+        app.get_as("/me", &token).await.status,
+        StatusCode::UNAUTHORIZED
+    );
+    let entries = app.audit_log.entries();
+    assert_eq!(entries[0].action, "user.deleted");
+    assert_eq!(entries[0].actor_id, Some(anna.id()));
+}
+
+#[tokio::test]
+async fn test_admins_delete_any_account() {
+    let app = TestApp::new().await;
+    let anna = user_with_email("anna@example.com");
+    app.sign_in(&anna).await;
+    let mut admin = user_with_email("admin@example.com");
+    admin.change_role(UserRole::Admin);
+    admin.take_events();
+    let token = app.sign_in(&admin).await;
+
+    let response = delete(&app, &anna, Some(&token)).await;
+
+    assert_eq!(response.status, StatusCode::NO_CONTENT);
+    assert_eq!(app.audit_log.entries()[0].actor_id, Some(admin.id()));
+}
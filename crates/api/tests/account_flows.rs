@@ -0,0 +1,206 @@
+mod common;
+
+use axum::body::Body;
+use axum::http::{Method, Request, StatusCode};
+use common::{TestApp, json_request};
+use serde_json::{Value, json};
+use test_support::contract::user_with_email;
+use test_support::{EmailKind, FastPasswordHasher};
+
+fn signup_body(email: &str) -> Value {
+    json!({
+        "first_name": "Anna",
+        "last_name": "Schmidt",
+        "email": email,
+        "password": "password123",
+    })
+}
+
+#[tokio::test]
+async fn test_signup_verify_and_reset_password() {
+    let app = TestApp::new().await;
+    let email = "anna@example.com";
+
+    let response = app.post_json("/auth/signup", signup_body(email)).await;
+    assert_eq!(response.status, StatusCode::CREATED);
+    let user_id = response.body["data"]["id"].as_str().unwrap().to_string();
+    assert_eq!(
+        response.header("location"),
+        Some(format!("/users/{user_id}").as_str())
+    );
+    assert_eq!(response.body["data"]["email"], email);
+    assert_eq!(response.body["data"]["is_email_verified"], false);
+
+    let token = app.last_token(EmailKind::Verification, email);
+    let response = app
+        .post_json("/auth/verify-email", json!({ "token": token }))
+        .await;
+    assert_eq!(response.status, StatusCode::NO_CONTENT);
+    assert!(app.users.users()[0].is_email_verified());
+
+    let response = app
+        .post_json("/auth/forgot-password", json!({ "email": email }))
+        .await;
+    assert_eq!(response.status, StatusCode::ACCEPTED);
+
+    let token = app.last_token(EmailKind::PasswordReset, email);
+    let response = app
+        .post_json(
+            "/auth/reset-password",
+            json!({ "token": token, "new_password": "new-password456" }),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::NO_CONTENT);
+
+    let user = &app.users.users()[0];
+    assert_eq!(user.id().to_string(), user_id);
+    assert_eq!(
+        user.password_hash().as_str(),
+        FastPasswordHasher::hash_of("new-password456")
+    );
+    assert_eq!(
+        app.outbox.names(),
+        [
+            "user.signed_up",
+            "user.email_verified",
+            "user.password_changed"
+        ]
+    );
+    assert_eq!(app.events.events().len(), 3);
+    let audit_entries = app.audit_log.entries();
+    let reset = audit_entries
+        .iter()
+        .find(|entry| entry.target_id == Some(user.id()))
+        .unwrap();
+    assert_eq!(reset.ip_address, Some(common::CLIENT_ADDR.ip()));
+
+    // Reset tokens are single-use.
+    let response = app
+        .post_json(
+            "/auth/reset-password",
+            json!({ "token": token, "new_password": "another-password789" }),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+    assert_eq!(response.body["error"]["code"], "INVALID_TOKEN");
+}
+
+#[tokio::test]
+async fn test_password_reset_signs_out_existing_sessions() {
+    let app = TestApp::new().await;
+    let anna = user_with_email("anna@example.com");
+    let token = app.sign_in(&anna).await;
+    assert_eq!(app.get_as("/me", &token).await.status, StatusCode::OK);
+
+    app.post_json(
+        "/auth/forgot-password",
+        json!({ "email": "anna@example.com" }),
+    )
+    .await;
+    let reset_token = app.last_token(EmailKind::PasswordReset, "anna@example.com");
+    let response = app
+        .post_json(
+            "/auth/reset-password",
+            json!({ "token": reset_token, "new_password": "new-password456" }),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::NO_CONTENT);
+
+    assert_eq!(
+        app.get_as("/me", &token).await.status,
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+#[tokio::test]
+async fn test_correlation_id_is_echoed_or_generated() {
+    let app = TestApp::new().await;
+
+    let mut request = json_request(Method::POST, "/auth/verify-email", &json!({ "token": "x" }));
+    request
+        .headers_mut()
+        .insert("x-correlation-id", "req-42".parse().unwrap());
+    let response = app.request(request).await;
+    assert_eq!(response.header("x-correlation-id"), Some("req-42"));
+    assert_eq!(response.body["error"]["correlation_id"], "req-42");
+
+    let response = app
+        .post_json("/auth/signup", signup_body("ben@example.com"))
+        .await;
+    assert_eq!(response.status, StatusCode::CREATED);
+    let generated = response.header("x-correlation-id").unwrap();
+    assert!(!generated.is_empty());
+}
+
+#[tokio::test]
+async fn test_duplicate_signup_returns_error_envelope() {
+    let app = TestApp::new().await;
+    app.post_json("/auth/signup", signup_body("carla@example.com"))
+        .await;
+
+    let response = app
+        .post_json("/auth/signup", signup_body("Carla@Example.com"))
+        .await;
+
+    assert_eq!(response.status, StatusCode::CONFLICT);
+    assert_eq!(response.body["error"]["code"], "EMAIL_TAKEN");
+    assert!(response.body["error"]["message"].is_string());
+    assert_eq!(
+        response.body["error"]["correlation_id"].as_str(),
+        response.header("x-correlation-id")
+    );
+    assert_eq!(app.users.users().len(), 1);
+}
+
+#[tokio::test]
+async fn test_invalid_signup_lists_field_errors() {
+    let app = TestApp::new().await;
+
+    let response = app
+        .post_json(
+            "/auth/signup",
+            json!({
+                "first_name": "Dora",
+                "last_name": "Weber",
+                "email": "not-an-email",
+                "password": "short",
+            }),
+        )
+        .await;
+
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+    assert_eq!(response.body["error"]["code"], "VALIDATION_FAILED");
+    let fields: Vec<&str> = response.body["error"]["details"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|detail| detail["field"].as_str())
+        .collect();
+    assert!(fields.contains(&"email"));
+    assert!(fields.contains(&"password"));
+    assert!(app.emails.sent().is_empty());
+}
+
+#[tokio::test]
+async fn test_problem_details_are_negotiated() {
+    let app = TestApp::new().await;
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/auth/verify-email")
+        .header("content-type", "application/json")
+        .header("accept", "application/problem+json")
+        .body(Body::from(json!({ "token": "unknown" }).to_string()))
+        .unwrap();
+    let response = app.request(request).await;
+
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response.header("content-type"),
+        Some("application/problem+json")
+    );
+    assert_eq!(response.body["status"], 400);
+    assert_eq!(response.body["code"], "INVALID_TOKEN");
+    assert_eq!(response.body["instance"], "/auth/verify-email");
+    assert!(response.body["correlation_id"].is_string());
+}
@@ -0,0 +1,265 @@
+//! Builds the real app with in-memory adapters for the ports the account flows
+//! use. The other ports keep their Postgres adapters over a pool that never
+//! connects, so a test that strays onto them fails with a database error instead
+//! of touching a real database.
+
+// Each test binary uses a different part of the harness.
+#![allow(dead_code)]
+
+use api::app::{App, AppBuilder};
+use application::ports::{
+    AccessTokenClaims, AccessTokenScope, AccessTokenService, OidcIdentity, Session,
+};
+use application::ports::{SessionRepository, UserRepository};
+use axum::Router;
+use axum::body::{Body, to_bytes};
+use axum::extract::ConnectInfo;
+use axum::http::{HeaderMap, Method, Request, StatusCode};
+use chrono::Utc;
+use domain_users::User;
+use sea_orm::{ConnectOptions, Database, DatabaseConnection};
+use serde_json::Value;
+use shared::config::Settings;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use test_support::{
+    CapturingEmailService, EmailKind, FakeAccessTokenService, FakeOidcClient, FastPasswordHasher,
+    InMemoryAccountRestoreTokenRepository, InMemoryAuditLog,
+    InMemoryEmailVerificationTokenRepository, InMemoryEventOutbox,
+    InMemoryLinkedIdentityRepository, InMemoryOidcFlowStore, InMemoryPasswordResetTokenRepository,
+    InMemorySessionRepository, InMemoryTwoFactorRepository, InMemoryUnitOfWork,
+    InMemoryUserRepository, RecordingEventPublisher,
+};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+/// Stands in for the peer address `axum::serve` would provide.
+pub const CLIENT_ADDR: SocketAddr =
+    SocketAddr::new(IpAddr::V4(Ipv4Addr::new(203, 0, 113, 7)), 41000);
+
+/// Who signs in through the fake identity provider.
+pub const OIDC_EMAIL: &str = "sam@example.com";
+
+pub struct TestApp {
+    app: App,
+    router: Router,
+    pub users: Arc<InMemoryUserRepository>,
+    pub emails: Arc<CapturingEmailService>,
+    pub events: Arc<RecordingEventPublisher>,
+    pub outbox: Arc<InMemoryEventOutbox>,
+    pub audit_log: Arc<InMemoryAuditLog>,
+    pub sessions: Arc<InMemorySessionRepository>,
+    pub access_tokens: Arc<FakeAccessTokenService>,
+}
+
+pub struct TestResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    /// The body parsed as JSON, or `Value::Null` when it is empty.
+    pub body: Value,
+}
+
+impl TestResponse {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+}
+
+impl TestApp {
+    pub async fn new() -> Self {
+        let users = Arc::new(InMemoryUserRepository::new());
+        let email_verification_tokens = Arc::new(InMemoryEmailVerificationTokenRepository::new());
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+        let sessions = Arc::new(InMemorySessionRepository::new());
+        let outbox = Arc::new(InMemoryEventOutbox::new());
+        let unit_of_work = Arc::new(InMemoryUnitOfWork::new(
+            users.clone(),
+            email_verification_tokens.clone(),
+            audit_log.clone(),
+            sessions.clone(),
+            outbox.clone(),
+        ));
+        let emails = Arc::new(CapturingEmailService::new());
+        let events = Arc::new(RecordingEventPublisher::new());
+        let access_tokens = Arc::new(FakeAccessTokenService::new());
+
+        let mut settings = Settings::default();
+        // Readiness probes write here; keep them out of the source tree.
+        settings.data_export.storage_dir = std::env::temp_dir()
+            .join("api-tests")
+            .join("exports")
+            .to_string_lossy()
+            .into_owned();
+
+        let app = AppBuilder::new(settings)
+            .with_database(unreachable_database().await)
+            .with_ports({
+                let (users, emails, events, outbox, audit_log, sessions, access_tokens) = (
+                    users.clone(),
+                    emails.clone(),
+                    events.clone(),
+                    outbox.clone(),
+                    audit_log.clone(),
+                    sessions.clone(),
+                    access_tokens.clone(),
+                );
+                move |ports| {
+                    ports.users = users;
+                    ports.email_verification_tokens = email_verification_tokens;
+                    ports.unit_of_work = unit_of_work;
+                    ports.password_reset_tokens =
+                        Arc::new(InMemoryPasswordResetTokenRepository::new());
+                    ports.account_restore_tokens =
+                        Arc::new(InMemoryAccountRestoreTokenRepository::new());
+                    ports.password_hasher = Arc::new(FastPasswordHasher);
+                    ports.login_attempts =
+                        Arc::new(infrastructure::memory::InMemoryLoginAttemptStore::new());
+                    ports.email = emails;
+                    ports.event_publisher = events;
+                    ports.event_outbox = outbox;
+                    ports.audit_log = audit_log;
+                    ports.sessions = sessions;
+                    ports.access_tokens = access_tokens;
+                    ports.two_factor = Arc::new(InMemoryTwoFactorRepository::new());
+                    ports.linked_identities = Arc::new(InMemoryLinkedIdentityRepository::new());
+                    ports.oidc_flows = Arc::new(InMemoryOidcFlowStore::new());
+                    ports.oidc_client = Arc::new(FakeOidcClient::new(OidcIdentity {
+                        subject: "subject-1".to_string(),
+                        email: Some(OIDC_EMAIL.to_string()),
+                        email_verified: true,
+                        given_name: Some("Sam".to_string()),
+                        family_name: None,
+                    }));
+                }
+            })
+            .build()
+            .await
+            .expect("a lazy pool does not connect");
+
+        Self {
+            router: app.build_router(),
+            app,
+            users,
+            emails,
+            events,
+            outbox,
+            audit_log,
+            sessions,
+            access_tokens,
+        }
+    }
+
+    /// Stores `user` and returns a full access token for a fresh session of theirs.
+    pub async fn sign_in(&self, user: &User) -> String {
+        self.users.create(user).await.expect("the user is new");
+        let now = Utc::now();
+        let session = Session {
+            id: Uuid::new_v4(),
+            user_id: user.id(),
+            user_agent: None,
+            ip_address: None,
+            created_at: now,
+            last_seen_at: now,
+            expires_at: now + chrono::Duration::minutes(15),
+            revoked_at: None,
+        };
+        self.sessions
+            .create(&session)
+            .await
+            .expect("sessions are in memory");
+        self.access_tokens
+            .issue(&AccessTokenClaims {
+                user_id: user.id(),
+                role: user.role().clone(),
+                scope: AccessTokenScope::Full,
+                session_id: Some(session.id),
+            })
+            .expect("the fake never fails")
+            .token
+    }
+
+    /// The app itself, to serve it over TCP.
+    pub fn into_app(self) -> App {
+        self.app
+    }
+
+    pub async fn request(&self, mut request: Request<Body>) -> TestResponse {
+        request.extensions_mut().insert(ConnectInfo(CLIENT_ADDR));
+        let response = self
+            .router
+            .clone()
+            .oneshot(request)
+            .await
+            .expect("the router never fails");
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("the body is readable");
+        let body = if bytes.is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_slice(&bytes).expect("the body is JSON")
+        };
+        TestResponse {
+            status,
+            headers,
+            body,
+        }
+    }
+
+    pub async fn get(&self, uri: &str) -> TestResponse {
+        let request = Request::builder()
+            .uri(uri)
+            .body(Body::empty())
+            .expect("the request is valid");
+        self.request(request).await
+    }
+
+    /// A GET with `token` as its bearer token.
+    pub async fn get_as(&self, uri: &str, token: &str) -> TestResponse {
+        let request = Request::builder()
+            .uri(uri)
+            .header("authorization", format!("Bearer {token}"))
+            .body(Body::empty())
+            .expect("the request is valid");
+        self.request(request).await
+    }
+
+    pub async fn post_json(&self, uri: &str, body: Value) -> TestResponse {
+        self.request(json_request(Method::POST, uri, &body)).await
+    }
+
+    /// The token of the last email of `kind` sent to `to`.
+    pub fn last_token(&self, kind: EmailKind, to: &str) -> String {
+        self.emails
+            .sent()
+            .into_iter()
+            .rev()
+            .find(|email| email.kind == kind && email.to == to)
+            .map(|email| email.token)
+            .unwrap_or_else(|| panic!("no {kind:?} email was sent to {to}"))
+    }
+}
+
+pub fn json_request(method: Method, uri: &str, body: &Value) -> Request<Body> {
+    Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("the request is valid")
+}
+
+/// A pool that only connects on first use, to an address nothing listens on.
+async fn unreachable_database() -> DatabaseConnection {
+    let mut options = ConnectOptions::new("postgres://harness@127.0.0.1:9/unreachable");
+    options
+        .connect_lazy(true)
+        .acquire_timeout(Duration::from_millis(200))
+        .sqlx_logging(false);
+    Database::connect(options)
+        .await
+        .expect("a lazy pool does not connect")
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use axum::http::StatusCode;
+use common::TestApp;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::oneshot;
+
+#[tokio::test]
+async fn test_liveness_checks_no_dependencies() {
+    let app = TestApp::new().await;
+
+    let response = app.get("/health/live").await;
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body["data"]["status"], "ok");
+}
+
+#[tokio::test]
+async fn test_readiness_reports_every_check() {
+    // The harness database never connects and the default settings only carry a
+    // placeholder Mailtrap token.
+    let app = TestApp::new().await;
+
+    let response = app.get("/health/ready").await;
+
+    assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(response.body["success"], false);
+    assert_eq!(response.body["data"]["status"], "not_ready");
+    let checks = response.body["data"]["checks"].as_array().unwrap();
+    let status_of = |name: &str| {
+        let check = checks.iter().find(|check| check["name"] == name).unwrap();
+        assert!(check["latency_ms"].is_u64());
+        check["status"].as_str().unwrap().to_string()
+    };
+    assert_eq!(status_of("database"), "down");
+    assert_eq!(status_of("migrations"), "down");
+    assert_eq!(status_of("storage"), "up");
+    assert_eq!(status_of("mail"), "down");
+    assert_eq!(checks.len(), 4);
+}
+
+#[tokio::test]
+async fn test_shutdown_drains_requests_in_flight() {
+    let app = TestApp::new().await.into_app();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (shutdown, shutdown_requested) = oneshot::channel::<()>();
+    let server = tokio::spawn(app.serve_with_shutdown(listener, async {
+        shutdown_requested.await.ok();
+    }));
+
+    // A request whose body is still on its way when the shutdown starts.
+    let body = r#"{"token":"unknown"}"#;
+    let mut client = TcpStream::connect(addr).await.unwrap();
+    let head = format!(
+        "POST /auth/verify-email HTTP/1.1\r\nhost: localhost\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n",
+        body.len()
+    );
+    client.write_all(head.as_bytes()).await.unwrap();
+    client.write_all(&body.as_bytes()[..5]).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    shutdown.send(()).unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(!server.is_finished());
+    assert!(TcpStream::connect(addr).await.is_err());
+
+    client.write_all(&body.as_bytes()[5..]).await.unwrap();
+    let mut response = String::new();
+    client.read_to_string(&mut response).await.unwrap();
+    assert!(response.starts_with("HTTP/1.1 400"), "{response}");
+
+    tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .expect("the server stops once the request is answered")
+        .unwrap()
+        .unwrap();
+}
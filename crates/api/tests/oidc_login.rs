@@ -0,0 +1,62 @@
+mod common;
+
+use axum::http::{Method, StatusCode};
+use common::{OIDC_EMAIL, TestApp, TestResponse, json_request};
+use serde_json::json;
+
+async fn callback(app: &TestApp, cookie: Option<&str>) -> TestResponse {
+    let mut request = json_request(
+        Method::POST,
+        "/auth/oidc/google/callback",
+        &json!({ "code": "code", "state": "state" }),
+    );
+    if let Some(cookie) = cookie {
+        request
+            .headers_mut()
+            .insert("cookie", cookie.parse().expect("the cookie is valid"));
+    }
+    app.request(request).await
+}
+
+#[tokio::test]
+async fn test_authorize_binds_the_state_to_the_browser() {
+    let app = TestApp::new().await;
+
+    let response = app
+        .post_json("/auth/oidc/google/authorize", json!({}))
+        .await;
+
+    assert_eq!(response.status, StatusCode::OK);
+    let cookie = response
+        .header("set-cookie")
+        .expect("a state cookie is set");
+    assert!(cookie.starts_with("oidc_state=state;"));
+    assert!(cookie.contains("HttpOnly"));
+    assert!(cookie.contains("SameSite=Lax"));
+    assert!(cookie.contains("Path=/auth/oidc"));
+}
+
+#[tokio::test]
+async fn test_callback_requires_the_state_cookie_of_the_login() {
+    let app = TestApp::new().await;
+    app.post_json("/auth/oidc/google/authorize", json!({}))
+        .await;
+
+    let without_cookie = callback(&app, None).await;
+    let other_login = callback(&app, Some("theme=dark; oidc_state=other")).await;
+
+    assert_eq!(without_cookie.status, StatusCode::BAD_REQUEST);
+    assert_eq!(other_login.status, StatusCode::BAD_REQUEST);
+    assert!(app.users.users().is_empty());
+
+    // The rejected attempts leave the login to the browser that started it.
+    let response = callback(&app, Some("theme=dark; oidc_state=state")).await;
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert!(
+        response.header("set-cookie").is_some_and(
+            |cookie| cookie.starts_with("oidc_state=;") && cookie.contains("Max-Age=0")
+        )
+    );
+    assert_eq!(app.users.users()[0].email().as_str(), OIDC_EMAIL);
+}
@@ -0,0 +1,106 @@
+mod common;
+
+use application::ports::{AccessTokenClaims, AccessTokenScope, AccessTokenService};
+use axum::body::Body;
+use axum::http::{Method, Request, StatusCode, header};
+use common::{TestApp, TestResponse, json_request};
+use domain_users::User;
+use domain_users::models::user::UserRole;
+use serde_json::json;
+use test_support::contract::user_with_email;
+
+async fn rename(app: &TestApp, user: &User, token: Option<&str>) -> TestResponse {
+    let mut request: Request<Body> = json_request(
+        Method::PATCH,
+        &format!("/users/{}", user.id()),
+        &json!({ "first_name": "Jane" }),
+    );
+    if let Some(token) = token {
+        request.headers_mut().insert(
+            header::AUTHORIZATION,
+            format!("Bearer {token}").parse().unwrap(),
+        );
+    }
+    app.request(request).await
+}
+
+#[tokio::test]
+async fn test_anonymous_profile_update_is_unauthorized() {
+    let app = TestApp::new().await;
+    let anna = user_with_email("anna@example.com");
+    app.sign_in(&anna).await;
+
+    let response = rename(&app, &anna, None).await;
+
+    assert_eq!(response.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(app.users.users()[0].first_name(), "Anna");
+    assert!(app.audit_log.entries().is_empty());
+}
+
+#[tokio::test]
+async fn test_other_users_profile_update_is_forbidden() {
+    let app = TestApp::new().await;
+    let anna = user_with_email("anna@example.com");
+    app.sign_in(&anna).await;
+    let token = app.sign_in(&user_with_email("mallory@example.com")).await;
+
+    let response = rename(&app, &anna, Some(&token)).await;
+
+    assert_eq!(response.status, StatusCode::FORBIDDEN);
+    assert_eq!(response.body["error"]["code"], "FORBIDDEN");
+    assert!(app.audit_log.entries().is_empty());
+}
+
+#[tokio::test]
+async fn test_users_update_their_own_profile() {
+    let app = TestApp::new().await;
+    let anna = user_with_email("anna@example.com");
+    let token = app.sign_in(&anna).await;
+
+    let response = rename(&app, &anna, Some(&token)).await;
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body["data"]["first_name"], "Jane");
+    let entries = app.audit_log.entries();
+    assert_eq!(entries[0].action, "user.profile_updated");
+    assert_eq!(entries[0].actor_id, Some(anna.id()));
+    assert_eq!(entries[0].ip_address, Some(common::CLIENT_ADDR.ip()));
+}
+
+#[tokio::test]
+async fn test_admins_update_any_profile() {
+    let app = TestApp::new().await;
+    let anna = user_with_email("anna@example.com");
+    app.sign_in(&anna).await;
+    let mut admin = user_with_email("admin@example.com");
+    admin.change_role(UserRole::Admin);
+    admin.take_events();
+    let token = app.sign_in(&admin).await;
+
+    let response = rename(&app, &anna, Some(&token)).await;
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(app.audit_log.entries()[0].actor_id, Some(admin.id()));
+}
+
+#[tokio::test]
+async fn test_full_token_without_session_is_rejected() {
+    let app = TestApp::new().await;
+    let anna = user_with_email("anna@example.com");
+    app.sign_in(&anna).await;
+    let token = app
+        .access_tokens
+        .issue(&AccessTokenClaims {
+            user_id: anna.id(),
+            role: anna.role().clone(),
+            scope: AccessTokenScope::Full,
+            session_id: None,
+        })
+        .unwrap()
+        .token;
+
+    let response = rename(&app, &anna, Some(&token)).await;
+
+    assert_eq!(response.status, StatusCode::UNAUTHORIZED);
+    assert!(app.audit_log.entries().is_empty());
+}
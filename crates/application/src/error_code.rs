@@ -0,0 +1,136 @@
+/// Stable, machine-readable error identifiers exposed to API clients.
+///
+/// The string form returned by [`ErrorCode::as_str`] is part of the public API
+/// contract: clients branch on it, so existing codes must never be renamed or removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    BadRequest,
+    ValidationFailed,
+    Unauthorized,
+    InvalidCredentials,
+    InvalidToken,
+    ExpiredToken,
+    NotFound,
+    UserNotFound,
+    EmailTaken,
+    Conflict,
+    ConcurrentModification,
+    PreconditionFailed,
+    UnsupportedMediaType,
+    InternalServerError,
+    Forbidden,
+    TooManyRequests,
+    AccountLocked,
+    InvalidTwoFactorCode,
+    PasskeyChallengeInvalid,
+    PasskeyRejected,
+    OidcStateInvalid,
+    OidcIdentityRejected,
+    UpstreamUnavailable,
+    PayloadTooLarge,
+}
+
+impl ErrorCode {
+    pub const ALL: [ErrorCode; 24] = [
+        ErrorCode::BadRequest,
+        ErrorCode::ValidationFailed,
+        ErrorCode::Unauthorized,
+        ErrorCode::InvalidCredentials,
+        ErrorCode::InvalidToken,
+        ErrorCode::ExpiredToken,
+        ErrorCode::NotFound,
+        ErrorCode::UserNotFound,
+        ErrorCode::EmailTaken,
+        ErrorCode::Conflict,
+        ErrorCode::ConcurrentModification,
+        ErrorCode::PreconditionFailed,
+        ErrorCode::UnsupportedMediaType,
+        ErrorCode::InternalServerError,
+        ErrorCode::Forbidden,
+        ErrorCode::TooManyRequests,
+        ErrorCode::AccountLocked,
+        ErrorCode::InvalidTwoFactorCode,
+        ErrorCode::PasskeyChallengeInvalid,
+        ErrorCode::PasskeyRejected,
+        ErrorCode::OidcStateInvalid,
+        ErrorCode::OidcIdentityRejected,
+        ErrorCode::UpstreamUnavailable,
+        ErrorCode::PayloadTooLarge,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::BadRequest => "BAD_REQUEST",
+            Self::ValidationFailed => "VALIDATION_FAILED",
+            Self::Unauthorized => "UNAUTHORIZED",
+            Self::InvalidCredentials => "INVALID_CREDENTIALS",
+            Self::InvalidToken => "INVALID_TOKEN",
+            Self::ExpiredToken => "EXPIRED_TOKEN",
+            Self::NotFound => "NOT_FOUND",
+            Self::UserNotFound => "USER_NOT_FOUND",
+            Self::EmailTaken => "EMAIL_TAKEN",
+            Self::Conflict => "CONFLICT",
+            Self::ConcurrentModification => "CONCURRENT_MODIFICATION",
+            Self::PreconditionFailed => "PRECONDITION_FAILED",
+            Self::UnsupportedMediaType => "UNSUPPORTED_MEDIA_TYPE",
+            Self::InternalServerError => "INTERNAL_SERVER_ERROR",
+            Self::Forbidden => "FORBIDDEN",
+            Self::TooManyRequests => "TOO_MANY_REQUESTS",
+            Self::AccountLocked => "ACCOUNT_LOCKED",
+            Self::InvalidTwoFactorCode => "INVALID_TWO_FACTOR_CODE",
+            Self::PasskeyChallengeInvalid => "PASSKEY_CHALLENGE_INVALID",
+            Self::PasskeyRejected => "PASSKEY_REJECTED",
+            Self::OidcStateInvalid => "OIDC_STATE_INVALID",
+            Self::OidcIdentityRejected => "OIDC_IDENTITY_REJECTED",
+            Self::UpstreamUnavailable => "UPSTREAM_UNAVAILABLE",
+            Self::PayloadTooLarge => "PAYLOAD_TOO_LARGE",
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Renaming a code breaks clients. If this test fails, add a new code instead.
+    #[test]
+    fn test_error_codes_are_stable() {
+        let codes: Vec<&str> = ErrorCode::ALL.iter().map(ErrorCode::as_str).collect();
+
+        assert_eq!(
+            codes,
+            vec![
+                "BAD_REQUEST",
+                "VALIDATION_FAILED",
+                "UNAUTHORIZED",
+                "INVALID_CREDENTIALS",
+                "INVALID_TOKEN",
+                "EXPIRED_TOKEN",
+                "NOT_FOUND",
+                "USER_NOT_FOUND",
+                "EMAIL_TAKEN",
+                "CONFLICT",
+                "CONCURRENT_MODIFICATION",
+                "PRECONDITION_FAILED",
+                "UNSUPPORTED_MEDIA_TYPE",
+                "INTERNAL_SERVER_ERROR",
+                "FORBIDDEN",
+                "TOO_MANY_REQUESTS",
+                "ACCOUNT_LOCKED",
+                "INVALID_TWO_FACTOR_CODE",
+                "PASSKEY_CHALLENGE_INVALID",
+                "PASSKEY_REJECTED",
+                "OIDC_STATE_INVALID",
+                "OIDC_IDENTITY_REJECTED",
+                "UPSTREAM_UNAVAILABLE",
+                "PAYLOAD_TOO_LARGE",
+            ]
+        );
+    }
+}
@@ -13,5 +13,8 @@ mod tests {
     }
 }
 
+pub mod error_code;
 pub mod ports;
 pub mod use_cases;
+
+pub use error_code::ErrorCode;
@@ -0,0 +1,69 @@
+use domain_users::models::user::UserRole;
+use uuid::Uuid;
+
+/// What a token may be used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AccessTokenScope {
+    #[default]
+    Full,
+    /// Password accepted; only good for submitting the TOTP code.
+    TotpChallenge,
+    /// Password accepted for a staff account without TOTP; only good for enrolling.
+    TotpEnrollment,
+}
+
+impl AccessTokenScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::TotpChallenge => "totp_challenge",
+            Self::TotpEnrollment => "totp_enrollment",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "full" => Some(Self::Full),
+            "totp_challenge" => Some(Self::TotpChallenge),
+            "totp_enrollment" => Some(Self::TotpEnrollment),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AccessTokenClaims {
+    pub user_id: Uuid,
+    pub role: UserRole,
+    pub scope: AccessTokenScope,
+    /// Set on full tokens; the token stops working as soon as its session is revoked.
+    pub session_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IssuedAccessToken {
+    pub token: String,
+    pub expires_in_seconds: u64,
+}
+
+pub trait AccessTokenService: Send + Sync {
+    fn issue(&self, claims: &AccessTokenClaims) -> Result<IssuedAccessToken, AccessTokenError>;
+    fn verify(&self, token: &str) -> Result<AccessTokenClaims, AccessTokenError>;
+}
+
+#[derive(Debug)]
+pub enum AccessTokenError {
+    InvalidToken,
+    IssueError(String),
+}
+
+impl std::fmt::Display for AccessTokenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidToken => write!(f, "Invalid access token"),
+            Self::IssueError(msg) => write!(f, "Failed to issue access token: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AccessTokenError {}
@@ -0,0 +1,30 @@
+use crate::ports::TokenRepositoryError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Mailed when an account is deleted; restores it until the grace period ends.
+#[derive(Debug, Clone)]
+pub struct AccountRestoreToken {
+    /// Hash of the token in the link; the token itself is never stored.
+    pub token_hash: String,
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl AccountRestoreToken {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+#[async_trait]
+pub trait AccountRestoreTokenRepository: Send + Sync {
+    async fn create(&self, token: &AccountRestoreToken) -> Result<(), TokenRepositoryError>;
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<AccountRestoreToken>, TokenRepositoryError>;
+    async fn delete_by_user_id(&self, user_id: &Uuid) -> Result<(), TokenRepositoryError>;
+}
@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// What an API key may be used for. Keys are refused on every route that does not
+/// require one of these scopes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiKeyScope {
+    /// Read the profile of the key's owner.
+    ProfileRead,
+    /// Update the profile of the key's owner.
+    ProfileWrite,
+}
+
+impl ApiKeyScope {
+    pub const ALL: [ApiKeyScope; 2] = [ApiKeyScope::ProfileRead, ApiKeyScope::ProfileWrite];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ProfileRead => "profile:read",
+            Self::ProfileWrite => "profile:write",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|scope| scope.as_str() == value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    /// Chosen by the user to tell their keys apart, e.g. "Moodle sync".
+    pub name: String,
+    /// The start of the key, shown in listings so users can recognise it.
+    pub prefix: String,
+    /// Hash of the key; the key itself is only shown once, on creation.
+    pub key_hash: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKey {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    pub fn has_scope(&self, scope: ApiKeyScope) -> bool {
+        self.scopes.contains(&scope)
+    }
+}
+
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    async fn create(&self, api_key: &ApiKey) -> Result<(), ApiKeyRepositoryError>;
+
+    async fn find_by_key_hash(
+        &self,
+        key_hash: &str,
+    ) -> Result<Option<ApiKey>, ApiKeyRepositoryError>;
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<ApiKey>, ApiKeyRepositoryError>;
+
+    async fn record_use(
+        &self,
+        id: Uuid,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), ApiKeyRepositoryError>;
+
+    /// Returns whether a key with this id belonged to the user.
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiKeyRepositoryError>;
+}
+
+#[derive(Debug)]
+pub enum ApiKeyRepositoryError {
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for ApiKeyRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ApiKeyRepositoryError {}
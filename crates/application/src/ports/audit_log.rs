@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::net::IpAddr;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+/// A security-relevant or administrative action. Entries are never changed once
+/// recorded; they are only removed after the retention period.
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    pub id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+    /// The user who acted; `None` for anonymous requests and the system itself.
+    pub actor_id: Option<Uuid>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<Uuid>,
+    pub changes: Vec<FieldChange>,
+    /// Correlation id of the request the action was taken in.
+    pub correlation_id: Option<String>,
+    pub ip_address: Option<IpAddr>,
+}
+
+impl AuditEntry {
+    pub fn new(
+        actor_id: Option<Uuid>,
+        action: &str,
+        target_type: &str,
+        target_id: Option<Uuid>,
+        changes: Vec<FieldChange>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            actor_id,
+            action: action.to_string(),
+            target_type: target_type.to_string(),
+            target_id,
+            changes,
+            correlation_id: None,
+            ip_address: None,
+        }
+    }
+}
+
+/// Narrows an audit log search; unset fields match every entry.
+#[derive(Debug, Clone, Default)]
+pub struct AuditLogFilter {
+    pub actor_id: Option<Uuid>,
+    pub target_id: Option<Uuid>,
+    pub action: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[async_trait]
+pub trait AuditLog: Send + Sync {
+    async fn record(&self, entry: &AuditEntry) -> Result<(), AuditLogError>;
+
+    /// Matching entries, newest first, and the total number of matches.
+    async fn find(
+        &self,
+        filter: &AuditLogFilter,
+        page: u64,
+        page_size: u64,
+    ) -> Result<(Vec<AuditEntry>, u64), AuditLogError>;
+
+    /// Returns the number of entries removed.
+    async fn delete_older_than(&self, cutoff: DateTime<Utc>) -> Result<u64, AuditLogError>;
+}
+
+#[derive(Debug)]
+pub enum AuditLogError {
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for AuditLogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AuditLogError {}
@@ -0,0 +1,51 @@
+use crate::ports::api_key_repository::ApiKey;
+use crate::ports::audit_log::AuditEntry;
+use crate::ports::linked_identity_repository::LinkedIdentity;
+use crate::ports::passkey_repository::Passkey;
+use crate::ports::session_repository::Session;
+use async_trait::async_trait;
+use domain_users::User;
+use uuid::Uuid;
+
+/// Everything stored about a user, as collected for a data export. Secrets such as
+/// password hashes, TOTP secrets and API key hashes are left out by the archive.
+#[derive(Debug, Clone)]
+pub struct UserDataSnapshot {
+    pub user: User,
+    pub sessions: Vec<Session>,
+    pub passkeys: Vec<Passkey>,
+    pub linked_identities: Vec<LinkedIdentity>,
+    pub api_keys: Vec<ApiKey>,
+    /// Entries about the user and actions taken by the user.
+    pub audit_entries: Vec<AuditEntry>,
+}
+
+/// Renders a snapshot into a downloadable archive and keeps it until it expires.
+#[async_trait]
+pub trait DataExportArchive: Send + Sync {
+    async fn store(
+        &self,
+        export_id: Uuid,
+        snapshot: &UserDataSnapshot,
+    ) -> Result<(), DataExportArchiveError>;
+
+    /// The archive's bytes; `None` once it was deleted.
+    async fn open(&self, export_id: Uuid) -> Result<Option<Vec<u8>>, DataExportArchiveError>;
+
+    async fn delete(&self, export_id: Uuid) -> Result<(), DataExportArchiveError>;
+}
+
+#[derive(Debug)]
+pub enum DataExportArchiveError {
+    StorageError(String),
+}
+
+impl std::fmt::Display for DataExportArchiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StorageError(msg) => write!(f, "Archive storage error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for DataExportArchiveError {}
@@ -0,0 +1,127 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataExportStatus {
+    /// Waiting for the export worker.
+    Pending,
+    /// Claimed by a worker that is collecting the data.
+    Processing,
+    /// The archive can be downloaded until `expires_at`.
+    Ready,
+    Failed,
+    /// The download period is over and the archive was removed.
+    Expired,
+}
+
+impl DataExportStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Processing => "processing",
+            Self::Ready => "ready",
+            Self::Failed => "failed",
+            Self::Expired => "expired",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        [
+            Self::Pending,
+            Self::Processing,
+            Self::Ready,
+            Self::Failed,
+            Self::Expired,
+        ]
+        .into_iter()
+        .find(|status| status.as_str() == value)
+    }
+
+    /// Whether the export is still being worked on.
+    pub fn is_open(&self) -> bool {
+        matches!(self, Self::Pending | Self::Processing)
+    }
+}
+
+/// A request for a copy of all data stored about a user (GDPR Art. 15 and 20).
+#[derive(Debug, Clone)]
+pub struct DataExport {
+    pub id: Uuid,
+    /// Whose data is exported; the download link is always sent to this user.
+    pub user_id: Uuid,
+    /// The user themselves, or the admin acting on their behalf.
+    pub requested_by: Uuid,
+    pub status: DataExportStatus,
+    /// Hash of the token in the download link, set once the archive is ready.
+    pub download_token_hash: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl DataExport {
+    pub fn is_downloadable(&self, now: DateTime<Utc>) -> bool {
+        self.status == DataExportStatus::Ready && self.expires_at.is_some_and(|at| at > now)
+    }
+}
+
+#[async_trait]
+pub trait DataExportRepository: Send + Sync {
+    async fn create(&self, export: &DataExport) -> Result<(), DataExportRepositoryError>;
+
+    /// The most recent export of the user that is still pending or processing.
+    async fn find_open_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Option<DataExport>, DataExportRepositoryError>;
+
+    async fn find_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<DataExport>, DataExportRepositoryError>;
+
+    async fn find_by_download_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<DataExport>, DataExportRepositoryError>;
+
+    /// Moves the oldest pending export to processing and returns it. Concurrent
+    /// workers never claim the same export.
+    async fn claim_next_pending(&self) -> Result<Option<DataExport>, DataExportRepositoryError>;
+
+    async fn mark_ready(
+        &self,
+        id: Uuid,
+        download_token_hash: &str,
+        completed_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), DataExportRepositoryError>;
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        completed_at: DateTime<Utc>,
+    ) -> Result<(), DataExportRepositoryError>;
+
+    /// Marks ready exports whose download period ended as expired and returns them.
+    async fn expire(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<DataExport>, DataExportRepositoryError>;
+}
+
+#[derive(Debug)]
+pub enum DataExportRepositoryError {
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for DataExportRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for DataExportRepositoryError {}
@@ -16,6 +16,34 @@ pub trait EmailService: Send + Sync {
         token: &str,
         first_name: &str,
     ) -> Result<(), EmailError>;
+
+    async fn send_account_unlock_email(
+        &self,
+        to: &str,
+        token: &str,
+        first_name: &str,
+    ) -> Result<(), EmailError>;
+
+    async fn send_magic_link_email(
+        &self,
+        to: &str,
+        token: &str,
+        first_name: &str,
+    ) -> Result<(), EmailError>;
+
+    async fn send_account_restore_email(
+        &self,
+        to: &str,
+        token: &str,
+        first_name: &str,
+    ) -> Result<(), EmailError>;
+
+    async fn send_data_export_ready_email(
+        &self,
+        to: &str,
+        token: &str,
+        first_name: &str,
+    ) -> Result<(), EmailError>;
 }
 
 #[derive(Debug)]
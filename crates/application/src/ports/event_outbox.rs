@@ -0,0 +1,60 @@
+use crate::ports::event_publisher::EventHandlerError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain_users::UserEvent;
+use uuid::Uuid;
+
+/// A published event as stored in the outbox. `position` grows with every event,
+/// so a consumer only has to remember the last position it processed.
+#[derive(Debug, Clone)]
+pub struct OutboxEvent {
+    pub position: i64,
+    pub id: Uuid,
+    /// The event name, e.g. `user.signed_up`.
+    pub name: String,
+    pub aggregate_id: Uuid,
+    /// The event serialized as a JSON object.
+    pub payload: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+#[async_trait]
+pub trait EventOutbox: Send + Sync {
+    async fn append(&self, events: &[UserEvent]) -> Result<(), EventOutboxError>;
+
+    /// Events after `position`, oldest first.
+    async fn read_after(
+        &self,
+        position: i64,
+        limit: u64,
+    ) -> Result<Vec<OutboxEvent>, EventOutboxError>;
+
+    /// The last position `consumer` processed, or 0 if it never committed one.
+    async fn consumer_position(&self, consumer: &str) -> Result<i64, EventOutboxError>;
+
+    async fn commit_position(&self, consumer: &str, position: i64) -> Result<(), EventOutboxError>;
+}
+
+/// Processes outbox events asynchronously, at least once each and in order.
+#[async_trait]
+pub trait OutboxConsumer: Send + Sync {
+    /// Identifies the consumer's position in the outbox; must stay stable.
+    fn name(&self) -> &str;
+
+    async fn consume(&self, event: &OutboxEvent) -> Result<(), EventHandlerError>;
+}
+
+#[derive(Debug)]
+pub enum EventOutboxError {
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for EventOutboxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for EventOutboxError {}
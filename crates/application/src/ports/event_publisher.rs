@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use domain_users::UserEvent;
+
+/// Hands the events recorded by an aggregate to the in-process handlers.
+/// Use cases publish only after the transaction that stored the change, and appended
+/// the events to the outbox, has committed, so publishing cannot fail the request.
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    async fn publish(&self, events: &[UserEvent]);
+}
+
+/// Reacts to events in-process, while the publishing request is still running.
+#[async_trait]
+pub trait EventHandler: Send + Sync {
+    async fn handle(&self, event: &UserEvent) -> Result<(), EventHandlerError>;
+}
+
+#[derive(Debug)]
+pub struct EventHandlerError(pub String);
+
+impl std::fmt::Display for EventHandlerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Event handler error: {}", self.0)
+    }
+}
+
+impl std::error::Error for EventHandlerError {}
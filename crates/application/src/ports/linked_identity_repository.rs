@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// An account at an external identity provider that can log in as `user_id`.
+#[derive(Debug, Clone)]
+pub struct LinkedIdentity {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub provider: String,
+    pub subject: String,
+    /// Email the provider reported when the identity was linked.
+    pub email: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[async_trait]
+pub trait LinkedIdentityRepository: Send + Sync {
+    async fn create(&self, identity: &LinkedIdentity) -> Result<(), LinkedIdentityRepositoryError>;
+
+    async fn find_by_subject(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> Result<Option<LinkedIdentity>, LinkedIdentityRepositoryError>;
+
+    async fn find_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<LinkedIdentity>, LinkedIdentityRepositoryError>;
+}
+
+#[derive(Debug)]
+pub enum LinkedIdentityRepositoryError {
+    /// The provider account is already linked to a user.
+    AlreadyLinked,
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for LinkedIdentityRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadyLinked => write!(f, "Identity is already linked"),
+            Self::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for LinkedIdentityRepositoryError {}
@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain_users::EmailAddress;
+use std::net::IpAddr;
+
+/// What failed login attempts are counted against.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AttemptSubject {
+    /// The email address as submitted (normalized like [`EmailAddress`]), whether or not
+    /// an account exists.
+    Account(String),
+    Ip(IpAddr),
+}
+
+impl AttemptSubject {
+    pub fn account(email: &str) -> Self {
+        Self::Account(match EmailAddress::parse(email) {
+            Ok(email) => email.into_string(),
+            Err(_) => email.trim().to_lowercase(),
+        })
+    }
+
+    /// Storage key, e.g. `account:john@example.com` or `ip:203.0.113.7`.
+    pub fn key(&self) -> String {
+        match self {
+            Self::Account(email) => format!("account:{}", email),
+            Self::Ip(ip) => format!("ip:{}", ip),
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        let (kind, value) = key.split_once(':')?;
+        match kind {
+            "account" => Some(Self::Account(value.to_string())),
+            "ip" => value.parse().ok().map(Self::Ip),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LoginAttempts {
+    pub subject: AttemptSubject,
+    /// Consecutive failures within the current failure window.
+    pub failed_attempts: u32,
+    pub last_failed_at: DateTime<Utc>,
+    /// Short exponential backoff after a failure.
+    pub blocked_until: Option<DateTime<Utc>>,
+    /// Lockout after too many failures; lifted by time, by the unlock link or by an admin.
+    pub locked_until: Option<DateTime<Utc>>,
+    /// Hash of the token mailed with an account lockout; the token itself is not stored.
+    pub unlock_token_hash: Option<String>,
+}
+
+impl LoginAttempts {
+    /// Seconds until login may be attempted again, if currently blocked or locked.
+    pub fn retry_after(&self, now: DateTime<Utc>) -> Option<u64> {
+        [self.locked_until, self.blocked_until]
+            .into_iter()
+            .flatten()
+            .filter(|until| *until > now)
+            .max()
+            .map(|until| (until - now).num_seconds().max(1) as u64)
+    }
+
+    pub fn is_locked(&self, now: DateTime<Utc>) -> bool {
+        self.locked_until.is_some_and(|until| until > now)
+    }
+}
+
+#[async_trait]
+pub trait LoginAttemptStore: Send + Sync {
+    async fn find(
+        &self,
+        subject: &AttemptSubject,
+    ) -> Result<Option<LoginAttempts>, LoginAttemptStoreError>;
+
+    /// Atomically counts a failure at `at`. Failures before `window_start` are forgotten,
+    /// so the returned count restarts at 1 after a quiet period.
+    async fn record_failure(
+        &self,
+        subject: &AttemptSubject,
+        at: DateTime<Utc>,
+        window_start: DateTime<Utc>,
+    ) -> Result<LoginAttempts, LoginAttemptStoreError>;
+
+    /// Stores block, lock and unlock token hash of an existing entry.
+    async fn save(&self, attempts: &LoginAttempts) -> Result<(), LoginAttemptStoreError>;
+
+    /// Forgets all failures of a subject. Returns whether there was anything to clear.
+    async fn clear(&self, subject: &AttemptSubject) -> Result<bool, LoginAttemptStoreError>;
+
+    async fn find_by_unlock_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<LoginAttempts>, LoginAttemptStoreError>;
+
+    /// Entries that are blocked or locked at `now`.
+    async fn find_restricted(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<LoginAttempts>, LoginAttemptStoreError>;
+}
+
+#[derive(Debug)]
+pub enum LoginAttemptStoreError {
+    StorageError(String),
+}
+
+impl std::fmt::Display for LoginAttemptStoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StorageError(msg) => write!(f, "Login attempt storage error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for LoginAttemptStoreError {}
@@ -0,0 +1,31 @@
+use crate::ports::TokenRepositoryError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct MagicLinkToken {
+    /// Hash of the token in the link; the token itself is never stored.
+    pub token_hash: String,
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl MagicLinkToken {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+#[async_trait]
+pub trait MagicLinkTokenRepository: Send + Sync {
+    async fn create(&self, token: &MagicLinkToken) -> Result<(), TokenRepositoryError>;
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<MagicLinkToken>, TokenRepositoryError>;
+    /// Returns whether the token still existed, so only one request can redeem it.
+    async fn delete_by_token_hash(&self, token_hash: &str) -> Result<bool, TokenRepositoryError>;
+    async fn delete_by_user_id(&self, user_id: &Uuid) -> Result<(), TokenRepositoryError>;
+}
@@ -1,16 +1,82 @@
+pub mod access_token_service;
+pub mod account_restore_token_repository;
+pub mod api_key_repository;
+pub mod audit_log;
+pub mod data_export_archive;
+pub mod data_export_repository;
 pub mod email_service;
 pub mod email_verification_token_repository;
+pub mod event_outbox;
+pub mod event_publisher;
+pub mod linked_identity_repository;
+pub mod login_attempt_store;
+pub mod magic_link_token_repository;
+pub mod oidc_client;
+pub mod oidc_flow_store;
+pub mod one_time_token_service;
+pub mod passkey_ceremony_store;
+pub mod passkey_repository;
 pub mod password_hasher;
 pub mod password_reset_token_repository;
+pub mod session_repository;
+pub mod totp_service;
+pub mod two_factor_repository;
+pub mod unit_of_work;
+pub mod user_erasure_repository;
 pub mod user_repository;
+pub mod webauthn_service;
+pub mod webhook_repository;
+pub mod webhook_sender;
 
+pub use access_token_service::{
+    AccessTokenClaims, AccessTokenError, AccessTokenScope, AccessTokenService, IssuedAccessToken,
+};
+pub use account_restore_token_repository::{AccountRestoreToken, AccountRestoreTokenRepository};
+pub use api_key_repository::{ApiKey, ApiKeyRepository, ApiKeyRepositoryError, ApiKeyScope};
+pub use audit_log::{AuditEntry, AuditLog, AuditLogError, AuditLogFilter, FieldChange};
+pub use data_export_archive::{DataExportArchive, DataExportArchiveError, UserDataSnapshot};
+pub use data_export_repository::{
+    DataExport, DataExportRepository, DataExportRepositoryError, DataExportStatus,
+};
 pub use email_service::{EmailError, EmailService};
 pub use email_verification_token_repository::{
     EmailVerificationToken, EmailVerificationTokenRepository,
 };
+pub use event_outbox::{EventOutbox, EventOutboxError, OutboxConsumer, OutboxEvent};
+pub use event_publisher::{EventHandler, EventHandlerError, EventPublisher};
+pub use linked_identity_repository::{
+    LinkedIdentity, LinkedIdentityRepository, LinkedIdentityRepositoryError,
+};
+pub use login_attempt_store::{
+    AttemptSubject, LoginAttemptStore, LoginAttemptStoreError, LoginAttempts,
+};
+pub use magic_link_token_repository::{MagicLinkToken, MagicLinkTokenRepository};
+pub use oidc_client::{OidcAuthorization, OidcClient, OidcError, OidcIdentity};
+pub use oidc_flow_store::{
+    OidcFlowStore, OidcFlowStoreError, PendingIdentityLink, PendingOidcLogin,
+};
+pub use one_time_token_service::OneTimeTokenService;
+pub use passkey_ceremony_store::{
+    PasskeyCeremony, PasskeyCeremonyKind, PasskeyCeremonyStore, PasskeyCeremonyStoreError,
+};
+pub use passkey_repository::{Passkey, PasskeyRepository, PasskeyRepositoryError};
 pub use password_hasher::{PasswordHasher, PasswordHasherError};
 pub use password_reset_token_repository::{PasswordResetToken, PasswordResetTokenRepository};
+pub use session_repository::{Session, SessionRepository, SessionRepositoryError};
+pub use totp_service::{TotpError, TotpService};
+pub use two_factor_repository::{TotpEnrollment, TwoFactorRepository, TwoFactorRepositoryError};
+pub use unit_of_work::{Transaction, UnitOfWork, UnitOfWorkError};
+pub use user_erasure_repository::{UserErasureRepository, UserErasureRepositoryError};
 pub use user_repository::{UserRepository, UserRepositoryError};
+pub use webauthn_service::{
+    PasskeyAssertionResponse, PasskeyRegistrationResponse, VerifiedPasskey, WebauthnError,
+    WebauthnService,
+};
+pub use webhook_repository::{
+    WebhookDelivery, WebhookDeliveryStatus, WebhookRepository, WebhookRepositoryError,
+    WebhookSubscription,
+};
+pub use webhook_sender::{WebhookSendError, WebhookSender};
 
 #[derive(Debug)]
 pub enum TokenRepositoryError {
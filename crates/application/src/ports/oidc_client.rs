@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+
+/// A started authorization code flow. `state`, `nonce` and `code_verifier` must be kept
+/// until the provider redirects back, the URL is where the browser is sent.
+#[derive(Debug, Clone)]
+pub struct OidcAuthorization {
+    pub authorization_url: String,
+    pub state: String,
+    pub nonce: String,
+    /// PKCE verifier; only its hash is part of the authorization URL.
+    pub code_verifier: String,
+}
+
+/// Claims of a validated ID token.
+#[derive(Debug, Clone)]
+pub struct OidcIdentity {
+    /// Stable id of the account at the provider; emails can change, this cannot.
+    pub subject: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+}
+
+/// An OpenID Connect relying party for the configured providers, e.g. `google`.
+#[async_trait]
+pub trait OidcClient: Send + Sync {
+    async fn authorize(&self, provider: &str) -> Result<OidcAuthorization, OidcError>;
+
+    /// Redeems the authorization code and validates the returned ID token: signature,
+    /// issuer, audience, expiry and that it carries `nonce`.
+    async fn exchange_code(
+        &self,
+        provider: &str,
+        code: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> Result<OidcIdentity, OidcError>;
+}
+
+#[derive(Debug)]
+pub enum OidcError {
+    UnknownProvider,
+    /// Discovery, key or token endpoint could not be reached or answered garbage.
+    ProviderUnavailable(String),
+    /// The code was refused or the ID token failed validation.
+    Rejected(String),
+}
+
+impl std::fmt::Display for OidcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownProvider => write!(f, "Unknown identity provider"),
+            Self::ProviderUnavailable(msg) => write!(f, "Identity provider unavailable: {}", msg),
+            Self::Rejected(msg) => write!(f, "Identity rejected: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for OidcError {}
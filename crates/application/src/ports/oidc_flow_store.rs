@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// An authorization request waiting for the provider's redirect.
+#[derive(Debug, Clone)]
+pub struct PendingOidcLogin {
+    pub state: String,
+    pub provider: String,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl PendingOidcLogin {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+/// A provider account whose verified email matches an existing user. It is only linked
+/// once that user, logged in by other means, confirms it.
+#[derive(Debug, Clone)]
+pub struct PendingIdentityLink {
+    /// Hash of the token handed to the client; the token itself is never stored.
+    pub token_hash: String,
+    pub user_id: Uuid,
+    pub provider: String,
+    pub subject: String,
+    pub email: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl PendingIdentityLink {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+#[async_trait]
+pub trait OidcFlowStore: Send + Sync {
+    async fn save_login(&self, login: &PendingOidcLogin) -> Result<(), OidcFlowStoreError>;
+
+    /// Removes and returns the login, so every `state` can be redeemed only once.
+    async fn take_login(&self, state: &str)
+    -> Result<Option<PendingOidcLogin>, OidcFlowStoreError>;
+
+    async fn save_link(&self, link: &PendingIdentityLink) -> Result<(), OidcFlowStoreError>;
+
+    /// Removes and returns the link, so it can be confirmed only once.
+    async fn take_link(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PendingIdentityLink>, OidcFlowStoreError>;
+}
+
+#[derive(Debug)]
+pub enum OidcFlowStoreError {
+    StorageError(String),
+}
+
+impl std::fmt::Display for OidcFlowStoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StorageError(msg) => write!(f, "OIDC flow storage error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for OidcFlowStoreError {}
@@ -0,0 +1,8 @@
+/// Random bearer secrets that are mailed or shown once and only stored as a hash,
+/// so a database leak does not hand out working links.
+pub trait OneTimeTokenService: Send + Sync {
+    fn generate(&self) -> String;
+
+    /// Deterministic, so a presented token can be looked up by its hash.
+    fn hash(&self, token: &str) -> String;
+}
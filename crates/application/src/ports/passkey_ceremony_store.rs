@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasskeyCeremonyKind {
+    Registration,
+    Authentication,
+}
+
+impl PasskeyCeremonyKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Registration => "registration",
+            Self::Authentication => "authentication",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "registration" => Some(Self::Registration),
+            "authentication" => Some(Self::Authentication),
+            _ => None,
+        }
+    }
+}
+
+/// The challenge of a started registration or login, kept until the browser answers.
+#[derive(Debug, Clone)]
+pub struct PasskeyCeremony {
+    pub id: Uuid,
+    pub kind: PasskeyCeremonyKind,
+    /// The registering user; `None` for logins, where the passkey names the user.
+    pub user_id: Option<Uuid>,
+    pub challenge: Vec<u8>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl PasskeyCeremony {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+#[async_trait]
+pub trait PasskeyCeremonyStore: Send + Sync {
+    async fn save(&self, ceremony: &PasskeyCeremony) -> Result<(), PasskeyCeremonyStoreError>;
+
+    /// Removes and returns the ceremony, so every challenge can be answered only once.
+    async fn take(&self, id: Uuid) -> Result<Option<PasskeyCeremony>, PasskeyCeremonyStoreError>;
+}
+
+#[derive(Debug)]
+pub enum PasskeyCeremonyStoreError {
+    StorageError(String),
+}
+
+impl std::fmt::Display for PasskeyCeremonyStoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StorageError(msg) => write!(f, "Passkey ceremony storage error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for PasskeyCeremonyStoreError {}
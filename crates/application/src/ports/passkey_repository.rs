@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct Passkey {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub credential_id: Vec<u8>,
+    pub public_key: Vec<u8>,
+    pub sign_count: u32,
+    /// Chosen by the user to tell their passkeys apart, e.g. "iPhone".
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+#[async_trait]
+pub trait PasskeyRepository: Send + Sync {
+    async fn create(&self, passkey: &Passkey) -> Result<(), PasskeyRepositoryError>;
+
+    async fn find_by_credential_id(
+        &self,
+        credential_id: &[u8],
+    ) -> Result<Option<Passkey>, PasskeyRepositoryError>;
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Passkey>, PasskeyRepositoryError>;
+
+    async fn record_use(
+        &self,
+        id: Uuid,
+        sign_count: u32,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), PasskeyRepositoryError>;
+
+    /// Returns whether a passkey with this id belonged to the user.
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, PasskeyRepositoryError>;
+}
+
+#[derive(Debug)]
+pub enum PasskeyRepositoryError {
+    /// A passkey with this credential id is already registered.
+    DuplicateCredential,
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for PasskeyRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DuplicateCredential => write!(f, "Passkey is already registered"),
+            Self::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for PasskeyRepositoryError {}
@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::net::IpAddr;
+use uuid::Uuid;
+
+/// A signed in device: opened by every login that ends in a full access token, which
+/// carries the session id. It lasts as long as that token unless it is revoked first.
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<IpAddr>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl Session {
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+}
+
+#[async_trait]
+pub trait SessionRepository: Send + Sync {
+    async fn create(&self, session: &Session) -> Result<(), SessionRepositoryError>;
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Session>, SessionRepositoryError>;
+
+    /// Sessions of the user that are neither revoked nor expired, most recently seen first.
+    async fn find_active_by_user_id(
+        &self,
+        user_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Session>, SessionRepositoryError>;
+
+    /// Every session of the user, including revoked and expired ones, newest first.
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Session>, SessionRepositoryError>;
+
+    async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>) -> Result<(), SessionRepositoryError>;
+
+    /// Returns whether an active session with this id belonged to the user.
+    async fn revoke(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<bool, SessionRepositoryError>;
+
+    /// Returns the number of sessions that were revoked.
+    async fn revoke_all(
+        &self,
+        user_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<u64, SessionRepositoryError>;
+}
+
+#[derive(Debug)]
+pub enum SessionRepositoryError {
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for SessionRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SessionRepositoryError {}
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+
+/// Time-based one-time passwords (RFC 6238) and recovery codes for the second login factor.
+pub trait TotpService: Send + Sync {
+    /// A new random shared secret, base32 encoded.
+    fn generate_secret(&self) -> String;
+
+    /// `otpauth://` URI for authenticator apps, usually rendered as a QR code.
+    fn provisioning_uri(&self, secret: &str, account_name: &str) -> Result<String, TotpError>;
+
+    /// The time step `code` belongs to if it is valid at `at`, allowing one step of clock skew.
+    fn verify(&self, secret: &str, code: &str, at: DateTime<Utc>)
+    -> Result<Option<u64>, TotpError>;
+
+    fn generate_recovery_codes(&self, count: usize) -> Vec<String>;
+
+    /// Recovery codes are random, so a fast one-way hash is enough to store them.
+    fn hash_recovery_code(&self, code: &str) -> String;
+}
+
+#[derive(Debug)]
+pub enum TotpError {
+    InvalidSecret(String),
+}
+
+impl std::fmt::Display for TotpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidSecret(msg) => write!(f, "Invalid TOTP secret: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for TotpError {}
@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct TotpEnrollment {
+    pub user_id: Uuid,
+    pub secret: String,
+    /// `None` until the user proved possession of the secret with a first code.
+    pub confirmed_at: Option<DateTime<Utc>>,
+    /// Time step of the last accepted code; codes cannot be replayed within their window.
+    pub last_used_step: Option<u64>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TotpEnrollment {
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed_at.is_some()
+    }
+}
+
+#[async_trait]
+pub trait TwoFactorRepository: Send + Sync {
+    async fn find(&self, user_id: Uuid)
+    -> Result<Option<TotpEnrollment>, TwoFactorRepositoryError>;
+
+    /// Inserts or replaces the enrollment of the user.
+    async fn save(&self, enrollment: &TotpEnrollment) -> Result<(), TwoFactorRepositoryError>;
+
+    /// Atomically records `step` as used. Returns false if it is not newer than the last
+    /// used step, i.e. the code was already used.
+    async fn mark_step_used(
+        &self,
+        user_id: Uuid,
+        step: u64,
+    ) -> Result<bool, TwoFactorRepositoryError>;
+
+    /// Replaces all recovery codes of the user with the given hashes.
+    async fn replace_recovery_codes(
+        &self,
+        user_id: Uuid,
+        code_hashes: &[String],
+    ) -> Result<(), TwoFactorRepositoryError>;
+
+    /// Atomically consumes an unused recovery code. Returns false if none matched.
+    async fn use_recovery_code(
+        &self,
+        user_id: Uuid,
+        code_hash: &str,
+    ) -> Result<bool, TwoFactorRepositoryError>;
+}
+
+#[derive(Debug)]
+pub enum TwoFactorRepositoryError {
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for TwoFactorRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for TwoFactorRepositoryError {}
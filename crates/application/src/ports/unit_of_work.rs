@@ -0,0 +1,50 @@
+use crate::ports::audit_log::AuditLog;
+use crate::ports::email_verification_token_repository::EmailVerificationTokenRepository;
+use crate::ports::event_outbox::EventOutbox;
+use crate::ports::session_repository::SessionRepository;
+use crate::ports::user_repository::UserRepository;
+use async_trait::async_trait;
+
+/// Starts transactions, for use cases whose writes to several repositories must
+/// be stored together or not at all.
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    async fn begin(&self) -> Result<Box<dyn Transaction>, UnitOfWorkError>;
+}
+
+/// Repositories that write inside one transaction. Nothing they write is visible
+/// to others until [`Transaction::commit`]; a transaction dropped without being
+/// committed is rolled back.
+#[async_trait]
+pub trait Transaction: Send + Sync {
+    fn users(&self) -> &dyn UserRepository;
+
+    fn email_verification_tokens(&self) -> &dyn EmailVerificationTokenRepository;
+
+    fn audit_log(&self) -> &dyn AuditLog;
+
+    fn sessions(&self) -> &dyn SessionRepository;
+
+    /// Events appended here become visible to outbox consumers only if the
+    /// transaction commits, together with the change that raised them.
+    fn event_outbox(&self) -> &dyn EventOutbox;
+
+    async fn commit(self: Box<Self>) -> Result<(), UnitOfWorkError>;
+
+    async fn rollback(self: Box<Self>) -> Result<(), UnitOfWorkError>;
+}
+
+#[derive(Debug)]
+pub enum UnitOfWorkError {
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for UnitOfWorkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for UnitOfWorkError {}
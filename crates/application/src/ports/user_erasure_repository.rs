@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Permanently removes deleted accounts once they can no longer be restored.
+#[async_trait]
+pub trait UserErasureRepository: Send + Sync {
+    /// Ids of users deleted before the cutoff, oldest deletion first.
+    async fn find_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<Uuid>, UserErasureRepositoryError>;
+
+    /// Removes the user with everything that belongs to them and redacts their
+    /// personal data from the audit log, leaving a tombstone with the user id and
+    /// dates. Returns false if the user is gone or was not deleted before the
+    /// cutoff, e.g. because they were restored in the meantime.
+    async fn erase(
+        &self,
+        user_id: Uuid,
+        deleted_before: DateTime<Utc>,
+        erased_at: DateTime<Utc>,
+    ) -> Result<bool, UserErasureRepositoryError>;
+}
+
+#[derive(Debug)]
+pub enum UserErasureRepositoryError {
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for UserErasureRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for UserErasureRepositoryError {}
@@ -22,6 +22,7 @@ pub enum UserRepositoryError {
     DatabaseError(String),
     AlreadyExists(String),
     NotFound(String),
+    VersionConflict(String),
 }
 
 impl std::fmt::Display for UserRepositoryError {
@@ -30,6 +31,7 @@ impl std::fmt::Display for UserRepositoryError {
             Self::DatabaseError(msg) => write!(f, "Database error: {}", msg),
             Self::AlreadyExists(msg) => write!(f, "User already exists: {}", msg),
             Self::NotFound(msg) => write!(f, "User not found: {}", msg),
+            Self::VersionConflict(msg) => write!(f, "User was modified concurrently: {}", msg),
         }
     }
 }
@@ -0,0 +1,70 @@
+/// Response of `navigator.credentials.create()`, fields base64url-decoded.
+#[derive(Debug, Clone)]
+pub struct PasskeyRegistrationResponse {
+    pub credential_id: Vec<u8>,
+    pub client_data_json: Vec<u8>,
+    pub attestation_object: Vec<u8>,
+}
+
+/// Response of `navigator.credentials.get()`, fields base64url-decoded.
+#[derive(Debug, Clone)]
+pub struct PasskeyAssertionResponse {
+    pub credential_id: Vec<u8>,
+    pub client_data_json: Vec<u8>,
+    pub authenticator_data: Vec<u8>,
+    pub signature: Vec<u8>,
+    /// The user handle the passkey was registered with; sent by discoverable credentials.
+    pub user_handle: Option<Vec<u8>>,
+}
+
+/// A credential that passed the registration ceremony.
+#[derive(Debug, Clone)]
+pub struct VerifiedPasskey {
+    pub credential_id: Vec<u8>,
+    /// Public key in the encoding the service verifies assertions with.
+    pub public_key: Vec<u8>,
+    pub sign_count: u32,
+}
+
+/// WebAuthn relying party operations: checks what the browser and authenticator return
+/// against the challenge we issued, our origins and our relying party id.
+pub trait WebauthnService: Send + Sync {
+    fn generate_challenge(&self) -> Vec<u8>;
+
+    /// COSE algorithm identifiers of the supported public key types, most preferred first.
+    fn supported_algorithms(&self) -> Vec<i64>;
+
+    fn verify_registration(
+        &self,
+        response: &PasskeyRegistrationResponse,
+        challenge: &[u8],
+    ) -> Result<VerifiedPasskey, WebauthnError>;
+
+    /// Returns the authenticator's new signature counter.
+    fn verify_assertion(
+        &self,
+        response: &PasskeyAssertionResponse,
+        challenge: &[u8],
+        public_key: &[u8],
+        stored_sign_count: u32,
+    ) -> Result<u32, WebauthnError>;
+}
+
+#[derive(Debug)]
+pub enum WebauthnError {
+    /// The response is malformed or does not match the ceremony.
+    Rejected(String),
+    /// The signature counter did not increase, which hints at a cloned authenticator.
+    CounterRegression,
+}
+
+impl std::fmt::Display for WebauthnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rejected(msg) => write!(f, "Passkey rejected: {}", msg),
+            Self::CounterRegression => write!(f, "Passkey signature counter did not increase"),
+        }
+    }
+}
+
+impl std::error::Error for WebauthnError {}
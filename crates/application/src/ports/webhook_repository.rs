@@ -0,0 +1,155 @@
+use crate::ports::event_outbox::OutboxEvent;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// A partner endpoint that is sent the events it subscribed to.
+#[derive(Debug, Clone)]
+pub struct WebhookSubscription {
+    pub id: Uuid,
+    pub url: String,
+    /// Event names, e.g. `user.deleted`.
+    pub events: Vec<String>,
+    /// Key for the signature of every delivery; shared with the partner once, on
+    /// creation.
+    pub secret: String,
+    /// The admin who created the subscription.
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    pub fn accepts(&self, event_name: &str) -> bool {
+        self.events.iter().any(|name| name == event_name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookDeliveryStatus {
+    /// Waiting for its first or next attempt.
+    Pending,
+    /// The endpoint answered with a 2xx status.
+    Succeeded,
+    /// Every attempt failed; only a manual redelivery sends it again.
+    Failed,
+}
+
+impl WebhookDeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        [Self::Pending, Self::Succeeded, Self::Failed]
+            .into_iter()
+            .find(|status| status.as_str() == value)
+    }
+}
+
+/// One event on its way to one subscription, with the outcome of the last attempt.
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    pub event: OutboxEvent,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: u32,
+    /// Set while the delivery is pending.
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    /// HTTP status of the last response, if the endpoint answered at all.
+    pub response_status: Option<u16>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookDelivery {
+    pub fn new(subscription_id: Uuid, event: OutboxEvent, now: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            subscription_id,
+            event,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: Some(now),
+            last_attempt_at: None,
+            response_status: None,
+            last_error: None,
+            created_at: now,
+        }
+    }
+}
+
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn create_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), WebhookRepositoryError>;
+
+    async fn find_subscription(
+        &self,
+        id: Uuid,
+    ) -> Result<Option<WebhookSubscription>, WebhookRepositoryError>;
+
+    /// Oldest first.
+    async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, WebhookRepositoryError>;
+
+    /// Removes the subscription with its deliveries. Returns whether it existed.
+    async fn delete_subscription(&self, id: Uuid) -> Result<bool, WebhookRepositoryError>;
+
+    /// Stores new deliveries. A delivery of the same event to the same subscription
+    /// that already exists is kept as is, so an event relayed twice is sent once.
+    async fn create_deliveries(
+        &self,
+        deliveries: &[WebhookDelivery],
+    ) -> Result<(), WebhookRepositoryError>;
+
+    /// Pending deliveries due at `now`, oldest first. They are not handed out again
+    /// for `lease`, so concurrent workers never send the same delivery, and one
+    /// a crashed worker claimed is retried afterwards.
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease: Duration,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, WebhookRepositoryError>;
+
+    async fn find_delivery(
+        &self,
+        id: Uuid,
+    ) -> Result<Option<WebhookDelivery>, WebhookRepositoryError>;
+
+    /// Deliveries of the subscription, newest first, with the total count.
+    async fn list_deliveries(
+        &self,
+        subscription_id: Uuid,
+        page: u64,
+        page_size: u64,
+    ) -> Result<(Vec<WebhookDelivery>, u64), WebhookRepositoryError>;
+
+    /// Stores the status and attempt fields of the delivery.
+    async fn update_delivery(
+        &self,
+        delivery: &WebhookDelivery,
+    ) -> Result<(), WebhookRepositoryError>;
+}
+
+#[derive(Debug)]
+pub enum WebhookRepositoryError {
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for WebhookRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for WebhookRepositoryError {}
@@ -0,0 +1,30 @@
+use crate::ports::event_outbox::OutboxEvent;
+use async_trait::async_trait;
+
+/// Posts an event to a webhook endpoint, signed with the subscription's secret.
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// Returns the HTTP status of the response, whatever it is. Fails only when no
+    /// response arrived, e.g. on connection errors and timeouts.
+    async fn send(
+        &self,
+        url: &str,
+        secret: &str,
+        event: &OutboxEvent,
+    ) -> Result<u16, WebhookSendError>;
+}
+
+#[derive(Debug)]
+pub enum WebhookSendError {
+    Unreachable(String),
+}
+
+impl std::fmt::Display for WebhookSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unreachable(msg) => write!(f, "Endpoint unreachable: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for WebhookSendError {}
@@ -0,0 +1,112 @@
+use crate::error_code::ErrorCode;
+use crate::ports::api_key_repository::{ApiKeyRepository, ApiKeyRepositoryError, ApiKeyScope};
+use crate::ports::one_time_token_service::OneTimeTokenService;
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use chrono::{Duration, Utc};
+use domain_users::models::user::UserRole;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Uses within this long of the last recorded one do not update `last_used_at`.
+const LAST_USED_RESOLUTION: Duration = Duration::minutes(1);
+
+/// The owner of a valid API key, acting with the key's scopes.
+#[derive(Debug, Clone)]
+pub struct ApiKeyPrincipal {
+    pub api_key_id: Uuid,
+    pub user_id: Uuid,
+    pub role: UserRole,
+    pub scopes: Vec<ApiKeyScope>,
+}
+
+/// Resolves the key of an `Authorization: ApiKey ...` header. Keys act with the
+/// owner's current role and stop working once the owner may no longer hold keys.
+pub struct AuthenticateApiKeyUseCase {
+    api_key_repo: Arc<dyn ApiKeyRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    token_service: Arc<dyn OneTimeTokenService>,
+}
+
+impl AuthenticateApiKeyUseCase {
+    pub fn new(
+        api_key_repo: Arc<dyn ApiKeyRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        token_service: Arc<dyn OneTimeTokenService>,
+    ) -> Self {
+        Self {
+            api_key_repo,
+            user_repo,
+            token_service,
+        }
+    }
+
+    pub async fn execute(&self, key: &str) -> Result<ApiKeyPrincipal, AuthenticateApiKeyError> {
+        let now = Utc::now();
+        let api_key = self
+            .api_key_repo
+            .find_by_key_hash(&self.token_service.hash(key.trim()))
+            .await?
+            .filter(|api_key| !api_key.is_expired(now))
+            .ok_or(AuthenticateApiKeyError::InvalidKey)?;
+
+        let user = self
+            .user_repo
+            .find_by_id(api_key.user_id)
+            .await?
+            .filter(|user| !user.is_deleted() && user.role().can_use_api_keys())
+            .ok_or(AuthenticateApiKeyError::InvalidKey)?;
+
+        if api_key
+            .last_used_at
+            .is_none_or(|used_at| now - used_at >= LAST_USED_RESOLUTION)
+        {
+            self.api_key_repo.record_use(api_key.id, now).await?;
+        }
+
+        Ok(ApiKeyPrincipal {
+            api_key_id: api_key.id,
+            user_id: user.id(),
+            role: user.role().clone(),
+            scopes: api_key.scopes,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum AuthenticateApiKeyError {
+    /// The key is unknown, expired, deleted or its owner lost the right to use keys.
+    InvalidKey,
+    RepositoryError(String),
+}
+
+impl From<ApiKeyRepositoryError> for AuthenticateApiKeyError {
+    fn from(err: ApiKeyRepositoryError) -> Self {
+        AuthenticateApiKeyError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UserRepositoryError> for AuthenticateApiKeyError {
+    fn from(err: UserRepositoryError) -> Self {
+        AuthenticateApiKeyError::RepositoryError(err.to_string())
+    }
+}
+
+impl AuthenticateApiKeyError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidKey => ErrorCode::Unauthorized,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for AuthenticateApiKeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidKey => write!(f, "Invalid or expired API key"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AuthenticateApiKeyError {}
@@ -0,0 +1,143 @@
+use crate::error_code::ErrorCode;
+use crate::ports::audit_log::{AuditEntry, AuditLogError, FieldChange};
+use crate::ports::event_outbox::EventOutboxError;
+use crate::ports::event_publisher::EventPublisher;
+use crate::ports::session_repository::SessionRepositoryError;
+use crate::ports::unit_of_work::{UnitOfWork, UnitOfWorkError};
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use chrono::Utc;
+use domain_users::User;
+use domain_users::models::user::UserRole;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Lets an admin change the role of another user. Access tokens carry the role, so
+/// the user's sessions are revoked and the new role applies from the next login.
+pub struct ChangeUserRoleUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    event_publisher: Arc<dyn EventPublisher>,
+}
+
+impl ChangeUserRoleUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        unit_of_work: Arc<dyn UnitOfWork>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            user_repo,
+            unit_of_work,
+            event_publisher,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        actor_id: Uuid,
+        user_id: Uuid,
+        role: UserRole,
+    ) -> Result<User, ChangeUserRoleError> {
+        // Admins could otherwise lock themselves out of the admin endpoints.
+        if actor_id == user_id {
+            return Err(ChangeUserRoleError::OwnRole);
+        }
+
+        let mut user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .filter(|u| !u.is_deleted())
+            .ok_or(ChangeUserRoleError::NotFound(user_id))?;
+        if *user.role() == role {
+            return Ok(user);
+        }
+
+        let previous = user.role().clone();
+        user.change_role(role);
+        let events = user.take_events();
+        let entry = AuditEntry::new(
+            Some(actor_id),
+            "user.role_changed",
+            "user",
+            Some(user.id()),
+            vec![FieldChange {
+                field: "role".to_string(),
+                old_value: Some(previous.to_string()),
+                new_value: Some(user.role().to_string()),
+            }],
+        );
+        let transaction = self.unit_of_work.begin().await?;
+        transaction.users().update(&user).await?;
+        transaction
+            .sessions()
+            .revoke_all(user.id(), Utc::now())
+            .await?;
+        transaction.audit_log().record(&entry).await?;
+        transaction.event_outbox().append(&events).await?;
+        transaction.commit().await?;
+        user.increment_version();
+        self.event_publisher.publish(&events).await;
+
+        Ok(user)
+    }
+}
+
+#[derive(Debug)]
+pub enum ChangeUserRoleError {
+    NotFound(Uuid),
+    OwnRole,
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for ChangeUserRoleError {
+    fn from(err: UserRepositoryError) -> Self {
+        ChangeUserRoleError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SessionRepositoryError> for ChangeUserRoleError {
+    fn from(err: SessionRepositoryError) -> Self {
+        ChangeUserRoleError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuditLogError> for ChangeUserRoleError {
+    fn from(err: AuditLogError) -> Self {
+        ChangeUserRoleError::RepositoryError(err.to_string())
+    }
+}
+
+impl ChangeUserRoleError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound(_) => ErrorCode::UserNotFound,
+            Self::OwnRole => ErrorCode::Forbidden,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl From<EventOutboxError> for ChangeUserRoleError {
+    fn from(err: EventOutboxError) -> Self {
+        ChangeUserRoleError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UnitOfWorkError> for ChangeUserRoleError {
+    fn from(err: UnitOfWorkError) -> Self {
+        ChangeUserRoleError::RepositoryError(err.to_string())
+    }
+}
+
+impl std::fmt::Display for ChangeUserRoleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound(id) => write!(f, "User with ID {} not found", id),
+            Self::OwnRole => write!(f, "Admins cannot change their own role"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ChangeUserRoleError {}
@@ -0,0 +1,69 @@
+use crate::error_code::ErrorCode;
+use crate::ports::session_repository::{SessionRepository, SessionRepositoryError};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Requests within this long of the last recorded one do not update `last_seen_at`,
+/// so a busy client does not cost a write per request.
+const LAST_SEEN_RESOLUTION: Duration = Duration::minutes(1);
+
+/// Runs on every request with a full access token, so that revoking its session
+/// takes effect immediately rather than when the token expires.
+pub struct CheckSessionUseCase {
+    session_repo: Arc<dyn SessionRepository>,
+}
+
+impl CheckSessionUseCase {
+    pub fn new(session_repo: Arc<dyn SessionRepository>) -> Self {
+        Self { session_repo }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, session_id: Uuid) -> Result<(), CheckSessionError> {
+        let now = Utc::now();
+        let session = self
+            .session_repo
+            .find_by_id(session_id)
+            .await?
+            .filter(|session| session.user_id == user_id && session.is_active(now))
+            .ok_or(CheckSessionError::Revoked)?;
+
+        if now - session.last_seen_at >= LAST_SEEN_RESOLUTION {
+            self.session_repo.touch(session.id, now).await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum CheckSessionError {
+    /// The session was revoked, has expired or does not exist.
+    Revoked,
+    RepositoryError(String),
+}
+
+impl From<SessionRepositoryError> for CheckSessionError {
+    fn from(err: SessionRepositoryError) -> Self {
+        CheckSessionError::RepositoryError(err.to_string())
+    }
+}
+
+impl CheckSessionError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::Revoked => ErrorCode::Unauthorized,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for CheckSessionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Revoked => write!(f, "Session has been signed out"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for CheckSessionError {}
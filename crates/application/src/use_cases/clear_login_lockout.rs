@@ -0,0 +1,86 @@
+use crate::error_code::ErrorCode;
+use crate::ports::audit_log::{AuditEntry, AuditLog, AuditLogError, FieldChange};
+use crate::ports::login_attempt_store::{
+    AttemptSubject, LoginAttemptStore, LoginAttemptStoreError,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Lets an admin lift a lockout and reset the failure counter of an account or IP.
+pub struct ClearLoginLockoutUseCase {
+    attempt_store: Arc<dyn LoginAttemptStore>,
+    audit_log: Arc<dyn AuditLog>,
+}
+
+impl ClearLoginLockoutUseCase {
+    pub fn new(attempt_store: Arc<dyn LoginAttemptStore>, audit_log: Arc<dyn AuditLog>) -> Self {
+        Self {
+            attempt_store,
+            audit_log,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        actor_id: Uuid,
+        subject: &AttemptSubject,
+    ) -> Result<(), ClearLoginLockoutError> {
+        if !self.attempt_store.clear(subject).await? {
+            return Err(ClearLoginLockoutError::NotFound);
+        }
+
+        // Lockouts are keyed by email or IP rather than by an id, so the key is the change.
+        let entry = AuditEntry::new(
+            Some(actor_id),
+            "login_lockout.cleared",
+            "login_lockout",
+            None,
+            vec![FieldChange {
+                field: "subject".to_string(),
+                old_value: Some(subject.key()),
+                new_value: None,
+            }],
+        );
+        self.audit_log.record(&entry).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum ClearLoginLockoutError {
+    NotFound,
+    RepositoryError(String),
+}
+
+impl From<LoginAttemptStoreError> for ClearLoginLockoutError {
+    fn from(err: LoginAttemptStoreError) -> Self {
+        ClearLoginLockoutError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuditLogError> for ClearLoginLockoutError {
+    fn from(err: AuditLogError) -> Self {
+        ClearLoginLockoutError::RepositoryError(err.to_string())
+    }
+}
+
+impl ClearLoginLockoutError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound => ErrorCode::NotFound,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for ClearLoginLockoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "No failed login attempts recorded"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ClearLoginLockoutError {}
@@ -0,0 +1,157 @@
+use crate::ports::api_key_repository::{ApiKeyRepository, ApiKeyRepositoryError};
+use crate::ports::audit_log::{AuditEntry, AuditLog, AuditLogError, AuditLogFilter};
+use crate::ports::data_export_archive::UserDataSnapshot;
+use crate::ports::linked_identity_repository::{
+    LinkedIdentityRepository, LinkedIdentityRepositoryError,
+};
+use crate::ports::passkey_repository::{PasskeyRepository, PasskeyRepositoryError};
+use crate::ports::session_repository::{SessionRepository, SessionRepositoryError};
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use std::sync::Arc;
+use uuid::Uuid;
+
+const AUDIT_LOG_PAGE_SIZE: u64 = 100;
+
+/// Gathers everything stored about a user from the repositories that hold it.
+pub struct UserDataCollector {
+    user_repo: Arc<dyn UserRepository>,
+    session_repo: Arc<dyn SessionRepository>,
+    passkey_repo: Arc<dyn PasskeyRepository>,
+    identity_repo: Arc<dyn LinkedIdentityRepository>,
+    api_key_repo: Arc<dyn ApiKeyRepository>,
+    audit_log: Arc<dyn AuditLog>,
+}
+
+impl UserDataCollector {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        session_repo: Arc<dyn SessionRepository>,
+        passkey_repo: Arc<dyn PasskeyRepository>,
+        identity_repo: Arc<dyn LinkedIdentityRepository>,
+        api_key_repo: Arc<dyn ApiKeyRepository>,
+        audit_log: Arc<dyn AuditLog>,
+    ) -> Self {
+        Self {
+            user_repo,
+            session_repo,
+            passkey_repo,
+            identity_repo,
+            api_key_repo,
+            audit_log,
+        }
+    }
+
+    /// `None` when the user no longer exists.
+    pub(crate) async fn collect(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Option<UserDataSnapshot>, CollectUserDataError> {
+        let Some(user) = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .filter(|user| user.deleted_at().is_none())
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(UserDataSnapshot {
+            user,
+            sessions: self.session_repo.find_by_user_id(user_id).await?,
+            passkeys: self.passkey_repo.find_by_user_id(user_id).await?,
+            linked_identities: self.identity_repo.find_by_user_id(user_id).await?,
+            api_keys: self.api_key_repo.find_by_user_id(user_id).await?,
+            audit_entries: self.audit_entries(user_id).await?,
+        }))
+    }
+
+    /// Entries the user is the subject of and actions the user took, newest first.
+    async fn audit_entries(&self, user_id: Uuid) -> Result<Vec<AuditEntry>, AuditLogError> {
+        let mut entries = self
+            .all_audit_entries(&AuditLogFilter {
+                target_id: Some(user_id),
+                ..AuditLogFilter::default()
+            })
+            .await?;
+        for entry in self
+            .all_audit_entries(&AuditLogFilter {
+                actor_id: Some(user_id),
+                ..AuditLogFilter::default()
+            })
+            .await?
+        {
+            if !entries.iter().any(|known| known.id == entry.id) {
+                entries.push(entry);
+            }
+        }
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.occurred_at));
+        Ok(entries)
+    }
+
+    async fn all_audit_entries(
+        &self,
+        filter: &AuditLogFilter,
+    ) -> Result<Vec<AuditEntry>, AuditLogError> {
+        let mut entries = Vec::new();
+        let mut page = 0;
+        loop {
+            let (batch, total) = self
+                .audit_log
+                .find(filter, page, AUDIT_LOG_PAGE_SIZE)
+                .await?;
+            let done = batch.is_empty();
+            entries.extend(batch);
+            if done || entries.len() as u64 >= total {
+                return Ok(entries);
+            }
+            page += 1;
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CollectUserDataError(String);
+
+impl From<UserRepositoryError> for CollectUserDataError {
+    fn from(err: UserRepositoryError) -> Self {
+        Self(err.to_string())
+    }
+}
+
+impl From<SessionRepositoryError> for CollectUserDataError {
+    fn from(err: SessionRepositoryError) -> Self {
+        Self(err.to_string())
+    }
+}
+
+impl From<PasskeyRepositoryError> for CollectUserDataError {
+    fn from(err: PasskeyRepositoryError) -> Self {
+        Self(err.to_string())
+    }
+}
+
+impl From<LinkedIdentityRepositoryError> for CollectUserDataError {
+    fn from(err: LinkedIdentityRepositoryError) -> Self {
+        Self(err.to_string())
+    }
+}
+
+impl From<ApiKeyRepositoryError> for CollectUserDataError {
+    fn from(err: ApiKeyRepositoryError) -> Self {
+        Self(err.to_string())
+    }
+}
+
+impl From<AuditLogError> for CollectUserDataError {
+    fn from(err: AuditLogError) -> Self {
+        Self(err.to_string())
+    }
+}
+
+impl std::fmt::Display for CollectUserDataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for CollectUserDataError {}
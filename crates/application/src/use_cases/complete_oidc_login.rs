@@ -0,0 +1,335 @@
+use crate::error_code::ErrorCode;
+use crate::ports::event_outbox::EventOutboxError;
+use crate::ports::event_publisher::EventPublisher;
+use crate::ports::linked_identity_repository::{
+    LinkedIdentity, LinkedIdentityRepository, LinkedIdentityRepositoryError,
+};
+use crate::ports::oidc_client::{OidcClient, OidcError, OidcIdentity};
+use crate::ports::oidc_flow_store::{OidcFlowStore, OidcFlowStoreError, PendingIdentityLink};
+use crate::ports::one_time_token_service::OneTimeTokenService;
+use crate::ports::password_hasher::PasswordHasher;
+use crate::ports::two_factor_repository::{TwoFactorRepository, TwoFactorRepositoryError};
+use crate::ports::unit_of_work::{UnitOfWork, UnitOfWorkError};
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use crate::use_cases::login::{LoginOutput, next_login_step};
+use crate::use_cases::session_tokens::{ClientInfo, SessionTokenError, SessionTokenIssuer};
+use chrono::{DateTime, Duration, Utc};
+use domain_users::{EmailAddress, PasswordHash, PersonName, User};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// A provider account matching an existing user, to be confirmed with
+/// [`ConfirmIdentityLinkUseCase`](super::ConfirmIdentityLinkUseCase).
+#[derive(Debug)]
+pub struct IdentityLinkRequired {
+    pub link_token: String,
+    pub provider: String,
+    pub email: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+pub enum OidcLoginOutcome {
+    LoggedIn(LoginOutput),
+    LinkRequired(IdentityLinkRequired),
+}
+
+/// Finishes a "Sign in with ..." login once the provider redirected back.
+///
+/// Linked identities log in as their user. Unknown identities sign up a new, verified
+/// user, unless their email belongs to an existing user: linking those automatically
+/// would hand the account to whoever controls the provider account, so it has to be
+/// confirmed by the user first.
+pub struct CompleteOidcLoginUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    identity_repo: Arc<dyn LinkedIdentityRepository>,
+    flow_store: Arc<dyn OidcFlowStore>,
+    oidc_client: Arc<dyn OidcClient>,
+    token_service: Arc<dyn OneTimeTokenService>,
+    password_hasher: Arc<dyn PasswordHasher>,
+    two_factor_repo: Arc<dyn TwoFactorRepository>,
+    sessions: Arc<SessionTokenIssuer>,
+    link_ttl: Duration,
+    event_publisher: Arc<dyn EventPublisher>,
+}
+
+impl CompleteOidcLoginUseCase {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        unit_of_work: Arc<dyn UnitOfWork>,
+        identity_repo: Arc<dyn LinkedIdentityRepository>,
+        flow_store: Arc<dyn OidcFlowStore>,
+        oidc_client: Arc<dyn OidcClient>,
+        token_service: Arc<dyn OneTimeTokenService>,
+        password_hasher: Arc<dyn PasswordHasher>,
+        two_factor_repo: Arc<dyn TwoFactorRepository>,
+        sessions: Arc<SessionTokenIssuer>,
+        link_ttl: Duration,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            user_repo,
+            unit_of_work,
+            identity_repo,
+            flow_store,
+            oidc_client,
+            token_service,
+            password_hasher,
+            two_factor_repo,
+            sessions,
+            link_ttl,
+            event_publisher,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        provider: &str,
+        code: &str,
+        state: &str,
+        client: &ClientInfo,
+    ) -> Result<OidcLoginOutcome, CompleteOidcLoginError> {
+        let now = Utc::now();
+        let login = self
+            .flow_store
+            .take_login(state)
+            .await?
+            .filter(|login| login.provider == provider && !login.is_expired(now))
+            .ok_or(CompleteOidcLoginError::InvalidState)?;
+
+        let identity = self
+            .oidc_client
+            .exchange_code(provider, code, &login.code_verifier, &login.nonce)
+            .await?;
+
+        if let Some(linked) = self
+            .identity_repo
+            .find_by_subject(provider, &identity.subject)
+            .await?
+        {
+            let user = self
+                .user_repo
+                .find_by_id(linked.user_id)
+                .await?
+                .filter(|user| !user.is_deleted())
+                .ok_or(CompleteOidcLoginError::IdentityRejected)?;
+            return self
+                .log_in(user, client)
+                .await
+                .map(OidcLoginOutcome::LoggedIn);
+        }
+
+        let email = match &identity.email {
+            Some(email) if identity.email_verified => {
+                EmailAddress::parse(email).map_err(|_| CompleteOidcLoginError::IdentityRejected)?
+            }
+            _ => return Err(CompleteOidcLoginError::EmailNotVerified),
+        };
+
+        if let Some(user) = self.user_repo.find_active_by_email(&email).await? {
+            let link_token = self.token_service.generate();
+            let expires_at = now + self.link_ttl;
+            self.flow_store
+                .save_link(&PendingIdentityLink {
+                    token_hash: self.token_service.hash(&link_token),
+                    user_id: user.id(),
+                    provider: provider.to_string(),
+                    subject: identity.subject,
+                    email: email.to_string(),
+                    expires_at,
+                })
+                .await?;
+
+            return Ok(OidcLoginOutcome::LinkRequired(IdentityLinkRequired {
+                link_token,
+                provider: provider.to_string(),
+                email: email.into_string(),
+                expires_at,
+            }));
+        }
+
+        let user = self.sign_up(provider, identity, email).await?;
+        self.log_in(user, client)
+            .await
+            .map(OidcLoginOutcome::LoggedIn)
+    }
+
+    async fn sign_up(
+        &self,
+        provider: &str,
+        identity: OidcIdentity,
+        email: EmailAddress,
+    ) -> Result<User, CompleteOidcLoginError> {
+        // Nobody knows this password; the user can set one via the password reset.
+        let password_hash = self
+            .password_hasher
+            .hash(&self.token_service.generate())
+            .await
+            .map_err(|e| e.to_string())
+            .and_then(|hash| PasswordHash::new(hash).map_err(|e| e.to_string()))
+            .map_err(|e| {
+                CompleteOidcLoginError::InternalError(format!("Failed to hash password: {}", e))
+            })?;
+
+        // Providers may omit or send unusable names; the mailbox name stands in until
+        // the user edits their profile.
+        let mailbox = email.split('@').next().unwrap_or_default();
+        let name = |claim: Option<String>| {
+            claim
+                .and_then(|name| PersonName::parse(&name).ok())
+                .unwrap_or_else(|| PersonName::new_unchecked(mailbox.to_string()))
+        };
+        let mut user = User::register(
+            Uuid::new_v4(),
+            name(identity.given_name),
+            name(identity.family_name),
+            email.clone(),
+            None,
+            password_hash,
+            None,
+        );
+        user.verify_email();
+        let events = user.take_events();
+        let transaction = self.unit_of_work.begin().await?;
+        transaction.users().create(&user).await?;
+        transaction.event_outbox().append(&events).await?;
+        transaction.commit().await?;
+        self.event_publisher.publish(&events).await;
+
+        self.identity_repo
+            .create(&LinkedIdentity {
+                id: Uuid::new_v4(),
+                user_id: user.id(),
+                provider: provider.to_string(),
+                subject: identity.subject,
+                email: Some(email.into_string()),
+                created_at: Utc::now(),
+            })
+            .await?;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(user_id = %user.id(), provider = %provider, "user signed up with identity provider");
+
+        Ok(user)
+    }
+
+    /// The provider stands in for the password only, so second factors still apply.
+    async fn log_in(
+        &self,
+        user: User,
+        client: &ClientInfo,
+    ) -> Result<LoginOutput, CompleteOidcLoginError> {
+        let scope = next_login_step(self.two_factor_repo.as_ref(), &user).await?;
+        let access_token = self.sessions.issue(&user, scope, client).await?;
+
+        Ok(LoginOutput {
+            user,
+            access_token,
+            scope,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum CompleteOidcLoginError {
+    /// The `state` is unknown, expired, already used or belongs to another provider.
+    InvalidState,
+    UnknownProvider,
+    IdentityRejected,
+    /// The provider did not vouch for an email, so no account can be matched or created.
+    EmailNotVerified,
+    ProviderUnavailable(String),
+    RepositoryError(String),
+    InternalError(String),
+}
+
+impl From<OidcError> for CompleteOidcLoginError {
+    fn from(err: OidcError) -> Self {
+        match err {
+            OidcError::UnknownProvider => CompleteOidcLoginError::UnknownProvider,
+            OidcError::ProviderUnavailable(msg) => CompleteOidcLoginError::ProviderUnavailable(msg),
+            OidcError::Rejected(_msg) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(error = %_msg, "identity provider login rejected");
+                CompleteOidcLoginError::IdentityRejected
+            }
+        }
+    }
+}
+
+impl From<OidcFlowStoreError> for CompleteOidcLoginError {
+    fn from(err: OidcFlowStoreError) -> Self {
+        CompleteOidcLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<LinkedIdentityRepositoryError> for CompleteOidcLoginError {
+    fn from(err: LinkedIdentityRepositoryError) -> Self {
+        CompleteOidcLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UserRepositoryError> for CompleteOidcLoginError {
+    fn from(err: UserRepositoryError) -> Self {
+        CompleteOidcLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<TwoFactorRepositoryError> for CompleteOidcLoginError {
+    fn from(err: TwoFactorRepositoryError) -> Self {
+        CompleteOidcLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SessionTokenError> for CompleteOidcLoginError {
+    fn from(err: SessionTokenError) -> Self {
+        match err {
+            SessionTokenError::Repository(err) => {
+                CompleteOidcLoginError::RepositoryError(err.to_string())
+            }
+            SessionTokenError::Token(err) => CompleteOidcLoginError::InternalError(err.to_string()),
+        }
+    }
+}
+
+impl CompleteOidcLoginError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidState => ErrorCode::OidcStateInvalid,
+            Self::UnknownProvider => ErrorCode::NotFound,
+            Self::IdentityRejected => ErrorCode::OidcIdentityRejected,
+            Self::EmailNotVerified => ErrorCode::Forbidden,
+            Self::ProviderUnavailable(_) => ErrorCode::UpstreamUnavailable,
+            Self::RepositoryError(_) | Self::InternalError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl From<EventOutboxError> for CompleteOidcLoginError {
+    fn from(err: EventOutboxError) -> Self {
+        CompleteOidcLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UnitOfWorkError> for CompleteOidcLoginError {
+    fn from(err: UnitOfWorkError) -> Self {
+        CompleteOidcLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl std::fmt::Display for CompleteOidcLoginError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidState => write!(f, "Login request is unknown or has expired"),
+            Self::UnknownProvider => write!(f, "Unknown identity provider"),
+            Self::IdentityRejected => write!(f, "Identity provider login was rejected"),
+            Self::EmailNotVerified => write!(f, "Identity provider did not verify the email"),
+            Self::ProviderUnavailable(msg) => write!(f, "Identity provider unavailable: {}", msg),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+            Self::InternalError(msg) => write!(f, "Internal error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for CompleteOidcLoginError {}
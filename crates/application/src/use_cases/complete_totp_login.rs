@@ -0,0 +1,111 @@
+use crate::ports::access_token_service::AccessTokenScope;
+use crate::ports::totp_service::TotpService;
+use crate::ports::two_factor_repository::TwoFactorRepository;
+use crate::ports::user_repository::UserRepository;
+use crate::use_cases::login::{LoginError, LoginOutput, LoginThrottle};
+use crate::use_cases::session_tokens::{ClientInfo, SessionTokenIssuer};
+use chrono::Utc;
+use domain_users::User;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Second login step: exchanges the TOTP challenge token plus an authenticator or
+/// recovery code for a full access token.
+pub struct CompleteTotpLoginUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    two_factor_repo: Arc<dyn TwoFactorRepository>,
+    totp_service: Arc<dyn TotpService>,
+    sessions: Arc<SessionTokenIssuer>,
+    throttle: Arc<LoginThrottle>,
+}
+
+impl CompleteTotpLoginUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        two_factor_repo: Arc<dyn TwoFactorRepository>,
+        totp_service: Arc<dyn TotpService>,
+        sessions: Arc<SessionTokenIssuer>,
+        throttle: Arc<LoginThrottle>,
+    ) -> Self {
+        Self {
+            user_repo,
+            two_factor_repo,
+            totp_service,
+            sessions,
+            throttle,
+        }
+    }
+
+    /// `code` is either the six digit authenticator code or one of the recovery codes.
+    /// Wrong codes count as failed logins of the account, so they cannot be brute forced.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        code: &str,
+        client: &ClientInfo,
+    ) -> Result<LoginOutput, LoginError> {
+        let user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .filter(|user| !user.is_deleted())
+            .ok_or(LoginError::InvalidCredentials)?;
+
+        let now = Utc::now();
+        let subjects = LoginThrottle::subjects(user.email(), client.ip);
+        self.throttle.ensure_allowed(&subjects, now).await?;
+
+        if !self.check_code(&user, code).await? {
+            self.throttle.register_failures(&subjects, now).await?;
+            return Err(LoginError::InvalidTwoFactorCode);
+        }
+
+        self.throttle.clear_account(user.email()).await?;
+
+        let access_token = self
+            .sessions
+            .issue(&user, AccessTokenScope::Full, client)
+            .await?;
+
+        Ok(LoginOutput {
+            user,
+            access_token,
+            scope: AccessTokenScope::Full,
+        })
+    }
+
+    async fn check_code(&self, user: &User, code: &str) -> Result<bool, LoginError> {
+        let Some(enrollment) = self
+            .two_factor_repo
+            .find(user.id())
+            .await?
+            .filter(|enrollment| enrollment.is_confirmed())
+        else {
+            return Ok(false);
+        };
+
+        let code = code.trim();
+        if is_totp_code(code) {
+            let step = self
+                .totp_service
+                .verify(&enrollment.secret, code, Utc::now())
+                .map_err(|e| LoginError::InternalError(e.to_string()))?;
+            return match step {
+                Some(step) => Ok(self.two_factor_repo.mark_step_used(user.id(), step).await?),
+                None => Ok(false),
+            };
+        }
+
+        let code_hash = self
+            .totp_service
+            .hash_recovery_code(&code.to_ascii_lowercase());
+        Ok(self
+            .two_factor_repo
+            .use_recovery_code(user.id(), &code_hash)
+            .await?)
+    }
+}
+
+pub(crate) fn is_totp_code(code: &str) -> bool {
+    code.len() == 6 && code.bytes().all(|b| b.is_ascii_digit())
+}
@@ -0,0 +1,108 @@
+use crate::error_code::ErrorCode;
+use crate::ports::linked_identity_repository::{
+    LinkedIdentity, LinkedIdentityRepository, LinkedIdentityRepositoryError,
+};
+use crate::ports::oidc_flow_store::{OidcFlowStore, OidcFlowStoreError};
+use crate::ports::one_time_token_service::OneTimeTokenService;
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Links a provider account to the logged-in user after
+/// [`CompleteOidcLoginUseCase`](super::CompleteOidcLoginUseCase) found it matches their
+/// email. Having to log in first proves the user owns the existing account.
+pub struct ConfirmIdentityLinkUseCase {
+    identity_repo: Arc<dyn LinkedIdentityRepository>,
+    flow_store: Arc<dyn OidcFlowStore>,
+    token_service: Arc<dyn OneTimeTokenService>,
+}
+
+impl ConfirmIdentityLinkUseCase {
+    pub fn new(
+        identity_repo: Arc<dyn LinkedIdentityRepository>,
+        flow_store: Arc<dyn OidcFlowStore>,
+        token_service: Arc<dyn OneTimeTokenService>,
+    ) -> Self {
+        Self {
+            identity_repo,
+            flow_store,
+            token_service,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        link_token: &str,
+    ) -> Result<LinkedIdentity, ConfirmIdentityLinkError> {
+        let now = Utc::now();
+        let link = self
+            .flow_store
+            .take_link(&self.token_service.hash(link_token.trim()))
+            .await?
+            .filter(|link| link.user_id == user_id && !link.is_expired(now))
+            .ok_or(ConfirmIdentityLinkError::InvalidToken)?;
+
+        let identity = LinkedIdentity {
+            id: Uuid::new_v4(),
+            user_id,
+            provider: link.provider,
+            subject: link.subject,
+            email: Some(link.email),
+            created_at: now,
+        };
+        self.identity_repo.create(&identity).await?;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(user_id = %user_id, provider = %identity.provider, "identity linked");
+
+        Ok(identity)
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfirmIdentityLinkError {
+    /// Unknown, expired, already used or issued for another user.
+    InvalidToken,
+    AlreadyLinked,
+    RepositoryError(String),
+}
+
+impl From<OidcFlowStoreError> for ConfirmIdentityLinkError {
+    fn from(err: OidcFlowStoreError) -> Self {
+        ConfirmIdentityLinkError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<LinkedIdentityRepositoryError> for ConfirmIdentityLinkError {
+    fn from(err: LinkedIdentityRepositoryError) -> Self {
+        match err {
+            LinkedIdentityRepositoryError::AlreadyLinked => ConfirmIdentityLinkError::AlreadyLinked,
+            LinkedIdentityRepositoryError::DatabaseError(msg) => {
+                ConfirmIdentityLinkError::RepositoryError(msg)
+            }
+        }
+    }
+}
+
+impl ConfirmIdentityLinkError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidToken => ErrorCode::InvalidToken,
+            Self::AlreadyLinked => ErrorCode::Conflict,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for ConfirmIdentityLinkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidToken => write!(f, "Invalid or expired link token"),
+            Self::AlreadyLinked => write!(f, "Identity is already linked"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ConfirmIdentityLinkError {}
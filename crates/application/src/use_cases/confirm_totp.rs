@@ -0,0 +1,160 @@
+use crate::error_code::ErrorCode;
+use crate::ports::access_token_service::{AccessTokenScope, IssuedAccessToken};
+use crate::ports::totp_service::TotpService;
+use crate::ports::two_factor_repository::{TwoFactorRepository, TwoFactorRepositoryError};
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use crate::use_cases::complete_totp_login::is_totp_code;
+use crate::use_cases::session_tokens::{ClientInfo, SessionTokenError, SessionTokenIssuer};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const RECOVERY_CODE_COUNT: usize = 10;
+
+/// Activates a pending TOTP enrollment once the user proves it works with a first code.
+pub struct ConfirmTotpUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    two_factor_repo: Arc<dyn TwoFactorRepository>,
+    totp_service: Arc<dyn TotpService>,
+    sessions: Arc<SessionTokenIssuer>,
+}
+
+pub struct ConfirmTotpOutput {
+    /// Shown once; only their hashes are stored.
+    pub recovery_codes: Vec<String>,
+    /// A full token, so staff signed in for enrollment can continue right away.
+    pub access_token: IssuedAccessToken,
+}
+
+impl ConfirmTotpUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        two_factor_repo: Arc<dyn TwoFactorRepository>,
+        totp_service: Arc<dyn TotpService>,
+        sessions: Arc<SessionTokenIssuer>,
+    ) -> Self {
+        Self {
+            user_repo,
+            two_factor_repo,
+            totp_service,
+            sessions,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        code: &str,
+        client: &ClientInfo,
+    ) -> Result<ConfirmTotpOutput, ConfirmTotpError> {
+        let user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .filter(|user| !user.is_deleted())
+            .ok_or(ConfirmTotpError::UserNotFound)?;
+
+        let mut enrollment = self
+            .two_factor_repo
+            .find(user_id)
+            .await?
+            .ok_or(ConfirmTotpError::NoPendingEnrollment)?;
+        if enrollment.is_confirmed() {
+            return Err(ConfirmTotpError::AlreadyEnrolled);
+        }
+
+        let code = code.trim();
+        let step = if is_totp_code(code) {
+            self.totp_service
+                .verify(&enrollment.secret, code, Utc::now())
+                .map_err(|e| ConfirmTotpError::InternalError(e.to_string()))?
+        } else {
+            None
+        };
+        let step = step.ok_or(ConfirmTotpError::InvalidCode)?;
+
+        enrollment.confirmed_at = Some(Utc::now());
+        enrollment.last_used_step = Some(step);
+        self.two_factor_repo.save(&enrollment).await?;
+
+        let recovery_codes = self
+            .totp_service
+            .generate_recovery_codes(RECOVERY_CODE_COUNT);
+        let code_hashes: Vec<String> = recovery_codes
+            .iter()
+            .map(|code| self.totp_service.hash_recovery_code(code))
+            .collect();
+        self.two_factor_repo
+            .replace_recovery_codes(user_id, &code_hashes)
+            .await?;
+
+        let access_token = self
+            .sessions
+            .issue(&user, AccessTokenScope::Full, client)
+            .await?;
+
+        Ok(ConfirmTotpOutput {
+            recovery_codes,
+            access_token,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfirmTotpError {
+    UserNotFound,
+    NoPendingEnrollment,
+    AlreadyEnrolled,
+    InvalidCode,
+    RepositoryError(String),
+    InternalError(String),
+}
+
+impl From<UserRepositoryError> for ConfirmTotpError {
+    fn from(err: UserRepositoryError) -> Self {
+        ConfirmTotpError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<TwoFactorRepositoryError> for ConfirmTotpError {
+    fn from(err: TwoFactorRepositoryError) -> Self {
+        ConfirmTotpError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SessionTokenError> for ConfirmTotpError {
+    fn from(err: SessionTokenError) -> Self {
+        match err {
+            SessionTokenError::Repository(err) => {
+                ConfirmTotpError::RepositoryError(err.to_string())
+            }
+            SessionTokenError::Token(err) => ConfirmTotpError::InternalError(err.to_string()),
+        }
+    }
+}
+
+impl ConfirmTotpError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::UserNotFound => ErrorCode::UserNotFound,
+            Self::NoPendingEnrollment | Self::AlreadyEnrolled => ErrorCode::Conflict,
+            Self::InvalidCode => ErrorCode::InvalidTwoFactorCode,
+            Self::RepositoryError(_) | Self::InternalError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for ConfirmTotpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UserNotFound => write!(f, "User not found"),
+            Self::NoPendingEnrollment => write!(f, "No pending two-factor enrollment"),
+            Self::AlreadyEnrolled => write!(f, "Two-factor authentication is already enabled"),
+            Self::InvalidCode => write!(f, "Invalid authentication code"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+            Self::InternalError(msg) => write!(f, "Internal error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ConfirmTotpError {}
@@ -0,0 +1,157 @@
+use crate::error_code::ErrorCode;
+use crate::ports::api_key_repository::{
+    ApiKey, ApiKeyRepository, ApiKeyRepositoryError, ApiKeyScope,
+};
+use crate::ports::audit_log::{AuditEntry, AuditLog, AuditLogError, FieldChange};
+use crate::ports::one_time_token_service::OneTimeTokenService;
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Marks the secret as an API key, e.g. for secret scanners.
+pub const API_KEY_PREFIX: &str = "mak_";
+/// Characters of the key kept in clear text for listings.
+pub const API_KEY_DISPLAY_PREFIX_LENGTH: usize = API_KEY_PREFIX.len() + 8;
+
+pub struct CreateApiKeyInput {
+    pub user_id: Uuid,
+    pub name: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub expires_in: Duration,
+}
+
+pub struct CreatedApiKey {
+    pub api_key: ApiKey,
+    /// Shown once; only its hash is stored.
+    pub key: String,
+}
+
+/// Issues a personal API key for programmatic access, e.g. by the learning platform
+/// of a partner school. Only creators and admins may hold keys.
+pub struct CreateApiKeyUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    api_key_repo: Arc<dyn ApiKeyRepository>,
+    token_service: Arc<dyn OneTimeTokenService>,
+    audit_log: Arc<dyn AuditLog>,
+}
+
+impl CreateApiKeyUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        api_key_repo: Arc<dyn ApiKeyRepository>,
+        token_service: Arc<dyn OneTimeTokenService>,
+        audit_log: Arc<dyn AuditLog>,
+    ) -> Self {
+        Self {
+            user_repo,
+            api_key_repo,
+            token_service,
+            audit_log,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: CreateApiKeyInput,
+    ) -> Result<CreatedApiKey, CreateApiKeyError> {
+        let user = self
+            .user_repo
+            .find_by_id(input.user_id)
+            .await?
+            .filter(|user| !user.is_deleted())
+            .ok_or(CreateApiKeyError::UserNotFound)?;
+        if !user.role().can_use_api_keys() {
+            return Err(CreateApiKeyError::NotAllowed);
+        }
+        if input.scopes.is_empty() {
+            return Err(CreateApiKeyError::NoScopes);
+        }
+
+        let key = format!("{}{}", API_KEY_PREFIX, self.token_service.generate());
+        let mut scopes = input.scopes;
+        scopes.sort_by_key(|scope| scope.as_str());
+        scopes.dedup();
+
+        let now = Utc::now();
+        let api_key = ApiKey {
+            id: Uuid::new_v4(),
+            user_id: user.id(),
+            name: input.name.trim().to_string(),
+            prefix: key.chars().take(API_KEY_DISPLAY_PREFIX_LENGTH).collect(),
+            key_hash: self.token_service.hash(&key),
+            scopes,
+            created_at: now,
+            expires_at: now + input.expires_in,
+            last_used_at: None,
+        };
+        self.api_key_repo.create(&api_key).await?;
+
+        let scopes: Vec<&str> = api_key.scopes.iter().map(ApiKeyScope::as_str).collect();
+        let entry = AuditEntry::new(
+            Some(user.id()),
+            "api_key.created",
+            "api_key",
+            Some(api_key.id),
+            vec![FieldChange {
+                field: "scopes".to_string(),
+                old_value: None,
+                new_value: Some(scopes.join(" ")),
+            }],
+        );
+        self.audit_log.record(&entry).await?;
+
+        Ok(CreatedApiKey { api_key, key })
+    }
+}
+
+#[derive(Debug)]
+pub enum CreateApiKeyError {
+    UserNotFound,
+    /// The user's role does not allow API keys.
+    NotAllowed,
+    NoScopes,
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for CreateApiKeyError {
+    fn from(err: UserRepositoryError) -> Self {
+        CreateApiKeyError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<ApiKeyRepositoryError> for CreateApiKeyError {
+    fn from(err: ApiKeyRepositoryError) -> Self {
+        CreateApiKeyError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuditLogError> for CreateApiKeyError {
+    fn from(err: AuditLogError) -> Self {
+        CreateApiKeyError::RepositoryError(err.to_string())
+    }
+}
+
+impl CreateApiKeyError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::UserNotFound => ErrorCode::UserNotFound,
+            Self::NotAllowed => ErrorCode::Forbidden,
+            Self::NoScopes => ErrorCode::BadRequest,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for CreateApiKeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UserNotFound => write!(f, "User not found"),
+            Self::NotAllowed => write!(f, "Only creators and admins can create API keys"),
+            Self::NoScopes => write!(f, "An API key needs at least one scope"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for CreateApiKeyError {}
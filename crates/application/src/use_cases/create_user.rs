@@ -1,55 +1,80 @@
+use crate::error_code::ErrorCode;
+use crate::ports::event_outbox::EventOutboxError;
+use crate::ports::event_publisher::EventPublisher;
 use crate::ports::password_hasher::PasswordHasher;
+use crate::ports::unit_of_work::{UnitOfWork, UnitOfWorkError};
 use crate::ports::user_repository::UserRepository;
+use crate::use_cases::user_fields::{FieldError, NewUserFields};
 use chrono::NaiveDate;
-use domain_users::User;
+use domain_users::{PasswordHash, User};
 use std::sync::Arc;
 use uuid::Uuid;
 
 pub struct CreateUserUseCase {
     user_repo: Arc<dyn UserRepository>,
+    unit_of_work: Arc<dyn UnitOfWork>,
     password_hasher: Arc<dyn PasswordHasher>,
+    event_publisher: Arc<dyn EventPublisher>,
 }
 
 impl CreateUserUseCase {
     pub fn new(
         user_repo: Arc<dyn UserRepository>,
+        unit_of_work: Arc<dyn UnitOfWork>,
         password_hasher: Arc<dyn PasswordHasher>,
+        event_publisher: Arc<dyn EventPublisher>,
     ) -> Self {
         Self {
             user_repo,
+            unit_of_work,
             password_hasher,
+            event_publisher,
         }
     }
 
     pub async fn execute(&self, input: CreateUserInput) -> Result<User, CreateUserError> {
+        let fields = NewUserFields::parse(
+            &input.first_name,
+            &input.last_name,
+            &input.email,
+            input.phone_number.as_deref(),
+        )
+        .map_err(CreateUserError::Validation)?;
         if self
             .user_repo
-            .find_active_by_email(&input.email)
+            .find_active_by_email(fields.email.as_str())
             .await?
             .is_some()
         {
-            return Err(CreateUserError::AlreadyExists(input.email));
+            return Err(CreateUserError::AlreadyExists(fields.email.into_string()));
         }
 
         let password_hash = self
             .password_hasher
             .hash(&input.password)
             .await
+            .map_err(|e| e.to_string())
+            .and_then(|hash| PasswordHash::new(hash).map_err(|e| e.to_string()))
             .map_err(|e| {
                 CreateUserError::InternalError(format!("Failed to hash password: {}", e))
             })?;
 
-        let user = User::new(
+        let mut user = User::register(
             Uuid::new_v4(),
-            input.first_name,
-            input.last_name,
-            input.email,
-            input.phone_number,
+            fields.first_name,
+            fields.last_name,
+            fields.email,
+            fields.phone_number,
             password_hash,
             input.birth_date,
         );
 
-        self.user_repo.create(&user).await?;
+        let events = user.take_events();
+        let transaction = self.unit_of_work.begin().await?;
+        transaction.users().create(&user).await?;
+        transaction.event_outbox().append(&events).await?;
+        transaction.commit().await?;
+        self.event_publisher.publish(&events).await;
 
         Ok(user)
     }
@@ -67,6 +92,7 @@ pub struct CreateUserInput {
 
 #[derive(Debug)]
 pub enum CreateUserError {
+    Validation(Vec<FieldError>),
     AlreadyExists(String),
     RepositoryError(String),
     InternalError(String),
@@ -84,158 +110,47 @@ impl From<crate::ports::user_repository::UserRepositoryError> for CreateUserErro
             crate::ports::user_repository::UserRepositoryError::NotFound(msg) => {
                 Self::RepositoryError(msg)
             }
+            crate::ports::user_repository::UserRepositoryError::VersionConflict(msg) => {
+                Self::RepositoryError(msg)
+            }
         }
     }
 }
 
-impl std::fmt::Display for CreateUserError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl CreateUserError {
+    pub fn code(&self) -> ErrorCode {
         match self {
-            Self::AlreadyExists(email) => write!(f, "User with email {} already exists", email),
-            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
-            Self::InternalError(msg) => write!(f, "Internal error: {}", msg),
+            Self::Validation(_) => ErrorCode::ValidationFailed,
+            Self::AlreadyExists(_) => ErrorCode::EmailTaken,
+            Self::RepositoryError(_) | Self::InternalError(_) => ErrorCode::InternalServerError,
         }
     }
 }
 
-impl std::error::Error for CreateUserError {}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::ports::password_hasher::PasswordHasher;
-    use crate::ports::user_repository::{UserRepository, UserRepositoryError};
-    use async_trait::async_trait;
-    use domain_users::User;
-    use std::sync::Mutex;
-    use uuid::Uuid;
-
-    struct MockUserRepository {
-        users: Mutex<Vec<User>>,
+impl From<EventOutboxError> for CreateUserError {
+    fn from(err: EventOutboxError) -> Self {
+        CreateUserError::RepositoryError(err.to_string())
     }
+}
 
-    #[async_trait]
-    impl UserRepository for MockUserRepository {
-        async fn create(&self, user: &User) -> Result<(), UserRepositoryError> {
-            self.users.lock().unwrap().push(user.clone());
-            Ok(())
-        }
-        async fn update(&self, user: &User) -> Result<(), UserRepositoryError> {
-            let mut users = self.users.lock().unwrap();
-            if let Some(u) = users.iter_mut().find(|u| u.id == user.id) {
-                *u = user.clone();
-                Ok(())
-            } else {
-                Err(UserRepositoryError::NotFound(user.id.to_string()))
-            }
-        }
-        async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, UserRepositoryError> {
-            Ok(self
-                .users
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|u| u.id == id)
-                .cloned())
-        }
-        async fn find_by_email(&self, email: &str) -> Result<Option<User>, UserRepositoryError> {
-            Ok(self
-                .users
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|u| u.email == email)
-                .cloned())
-        }
-        async fn find_active_by_email(
-            &self,
-            email: &str,
-        ) -> Result<Option<User>, UserRepositoryError> {
-            Ok(self
-                .users
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|u| u.email == email && !u.is_deleted())
-                .cloned())
-        }
-        async fn find_all_active(&self) -> Result<Vec<User>, UserRepositoryError> {
-            Ok(self
-                .users
-                .lock()
-                .unwrap()
-                .iter()
-                .filter(|u| !u.is_deleted())
-                .cloned()
-                .collect())
-        }
-        async fn find_all_active_paginated(
-            &self,
-            page: u64,
-            page_size: u64,
-        ) -> Result<(Vec<User>, u64), UserRepositoryError> {
-            let users = self.users.lock().unwrap();
-            let active_users: Vec<User> =
-                users.iter().filter(|u| !u.is_deleted()).cloned().collect();
-            let total = active_users.len() as u64;
-            let offset = (page * page_size) as usize;
-            let paged_users = active_users
-                .into_iter()
-                .skip(offset)
-                .take(page_size as usize)
-                .collect();
-            Ok((paged_users, total))
-        }
+impl From<UnitOfWorkError> for CreateUserError {
+    fn from(err: UnitOfWorkError) -> Self {
+        CreateUserError::RepositoryError(err.to_string())
     }
+}
 
-    struct MockPasswordHasher;
-    #[async_trait]
-    impl PasswordHasher for MockPasswordHasher {
-        async fn hash(
-            &self,
-            password: &str,
-        ) -> Result<String, crate::ports::password_hasher::PasswordHasherError> {
-            Ok(password.to_string())
-        }
-        async fn verify(
-            &self,
-            _password: &str,
-            _hash: &str,
-        ) -> Result<bool, crate::ports::password_hasher::PasswordHasherError> {
-            Ok(true)
+impl std::fmt::Display for CreateUserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Validation(errors) => {
+                let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+                write!(f, "Validation failed: {}", messages.join("; "))
+            }
+            Self::AlreadyExists(email) => write!(f, "User with email {} already exists", email),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+            Self::InternalError(msg) => write!(f, "Internal error: {}", msg),
         }
     }
-
-    #[tokio::test]
-    async fn test_create_user_after_soft_delete() {
-        let repo = Arc::new(MockUserRepository {
-            users: Mutex::new(vec![]),
-        });
-        let hasher = Arc::new(MockPasswordHasher);
-        let use_case = CreateUserUseCase::new(repo.clone(), hasher);
-
-        let input = CreateUserInput {
-            first_name: "Test".to_string(),
-            last_name: "User".to_string(),
-            email: "test@example.com".to_string(),
-            phone_number: None,
-            password: "password".to_string(),
-            birth_date: None,
-        };
-
-        // Create first user
-        let user1 = use_case.execute(input.clone()).await.unwrap();
-
-        // Soft delete first user
-        let mut user1_deleted = user1.clone();
-        user1_deleted.delete();
-        repo.update(&user1_deleted).await.unwrap();
-
-        // Create second user with same email
-        let user2 = use_case.execute(input).await.unwrap();
-
-        assert_ne!(user1.id, user2.id);
-        assert_eq!(user1.email, user2.email);
-        assert!(!user2.is_deleted());
-    }
 }
+
+impl std::error::Error for CreateUserError {}
@@ -0,0 +1,145 @@
+use crate::error_code::ErrorCode;
+use crate::ports::audit_log::{AuditEntry, AuditLog, AuditLogError, FieldChange};
+use crate::ports::one_time_token_service::OneTimeTokenService;
+use crate::ports::webhook_repository::{
+    WebhookRepository, WebhookRepositoryError, WebhookSubscription,
+};
+use chrono::Utc;
+use domain_users::UserEvent;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Marks the secret as a webhook signing secret, e.g. for secret scanners.
+pub const WEBHOOK_SECRET_PREFIX: &str = "whsec_";
+
+pub struct CreateWebhookSubscriptionInput {
+    pub admin_id: Uuid,
+    pub url: String,
+    pub events: Vec<String>,
+}
+
+/// Subscribes a partner endpoint to events. The response is the only place the
+/// signing secret is shown.
+pub struct CreateWebhookSubscriptionUseCase {
+    webhook_repo: Arc<dyn WebhookRepository>,
+    token_service: Arc<dyn OneTimeTokenService>,
+    audit_log: Arc<dyn AuditLog>,
+    allow_insecure_urls: bool,
+}
+
+impl CreateWebhookSubscriptionUseCase {
+    pub fn new(
+        webhook_repo: Arc<dyn WebhookRepository>,
+        token_service: Arc<dyn OneTimeTokenService>,
+        audit_log: Arc<dyn AuditLog>,
+        allow_insecure_urls: bool,
+    ) -> Self {
+        Self {
+            webhook_repo,
+            token_service,
+            audit_log,
+            allow_insecure_urls,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: CreateWebhookSubscriptionInput,
+    ) -> Result<WebhookSubscription, CreateWebhookSubscriptionError> {
+        let url = input.url.trim().to_string();
+        if !(url.starts_with("https://") || self.allow_insecure_urls && url.starts_with("http://"))
+        {
+            return Err(CreateWebhookSubscriptionError::InvalidUrl);
+        }
+
+        let mut events = input.events;
+        if events.is_empty() {
+            return Err(CreateWebhookSubscriptionError::NoEvents);
+        }
+        if let Some(unknown) = events
+            .iter()
+            .find(|name| !UserEvent::NAMES.contains(&name.as_str()))
+        {
+            return Err(CreateWebhookSubscriptionError::UnknownEvent(
+                unknown.clone(),
+            ));
+        }
+        events.sort();
+        events.dedup();
+
+        let subscription = WebhookSubscription {
+            id: Uuid::new_v4(),
+            url,
+            events,
+            secret: format!("{}{}", WEBHOOK_SECRET_PREFIX, self.token_service.generate()),
+            created_by: input.admin_id,
+            created_at: Utc::now(),
+        };
+        self.webhook_repo.create_subscription(&subscription).await?;
+
+        self.audit_log
+            .record(&AuditEntry::new(
+                Some(input.admin_id),
+                "webhook.created",
+                "webhook",
+                Some(subscription.id),
+                vec![
+                    FieldChange {
+                        field: "url".to_string(),
+                        old_value: None,
+                        new_value: Some(subscription.url.clone()),
+                    },
+                    FieldChange {
+                        field: "events".to_string(),
+                        old_value: None,
+                        new_value: Some(subscription.events.join(" ")),
+                    },
+                ],
+            ))
+            .await?;
+
+        Ok(subscription)
+    }
+}
+
+#[derive(Debug)]
+pub enum CreateWebhookSubscriptionError {
+    InvalidUrl,
+    NoEvents,
+    UnknownEvent(String),
+    RepositoryError(String),
+}
+
+impl From<WebhookRepositoryError> for CreateWebhookSubscriptionError {
+    fn from(err: WebhookRepositoryError) -> Self {
+        CreateWebhookSubscriptionError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuditLogError> for CreateWebhookSubscriptionError {
+    fn from(err: AuditLogError) -> Self {
+        CreateWebhookSubscriptionError::RepositoryError(err.to_string())
+    }
+}
+
+impl CreateWebhookSubscriptionError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidUrl | Self::NoEvents | Self::UnknownEvent(_) => ErrorCode::BadRequest,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for CreateWebhookSubscriptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidUrl => write!(f, "The webhook URL must use https"),
+            Self::NoEvents => write!(f, "A webhook needs at least one event"),
+            Self::UnknownEvent(name) => write!(f, "Unknown event: {}", name),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for CreateWebhookSubscriptionError {}
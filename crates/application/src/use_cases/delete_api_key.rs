@@ -0,0 +1,76 @@
+use crate::error_code::ErrorCode;
+use crate::ports::api_key_repository::{ApiKeyRepository, ApiKeyRepositoryError};
+use crate::ports::audit_log::{AuditEntry, AuditLog, AuditLogError};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Revokes one of the user's own API keys; it is refused from the next request on.
+pub struct DeleteApiKeyUseCase {
+    api_key_repo: Arc<dyn ApiKeyRepository>,
+    audit_log: Arc<dyn AuditLog>,
+}
+
+impl DeleteApiKeyUseCase {
+    pub fn new(api_key_repo: Arc<dyn ApiKeyRepository>, audit_log: Arc<dyn AuditLog>) -> Self {
+        Self {
+            api_key_repo,
+            audit_log,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, api_key_id: Uuid) -> Result<(), DeleteApiKeyError> {
+        if !self.api_key_repo.delete(user_id, api_key_id).await? {
+            return Err(DeleteApiKeyError::NotFound);
+        }
+
+        self.audit_log
+            .record(&AuditEntry::new(
+                Some(user_id),
+                "api_key.revoked",
+                "api_key",
+                Some(api_key_id),
+                Vec::new(),
+            ))
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum DeleteApiKeyError {
+    NotFound,
+    RepositoryError(String),
+}
+
+impl From<ApiKeyRepositoryError> for DeleteApiKeyError {
+    fn from(err: ApiKeyRepositoryError) -> Self {
+        DeleteApiKeyError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuditLogError> for DeleteApiKeyError {
+    fn from(err: AuditLogError) -> Self {
+        DeleteApiKeyError::RepositoryError(err.to_string())
+    }
+}
+
+impl DeleteApiKeyError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound => ErrorCode::NotFound,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for DeleteApiKeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "API key not found"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for DeleteApiKeyError {}
@@ -0,0 +1,55 @@
+use crate::error_code::ErrorCode;
+use crate::ports::passkey_repository::{PasskeyRepository, PasskeyRepositoryError};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Removes one of the user's own passkeys. The password keeps working, so removing
+/// the last passkey never locks the user out.
+pub struct DeletePasskeyUseCase {
+    passkey_repo: Arc<dyn PasskeyRepository>,
+}
+
+impl DeletePasskeyUseCase {
+    pub fn new(passkey_repo: Arc<dyn PasskeyRepository>) -> Self {
+        Self { passkey_repo }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, passkey_id: Uuid) -> Result<(), DeletePasskeyError> {
+        if !self.passkey_repo.delete(user_id, passkey_id).await? {
+            return Err(DeletePasskeyError::NotFound);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum DeletePasskeyError {
+    NotFound,
+    RepositoryError(String),
+}
+
+impl From<PasskeyRepositoryError> for DeletePasskeyError {
+    fn from(err: PasskeyRepositoryError) -> Self {
+        DeletePasskeyError::RepositoryError(err.to_string())
+    }
+}
+
+impl DeletePasskeyError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound => ErrorCode::NotFound,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for DeletePasskeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "Passkey not found"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for DeletePasskeyError {}
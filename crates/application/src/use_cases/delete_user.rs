@@ -1,17 +1,54 @@
+use crate::error_code::ErrorCode;
+use crate::ports::TokenRepositoryError;
+use crate::ports::account_restore_token_repository::{
+    AccountRestoreToken, AccountRestoreTokenRepository,
+};
+use crate::ports::audit_log::{AuditEntry, AuditLogError};
+use crate::ports::email_service::EmailService;
+use crate::ports::event_outbox::EventOutboxError;
+use crate::ports::event_publisher::EventPublisher;
+use crate::ports::one_time_token_service::OneTimeTokenService;
+use crate::ports::session_repository::SessionRepositoryError;
+use crate::ports::unit_of_work::{UnitOfWork, UnitOfWorkError};
 use crate::ports::user_repository::UserRepository;
+use chrono::{Duration, Utc};
 use std::sync::Arc;
 use uuid::Uuid;
 
+/// Soft-deletes an account. The user is mailed a link that restores it until the
+/// grace period ends; afterwards the account is erased.
 pub struct DeleteUserUseCase {
     user_repo: Arc<dyn UserRepository>,
+    restore_token_repo: Arc<dyn AccountRestoreTokenRepository>,
+    token_service: Arc<dyn OneTimeTokenService>,
+    email_service: Arc<dyn EmailService>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    grace_period: Duration,
+    event_publisher: Arc<dyn EventPublisher>,
 }
 
 impl DeleteUserUseCase {
-    pub fn new(user_repo: Arc<dyn UserRepository>) -> Self {
-        Self { user_repo }
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        restore_token_repo: Arc<dyn AccountRestoreTokenRepository>,
+        token_service: Arc<dyn OneTimeTokenService>,
+        email_service: Arc<dyn EmailService>,
+        unit_of_work: Arc<dyn UnitOfWork>,
+        grace_period: Duration,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            user_repo,
+            restore_token_repo,
+            token_service,
+            email_service,
+            unit_of_work,
+            grace_period,
+            event_publisher,
+        }
     }
 
-    pub async fn execute(&self, id: Uuid) -> Result<(), DeleteUserError> {
+    pub async fn execute(&self, id: Uuid, actor_id: Option<Uuid>) -> Result<(), DeleteUserError> {
         let user = self.user_repo.find_by_id(id).await?;
 
         match user {
@@ -20,7 +57,46 @@ impl DeleteUserUseCase {
                     return Ok(());
                 }
                 user.delete();
-                self.user_repo.update(&user).await?;
+                let now = Utc::now();
+                // A deleted account keeps no signed-in devices, not even during the grace period.
+                let events = user.take_events();
+                let transaction = self.unit_of_work.begin().await?;
+                transaction.users().update(&user).await?;
+                transaction.sessions().revoke_all(user.id(), now).await?;
+                transaction
+                    .audit_log()
+                    .record(&AuditEntry::new(
+                        actor_id,
+                        "user.deleted",
+                        "user",
+                        Some(user.id()),
+                        Vec::new(),
+                    ))
+                    .await?;
+                transaction.event_outbox().append(&events).await?;
+                transaction.commit().await?;
+                self.event_publisher.publish(&events).await;
+
+                let token = self.token_service.generate();
+                self.restore_token_repo
+                    .create(&AccountRestoreToken {
+                        token_hash: self.token_service.hash(&token),
+                        user_id: user.id(),
+                        created_at: now,
+                        expires_at: now + self.grace_period,
+                    })
+                    .await?;
+
+                // The deletion stands even if the mail cannot be sent.
+                if let Err(_err) = self
+                    .email_service
+                    .send_account_restore_email(user.email(), &token, user.first_name())
+                    .await
+                {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(error = %_err, "failed to send account restore email");
+                }
+
                 Ok(())
             }
             None => Err(DeleteUserError::NotFound(id)),
@@ -46,10 +122,52 @@ impl From<crate::ports::user_repository::UserRepositoryError> for DeleteUserErro
             crate::ports::user_repository::UserRepositoryError::DatabaseError(msg) => {
                 Self::RepositoryError(msg)
             }
+            crate::ports::user_repository::UserRepositoryError::VersionConflict(msg) => {
+                Self::RepositoryError(msg)
+            }
         }
     }
 }
 
+impl From<TokenRepositoryError> for DeleteUserError {
+    fn from(err: TokenRepositoryError) -> Self {
+        Self::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuditLogError> for DeleteUserError {
+    fn from(err: AuditLogError) -> Self {
+        Self::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SessionRepositoryError> for DeleteUserError {
+    fn from(err: SessionRepositoryError) -> Self {
+        Self::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UnitOfWorkError> for DeleteUserError {
+    fn from(err: UnitOfWorkError) -> Self {
+        Self::RepositoryError(err.to_string())
+    }
+}
+
+impl DeleteUserError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound(_) => ErrorCode::UserNotFound,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl From<EventOutboxError> for DeleteUserError {
+    fn from(err: EventOutboxError) -> Self {
+        DeleteUserError::RepositoryError(err.to_string())
+    }
+}
+
 impl std::fmt::Display for DeleteUserError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -0,0 +1,84 @@
+use crate::error_code::ErrorCode;
+use crate::ports::audit_log::{AuditEntry, AuditLog, AuditLogError};
+use crate::ports::webhook_repository::{WebhookRepository, WebhookRepositoryError};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Unsubscribes an endpoint. Deliveries still pending are dropped with its log.
+pub struct DeleteWebhookSubscriptionUseCase {
+    webhook_repo: Arc<dyn WebhookRepository>,
+    audit_log: Arc<dyn AuditLog>,
+}
+
+impl DeleteWebhookSubscriptionUseCase {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepository>, audit_log: Arc<dyn AuditLog>) -> Self {
+        Self {
+            webhook_repo,
+            audit_log,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        admin_id: Uuid,
+        subscription_id: Uuid,
+    ) -> Result<(), DeleteWebhookSubscriptionError> {
+        if !self
+            .webhook_repo
+            .delete_subscription(subscription_id)
+            .await?
+        {
+            return Err(DeleteWebhookSubscriptionError::NotFound);
+        }
+
+        self.audit_log
+            .record(&AuditEntry::new(
+                Some(admin_id),
+                "webhook.deleted",
+                "webhook",
+                Some(subscription_id),
+                Vec::new(),
+            ))
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum DeleteWebhookSubscriptionError {
+    NotFound,
+    RepositoryError(String),
+}
+
+impl From<WebhookRepositoryError> for DeleteWebhookSubscriptionError {
+    fn from(err: WebhookRepositoryError) -> Self {
+        DeleteWebhookSubscriptionError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuditLogError> for DeleteWebhookSubscriptionError {
+    fn from(err: AuditLogError) -> Self {
+        DeleteWebhookSubscriptionError::RepositoryError(err.to_string())
+    }
+}
+
+impl DeleteWebhookSubscriptionError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound => ErrorCode::NotFound,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for DeleteWebhookSubscriptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "Webhook not found"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for DeleteWebhookSubscriptionError {}
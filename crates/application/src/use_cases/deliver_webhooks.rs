@@ -0,0 +1,149 @@
+use crate::ports::webhook_repository::{
+    WebhookDelivery, WebhookDeliveryStatus, WebhookRepository, WebhookRepositoryError,
+    WebhookSubscription,
+};
+use crate::ports::webhook_sender::WebhookSender;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const BATCH_SIZE: u64 = 10;
+/// Longer than a batch takes to send, even when every endpoint times out.
+const CLAIM_LEASE: Duration = Duration::minutes(5);
+
+#[derive(Debug, Clone, Copy)]
+pub struct WebhookRetryPolicy {
+    /// Attempts before a delivery is marked as failed.
+    pub max_attempts: u32,
+    /// Wait after the first failed attempt; doubles with every further one.
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl WebhookRetryPolicy {
+    /// How long to wait after the given number of failed attempts.
+    pub fn delay_after(&self, attempts: u32) -> Duration {
+        let factor = 2i32.pow(attempts.saturating_sub(1).min(30));
+        self.base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+}
+
+/// The webhook worker: sends due deliveries and schedules retries of failed ones
+/// with exponential backoff. Run periodically.
+pub struct DeliverWebhooksUseCase {
+    webhook_repo: Arc<dyn WebhookRepository>,
+    sender: Arc<dyn WebhookSender>,
+    policy: WebhookRetryPolicy,
+}
+
+impl DeliverWebhooksUseCase {
+    pub fn new(
+        webhook_repo: Arc<dyn WebhookRepository>,
+        sender: Arc<dyn WebhookSender>,
+        policy: WebhookRetryPolicy,
+    ) -> Self {
+        Self {
+            webhook_repo,
+            sender,
+            policy,
+        }
+    }
+
+    /// Returns the number of deliveries attempted.
+    pub async fn execute(&self) -> Result<u64, DeliverWebhooksError> {
+        let subscriptions: HashMap<Uuid, WebhookSubscription> = self
+            .webhook_repo
+            .list_subscriptions()
+            .await?
+            .into_iter()
+            .map(|subscription| (subscription.id, subscription))
+            .collect();
+        let mut attempted = 0;
+
+        loop {
+            let deliveries = self
+                .webhook_repo
+                .claim_due_deliveries(Utc::now(), CLAIM_LEASE, BATCH_SIZE)
+                .await?;
+            let batch_size = deliveries.len() as u64;
+
+            for mut delivery in deliveries {
+                // Created after this run started; the next run picks it up.
+                let Some(subscription) = subscriptions.get(&delivery.subscription_id) else {
+                    continue;
+                };
+                self.attempt(subscription, &mut delivery).await;
+                self.webhook_repo.update_delivery(&delivery).await?;
+                attempted += 1;
+            }
+
+            if batch_size < BATCH_SIZE {
+                break;
+            }
+        }
+
+        Ok(attempted)
+    }
+
+    async fn attempt(&self, subscription: &WebhookSubscription, delivery: &mut WebhookDelivery) {
+        let now = Utc::now();
+        delivery.attempts += 1;
+        delivery.last_attempt_at = Some(now);
+
+        match self
+            .sender
+            .send(&subscription.url, &subscription.secret, &delivery.event)
+            .await
+        {
+            Ok(status) if (200..300).contains(&status) => {
+                delivery.status = WebhookDeliveryStatus::Succeeded;
+                delivery.next_attempt_at = None;
+                delivery.response_status = Some(status);
+                delivery.last_error = None;
+            }
+            Ok(status) => {
+                delivery.response_status = Some(status);
+                delivery.last_error = Some(format!("Endpoint answered with HTTP {}", status));
+                self.schedule_retry(delivery, now);
+            }
+            Err(err) => {
+                delivery.response_status = None;
+                delivery.last_error = Some(err.to_string());
+                self.schedule_retry(delivery, now);
+            }
+        }
+    }
+
+    fn schedule_retry(&self, delivery: &mut WebhookDelivery, now: DateTime<Utc>) {
+        if delivery.attempts >= self.policy.max_attempts {
+            delivery.status = WebhookDeliveryStatus::Failed;
+            delivery.next_attempt_at = None;
+        } else {
+            delivery.next_attempt_at = Some(now + self.policy.delay_after(delivery.attempts));
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum DeliverWebhooksError {
+    RepositoryError(String),
+}
+
+impl From<WebhookRepositoryError> for DeliverWebhooksError {
+    fn from(err: WebhookRepositoryError) -> Self {
+        DeliverWebhooksError::RepositoryError(err.to_string())
+    }
+}
+
+impl std::fmt::Display for DeliverWebhooksError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for DeliverWebhooksError {}
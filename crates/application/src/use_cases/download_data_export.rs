@@ -0,0 +1,99 @@
+use crate::error_code::ErrorCode;
+use crate::ports::data_export_archive::{DataExportArchive, DataExportArchiveError};
+use crate::ports::data_export_repository::{
+    DataExport, DataExportRepository, DataExportRepositoryError,
+};
+use crate::ports::one_time_token_service::OneTimeTokenService;
+use chrono::Utc;
+use std::sync::Arc;
+
+pub struct DataExportDownload {
+    pub export: DataExport,
+    /// The ZIP archive.
+    pub archive: Vec<u8>,
+}
+
+/// Hands out a finished export to whoever holds the emailed link, until it expires.
+pub struct DownloadDataExportUseCase {
+    export_repo: Arc<dyn DataExportRepository>,
+    archive: Arc<dyn DataExportArchive>,
+    token_service: Arc<dyn OneTimeTokenService>,
+}
+
+impl DownloadDataExportUseCase {
+    pub fn new(
+        export_repo: Arc<dyn DataExportRepository>,
+        archive: Arc<dyn DataExportArchive>,
+        token_service: Arc<dyn OneTimeTokenService>,
+    ) -> Self {
+        Self {
+            export_repo,
+            archive,
+            token_service,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        token: &str,
+    ) -> Result<DataExportDownload, DownloadDataExportError> {
+        let export = self
+            .export_repo
+            .find_by_download_token_hash(&self.token_service.hash(token))
+            .await?
+            .ok_or(DownloadDataExportError::InvalidToken)?;
+
+        if !export.is_downloadable(Utc::now()) {
+            return Err(DownloadDataExportError::ExpiredToken);
+        }
+
+        let archive = self
+            .archive
+            .open(export.id)
+            .await?
+            .ok_or(DownloadDataExportError::ExpiredToken)?;
+
+        Ok(DataExportDownload { export, archive })
+    }
+}
+
+#[derive(Debug)]
+pub enum DownloadDataExportError {
+    InvalidToken,
+    ExpiredToken,
+    RepositoryError(String),
+}
+
+impl From<DataExportRepositoryError> for DownloadDataExportError {
+    fn from(err: DataExportRepositoryError) -> Self {
+        DownloadDataExportError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<DataExportArchiveError> for DownloadDataExportError {
+    fn from(err: DataExportArchiveError) -> Self {
+        DownloadDataExportError::RepositoryError(err.to_string())
+    }
+}
+
+impl DownloadDataExportError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidToken => ErrorCode::InvalidToken,
+            Self::ExpiredToken => ErrorCode::ExpiredToken,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for DownloadDataExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidToken => write!(f, "Invalid download link"),
+            Self::ExpiredToken => write!(f, "Download link has expired"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for DownloadDataExportError {}
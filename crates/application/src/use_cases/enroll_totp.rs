@@ -0,0 +1,119 @@
+use crate::error_code::ErrorCode;
+use crate::ports::totp_service::{TotpError, TotpService};
+use crate::ports::two_factor_repository::{
+    TotpEnrollment, TwoFactorRepository, TwoFactorRepositoryError,
+};
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Starts TOTP enrollment with a fresh secret. The enrollment stays pending until it is
+/// confirmed with a code; starting again replaces a pending secret.
+pub struct EnrollTotpUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    two_factor_repo: Arc<dyn TwoFactorRepository>,
+    totp_service: Arc<dyn TotpService>,
+}
+
+#[derive(Debug)]
+pub struct TotpSetup {
+    pub secret: String,
+    pub provisioning_uri: String,
+}
+
+impl EnrollTotpUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        two_factor_repo: Arc<dyn TwoFactorRepository>,
+        totp_service: Arc<dyn TotpService>,
+    ) -> Self {
+        Self {
+            user_repo,
+            two_factor_repo,
+            totp_service,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<TotpSetup, EnrollTotpError> {
+        let user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .filter(|user| !user.is_deleted())
+            .ok_or(EnrollTotpError::UserNotFound)?;
+
+        if let Some(existing) = self.two_factor_repo.find(user_id).await?
+            && existing.is_confirmed()
+        {
+            return Err(EnrollTotpError::AlreadyEnrolled);
+        }
+
+        let secret = self.totp_service.generate_secret();
+        let provisioning_uri = self.totp_service.provisioning_uri(&secret, user.email())?;
+
+        self.two_factor_repo
+            .save(&TotpEnrollment {
+                user_id,
+                secret: secret.clone(),
+                confirmed_at: None,
+                last_used_step: None,
+                created_at: Utc::now(),
+            })
+            .await?;
+
+        Ok(TotpSetup {
+            secret,
+            provisioning_uri,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum EnrollTotpError {
+    UserNotFound,
+    AlreadyEnrolled,
+    RepositoryError(String),
+    InternalError(String),
+}
+
+impl From<UserRepositoryError> for EnrollTotpError {
+    fn from(err: UserRepositoryError) -> Self {
+        EnrollTotpError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<TwoFactorRepositoryError> for EnrollTotpError {
+    fn from(err: TwoFactorRepositoryError) -> Self {
+        EnrollTotpError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<TotpError> for EnrollTotpError {
+    fn from(err: TotpError) -> Self {
+        EnrollTotpError::InternalError(err.to_string())
+    }
+}
+
+impl EnrollTotpError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::UserNotFound => ErrorCode::UserNotFound,
+            Self::AlreadyEnrolled => ErrorCode::Conflict,
+            Self::RepositoryError(_) | Self::InternalError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for EnrollTotpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UserNotFound => write!(f, "User not found"),
+            Self::AlreadyEnrolled => write!(f, "Two-factor authentication is already enabled"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+            Self::InternalError(msg) => write!(f, "Internal error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for EnrollTotpError {}
@@ -0,0 +1,121 @@
+use crate::ports::audit_log::{AuditEntry, AuditLog, AuditLogError};
+use crate::ports::data_export_archive::{DataExportArchive, DataExportArchiveError};
+use crate::ports::data_export_repository::{DataExportRepository, DataExportRepositoryError};
+use crate::ports::user_erasure_repository::{UserErasureRepository, UserErasureRepositoryError};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+const BATCH_SIZE: u64 = 100;
+
+/// Erases accounts that were deleted longer than the grace period ago. Run periodically.
+pub struct EraseDeletedUsersUseCase {
+    erasure_repo: Arc<dyn UserErasureRepository>,
+    export_repo: Arc<dyn DataExportRepository>,
+    export_archive: Arc<dyn DataExportArchive>,
+    audit_log: Arc<dyn AuditLog>,
+    grace_period: Duration,
+}
+
+impl EraseDeletedUsersUseCase {
+    pub fn new(
+        erasure_repo: Arc<dyn UserErasureRepository>,
+        export_repo: Arc<dyn DataExportRepository>,
+        export_archive: Arc<dyn DataExportArchive>,
+        audit_log: Arc<dyn AuditLog>,
+        grace_period: Duration,
+    ) -> Self {
+        Self {
+            erasure_repo,
+            export_repo,
+            export_archive,
+            audit_log,
+            grace_period,
+        }
+    }
+
+    /// Returns the number of accounts erased.
+    pub async fn execute(&self) -> Result<u64, EraseDeletedUsersError> {
+        let cutoff = Utc::now() - self.grace_period;
+        let mut erased = 0;
+
+        loop {
+            let user_ids = self
+                .erasure_repo
+                .find_deleted_before(cutoff, BATCH_SIZE)
+                .await?;
+            let batch_size = user_ids.len() as u64;
+            let erased_before = erased;
+
+            for user_id in user_ids {
+                // Archives live outside the database and are not removed with the rows.
+                for export in self.export_repo.find_by_user_id(user_id).await? {
+                    self.export_archive.delete(export.id).await?;
+                }
+
+                if self.erasure_repo.erase(user_id, cutoff, Utc::now()).await? {
+                    self.audit_log
+                        .record(&AuditEntry::new(
+                            None,
+                            "user.erased",
+                            "user",
+                            Some(user_id),
+                            Vec::new(),
+                        ))
+                        .await?;
+                    erased += 1;
+                }
+            }
+
+            // Users restored while the batch ran are skipped; stop rather than spin on them.
+            if batch_size < BATCH_SIZE || erased == erased_before {
+                break;
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        if erased > 0 {
+            tracing::info!(erased, "deleted accounts erased after the grace period");
+        }
+
+        Ok(erased)
+    }
+}
+
+#[derive(Debug)]
+pub enum EraseDeletedUsersError {
+    RepositoryError(String),
+}
+
+impl From<UserErasureRepositoryError> for EraseDeletedUsersError {
+    fn from(err: UserErasureRepositoryError) -> Self {
+        EraseDeletedUsersError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<DataExportRepositoryError> for EraseDeletedUsersError {
+    fn from(err: DataExportRepositoryError) -> Self {
+        EraseDeletedUsersError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<DataExportArchiveError> for EraseDeletedUsersError {
+    fn from(err: DataExportArchiveError) -> Self {
+        EraseDeletedUsersError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuditLogError> for EraseDeletedUsersError {
+    fn from(err: AuditLogError) -> Self {
+        EraseDeletedUsersError::RepositoryError(err.to_string())
+    }
+}
+
+impl std::fmt::Display for EraseDeletedUsersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for EraseDeletedUsersError {}
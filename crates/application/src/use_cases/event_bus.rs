@@ -0,0 +1,100 @@
+use crate::ports::event_publisher::{EventHandler, EventPublisher};
+use async_trait::async_trait;
+use domain_users::UserEvent;
+use std::sync::Arc;
+
+/// Runs the in-process handlers in registration order.
+///
+/// The events and the change that raised them are already committed, together with
+/// their outbox entries, when they are published, so a failing handler is logged and
+/// skipped rather than failing the request.
+#[derive(Default)]
+pub struct EventBus {
+    handlers: Vec<Arc<dyn EventHandler>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_handler(mut self, handler: Arc<dyn EventHandler>) -> Self {
+        self.handlers.push(handler);
+        self
+    }
+}
+
+#[async_trait]
+impl EventPublisher for EventBus {
+    async fn publish(&self, events: &[UserEvent]) {
+        for event in events {
+            for handler in &self.handlers {
+                if let Err(_err) = handler.handle(event).await {
+                    #[cfg(feature = "tracing")]
+                    tracing::error!(error = %_err, event = event.name(), "event handler failed");
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::event_publisher::EventHandlerError;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    struct RecordingHandler {
+        name: &'static str,
+        calls: Arc<Mutex<Vec<String>>>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl EventHandler for RecordingHandler {
+        async fn handle(&self, event: &UserEvent) -> Result<(), EventHandlerError> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("{}:{}", self.name, event.name()));
+            if self.fail {
+                return Err(EventHandlerError("boom".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_publish_runs_every_handler_even_after_one_fails() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let bus = EventBus::new()
+            .with_handler(Arc::new(RecordingHandler {
+                name: "first",
+                calls: calls.clone(),
+                fail: true,
+            }))
+            .with_handler(Arc::new(RecordingHandler {
+                name: "second",
+                calls: calls.clone(),
+                fail: false,
+            }));
+        let user_id = Uuid::new_v4();
+        let events = vec![
+            UserEvent::EmailVerified { user_id },
+            UserEvent::Deleted { user_id },
+        ];
+
+        bus.publish(&events).await;
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                "first:user.email_verified",
+                "second:user.email_verified",
+                "first:user.deleted",
+                "second:user.deleted",
+            ]
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::error_code::ErrorCode;
+use crate::ports::access_token_service::AccessTokenScope;
+use crate::ports::passkey_ceremony_store::{
+    PasskeyCeremonyKind, PasskeyCeremonyStore, PasskeyCeremonyStoreError,
+};
+use crate::ports::passkey_repository::{PasskeyRepository, PasskeyRepositoryError};
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use crate::ports::webauthn_service::{PasskeyAssertionResponse, WebauthnService};
+use crate::use_cases::login::LoginOutput;
+use crate::use_cases::session_tokens::{ClientInfo, SessionTokenError, SessionTokenIssuer};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Verifies a passkey assertion and logs its owner in. Passkeys require user
+/// verification on the authenticator, so they count as both factors and always yield
+/// a full access token, also for staff accounts.
+pub struct FinishPasskeyLoginUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    passkey_repo: Arc<dyn PasskeyRepository>,
+    ceremony_store: Arc<dyn PasskeyCeremonyStore>,
+    webauthn_service: Arc<dyn WebauthnService>,
+    sessions: Arc<SessionTokenIssuer>,
+}
+
+impl FinishPasskeyLoginUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        passkey_repo: Arc<dyn PasskeyRepository>,
+        ceremony_store: Arc<dyn PasskeyCeremonyStore>,
+        webauthn_service: Arc<dyn WebauthnService>,
+        sessions: Arc<SessionTokenIssuer>,
+    ) -> Self {
+        Self {
+            user_repo,
+            passkey_repo,
+            ceremony_store,
+            webauthn_service,
+            sessions,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        ceremony_id: Uuid,
+        response: &PasskeyAssertionResponse,
+        client: &ClientInfo,
+    ) -> Result<LoginOutput, PasskeyLoginError> {
+        let now = Utc::now();
+        let ceremony = self
+            .ceremony_store
+            .take(ceremony_id)
+            .await?
+            .filter(|ceremony| {
+                ceremony.kind == PasskeyCeremonyKind::Authentication && !ceremony.is_expired(now)
+            })
+            .ok_or(PasskeyLoginError::InvalidCeremony)?;
+
+        let passkey = self
+            .passkey_repo
+            .find_by_credential_id(&response.credential_id)
+            .await?
+            .ok_or(PasskeyLoginError::InvalidCredentials)?;
+
+        if let Some(user_handle) = &response.user_handle
+            && user_handle.as_slice() != passkey.user_id.as_bytes()
+        {
+            return Err(PasskeyLoginError::InvalidCredentials);
+        }
+
+        let sign_count = self
+            .webauthn_service
+            .verify_assertion(
+                response,
+                &ceremony.challenge,
+                &passkey.public_key,
+                passkey.sign_count,
+            )
+            .map_err(|_err| {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(passkey_id = %passkey.id, error = %_err, "passkey assertion rejected");
+                PasskeyLoginError::InvalidCredentials
+            })?;
+
+        let user = self
+            .user_repo
+            .find_by_id(passkey.user_id)
+            .await?
+            .filter(|user| !user.is_deleted())
+            .ok_or(PasskeyLoginError::InvalidCredentials)?;
+
+        self.passkey_repo
+            .record_use(passkey.id, sign_count, now)
+            .await?;
+
+        let access_token = self
+            .sessions
+            .issue(&user, AccessTokenScope::Full, client)
+            .await?;
+
+        Ok(LoginOutput {
+            user,
+            access_token,
+            scope: AccessTokenScope::Full,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum PasskeyLoginError {
+    /// The ceremony is unknown, expired or already used.
+    InvalidCeremony,
+    InvalidCredentials,
+    RepositoryError(String),
+    InternalError(String),
+}
+
+impl From<UserRepositoryError> for PasskeyLoginError {
+    fn from(err: UserRepositoryError) -> Self {
+        PasskeyLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<PasskeyRepositoryError> for PasskeyLoginError {
+    fn from(err: PasskeyRepositoryError) -> Self {
+        PasskeyLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<PasskeyCeremonyStoreError> for PasskeyLoginError {
+    fn from(err: PasskeyCeremonyStoreError) -> Self {
+        PasskeyLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SessionTokenError> for PasskeyLoginError {
+    fn from(err: SessionTokenError) -> Self {
+        match err {
+            SessionTokenError::Repository(err) => {
+                PasskeyLoginError::RepositoryError(err.to_string())
+            }
+            SessionTokenError::Token(err) => PasskeyLoginError::InternalError(err.to_string()),
+        }
+    }
+}
+
+impl PasskeyLoginError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidCeremony => ErrorCode::PasskeyChallengeInvalid,
+            Self::InvalidCredentials => ErrorCode::InvalidCredentials,
+            Self::RepositoryError(_) | Self::InternalError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for PasskeyLoginError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidCeremony => write!(f, "Passkey challenge is invalid or has expired"),
+            Self::InvalidCredentials => write!(f, "Passkey was not accepted"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+            Self::InternalError(msg) => write!(f, "Internal error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for PasskeyLoginError {}
@@ -0,0 +1,129 @@
+use crate::error_code::ErrorCode;
+use crate::ports::passkey_ceremony_store::{
+    PasskeyCeremonyKind, PasskeyCeremonyStore, PasskeyCeremonyStoreError,
+};
+use crate::ports::passkey_repository::{Passkey, PasskeyRepository, PasskeyRepositoryError};
+use crate::ports::webauthn_service::{PasskeyRegistrationResponse, WebauthnService};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const DEFAULT_PASSKEY_NAME: &str = "Passkey";
+
+/// Verifies the authenticator's answer to a registration ceremony and stores the new
+/// passkey. A user can register any number of passkeys next to their password.
+pub struct FinishPasskeyRegistrationUseCase {
+    passkey_repo: Arc<dyn PasskeyRepository>,
+    ceremony_store: Arc<dyn PasskeyCeremonyStore>,
+    webauthn_service: Arc<dyn WebauthnService>,
+}
+
+impl FinishPasskeyRegistrationUseCase {
+    pub fn new(
+        passkey_repo: Arc<dyn PasskeyRepository>,
+        ceremony_store: Arc<dyn PasskeyCeremonyStore>,
+        webauthn_service: Arc<dyn WebauthnService>,
+    ) -> Self {
+        Self {
+            passkey_repo,
+            ceremony_store,
+            webauthn_service,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        ceremony_id: Uuid,
+        name: &str,
+        response: &PasskeyRegistrationResponse,
+    ) -> Result<Passkey, FinishPasskeyRegistrationError> {
+        let now = Utc::now();
+        let ceremony = self
+            .ceremony_store
+            .take(ceremony_id)
+            .await?
+            .filter(|ceremony| {
+                ceremony.kind == PasskeyCeremonyKind::Registration
+                    && ceremony.user_id == Some(user_id)
+                    && !ceremony.is_expired(now)
+            })
+            .ok_or(FinishPasskeyRegistrationError::InvalidCeremony)?;
+
+        let verified = self
+            .webauthn_service
+            .verify_registration(response, &ceremony.challenge)
+            .map_err(|e| FinishPasskeyRegistrationError::Rejected(e.to_string()))?;
+
+        let name = name.trim();
+        let passkey = Passkey {
+            id: Uuid::new_v4(),
+            user_id,
+            credential_id: verified.credential_id,
+            public_key: verified.public_key,
+            sign_count: verified.sign_count,
+            name: if name.is_empty() {
+                DEFAULT_PASSKEY_NAME.to_string()
+            } else {
+                name.to_string()
+            },
+            created_at: now,
+            last_used_at: None,
+        };
+        self.passkey_repo.create(&passkey).await?;
+
+        Ok(passkey)
+    }
+}
+
+#[derive(Debug)]
+pub enum FinishPasskeyRegistrationError {
+    /// The ceremony is unknown, expired, already used or belongs to someone else.
+    InvalidCeremony,
+    Rejected(String),
+    AlreadyRegistered,
+    RepositoryError(String),
+}
+
+impl From<PasskeyRepositoryError> for FinishPasskeyRegistrationError {
+    fn from(err: PasskeyRepositoryError) -> Self {
+        match err {
+            PasskeyRepositoryError::DuplicateCredential => {
+                FinishPasskeyRegistrationError::AlreadyRegistered
+            }
+            PasskeyRepositoryError::DatabaseError(msg) => {
+                FinishPasskeyRegistrationError::RepositoryError(msg)
+            }
+        }
+    }
+}
+
+impl From<PasskeyCeremonyStoreError> for FinishPasskeyRegistrationError {
+    fn from(err: PasskeyCeremonyStoreError) -> Self {
+        FinishPasskeyRegistrationError::RepositoryError(err.to_string())
+    }
+}
+
+impl FinishPasskeyRegistrationError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidCeremony => ErrorCode::PasskeyChallengeInvalid,
+            Self::Rejected(_) => ErrorCode::PasskeyRejected,
+            Self::AlreadyRegistered => ErrorCode::Conflict,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for FinishPasskeyRegistrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidCeremony => write!(f, "Passkey challenge is invalid or has expired"),
+            Self::Rejected(msg) => write!(f, "{}", msg),
+            Self::AlreadyRegistered => write!(f, "Passkey is already registered"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for FinishPasskeyRegistrationError {}
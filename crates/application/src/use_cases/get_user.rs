@@ -1,3 +1,4 @@
+use crate::error_code::ErrorCode;
 use crate::ports::user_repository::{UserRepository, UserRepositoryError};
 use domain_users::User;
 use std::sync::Arc;
@@ -29,6 +30,15 @@ impl From<UserRepositoryError> for GetUserError {
             UserRepositoryError::DatabaseError(msg) => Self::RepositoryError(msg),
             UserRepositoryError::AlreadyExists(msg) => Self::RepositoryError(msg), // Should not happen for find_by_id
             UserRepositoryError::NotFound(msg) => Self::RepositoryError(msg),
+            UserRepositoryError::VersionConflict(msg) => Self::RepositoryError(msg),
+        }
+    }
+}
+
+impl GetUserError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
         }
     }
 }
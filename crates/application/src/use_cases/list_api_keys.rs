@@ -0,0 +1,47 @@
+use crate::error_code::ErrorCode;
+use crate::ports::api_key_repository::{ApiKey, ApiKeyRepository, ApiKeyRepositoryError};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ListApiKeysUseCase {
+    api_key_repo: Arc<dyn ApiKeyRepository>,
+}
+
+impl ListApiKeysUseCase {
+    pub fn new(api_key_repo: Arc<dyn ApiKeyRepository>) -> Self {
+        Self { api_key_repo }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<ApiKey>, ListApiKeysError> {
+        Ok(self.api_key_repo.find_by_user_id(user_id).await?)
+    }
+}
+
+#[derive(Debug)]
+pub enum ListApiKeysError {
+    RepositoryError(String),
+}
+
+impl From<ApiKeyRepositoryError> for ListApiKeysError {
+    fn from(err: ApiKeyRepositoryError) -> Self {
+        ListApiKeysError::RepositoryError(err.to_string())
+    }
+}
+
+impl ListApiKeysError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for ListApiKeysError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ListApiKeysError {}
@@ -0,0 +1,51 @@
+use crate::error_code::ErrorCode;
+use crate::ports::audit_log::{AuditEntry, AuditLog, AuditLogError, AuditLogFilter};
+use std::sync::Arc;
+
+pub struct ListAuditLogUseCase {
+    audit_log: Arc<dyn AuditLog>,
+}
+
+impl ListAuditLogUseCase {
+    pub fn new(audit_log: Arc<dyn AuditLog>) -> Self {
+        Self { audit_log }
+    }
+
+    pub async fn execute(
+        &self,
+        filter: &AuditLogFilter,
+        page: u64,
+        page_size: u64,
+    ) -> Result<(Vec<AuditEntry>, u64), ListAuditLogError> {
+        Ok(self.audit_log.find(filter, page, page_size).await?)
+    }
+}
+
+#[derive(Debug)]
+pub enum ListAuditLogError {
+    RepositoryError(String),
+}
+
+impl From<AuditLogError> for ListAuditLogError {
+    fn from(err: AuditLogError) -> Self {
+        ListAuditLogError::RepositoryError(err.to_string())
+    }
+}
+
+impl ListAuditLogError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for ListAuditLogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ListAuditLogError {}
@@ -0,0 +1,52 @@
+use crate::error_code::ErrorCode;
+use crate::ports::linked_identity_repository::{
+    LinkedIdentity, LinkedIdentityRepository, LinkedIdentityRepositoryError,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ListLinkedIdentitiesUseCase {
+    identity_repo: Arc<dyn LinkedIdentityRepository>,
+}
+
+impl ListLinkedIdentitiesUseCase {
+    pub fn new(identity_repo: Arc<dyn LinkedIdentityRepository>) -> Self {
+        Self { identity_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<LinkedIdentity>, ListLinkedIdentitiesError> {
+        Ok(self.identity_repo.find_by_user_id(user_id).await?)
+    }
+}
+
+#[derive(Debug)]
+pub enum ListLinkedIdentitiesError {
+    RepositoryError(String),
+}
+
+impl From<LinkedIdentityRepositoryError> for ListLinkedIdentitiesError {
+    fn from(err: LinkedIdentityRepositoryError) -> Self {
+        ListLinkedIdentitiesError::RepositoryError(err.to_string())
+    }
+}
+
+impl ListLinkedIdentitiesError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for ListLinkedIdentitiesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ListLinkedIdentitiesError {}
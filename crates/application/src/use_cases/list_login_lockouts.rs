@@ -0,0 +1,52 @@
+use crate::error_code::ErrorCode;
+use crate::ports::login_attempt_store::{LoginAttemptStore, LoginAttemptStoreError, LoginAttempts};
+use std::sync::Arc;
+
+/// Lists accounts and IPs that are currently throttled or locked out of login.
+pub struct ListLoginLockoutsUseCase {
+    attempt_store: Arc<dyn LoginAttemptStore>,
+}
+
+impl ListLoginLockoutsUseCase {
+    pub fn new(attempt_store: Arc<dyn LoginAttemptStore>) -> Self {
+        Self { attempt_store }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<LoginAttempts>, ListLoginLockoutsError> {
+        let mut lockouts = self
+            .attempt_store
+            .find_restricted(chrono::Utc::now())
+            .await?;
+        lockouts.sort_by_key(|l| std::cmp::Reverse(l.last_failed_at));
+        Ok(lockouts)
+    }
+}
+
+#[derive(Debug)]
+pub enum ListLoginLockoutsError {
+    RepositoryError(String),
+}
+
+impl From<LoginAttemptStoreError> for ListLoginLockoutsError {
+    fn from(err: LoginAttemptStoreError) -> Self {
+        ListLoginLockoutsError::RepositoryError(err.to_string())
+    }
+}
+
+impl ListLoginLockoutsError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for ListLoginLockoutsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ListLoginLockoutsError {}
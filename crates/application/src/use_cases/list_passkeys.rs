@@ -0,0 +1,47 @@
+use crate::error_code::ErrorCode;
+use crate::ports::passkey_repository::{Passkey, PasskeyRepository, PasskeyRepositoryError};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ListPasskeysUseCase {
+    passkey_repo: Arc<dyn PasskeyRepository>,
+}
+
+impl ListPasskeysUseCase {
+    pub fn new(passkey_repo: Arc<dyn PasskeyRepository>) -> Self {
+        Self { passkey_repo }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<Passkey>, ListPasskeysError> {
+        Ok(self.passkey_repo.find_by_user_id(user_id).await?)
+    }
+}
+
+#[derive(Debug)]
+pub enum ListPasskeysError {
+    RepositoryError(String),
+}
+
+impl From<PasskeyRepositoryError> for ListPasskeysError {
+    fn from(err: PasskeyRepositoryError) -> Self {
+        ListPasskeysError::RepositoryError(err.to_string())
+    }
+}
+
+impl ListPasskeysError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for ListPasskeysError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ListPasskeysError {}
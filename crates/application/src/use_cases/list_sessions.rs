@@ -0,0 +1,51 @@
+use crate::error_code::ErrorCode;
+use crate::ports::session_repository::{Session, SessionRepository, SessionRepositoryError};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ListSessionsUseCase {
+    session_repo: Arc<dyn SessionRepository>,
+}
+
+impl ListSessionsUseCase {
+    pub fn new(session_repo: Arc<dyn SessionRepository>) -> Self {
+        Self { session_repo }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<Session>, ListSessionsError> {
+        Ok(self
+            .session_repo
+            .find_active_by_user_id(user_id, Utc::now())
+            .await?)
+    }
+}
+
+#[derive(Debug)]
+pub enum ListSessionsError {
+    RepositoryError(String),
+}
+
+impl From<SessionRepositoryError> for ListSessionsError {
+    fn from(err: SessionRepositoryError) -> Self {
+        ListSessionsError::RepositoryError(err.to_string())
+    }
+}
+
+impl ListSessionsError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for ListSessionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ListSessionsError {}
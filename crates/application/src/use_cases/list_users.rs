@@ -1,3 +1,4 @@
+use crate::error_code::ErrorCode;
 use crate::ports::user_repository::{UserRepository, UserRepositoryError};
 use domain_users::User;
 use std::sync::Arc;
@@ -35,6 +36,15 @@ impl From<UserRepositoryError> for ListUsersError {
             UserRepositoryError::DatabaseError(msg) => Self::RepositoryError(msg),
             UserRepositoryError::AlreadyExists(msg) => Self::RepositoryError(msg),
             UserRepositoryError::NotFound(msg) => Self::RepositoryError(msg),
+            UserRepositoryError::VersionConflict(msg) => Self::RepositoryError(msg),
+        }
+    }
+}
+
+impl ListUsersError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
         }
     }
 }
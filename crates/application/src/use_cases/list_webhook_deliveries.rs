@@ -0,0 +1,70 @@
+use crate::error_code::ErrorCode;
+use crate::ports::webhook_repository::{
+    WebhookDelivery, WebhookRepository, WebhookRepositoryError,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// The delivery log of one subscription, newest first.
+pub struct ListWebhookDeliveriesUseCase {
+    webhook_repo: Arc<dyn WebhookRepository>,
+}
+
+impl ListWebhookDeliveriesUseCase {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepository>) -> Self {
+        Self { webhook_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        subscription_id: Uuid,
+        page: u64,
+        page_size: u64,
+    ) -> Result<(Vec<WebhookDelivery>, u64), ListWebhookDeliveriesError> {
+        if self
+            .webhook_repo
+            .find_subscription(subscription_id)
+            .await?
+            .is_none()
+        {
+            return Err(ListWebhookDeliveriesError::NotFound);
+        }
+
+        Ok(self
+            .webhook_repo
+            .list_deliveries(subscription_id, page, page_size)
+            .await?)
+    }
+}
+
+#[derive(Debug)]
+pub enum ListWebhookDeliveriesError {
+    NotFound,
+    RepositoryError(String),
+}
+
+impl From<WebhookRepositoryError> for ListWebhookDeliveriesError {
+    fn from(err: WebhookRepositoryError) -> Self {
+        ListWebhookDeliveriesError::RepositoryError(err.to_string())
+    }
+}
+
+impl ListWebhookDeliveriesError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound => ErrorCode::NotFound,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for ListWebhookDeliveriesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "Webhook not found"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ListWebhookDeliveriesError {}
@@ -0,0 +1,48 @@
+use crate::error_code::ErrorCode;
+use crate::ports::webhook_repository::{
+    WebhookRepository, WebhookRepositoryError, WebhookSubscription,
+};
+use std::sync::Arc;
+
+pub struct ListWebhookSubscriptionsUseCase {
+    webhook_repo: Arc<dyn WebhookRepository>,
+}
+
+impl ListWebhookSubscriptionsUseCase {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepository>) -> Self {
+        Self { webhook_repo }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<WebhookSubscription>, ListWebhookSubscriptionsError> {
+        Ok(self.webhook_repo.list_subscriptions().await?)
+    }
+}
+
+#[derive(Debug)]
+pub enum ListWebhookSubscriptionsError {
+    RepositoryError(String),
+}
+
+impl From<WebhookRepositoryError> for ListWebhookSubscriptionsError {
+    fn from(err: WebhookRepositoryError) -> Self {
+        ListWebhookSubscriptionsError::RepositoryError(err.to_string())
+    }
+}
+
+impl ListWebhookSubscriptionsError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for ListWebhookSubscriptionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ListWebhookSubscriptionsError {}
@@ -0,0 +1,363 @@
+use crate::error_code::ErrorCode;
+use crate::ports::access_token_service::{AccessTokenScope, IssuedAccessToken};
+use crate::ports::email_service::EmailService;
+use crate::ports::login_attempt_store::{
+    AttemptSubject, LoginAttemptStore, LoginAttemptStoreError,
+};
+use crate::ports::one_time_token_service::OneTimeTokenService;
+use crate::ports::password_hasher::PasswordHasher;
+use crate::ports::two_factor_repository::{TwoFactorRepository, TwoFactorRepositoryError};
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use crate::use_cases::session_tokens::{ClientInfo, SessionTokenError, SessionTokenIssuer};
+use chrono::{DateTime, Duration, Utc};
+use domain_users::{EmailAddress, User};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// Thresholds for slowing down and locking out repeated failed logins.
+#[derive(Debug, Clone)]
+pub struct LoginThrottlePolicy {
+    /// Failures on one email address before it is locked.
+    pub max_account_failures: u32,
+    /// Failures from one IP before it is locked; higher, as NAT puts many users behind one IP.
+    pub max_ip_failures: u32,
+    /// Failures older than this no longer count.
+    pub failure_window: Duration,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub lockout_duration: Duration,
+}
+
+impl Default for LoginThrottlePolicy {
+    fn default() -> Self {
+        Self {
+            max_account_failures: 5,
+            max_ip_failures: 20,
+            failure_window: Duration::minutes(15),
+            base_delay: Duration::seconds(1),
+            max_delay: Duration::minutes(1),
+            lockout_duration: Duration::minutes(15),
+        }
+    }
+}
+
+impl LoginThrottlePolicy {
+    /// Delay after the n-th consecutive failure: base, 2 x base, 4 x base, ... up to `max_delay`.
+    pub fn backoff(&self, failures: u32) -> Duration {
+        let exponent = failures.saturating_sub(1).min(20);
+        self.base_delay
+            .checked_mul(1 << exponent)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+
+    fn max_failures(&self, subject: &AttemptSubject) -> u32 {
+        match subject {
+            AttemptSubject::Account(_) => self.max_account_failures,
+            AttemptSubject::Ip(_) => self.max_ip_failures,
+        }
+    }
+}
+
+/// Counts failed sign-in attempts per account and client IP and turns them into backoff
+/// and lockouts. Shared by every step of the login that checks a secret.
+pub struct LoginThrottle {
+    attempt_store: Arc<dyn LoginAttemptStore>,
+    user_repo: Arc<dyn UserRepository>,
+    email_service: Arc<dyn EmailService>,
+    token_service: Arc<dyn OneTimeTokenService>,
+    policy: LoginThrottlePolicy,
+}
+
+impl LoginThrottle {
+    pub fn new(
+        attempt_store: Arc<dyn LoginAttemptStore>,
+        user_repo: Arc<dyn UserRepository>,
+        email_service: Arc<dyn EmailService>,
+        token_service: Arc<dyn OneTimeTokenService>,
+        policy: LoginThrottlePolicy,
+    ) -> Self {
+        Self {
+            attempt_store,
+            user_repo,
+            email_service,
+            token_service,
+            policy,
+        }
+    }
+
+    pub(crate) fn subjects(email: &str, client_ip: Option<IpAddr>) -> Vec<AttemptSubject> {
+        let mut subjects = vec![AttemptSubject::account(email)];
+        subjects.extend(client_ip.map(AttemptSubject::Ip));
+        subjects
+    }
+
+    /// Checked before any secret so a locked account is no password oracle.
+    pub(crate) async fn ensure_allowed(
+        &self,
+        subjects: &[AttemptSubject],
+        now: DateTime<Utc>,
+    ) -> Result<(), LoginError> {
+        for subject in subjects {
+            if let Some(attempts) = self.attempt_store.find(subject).await?
+                && let Some(retry_after_seconds) = attempts.retry_after(now)
+            {
+                return Err(match subject {
+                    AttemptSubject::Account(_) if attempts.is_locked(now) => {
+                        LoginError::AccountLocked {
+                            retry_after_seconds,
+                        }
+                    }
+                    _ => LoginError::TooManyAttempts {
+                        retry_after_seconds,
+                    },
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn register_failures(
+        &self,
+        subjects: &[AttemptSubject],
+        now: DateTime<Utc>,
+    ) -> Result<(), LoginError> {
+        for subject in subjects {
+            self.register_failure(subject, now).await?;
+        }
+        Ok(())
+    }
+
+    /// Forgets the failures of the account; IP failures expire on their own.
+    pub(crate) async fn clear_account(&self, email: &str) -> Result<(), LoginError> {
+        self.attempt_store
+            .clear(&AttemptSubject::account(email))
+            .await?;
+        Ok(())
+    }
+
+    async fn register_failure(
+        &self,
+        subject: &AttemptSubject,
+        now: DateTime<Utc>,
+    ) -> Result<(), LoginError> {
+        let mut attempts = self
+            .attempt_store
+            .record_failure(subject, now, now - self.policy.failure_window)
+            .await?;
+
+        if attempts.failed_attempts < self.policy.max_failures(subject) {
+            attempts.blocked_until = Some(now + self.policy.backoff(attempts.failed_attempts));
+            self.attempt_store.save(&attempts).await?;
+            return Ok(());
+        }
+
+        attempts.blocked_until = None;
+        attempts.locked_until = Some(now + self.policy.lockout_duration);
+        if let AttemptSubject::Account(email) = subject {
+            let token = self.token_service.generate();
+            attempts.unlock_token_hash = Some(self.token_service.hash(&token));
+            self.attempt_store.save(&attempts).await?;
+            self.send_unlock_email(email, &token).await?;
+        } else {
+            self.attempt_store.save(&attempts).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Only existing accounts get a mail; failures are counted for unknown emails too
+    /// so that lockouts do not reveal which addresses are registered.
+    async fn send_unlock_email(&self, email: &str, token: &str) -> Result<(), LoginError> {
+        let Some(user) = self.user_repo.find_active_by_email(email).await? else {
+            return Ok(());
+        };
+
+        // The lockout stays in place even if the mail cannot be sent; it expires on its own.
+        if let Err(_err) = self
+            .email_service
+            .send_account_unlock_email(user.email(), token, user.first_name())
+            .await
+        {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(error = %_err, "failed to send account unlock email");
+        }
+
+        Ok(())
+    }
+}
+
+pub struct LoginUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    password_hasher: Arc<dyn PasswordHasher>,
+    sessions: Arc<SessionTokenIssuer>,
+    two_factor_repo: Arc<dyn TwoFactorRepository>,
+    throttle: Arc<LoginThrottle>,
+}
+
+impl LoginUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        password_hasher: Arc<dyn PasswordHasher>,
+        sessions: Arc<SessionTokenIssuer>,
+        two_factor_repo: Arc<dyn TwoFactorRepository>,
+        throttle: Arc<LoginThrottle>,
+    ) -> Self {
+        Self {
+            user_repo,
+            password_hasher,
+            sessions,
+            two_factor_repo,
+            throttle,
+        }
+    }
+
+    /// Accounts with a second factor, and staff accounts that still have to enroll one,
+    /// only get a short-lived token scoped to the next step.
+    pub async fn execute(
+        &self,
+        email: &str,
+        password: &str,
+        client: &ClientInfo,
+    ) -> Result<LoginOutput, LoginError> {
+        let now = Utc::now();
+        let subjects = LoginThrottle::subjects(email, client.ip);
+        self.throttle.ensure_allowed(&subjects, now).await?;
+
+        let Some(user) = self.authenticate(email, password).await? else {
+            self.throttle.register_failures(&subjects, now).await?;
+            return Err(LoginError::InvalidCredentials);
+        };
+
+        // Failures are only forgotten once every factor has been checked.
+        let scope = next_login_step(self.two_factor_repo.as_ref(), &user).await?;
+        if scope == AccessTokenScope::Full {
+            self.throttle.clear_account(email).await?;
+        }
+
+        let access_token = self.sessions.issue(&user, scope, client).await?;
+
+        Ok(LoginOutput {
+            user,
+            access_token,
+            scope,
+        })
+    }
+
+    async fn authenticate(&self, email: &str, password: &str) -> Result<Option<User>, LoginError> {
+        let user = match EmailAddress::parse(email) {
+            Ok(email) => self.user_repo.find_active_by_email(email.as_str()).await?,
+            Err(_) => None,
+        };
+        let Some(user) = user else {
+            // Hash anyway, so the response time does not tell which emails are registered.
+            let _ = self.password_hasher.hash(password).await;
+            return Ok(None);
+        };
+
+        let valid = self
+            .password_hasher
+            .verify(password, user.password_hash().as_str())
+            .await
+            .map_err(|e| LoginError::InternalError(format!("Failed to verify password: {}", e)))?;
+
+        Ok(valid.then_some(user))
+    }
+}
+
+/// Scope of the token issued after the first factor: accounts with a second factor, and
+/// staff accounts that still have to enroll one, are not done yet.
+pub(crate) async fn next_login_step(
+    two_factor_repo: &dyn TwoFactorRepository,
+    user: &User,
+) -> Result<AccessTokenScope, TwoFactorRepositoryError> {
+    let enrollment = two_factor_repo.find(user.id()).await?;
+    Ok(match enrollment {
+        Some(enrollment) if enrollment.is_confirmed() => AccessTokenScope::TotpChallenge,
+        _ if user.role().requires_two_factor() => AccessTokenScope::TotpEnrollment,
+        _ => AccessTokenScope::Full,
+    })
+}
+
+pub struct LoginOutput {
+    pub user: User,
+    pub access_token: IssuedAccessToken,
+    /// Anything but [`AccessTokenScope::Full`] means another login step is required.
+    pub scope: AccessTokenScope,
+}
+
+#[derive(Debug)]
+pub enum LoginError {
+    InvalidCredentials,
+    InvalidTwoFactorCode,
+    TooManyAttempts { retry_after_seconds: u64 },
+    AccountLocked { retry_after_seconds: u64 },
+    RepositoryError(String),
+    InternalError(String),
+}
+
+impl From<UserRepositoryError> for LoginError {
+    fn from(err: UserRepositoryError) -> Self {
+        LoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<LoginAttemptStoreError> for LoginError {
+    fn from(err: LoginAttemptStoreError) -> Self {
+        LoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<TwoFactorRepositoryError> for LoginError {
+    fn from(err: TwoFactorRepositoryError) -> Self {
+        LoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SessionTokenError> for LoginError {
+    fn from(err: SessionTokenError) -> Self {
+        match err {
+            SessionTokenError::Repository(err) => LoginError::RepositoryError(err.to_string()),
+            SessionTokenError::Token(err) => LoginError::InternalError(err.to_string()),
+        }
+    }
+}
+
+impl LoginError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidCredentials => ErrorCode::InvalidCredentials,
+            Self::InvalidTwoFactorCode => ErrorCode::InvalidTwoFactorCode,
+            Self::TooManyAttempts { .. } => ErrorCode::TooManyRequests,
+            Self::AccountLocked { .. } => ErrorCode::AccountLocked,
+            Self::RepositoryError(_) | Self::InternalError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for LoginError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidCredentials => write!(f, "Invalid email or password"),
+            Self::InvalidTwoFactorCode => write!(f, "Invalid authentication code"),
+            Self::TooManyAttempts {
+                retry_after_seconds,
+            } => write!(
+                f,
+                "Too many failed login attempts, retry in {} seconds",
+                retry_after_seconds
+            ),
+            Self::AccountLocked {
+                retry_after_seconds,
+            } => write!(
+                f,
+                "Account is temporarily locked, retry in {} seconds or use the unlock link sent by email",
+                retry_after_seconds
+            ),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+            Self::InternalError(msg) => write!(f, "Internal error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for LoginError {}
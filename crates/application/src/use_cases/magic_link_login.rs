@@ -0,0 +1,136 @@
+use crate::error_code::ErrorCode;
+use crate::ports::TokenRepositoryError;
+use crate::ports::magic_link_token_repository::MagicLinkTokenRepository;
+use crate::ports::one_time_token_service::OneTimeTokenService;
+use crate::ports::two_factor_repository::{TwoFactorRepository, TwoFactorRepositoryError};
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use crate::use_cases::login::{LoginOutput, next_login_step};
+use crate::use_cases::session_tokens::{ClientInfo, SessionTokenError, SessionTokenIssuer};
+use chrono::Utc;
+use std::sync::Arc;
+
+/// Redeems a login link from [`RequestMagicLinkUseCase`](super::RequestMagicLinkUseCase).
+/// The link replaces the password only: accounts with a second factor still get the
+/// partial token for the TOTP step.
+pub struct MagicLinkLoginUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    token_repo: Arc<dyn MagicLinkTokenRepository>,
+    one_time_token_service: Arc<dyn OneTimeTokenService>,
+    two_factor_repo: Arc<dyn TwoFactorRepository>,
+    sessions: Arc<SessionTokenIssuer>,
+}
+
+impl MagicLinkLoginUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        token_repo: Arc<dyn MagicLinkTokenRepository>,
+        one_time_token_service: Arc<dyn OneTimeTokenService>,
+        two_factor_repo: Arc<dyn TwoFactorRepository>,
+        sessions: Arc<SessionTokenIssuer>,
+    ) -> Self {
+        Self {
+            user_repo,
+            token_repo,
+            one_time_token_service,
+            two_factor_repo,
+            sessions,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        token: &str,
+        client: &ClientInfo,
+    ) -> Result<LoginOutput, MagicLinkLoginError> {
+        let token_hash = self.one_time_token_service.hash(token.trim());
+        let magic_link = self
+            .token_repo
+            .find_by_token_hash(&token_hash)
+            .await?
+            .ok_or(MagicLinkLoginError::InvalidToken)?;
+
+        // Deleting first makes the link single-use even under concurrent requests.
+        if !self.token_repo.delete_by_token_hash(&token_hash).await? {
+            return Err(MagicLinkLoginError::InvalidToken);
+        }
+        if magic_link.is_expired(Utc::now()) {
+            return Err(MagicLinkLoginError::ExpiredToken);
+        }
+
+        let user = self
+            .user_repo
+            .find_by_id(magic_link.user_id)
+            .await?
+            .filter(|user| !user.is_deleted() && user.is_email_verified())
+            .ok_or(MagicLinkLoginError::InvalidToken)?;
+
+        let scope = next_login_step(self.two_factor_repo.as_ref(), &user).await?;
+        let access_token = self.sessions.issue(&user, scope, client).await?;
+
+        Ok(LoginOutput {
+            user,
+            access_token,
+            scope,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum MagicLinkLoginError {
+    InvalidToken,
+    ExpiredToken,
+    RepositoryError(String),
+    InternalError(String),
+}
+
+impl From<UserRepositoryError> for MagicLinkLoginError {
+    fn from(err: UserRepositoryError) -> Self {
+        MagicLinkLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<TokenRepositoryError> for MagicLinkLoginError {
+    fn from(err: TokenRepositoryError) -> Self {
+        MagicLinkLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<TwoFactorRepositoryError> for MagicLinkLoginError {
+    fn from(err: TwoFactorRepositoryError) -> Self {
+        MagicLinkLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<SessionTokenError> for MagicLinkLoginError {
+    fn from(err: SessionTokenError) -> Self {
+        match err {
+            SessionTokenError::Repository(err) => {
+                MagicLinkLoginError::RepositoryError(err.to_string())
+            }
+            SessionTokenError::Token(err) => MagicLinkLoginError::InternalError(err.to_string()),
+        }
+    }
+}
+
+impl MagicLinkLoginError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidToken => ErrorCode::InvalidToken,
+            Self::ExpiredToken => ErrorCode::ExpiredToken,
+            Self::RepositoryError(_) | Self::InternalError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for MagicLinkLoginError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidToken => write!(f, "Invalid or already used login link"),
+            Self::ExpiredToken => write!(f, "Login link has expired"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+            Self::InternalError(msg) => write!(f, "Internal error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for MagicLinkLoginError {}
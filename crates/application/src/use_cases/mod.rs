@@ -1,17 +1,140 @@
+pub mod authenticate_api_key;
+pub mod change_user_role;
+pub mod check_session;
+pub mod clear_login_lockout;
+pub mod collect_user_data;
+pub mod complete_oidc_login;
+pub mod complete_totp_login;
+pub mod confirm_identity_link;
+pub mod confirm_totp;
+pub mod create_api_key;
 pub mod create_user;
+pub mod create_webhook_subscription;
+pub mod delete_api_key;
+pub mod delete_passkey;
 pub mod delete_user;
+pub mod delete_webhook_subscription;
+pub mod deliver_webhooks;
+pub mod download_data_export;
+pub mod enroll_totp;
+pub mod erase_deleted_users;
+pub mod event_bus;
+pub mod finish_passkey_login;
+pub mod finish_passkey_registration;
 pub mod get_user;
+pub mod list_api_keys;
+pub mod list_audit_log;
+pub mod list_linked_identities;
+pub mod list_login_lockouts;
+pub mod list_passkeys;
+pub mod list_sessions;
 pub mod list_users;
+pub mod list_webhook_deliveries;
+pub mod list_webhook_subscriptions;
+pub mod login;
+pub mod magic_link_login;
+pub mod process_data_export;
+pub mod purge_audit_log;
+pub mod redeliver_webhook;
+pub mod relay_outbox_events;
+pub mod request_data_export;
+pub mod request_magic_link;
 pub mod request_password_reset;
 pub mod reset_password;
+pub mod restore_account;
+pub mod restore_user;
+pub mod revoke_all_sessions;
+pub mod revoke_session;
+pub mod session_tokens;
 pub mod signup;
+pub mod start_oidc_login;
+pub mod start_passkey_login;
+pub mod start_passkey_registration;
+pub mod unlock_account;
+pub mod update_user_profile;
+pub mod user_fields;
 pub mod verify_email;
+pub mod webhook_fan_out;
 
+pub use authenticate_api_key::{
+    ApiKeyPrincipal, AuthenticateApiKeyError, AuthenticateApiKeyUseCase,
+};
+pub use change_user_role::{ChangeUserRoleError, ChangeUserRoleUseCase};
+pub use check_session::{CheckSessionError, CheckSessionUseCase};
+pub use clear_login_lockout::{ClearLoginLockoutError, ClearLoginLockoutUseCase};
+pub use collect_user_data::{CollectUserDataError, UserDataCollector};
+pub use complete_oidc_login::{
+    CompleteOidcLoginError, CompleteOidcLoginUseCase, IdentityLinkRequired, OidcLoginOutcome,
+};
+pub use complete_totp_login::CompleteTotpLoginUseCase;
+pub use confirm_identity_link::{ConfirmIdentityLinkError, ConfirmIdentityLinkUseCase};
+pub use confirm_totp::{ConfirmTotpError, ConfirmTotpOutput, ConfirmTotpUseCase};
+pub use create_api_key::{
+    API_KEY_DISPLAY_PREFIX_LENGTH, API_KEY_PREFIX, CreateApiKeyError, CreateApiKeyInput,
+    CreateApiKeyUseCase, CreatedApiKey,
+};
 pub use create_user::{CreateUserError, CreateUserInput, CreateUserUseCase};
+pub use create_webhook_subscription::{
+    CreateWebhookSubscriptionError, CreateWebhookSubscriptionInput,
+    CreateWebhookSubscriptionUseCase, WEBHOOK_SECRET_PREFIX,
+};
+pub use delete_api_key::{DeleteApiKeyError, DeleteApiKeyUseCase};
+pub use delete_passkey::{DeletePasskeyError, DeletePasskeyUseCase};
 pub use delete_user::{DeleteUserError, DeleteUserUseCase};
+pub use delete_webhook_subscription::{
+    DeleteWebhookSubscriptionError, DeleteWebhookSubscriptionUseCase,
+};
+pub use deliver_webhooks::{DeliverWebhooksError, DeliverWebhooksUseCase, WebhookRetryPolicy};
+pub use download_data_export::{
+    DataExportDownload, DownloadDataExportError, DownloadDataExportUseCase,
+};
+pub use enroll_totp::{EnrollTotpError, EnrollTotpUseCase, TotpSetup};
+pub use erase_deleted_users::{EraseDeletedUsersError, EraseDeletedUsersUseCase};
+pub use event_bus::EventBus;
+pub use finish_passkey_login::{FinishPasskeyLoginUseCase, PasskeyLoginError};
+pub use finish_passkey_registration::{
+    FinishPasskeyRegistrationError, FinishPasskeyRegistrationUseCase,
+};
 pub use get_user::{GetUserError, GetUserUseCase};
+pub use list_api_keys::{ListApiKeysError, ListApiKeysUseCase};
+pub use list_audit_log::{ListAuditLogError, ListAuditLogUseCase};
+pub use list_linked_identities::{ListLinkedIdentitiesError, ListLinkedIdentitiesUseCase};
+pub use list_login_lockouts::{ListLoginLockoutsError, ListLoginLockoutsUseCase};
+pub use list_passkeys::{ListPasskeysError, ListPasskeysUseCase};
+pub use list_sessions::{ListSessionsError, ListSessionsUseCase};
 pub use list_users::{ListUsersError, ListUsersUseCase};
+pub use list_webhook_deliveries::{ListWebhookDeliveriesError, ListWebhookDeliveriesUseCase};
+pub use list_webhook_subscriptions::{
+    ListWebhookSubscriptionsError, ListWebhookSubscriptionsUseCase,
+};
+pub use login::{LoginError, LoginOutput, LoginThrottle, LoginThrottlePolicy, LoginUseCase};
+pub use magic_link_login::{MagicLinkLoginError, MagicLinkLoginUseCase};
+pub use process_data_export::{ProcessDataExportError, ProcessDataExportUseCase};
+pub use purge_audit_log::PurgeAuditLogUseCase;
+pub use redeliver_webhook::{RedeliverWebhookError, RedeliverWebhookUseCase};
+pub use relay_outbox_events::{RelayOutboxEventsError, RelayOutboxEventsUseCase};
+pub use request_data_export::{RequestDataExportError, RequestDataExportUseCase};
+pub use request_magic_link::{RequestMagicLinkError, RequestMagicLinkUseCase};
 pub use request_password_reset::{RequestPasswordResetError, RequestPasswordResetUseCase};
 pub use reset_password::{ResetPasswordError, ResetPasswordUseCase};
+pub use restore_account::{RestoreAccountError, RestoreAccountUseCase};
+pub use restore_user::{RestoreUserError, RestoreUserUseCase};
+pub use revoke_all_sessions::{RevokeAllSessionsError, RevokeAllSessionsUseCase};
+pub use revoke_session::{RevokeSessionError, RevokeSessionUseCase};
+pub use session_tokens::{ClientInfo, SessionTokenError, SessionTokenIssuer};
 pub use signup::{SignupError, SignupInput, SignupUseCase};
+pub use start_oidc_login::{OidcLoginStarted, StartOidcLoginError, StartOidcLoginUseCase};
+pub use start_passkey_login::{
+    PasskeyLoginOptions, StartPasskeyLoginError, StartPasskeyLoginUseCase,
+};
+pub use start_passkey_registration::{
+    PasskeyOptions, PasskeyRegistrationOptions, StartPasskeyRegistrationError,
+    StartPasskeyRegistrationUseCase,
+};
+pub use unlock_account::{UnlockAccountError, UnlockAccountUseCase};
+pub use update_user_profile::{
+    UpdateUserProfileError, UpdateUserProfileInput, UpdateUserProfileUseCase,
+};
+pub use user_fields::FieldError;
 pub use verify_email::{VerifyEmailError, VerifyEmailUseCase};
+pub use webhook_fan_out::WebhookFanOut;
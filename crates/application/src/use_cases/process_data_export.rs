@@ -0,0 +1,137 @@
+use crate::ports::data_export_archive::{DataExportArchive, DataExportArchiveError};
+use crate::ports::data_export_repository::{
+    DataExport, DataExportRepository, DataExportRepositoryError,
+};
+use crate::ports::email_service::{EmailError, EmailService};
+use crate::ports::one_time_token_service::OneTimeTokenService;
+use crate::use_cases::collect_user_data::{CollectUserDataError, UserDataCollector};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// The export worker: builds the archive for the next queued export and emails the
+/// data subject a link that works for the download period. Run periodically.
+pub struct ProcessDataExportUseCase {
+    collector: Arc<UserDataCollector>,
+    export_repo: Arc<dyn DataExportRepository>,
+    archive: Arc<dyn DataExportArchive>,
+    token_service: Arc<dyn OneTimeTokenService>,
+    email_service: Arc<dyn EmailService>,
+    download_ttl: Duration,
+}
+
+impl ProcessDataExportUseCase {
+    pub fn new(
+        collector: Arc<UserDataCollector>,
+        export_repo: Arc<dyn DataExportRepository>,
+        archive: Arc<dyn DataExportArchive>,
+        token_service: Arc<dyn OneTimeTokenService>,
+        email_service: Arc<dyn EmailService>,
+        download_ttl: Duration,
+    ) -> Self {
+        Self {
+            collector,
+            export_repo,
+            archive,
+            token_service,
+            email_service,
+            download_ttl,
+        }
+    }
+
+    /// Processes one queued export and returns its id, or `None` when the queue is
+    /// empty. A failed export is marked as such and not retried.
+    pub async fn execute(&self) -> Result<Option<Uuid>, ProcessDataExportError> {
+        let Some(export) = self.export_repo.claim_next_pending().await? else {
+            return Ok(None);
+        };
+
+        if let Err(err) = self.export(&export).await {
+            self.export_repo.mark_failed(export.id, Utc::now()).await?;
+            self.archive.delete(export.id).await?;
+            return Err(err);
+        }
+
+        Ok(Some(export.id))
+    }
+
+    /// Removes archives whose download period ended and returns how many there were.
+    pub async fn remove_expired(&self) -> Result<u64, ProcessDataExportError> {
+        let expired = self.export_repo.expire(Utc::now()).await?;
+        for export in &expired {
+            self.archive.delete(export.id).await?;
+        }
+        Ok(expired.len() as u64)
+    }
+
+    async fn export(&self, export: &DataExport) -> Result<(), ProcessDataExportError> {
+        let snapshot = self
+            .collector
+            .collect(export.user_id)
+            .await?
+            .ok_or(ProcessDataExportError::UserNotFound(export.user_id))?;
+
+        self.archive.store(export.id, &snapshot).await?;
+
+        let token = self.token_service.generate();
+        let now = Utc::now();
+        self.export_repo
+            .mark_ready(
+                export.id,
+                &self.token_service.hash(&token),
+                now,
+                now + self.download_ttl,
+            )
+            .await?;
+
+        self.email_service
+            .send_data_export_ready_email(snapshot.user.email(), &token, snapshot.user.first_name())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum ProcessDataExportError {
+    /// The user was deleted after requesting the export.
+    UserNotFound(Uuid),
+    RepositoryError(String),
+    EmailError(String),
+}
+
+impl From<CollectUserDataError> for ProcessDataExportError {
+    fn from(err: CollectUserDataError) -> Self {
+        ProcessDataExportError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<DataExportRepositoryError> for ProcessDataExportError {
+    fn from(err: DataExportRepositoryError) -> Self {
+        ProcessDataExportError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<DataExportArchiveError> for ProcessDataExportError {
+    fn from(err: DataExportArchiveError) -> Self {
+        ProcessDataExportError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<EmailError> for ProcessDataExportError {
+    fn from(err: EmailError) -> Self {
+        ProcessDataExportError::EmailError(err.to_string())
+    }
+}
+
+impl std::fmt::Display for ProcessDataExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UserNotFound(id) => write!(f, "User with ID {} not found", id),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+            Self::EmailError(msg) => write!(f, "Email error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ProcessDataExportError {}
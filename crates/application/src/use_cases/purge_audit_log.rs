@@ -0,0 +1,33 @@
+use crate::ports::audit_log::{AuditLog, AuditLogError};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+/// Removes audit entries older than the retention period. Run periodically.
+pub struct PurgeAuditLogUseCase {
+    audit_log: Arc<dyn AuditLog>,
+    retention: Duration,
+}
+
+impl PurgeAuditLogUseCase {
+    pub fn new(audit_log: Arc<dyn AuditLog>, retention: Duration) -> Self {
+        Self {
+            audit_log,
+            retention,
+        }
+    }
+
+    /// Returns the number of entries removed.
+    pub async fn execute(&self) -> Result<u64, AuditLogError> {
+        let purged = self
+            .audit_log
+            .delete_older_than(Utc::now() - self.retention)
+            .await?;
+
+        #[cfg(feature = "tracing")]
+        if purged > 0 {
+            tracing::info!(purged, "expired audit log entries removed");
+        }
+
+        Ok(purged)
+    }
+}
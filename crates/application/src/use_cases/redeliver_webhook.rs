@@ -0,0 +1,91 @@
+use crate::error_code::ErrorCode;
+use crate::ports::audit_log::{AuditEntry, AuditLog, AuditLogError};
+use crate::ports::webhook_repository::{
+    WebhookDelivery, WebhookDeliveryStatus, WebhookRepository, WebhookRepositoryError,
+};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Queues a delivery to be sent again right away, with a fresh set of attempts,
+/// e.g. after a partner fixed their endpoint.
+pub struct RedeliverWebhookUseCase {
+    webhook_repo: Arc<dyn WebhookRepository>,
+    audit_log: Arc<dyn AuditLog>,
+}
+
+impl RedeliverWebhookUseCase {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepository>, audit_log: Arc<dyn AuditLog>) -> Self {
+        Self {
+            webhook_repo,
+            audit_log,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        admin_id: Uuid,
+        delivery_id: Uuid,
+    ) -> Result<WebhookDelivery, RedeliverWebhookError> {
+        let mut delivery = self
+            .webhook_repo
+            .find_delivery(delivery_id)
+            .await?
+            .ok_or(RedeliverWebhookError::NotFound)?;
+
+        delivery.status = WebhookDeliveryStatus::Pending;
+        delivery.attempts = 0;
+        delivery.next_attempt_at = Some(Utc::now());
+        self.webhook_repo.update_delivery(&delivery).await?;
+
+        self.audit_log
+            .record(&AuditEntry::new(
+                Some(admin_id),
+                "webhook.redelivered",
+                "webhook_delivery",
+                Some(delivery.id),
+                Vec::new(),
+            ))
+            .await?;
+
+        Ok(delivery)
+    }
+}
+
+#[derive(Debug)]
+pub enum RedeliverWebhookError {
+    NotFound,
+    RepositoryError(String),
+}
+
+impl From<WebhookRepositoryError> for RedeliverWebhookError {
+    fn from(err: WebhookRepositoryError) -> Self {
+        RedeliverWebhookError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuditLogError> for RedeliverWebhookError {
+    fn from(err: AuditLogError) -> Self {
+        RedeliverWebhookError::RepositoryError(err.to_string())
+    }
+}
+
+impl RedeliverWebhookError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound => ErrorCode::NotFound,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for RedeliverWebhookError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "Webhook delivery not found"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for RedeliverWebhookError {}
@@ -0,0 +1,196 @@
+use crate::ports::event_outbox::{EventOutbox, EventOutboxError, OutboxConsumer};
+use std::sync::Arc;
+
+const BATCH_SIZE: u64 = 100;
+
+/// Feeds outbox events to one consumer, picking up after the last event it processed.
+/// Run periodically. Delivery is at least once: an event whose position could not be
+/// committed is handed to the consumer again on the next run.
+pub struct RelayOutboxEventsUseCase {
+    outbox: Arc<dyn EventOutbox>,
+    consumer: Arc<dyn OutboxConsumer>,
+}
+
+impl RelayOutboxEventsUseCase {
+    pub fn new(outbox: Arc<dyn EventOutbox>, consumer: Arc<dyn OutboxConsumer>) -> Self {
+        Self { outbox, consumer }
+    }
+
+    /// Returns the number of events processed. Stops at the first event the consumer
+    /// rejects so that events are never skipped or reordered.
+    pub async fn execute(&self) -> Result<u64, RelayOutboxEventsError> {
+        let name = self.consumer.name();
+        let mut position = self.outbox.consumer_position(name).await?;
+        let mut processed = 0;
+
+        loop {
+            let events = self.outbox.read_after(position, BATCH_SIZE).await?;
+            let batch_size = events.len() as u64;
+
+            for event in events {
+                self.consumer.consume(&event).await.map_err(|e| {
+                    RelayOutboxEventsError::ConsumerError {
+                        position: event.position,
+                        message: e.to_string(),
+                    }
+                })?;
+                self.outbox.commit_position(name, event.position).await?;
+                position = event.position;
+                processed += 1;
+            }
+
+            if batch_size < BATCH_SIZE {
+                break;
+            }
+        }
+
+        Ok(processed)
+    }
+}
+
+#[derive(Debug)]
+pub enum RelayOutboxEventsError {
+    ConsumerError { position: i64, message: String },
+    RepositoryError(String),
+}
+
+impl From<EventOutboxError> for RelayOutboxEventsError {
+    fn from(err: EventOutboxError) -> Self {
+        RelayOutboxEventsError::RepositoryError(err.to_string())
+    }
+}
+
+impl std::fmt::Display for RelayOutboxEventsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ConsumerError { position, message } => {
+                write!(f, "Consumer failed at position {}: {}", position, message)
+            }
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for RelayOutboxEventsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::event_outbox::OutboxEvent;
+    use crate::ports::event_publisher::EventHandlerError;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use domain_users::UserEvent;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    #[derive(Default)]
+    struct InMemoryOutbox {
+        events: Mutex<Vec<OutboxEvent>>,
+        positions: Mutex<HashMap<String, i64>>,
+    }
+
+    #[async_trait]
+    impl EventOutbox for InMemoryOutbox {
+        async fn append(&self, events: &[UserEvent]) -> Result<(), EventOutboxError> {
+            let mut stored = self.events.lock().unwrap();
+            for event in events {
+                let position = stored.len() as i64 + 1;
+                stored.push(OutboxEvent {
+                    position,
+                    id: Uuid::new_v4(),
+                    name: event.name().to_string(),
+                    aggregate_id: event.user_id(),
+                    payload: "{}".to_string(),
+                    occurred_at: Utc::now(),
+                });
+            }
+            Ok(())
+        }
+        async fn read_after(
+            &self,
+            position: i64,
+            limit: u64,
+        ) -> Result<Vec<OutboxEvent>, EventOutboxError> {
+            Ok(self
+                .events
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|e| e.position > position)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+        async fn consumer_position(&self, consumer: &str) -> Result<i64, EventOutboxError> {
+            Ok(self
+                .positions
+                .lock()
+                .unwrap()
+                .get(consumer)
+                .copied()
+                .unwrap_or(0))
+        }
+        async fn commit_position(
+            &self,
+            consumer: &str,
+            position: i64,
+        ) -> Result<(), EventOutboxError> {
+            self.positions
+                .lock()
+                .unwrap()
+                .insert(consumer.to_string(), position);
+            Ok(())
+        }
+    }
+
+    struct RecordingConsumer {
+        seen: Mutex<Vec<i64>>,
+        fail_at: Mutex<Option<i64>>,
+    }
+
+    #[async_trait]
+    impl OutboxConsumer for RecordingConsumer {
+        fn name(&self) -> &str {
+            "recorder"
+        }
+        async fn consume(&self, event: &OutboxEvent) -> Result<(), EventHandlerError> {
+            if *self.fail_at.lock().unwrap() == Some(event.position) {
+                return Err(EventHandlerError("unavailable".to_string()));
+            }
+            self.seen.lock().unwrap().push(event.position);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_relay_resumes_after_a_failed_event() {
+        let outbox = Arc::new(InMemoryOutbox::default());
+        let user_id = Uuid::new_v4();
+        outbox
+            .append(&[
+                UserEvent::EmailVerified { user_id },
+                UserEvent::PasswordChanged { user_id },
+                UserEvent::Deleted { user_id },
+            ])
+            .await
+            .unwrap();
+        let consumer = Arc::new(RecordingConsumer {
+            seen: Mutex::new(Vec::new()),
+            fail_at: Mutex::new(Some(2)),
+        });
+        let use_case = RelayOutboxEventsUseCase::new(outbox.clone(), consumer.clone());
+
+        assert!(matches!(
+            use_case.execute().await,
+            Err(RelayOutboxEventsError::ConsumerError { position: 2, .. })
+        ));
+        assert_eq!(outbox.consumer_position("recorder").await.unwrap(), 1);
+
+        *consumer.fail_at.lock().unwrap() = None;
+        assert_eq!(use_case.execute().await.unwrap(), 2);
+        assert_eq!(*consumer.seen.lock().unwrap(), vec![1, 2, 3]);
+        assert_eq!(use_case.execute().await.unwrap(), 0);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::error_code::ErrorCode;
+use crate::ports::audit_log::{AuditEntry, AuditLog, AuditLogError};
+use crate::ports::data_export_repository::{
+    DataExport, DataExportRepository, DataExportRepositoryError, DataExportStatus,
+};
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Queues a copy of everything stored about a user. The export worker collects it
+/// and emails the user a download link; a request while one is queued returns that one.
+pub struct RequestDataExportUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    export_repo: Arc<dyn DataExportRepository>,
+    audit_log: Arc<dyn AuditLog>,
+}
+
+impl RequestDataExportUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        export_repo: Arc<dyn DataExportRepository>,
+        audit_log: Arc<dyn AuditLog>,
+    ) -> Self {
+        Self {
+            user_repo,
+            export_repo,
+            audit_log,
+        }
+    }
+
+    /// `requested_by` is the user themselves or an admin acting on their behalf.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        requested_by: Uuid,
+    ) -> Result<DataExport, RequestDataExportError> {
+        self.user_repo
+            .find_by_id(user_id)
+            .await?
+            .filter(|user| user.deleted_at().is_none())
+            .ok_or(RequestDataExportError::NotFound(user_id))?;
+
+        if let Some(open) = self.export_repo.find_open_by_user_id(user_id).await? {
+            return Ok(open);
+        }
+
+        let export = DataExport {
+            id: Uuid::new_v4(),
+            user_id,
+            requested_by,
+            status: DataExportStatus::Pending,
+            download_token_hash: None,
+            created_at: Utc::now(),
+            completed_at: None,
+            expires_at: None,
+        };
+        self.export_repo.create(&export).await?;
+
+        self.audit_log
+            .record(&AuditEntry::new(
+                Some(requested_by),
+                "user.data_export_requested",
+                "user",
+                Some(user_id),
+                Vec::new(),
+            ))
+            .await?;
+
+        Ok(export)
+    }
+}
+
+#[derive(Debug)]
+pub enum RequestDataExportError {
+    NotFound(Uuid),
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for RequestDataExportError {
+    fn from(err: UserRepositoryError) -> Self {
+        RequestDataExportError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<DataExportRepositoryError> for RequestDataExportError {
+    fn from(err: DataExportRepositoryError) -> Self {
+        RequestDataExportError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuditLogError> for RequestDataExportError {
+    fn from(err: AuditLogError) -> Self {
+        RequestDataExportError::RepositoryError(err.to_string())
+    }
+}
+
+impl RequestDataExportError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound(_) => ErrorCode::UserNotFound,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for RequestDataExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound(id) => write!(f, "User with ID {} not found", id),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for RequestDataExportError {}
@@ -0,0 +1,119 @@
+use crate::error_code::ErrorCode;
+use crate::ports::TokenRepositoryError;
+use crate::ports::email_service::{EmailError, EmailService};
+use crate::ports::magic_link_token_repository::{MagicLinkToken, MagicLinkTokenRepository};
+use crate::ports::one_time_token_service::OneTimeTokenService;
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use chrono::{Duration, Utc};
+use domain_users::EmailAddress;
+use std::sync::Arc;
+
+/// Emails a single-use login link. Only verified addresses get one, as the link is
+/// as good as the password.
+pub struct RequestMagicLinkUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    token_repo: Arc<dyn MagicLinkTokenRepository>,
+    token_service: Arc<dyn OneTimeTokenService>,
+    email_service: Arc<dyn EmailService>,
+    ttl: Duration,
+}
+
+impl RequestMagicLinkUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        token_repo: Arc<dyn MagicLinkTokenRepository>,
+        token_service: Arc<dyn OneTimeTokenService>,
+        email_service: Arc<dyn EmailService>,
+        ttl: Duration,
+    ) -> Self {
+        Self {
+            user_repo,
+            token_repo,
+            token_service,
+            email_service,
+            ttl,
+        }
+    }
+
+    pub async fn execute(&self, email: &str) -> Result<(), RequestMagicLinkError> {
+        let email = EmailAddress::parse(email).map_err(|_| RequestMagicLinkError::UserNotFound)?;
+        let user = self
+            .user_repo
+            .find_active_by_email(email.as_str())
+            .await?
+            .ok_or(RequestMagicLinkError::UserNotFound)?;
+
+        if !user.is_email_verified() {
+            return Err(RequestMagicLinkError::EmailNotVerified);
+        }
+
+        // Only the latest link works.
+        self.token_repo.delete_by_user_id(&user.id()).await?;
+
+        let token = self.token_service.generate();
+        let now = Utc::now();
+        self.token_repo
+            .create(&MagicLinkToken {
+                token_hash: self.token_service.hash(&token),
+                user_id: user.id(),
+                created_at: now,
+                expires_at: now + self.ttl,
+            })
+            .await?;
+
+        self.email_service
+            .send_magic_link_email(user.email(), &token, user.first_name())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum RequestMagicLinkError {
+    UserNotFound,
+    EmailNotVerified,
+    RepositoryError(String),
+    EmailError(String),
+}
+
+impl From<UserRepositoryError> for RequestMagicLinkError {
+    fn from(err: UserRepositoryError) -> Self {
+        RequestMagicLinkError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<TokenRepositoryError> for RequestMagicLinkError {
+    fn from(err: TokenRepositoryError) -> Self {
+        RequestMagicLinkError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<EmailError> for RequestMagicLinkError {
+    fn from(err: EmailError) -> Self {
+        RequestMagicLinkError::EmailError(err.to_string())
+    }
+}
+
+impl RequestMagicLinkError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::UserNotFound => ErrorCode::UserNotFound,
+            Self::EmailNotVerified => ErrorCode::Forbidden,
+            Self::RepositoryError(_) | Self::EmailError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for RequestMagicLinkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UserNotFound => write!(f, "User not found"),
+            Self::EmailNotVerified => write!(f, "Email address is not verified"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+            Self::EmailError(msg) => write!(f, "Email error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for RequestMagicLinkError {}
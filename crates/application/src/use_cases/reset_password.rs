@@ -1,14 +1,23 @@
+use crate::error_code::ErrorCode;
 use crate::ports::TokenRepositoryError;
+use crate::ports::audit_log::{AuditEntry, AuditLogError};
+use crate::ports::event_outbox::EventOutboxError;
+use crate::ports::event_publisher::EventPublisher;
 use crate::ports::password_hasher::PasswordHasher;
 use crate::ports::password_reset_token_repository::PasswordResetTokenRepository;
+use crate::ports::session_repository::SessionRepositoryError;
+use crate::ports::unit_of_work::{UnitOfWork, UnitOfWorkError};
 use crate::ports::user_repository::UserRepository;
+use domain_users::PasswordHash;
 use std::sync::Arc;
 
 pub struct ResetPasswordUseCase {
     user_repo: Arc<dyn UserRepository>,
     token_repo: Arc<dyn PasswordResetTokenRepository>,
     password_hasher: Arc<dyn PasswordHasher>,
+    unit_of_work: Arc<dyn UnitOfWork>,
     expiry_hours: u64,
+    event_publisher: Arc<dyn EventPublisher>,
 }
 
 impl ResetPasswordUseCase {
@@ -16,13 +25,17 @@ impl ResetPasswordUseCase {
         user_repo: Arc<dyn UserRepository>,
         token_repo: Arc<dyn PasswordResetTokenRepository>,
         password_hasher: Arc<dyn PasswordHasher>,
+        unit_of_work: Arc<dyn UnitOfWork>,
         expiry_hours: u64,
+        event_publisher: Arc<dyn EventPublisher>,
     ) -> Self {
         Self {
             user_repo,
             token_repo,
             password_hasher,
+            unit_of_work,
             expiry_hours,
+            event_publisher,
         }
     }
 
@@ -51,13 +64,39 @@ impl ResetPasswordUseCase {
             .await?
             .ok_or(ResetPasswordError::UserNotFound)?;
 
-        let password_hash = self.password_hasher.hash(new_password).await.map_err(|e| {
-            ResetPasswordError::InternalError(format!("Failed to hash password: {}", e))
-        })?;
-
-        user.password_hash = password_hash;
-
-        self.user_repo.update(&user).await?;
+        let password_hash = self
+            .password_hasher
+            .hash(new_password)
+            .await
+            .map_err(|e| e.to_string())
+            .and_then(|hash| PasswordHash::new(hash).map_err(|e| e.to_string()))
+            .map_err(|e| {
+                ResetPasswordError::InternalError(format!("Failed to hash password: {}", e))
+            })?;
+
+        user.change_password(password_hash);
+
+        // Whoever knew the old password may still hold a session; it ends together with
+        // the change. The reset link proves control of the mailbox, so the user counts
+        // as the actor.
+        let events = user.take_events();
+        let transaction = self.unit_of_work.begin().await?;
+        transaction.users().update(&user).await?;
+        transaction.sessions().revoke_all(user.id(), now).await?;
+        transaction
+            .audit_log()
+            .record(&AuditEntry::new(
+                Some(user.id()),
+                "user.password_reset",
+                "user",
+                Some(user.id()),
+                Vec::new(),
+            ))
+            .await?;
+        transaction.event_outbox().append(&events).await?;
+        transaction.commit().await?;
+
+        self.event_publisher.publish(&events).await;
         self.token_repo.delete_by_token(token_str).await?;
 
         Ok(())
@@ -85,191 +124,51 @@ impl From<TokenRepositoryError> for ResetPasswordError {
     }
 }
 
-impl std::fmt::Display for ResetPasswordError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::InvalidToken => write!(f, "Invalid token"),
-            Self::ExpiredToken => write!(f, "Expired token"),
-            Self::UserNotFound => write!(f, "User not found"),
-            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
-            Self::InternalError(msg) => write!(f, "Internal error: {}", msg),
-        }
+impl From<AuditLogError> for ResetPasswordError {
+    fn from(err: AuditLogError) -> Self {
+        ResetPasswordError::RepositoryError(err.to_string())
     }
 }
 
-impl std::error::Error for ResetPasswordError {}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::ports::TokenRepositoryError;
-    use crate::ports::password_hasher::{PasswordHasher, PasswordHasherError};
-    use crate::ports::password_reset_token_repository::{
-        PasswordResetToken, PasswordResetTokenRepository,
-    };
-    use crate::ports::user_repository::{UserRepository, UserRepositoryError};
-    use async_trait::async_trait;
-    use domain_users::User;
-    use std::sync::Mutex;
-    use uuid::Uuid;
-
-    struct MockUserRepository {
-        users: Mutex<Vec<User>>,
-    }
-
-    #[async_trait]
-    impl UserRepository for MockUserRepository {
-        async fn create(&self, _user: &User) -> Result<(), UserRepositoryError> {
-            unimplemented!()
-        }
-        async fn update(&self, user: &User) -> Result<(), UserRepositoryError> {
-            let mut users = self.users.lock().unwrap();
-            if let Some(u) = users.iter_mut().find(|u| u.id == user.id) {
-                *u = user.clone();
-                Ok(())
-            } else {
-                Err(UserRepositoryError::NotFound(user.id.to_string()))
-            }
-        }
-        async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, UserRepositoryError> {
-            Ok(self
-                .users
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|u| u.id == id)
-                .cloned())
-        }
-        async fn find_by_email(&self, _email: &str) -> Result<Option<User>, UserRepositoryError> {
-            unimplemented!()
-        }
-        async fn find_active_by_email(
-            &self,
-            _email: &str,
-        ) -> Result<Option<User>, UserRepositoryError> {
-            unimplemented!()
-        }
-        async fn find_all_active(&self) -> Result<Vec<User>, UserRepositoryError> {
-            unimplemented!()
-        }
-        async fn find_all_active_paginated(
-            &self,
-            _page: u64,
-            _page_size: u64,
-        ) -> Result<(Vec<User>, u64), UserRepositoryError> {
-            unimplemented!()
-        }
-    }
-
-    struct MockTokenRepository {
-        tokens: Mutex<Vec<PasswordResetToken>>,
+impl From<SessionRepositoryError> for ResetPasswordError {
+    fn from(err: SessionRepositoryError) -> Self {
+        ResetPasswordError::RepositoryError(err.to_string())
     }
+}
 
-    #[async_trait]
-    impl PasswordResetTokenRepository for MockTokenRepository {
-        async fn create(&self, _token: &PasswordResetToken) -> Result<(), TokenRepositoryError> {
-            unimplemented!()
-        }
-        async fn find_by_token(
-            &self,
-            token: &str,
-        ) -> Result<Option<PasswordResetToken>, TokenRepositoryError> {
-            Ok(self
-                .tokens
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|t| t.token == token)
-                .cloned())
-        }
-        async fn delete_by_token(&self, token: &str) -> Result<(), TokenRepositoryError> {
-            let mut tokens = self.tokens.lock().unwrap();
-            tokens.retain(|t| t.token != token);
-            Ok(())
-        }
-        async fn delete_by_user_id(&self, _user_id: &Uuid) -> Result<(), TokenRepositoryError> {
-            unimplemented!()
-        }
+impl From<UnitOfWorkError> for ResetPasswordError {
+    fn from(err: UnitOfWorkError) -> Self {
+        ResetPasswordError::RepositoryError(err.to_string())
     }
+}
 
-    struct MockPasswordHasher;
-    #[async_trait]
-    impl PasswordHasher for MockPasswordHasher {
-        async fn hash(&self, password: &str) -> Result<String, PasswordHasherError> {
-            Ok(format!("hashed_{}", password))
-        }
-        async fn verify(&self, _password: &str, _hash: &str) -> Result<bool, PasswordHasherError> {
-            Ok(true)
+impl ResetPasswordError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidToken => ErrorCode::InvalidToken,
+            Self::ExpiredToken => ErrorCode::ExpiredToken,
+            Self::UserNotFound => ErrorCode::UserNotFound,
+            Self::RepositoryError(_) | Self::InternalError(_) => ErrorCode::InternalServerError,
         }
     }
+}
 
-    #[tokio::test]
-    async fn test_reset_password_success() {
-        let user_id = Uuid::new_v4();
-        let user = User::new(
-            user_id,
-            "John".to_string(),
-            "Doe".to_string(),
-            "john@example.com".to_string(),
-            None,
-            "old_hash".to_string(),
-            None,
-        );
-
-        let user_repo = Arc::new(MockUserRepository {
-            users: Mutex::new(vec![user]),
-        });
-        let token = PasswordResetToken {
-            token: "token123".to_string(),
-            user_id,
-            created_at: chrono::Utc::now(),
-        };
-        let token_repo = Arc::new(MockTokenRepository {
-            tokens: Mutex::new(vec![token]),
-        });
-        let hasher = Arc::new(MockPasswordHasher);
-        let use_case = ResetPasswordUseCase::new(user_repo.clone(), token_repo.clone(), hasher, 24);
-
-        use_case.execute("token123", "new_password").await.unwrap();
-
-        let updated_user = user_repo.find_by_id(user_id).await.unwrap().unwrap();
-        assert_eq!(updated_user.password_hash, "hashed_new_password");
-
-        let tokens = token_repo.tokens.lock().unwrap();
-        assert!(tokens.is_empty());
+impl From<EventOutboxError> for ResetPasswordError {
+    fn from(err: EventOutboxError) -> Self {
+        ResetPasswordError::RepositoryError(err.to_string())
     }
+}
 
-    #[tokio::test]
-    async fn test_reset_password_expired_token() {
-        let user_id = Uuid::new_v4();
-        let user = User::new(
-            user_id,
-            "John".to_string(),
-            "Doe".to_string(),
-            "john@example.com".to_string(),
-            None,
-            "old_hash".to_string(),
-            None,
-        );
-
-        let user_repo = Arc::new(MockUserRepository {
-            users: Mutex::new(vec![user]),
-        });
-        let token = PasswordResetToken {
-            token: "token123".to_string(),
-            user_id,
-            created_at: chrono::Utc::now() - chrono::Duration::hours(25),
-        };
-        let token_repo = Arc::new(MockTokenRepository {
-            tokens: Mutex::new(vec![token]),
-        });
-        let hasher = Arc::new(MockPasswordHasher);
-        let use_case = ResetPasswordUseCase::new(user_repo.clone(), token_repo.clone(), hasher, 24);
-
-        let result = use_case.execute("token123", "new_password").await;
-        assert!(matches!(result, Err(ResetPasswordError::ExpiredToken)));
-
-        let tokens = token_repo.tokens.lock().unwrap();
-        assert!(tokens.is_empty());
+impl std::fmt::Display for ResetPasswordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidToken => write!(f, "Invalid token"),
+            Self::ExpiredToken => write!(f, "Expired token"),
+            Self::UserNotFound => write!(f, "User not found"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+            Self::InternalError(msg) => write!(f, "Internal error: {}", msg),
+        }
     }
 }
+
+impl std::error::Error for ResetPasswordError {}
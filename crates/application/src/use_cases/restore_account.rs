@@ -0,0 +1,159 @@
+use crate::error_code::ErrorCode;
+use crate::ports::TokenRepositoryError;
+use crate::ports::account_restore_token_repository::AccountRestoreTokenRepository;
+use crate::ports::audit_log::{AuditEntry, AuditLogError};
+use crate::ports::event_outbox::EventOutboxError;
+use crate::ports::event_publisher::EventPublisher;
+use crate::ports::one_time_token_service::OneTimeTokenService;
+use crate::ports::unit_of_work::{UnitOfWork, UnitOfWorkError};
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use chrono::Utc;
+use domain_users::User;
+use std::sync::Arc;
+
+/// Redeems the link mailed by [`DeleteUserUseCase`](super::DeleteUserUseCase) and
+/// undoes the deletion, as long as the account has not been erased yet.
+pub struct RestoreAccountUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    token_repo: Arc<dyn AccountRestoreTokenRepository>,
+    token_service: Arc<dyn OneTimeTokenService>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    event_publisher: Arc<dyn EventPublisher>,
+}
+
+impl RestoreAccountUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        token_repo: Arc<dyn AccountRestoreTokenRepository>,
+        token_service: Arc<dyn OneTimeTokenService>,
+        unit_of_work: Arc<dyn UnitOfWork>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            user_repo,
+            token_repo,
+            token_service,
+            unit_of_work,
+            event_publisher,
+        }
+    }
+
+    pub async fn execute(&self, token: &str) -> Result<User, RestoreAccountError> {
+        let restore_token = self
+            .token_repo
+            .find_by_token_hash(&self.token_service.hash(token.trim()))
+            .await?
+            .ok_or(RestoreAccountError::InvalidToken)?;
+
+        if restore_token.is_expired(Utc::now()) {
+            return Err(RestoreAccountError::ExpiredToken);
+        }
+
+        let mut user = self
+            .user_repo
+            .find_by_id(restore_token.user_id)
+            .await?
+            .ok_or(RestoreAccountError::InvalidToken)?;
+
+        if user.is_deleted() {
+            if self
+                .user_repo
+                .find_active_by_email(user.email())
+                .await?
+                .is_some_and(|other| other.id() != user.id())
+            {
+                return Err(RestoreAccountError::EmailTaken(user.email().to_string()));
+            }
+            user.restore();
+            let events = user.take_events();
+            let transaction = self.unit_of_work.begin().await?;
+            transaction.users().update(&user).await?;
+            transaction
+                .audit_log()
+                .record(&AuditEntry::new(
+                    Some(user.id()),
+                    "user.restored",
+                    "user",
+                    Some(user.id()),
+                    Vec::new(),
+                ))
+                .await?;
+            transaction.event_outbox().append(&events).await?;
+            transaction.commit().await?;
+            user.increment_version();
+            self.event_publisher.publish(&events).await;
+        }
+        self.token_repo.delete_by_user_id(&user.id()).await?;
+
+        Ok(user)
+    }
+}
+
+#[derive(Debug)]
+pub enum RestoreAccountError {
+    InvalidToken,
+    ExpiredToken,
+    EmailTaken(String),
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for RestoreAccountError {
+    fn from(err: UserRepositoryError) -> Self {
+        match err {
+            UserRepositoryError::AlreadyExists(email) => RestoreAccountError::EmailTaken(email),
+            err => RestoreAccountError::RepositoryError(err.to_string()),
+        }
+    }
+}
+
+impl From<TokenRepositoryError> for RestoreAccountError {
+    fn from(err: TokenRepositoryError) -> Self {
+        RestoreAccountError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuditLogError> for RestoreAccountError {
+    fn from(err: AuditLogError) -> Self {
+        RestoreAccountError::RepositoryError(err.to_string())
+    }
+}
+
+impl RestoreAccountError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidToken => ErrorCode::InvalidToken,
+            Self::ExpiredToken => ErrorCode::ExpiredToken,
+            Self::EmailTaken(_) => ErrorCode::EmailTaken,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl From<EventOutboxError> for RestoreAccountError {
+    fn from(err: EventOutboxError) -> Self {
+        RestoreAccountError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UnitOfWorkError> for RestoreAccountError {
+    fn from(err: UnitOfWorkError) -> Self {
+        RestoreAccountError::RepositoryError(err.to_string())
+    }
+}
+
+impl std::fmt::Display for RestoreAccountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidToken => write!(f, "Invalid restore link"),
+            Self::ExpiredToken => write!(f, "Restore link has expired"),
+            Self::EmailTaken(email) => write!(
+                f,
+                "Another account now uses {}; this one cannot be restored",
+                email
+            ),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for RestoreAccountError {}
@@ -0,0 +1,149 @@
+use crate::error_code::ErrorCode;
+use crate::ports::TokenRepositoryError;
+use crate::ports::account_restore_token_repository::AccountRestoreTokenRepository;
+use crate::ports::audit_log::{AuditEntry, AuditLogError};
+use crate::ports::event_outbox::EventOutboxError;
+use crate::ports::event_publisher::EventPublisher;
+use crate::ports::unit_of_work::{UnitOfWork, UnitOfWorkError};
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use domain_users::User;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Lets an admin undo a deletion before the account is erased. Emails are only
+/// unique among active accounts, so this fails if someone signed up with the
+/// address in the meantime.
+pub struct RestoreUserUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    restore_token_repo: Arc<dyn AccountRestoreTokenRepository>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    event_publisher: Arc<dyn EventPublisher>,
+}
+
+impl RestoreUserUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        restore_token_repo: Arc<dyn AccountRestoreTokenRepository>,
+        unit_of_work: Arc<dyn UnitOfWork>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            user_repo,
+            restore_token_repo,
+            unit_of_work,
+            event_publisher,
+        }
+    }
+
+    pub async fn execute(&self, actor_id: Uuid, user_id: Uuid) -> Result<User, RestoreUserError> {
+        let mut user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or(RestoreUserError::NotFound(user_id))?;
+        if !user.is_deleted() {
+            return Ok(user);
+        }
+
+        if self
+            .user_repo
+            .find_active_by_email(user.email())
+            .await?
+            .is_some_and(|other| other.id() != user.id())
+        {
+            return Err(RestoreUserError::EmailTaken(user.email().to_string()));
+        }
+
+        user.restore();
+        let events = user.take_events();
+        let transaction = self.unit_of_work.begin().await?;
+        transaction.users().update(&user).await?;
+        transaction
+            .audit_log()
+            .record(&AuditEntry::new(
+                Some(actor_id),
+                "user.restored",
+                "user",
+                Some(user.id()),
+                Vec::new(),
+            ))
+            .await?;
+        transaction.event_outbox().append(&events).await?;
+        transaction.commit().await?;
+        user.increment_version();
+        self.event_publisher.publish(&events).await;
+
+        self.restore_token_repo
+            .delete_by_user_id(&user.id())
+            .await?;
+
+        Ok(user)
+    }
+}
+
+#[derive(Debug)]
+pub enum RestoreUserError {
+    NotFound(Uuid),
+    EmailTaken(String),
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for RestoreUserError {
+    fn from(err: UserRepositoryError) -> Self {
+        match err {
+            // Lost the race against a signup with the same email.
+            UserRepositoryError::AlreadyExists(email) => RestoreUserError::EmailTaken(email),
+            err => RestoreUserError::RepositoryError(err.to_string()),
+        }
+    }
+}
+
+impl From<TokenRepositoryError> for RestoreUserError {
+    fn from(err: TokenRepositoryError) -> Self {
+        RestoreUserError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuditLogError> for RestoreUserError {
+    fn from(err: AuditLogError) -> Self {
+        RestoreUserError::RepositoryError(err.to_string())
+    }
+}
+
+impl RestoreUserError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound(_) => ErrorCode::UserNotFound,
+            Self::EmailTaken(_) => ErrorCode::EmailTaken,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl From<EventOutboxError> for RestoreUserError {
+    fn from(err: EventOutboxError) -> Self {
+        RestoreUserError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UnitOfWorkError> for RestoreUserError {
+    fn from(err: UnitOfWorkError) -> Self {
+        RestoreUserError::RepositoryError(err.to_string())
+    }
+}
+
+impl std::fmt::Display for RestoreUserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound(id) => write!(f, "User with ID {} not found", id),
+            Self::EmailTaken(email) => write!(
+                f,
+                "Another account now uses {}; the user cannot be restored",
+                email
+            ),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for RestoreUserError {}
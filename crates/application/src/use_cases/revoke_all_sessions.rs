@@ -0,0 +1,55 @@
+use crate::error_code::ErrorCode;
+use crate::ports::session_repository::{SessionRepository, SessionRepositoryError};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// "Log out everywhere": revokes every session of the user, the calling one included.
+pub struct RevokeAllSessionsUseCase {
+    session_repo: Arc<dyn SessionRepository>,
+}
+
+impl RevokeAllSessionsUseCase {
+    pub fn new(session_repo: Arc<dyn SessionRepository>) -> Self {
+        Self { session_repo }
+    }
+
+    /// Returns the number of sessions that were signed out.
+    pub async fn execute(&self, user_id: Uuid) -> Result<u64, RevokeAllSessionsError> {
+        let revoked = self.session_repo.revoke_all(user_id, Utc::now()).await?;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(user_id = %user_id, revoked, "all sessions revoked");
+
+        Ok(revoked)
+    }
+}
+
+#[derive(Debug)]
+pub enum RevokeAllSessionsError {
+    RepositoryError(String),
+}
+
+impl From<SessionRepositoryError> for RevokeAllSessionsError {
+    fn from(err: SessionRepositoryError) -> Self {
+        RevokeAllSessionsError::RepositoryError(err.to_string())
+    }
+}
+
+impl RevokeAllSessionsError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for RevokeAllSessionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for RevokeAllSessionsError {}
@@ -0,0 +1,82 @@
+use crate::error_code::ErrorCode;
+use crate::ports::audit_log::{AuditEntry, AuditLog, AuditLogError};
+use crate::ports::session_repository::{SessionRepository, SessionRepositoryError};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Signs one of the user's own sessions out, e.g. a lost phone. Its access token is
+/// rejected from the next request on.
+pub struct RevokeSessionUseCase {
+    session_repo: Arc<dyn SessionRepository>,
+    audit_log: Arc<dyn AuditLog>,
+}
+
+impl RevokeSessionUseCase {
+    pub fn new(session_repo: Arc<dyn SessionRepository>, audit_log: Arc<dyn AuditLog>) -> Self {
+        Self {
+            session_repo,
+            audit_log,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, session_id: Uuid) -> Result<(), RevokeSessionError> {
+        if !self
+            .session_repo
+            .revoke(user_id, session_id, Utc::now())
+            .await?
+        {
+            return Err(RevokeSessionError::NotFound);
+        }
+
+        self.audit_log
+            .record(&AuditEntry::new(
+                Some(user_id),
+                "session.revoked",
+                "session",
+                Some(session_id),
+                Vec::new(),
+            ))
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum RevokeSessionError {
+    NotFound,
+    RepositoryError(String),
+}
+
+impl From<SessionRepositoryError> for RevokeSessionError {
+    fn from(err: SessionRepositoryError) -> Self {
+        RevokeSessionError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<AuditLogError> for RevokeSessionError {
+    fn from(err: AuditLogError) -> Self {
+        RevokeSessionError::RepositoryError(err.to_string())
+    }
+}
+
+impl RevokeSessionError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound => ErrorCode::NotFound,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for RevokeSessionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "Session not found"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for RevokeSessionError {}
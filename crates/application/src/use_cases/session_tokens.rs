@@ -0,0 +1,97 @@
+use crate::ports::access_token_service::{
+    AccessTokenClaims, AccessTokenError, AccessTokenScope, AccessTokenService, IssuedAccessToken,
+};
+use crate::ports::session_repository::{Session, SessionRepository, SessionRepositoryError};
+use chrono::{Duration, Utc};
+use domain_users::User;
+use std::net::IpAddr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Where a login comes from, recorded on the session it opens.
+#[derive(Debug, Clone, Default)]
+pub struct ClientInfo {
+    pub ip: Option<IpAddr>,
+    pub user_agent: Option<String>,
+}
+
+/// Issues the access token at the end of every login. Full tokens open a session the
+/// user can see and revoke; partial tokens are short-lived and open none.
+pub struct SessionTokenIssuer {
+    session_repo: Arc<dyn SessionRepository>,
+    token_service: Arc<dyn AccessTokenService>,
+}
+
+impl SessionTokenIssuer {
+    pub fn new(
+        session_repo: Arc<dyn SessionRepository>,
+        token_service: Arc<dyn AccessTokenService>,
+    ) -> Self {
+        Self {
+            session_repo,
+            token_service,
+        }
+    }
+
+    pub(crate) async fn issue(
+        &self,
+        user: &User,
+        scope: AccessTokenScope,
+        client: &ClientInfo,
+    ) -> Result<IssuedAccessToken, SessionTokenError> {
+        let session_id = (scope == AccessTokenScope::Full).then(Uuid::new_v4);
+        let access_token = self.token_service.issue(&AccessTokenClaims {
+            user_id: user.id(),
+            role: user.role().clone(),
+            scope,
+            session_id,
+        })?;
+
+        if let Some(id) = session_id {
+            let now = Utc::now();
+            self.session_repo
+                .create(&Session {
+                    id,
+                    user_id: user.id(),
+                    user_agent: client.user_agent.clone(),
+                    ip_address: client.ip,
+                    created_at: now,
+                    last_seen_at: now,
+                    expires_at: now + Duration::seconds(access_token.expires_in_seconds as i64),
+                    revoked_at: None,
+                })
+                .await?;
+        }
+
+        Ok(access_token)
+    }
+}
+
+#[derive(Debug)]
+pub enum SessionTokenError {
+    Token(AccessTokenError),
+    Repository(SessionRepositoryError),
+}
+
+impl From<AccessTokenError> for SessionTokenError {
+    fn from(err: AccessTokenError) -> Self {
+        Self::Token(err)
+    }
+}
+
+impl From<SessionRepositoryError> for SessionTokenError {
+    fn from(err: SessionRepositoryError) -> Self {
+        Self::Repository(err)
+    }
+}
+
+impl std::fmt::Display for SessionTokenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Token(err) => write!(f, "{}", err),
+            Self::Repository(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SessionTokenError {}
@@ -1,76 +1,105 @@
+use crate::error_code::ErrorCode;
 use crate::ports::TokenRepositoryError;
 use crate::ports::email_service::EmailService;
-use crate::ports::email_verification_token_repository::{
-    EmailVerificationToken, EmailVerificationTokenRepository,
-};
+use crate::ports::email_verification_token_repository::EmailVerificationToken;
+use crate::ports::event_outbox::EventOutboxError;
+use crate::ports::event_publisher::EventPublisher;
 use crate::ports::password_hasher::PasswordHasher;
+use crate::ports::unit_of_work::{UnitOfWork, UnitOfWorkError};
 use crate::ports::user_repository::UserRepository;
+use crate::use_cases::user_fields::{FieldError, NewUserFields};
 use chrono::NaiveDate;
-use domain_users::User;
+use domain_users::{PasswordHash, User};
 use std::sync::Arc;
 use uuid::Uuid;
 
 pub struct SignupUseCase {
     user_repo: Arc<dyn UserRepository>,
-    token_repo: Arc<dyn EmailVerificationTokenRepository>,
+    unit_of_work: Arc<dyn UnitOfWork>,
     password_hasher: Arc<dyn PasswordHasher>,
     email_service: Arc<dyn EmailService>,
+    event_publisher: Arc<dyn EventPublisher>,
 }
 
 impl SignupUseCase {
     pub fn new(
         user_repo: Arc<dyn UserRepository>,
-        token_repo: Arc<dyn EmailVerificationTokenRepository>,
+        unit_of_work: Arc<dyn UnitOfWork>,
         password_hasher: Arc<dyn PasswordHasher>,
         email_service: Arc<dyn EmailService>,
+        event_publisher: Arc<dyn EventPublisher>,
     ) -> Self {
         Self {
             user_repo,
-            token_repo,
+            unit_of_work,
             password_hasher,
             email_service,
+            event_publisher,
         }
     }
 
     pub async fn execute(&self, input: SignupInput) -> Result<User, SignupError> {
+        let fields = NewUserFields::parse(
+            &input.first_name,
+            &input.last_name,
+            &input.email,
+            input.phone_number.as_deref(),
+        )
+        .map_err(SignupError::Validation)?;
         if self
             .user_repo
-            .find_active_by_email(&input.email)
+            .find_active_by_email(fields.email.as_str())
             .await?
             .is_some()
         {
-            return Err(SignupError::AlreadyExists(input.email));
+            return Err(SignupError::AlreadyExists(fields.email.into_string()));
         }
 
         let password_hash = self
             .password_hasher
             .hash(&input.password)
             .await
+            .map_err(|e| e.to_string())
+            .and_then(|hash| PasswordHash::new(hash).map_err(|e| e.to_string()))
             .map_err(|e| SignupError::InternalError(format!("Failed to hash password: {}", e)))?;
 
-        let user = User::new(
+        let mut user = User::register(
             Uuid::new_v4(),
-            input.first_name,
-            input.last_name,
-            input.email.clone(),
-            input.phone_number,
+            fields.first_name,
+            fields.last_name,
+            fields.email,
+            fields.phone_number,
             password_hash,
             input.birth_date,
         );
 
-        self.user_repo.create(&user).await?;
-
         // Generate token (for simplicity using UUID, can be more complex)
         let token_str = Uuid::new_v4().to_string();
         let token = EmailVerificationToken {
             token: token_str.clone(),
-            user_id: user.id,
+            user_id: user.id(),
         };
 
-        self.token_repo.create(&token).await?;
+        // A user without a token could never verify, so both are stored or neither.
+        let events = user.take_events();
+        let transaction = self.unit_of_work.begin().await?;
+        transaction.users().create(&user).await?;
+        transaction
+            .email_verification_tokens()
+            .create(&token)
+            .await?;
+        transaction.event_outbox().append(&events).await?;
+        transaction.commit().await?;
+
+        self.event_publisher.publish(&events).await;
 
         self.email_service
-            .send_verification_email(&input.email, &token_str, &user.first_name, &user.last_name)
+            .send_verification_email(
+                user.email(),
+                &token_str,
+                user.first_name(),
+                user.last_name(),
+            )
             .await?;
 
         Ok(user)
@@ -88,6 +117,7 @@ pub struct SignupInput {
 
 #[derive(Debug)]
 pub enum SignupError {
+    Validation(Vec<FieldError>),
     AlreadyExists(String),
     RepositoryError(String),
     EmailError(String),
@@ -106,15 +136,43 @@ impl From<TokenRepositoryError> for SignupError {
     }
 }
 
+impl From<UnitOfWorkError> for SignupError {
+    fn from(err: UnitOfWorkError) -> Self {
+        SignupError::RepositoryError(err.to_string())
+    }
+}
+
 impl From<crate::ports::email_service::EmailError> for SignupError {
     fn from(err: crate::ports::email_service::EmailError) -> Self {
         SignupError::EmailError(err.to_string())
     }
 }
 
+impl SignupError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::Validation(_) => ErrorCode::ValidationFailed,
+            Self::AlreadyExists(_) => ErrorCode::EmailTaken,
+            Self::RepositoryError(_) | Self::EmailError(_) | Self::InternalError(_) => {
+                ErrorCode::InternalServerError
+            }
+        }
+    }
+}
+
+impl From<EventOutboxError> for SignupError {
+    fn from(err: EventOutboxError) -> Self {
+        SignupError::RepositoryError(err.to_string())
+    }
+}
+
 impl std::fmt::Display for SignupError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::Validation(errors) => {
+                let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+                write!(f, "Validation failed: {}", messages.join("; "))
+            }
             Self::AlreadyExists(email) => write!(f, "User with email {} already exists", email),
             Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
             Self::EmailError(msg) => write!(f, "Email error: {}", msg),
@@ -124,193 +182,3 @@ impl std::fmt::Display for SignupError {
 }
 
 impl std::error::Error for SignupError {}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::ports::TokenRepositoryError;
-    use crate::ports::email_service::{EmailError, EmailService};
-    use crate::ports::email_verification_token_repository::{
-        EmailVerificationToken, EmailVerificationTokenRepository,
-    };
-    use crate::ports::password_hasher::{PasswordHasher, PasswordHasherError};
-    use crate::ports::user_repository::{UserRepository, UserRepositoryError};
-    use async_trait::async_trait;
-    use domain_users::User;
-    use std::sync::Mutex;
-    use uuid::Uuid;
-
-    struct MockUserRepository {
-        users: Mutex<Vec<User>>,
-    }
-
-    #[async_trait]
-    impl UserRepository for MockUserRepository {
-        async fn create(&self, user: &User) -> Result<(), UserRepositoryError> {
-            self.users.lock().unwrap().push(user.clone());
-            Ok(())
-        }
-        async fn update(&self, user: &User) -> Result<(), UserRepositoryError> {
-            let mut users = self.users.lock().unwrap();
-            if let Some(u) = users.iter_mut().find(|u| u.id == user.id) {
-                *u = user.clone();
-                Ok(())
-            } else {
-                Err(UserRepositoryError::NotFound(user.id.to_string()))
-            }
-        }
-        async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, UserRepositoryError> {
-            Ok(self
-                .users
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|u| u.id == id)
-                .cloned())
-        }
-        async fn find_by_email(&self, email: &str) -> Result<Option<User>, UserRepositoryError> {
-            Ok(self
-                .users
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|u| u.email == email)
-                .cloned())
-        }
-        async fn find_active_by_email(
-            &self,
-            email: &str,
-        ) -> Result<Option<User>, UserRepositoryError> {
-            Ok(self
-                .users
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|u| u.email == email && !u.is_deleted())
-                .cloned())
-        }
-        async fn find_all_active(&self) -> Result<Vec<User>, UserRepositoryError> {
-            Ok(self
-                .users
-                .lock()
-                .unwrap()
-                .iter()
-                .filter(|u| !u.is_deleted())
-                .cloned()
-                .collect())
-        }
-        async fn find_all_active_paginated(
-            &self,
-            page: u64,
-            page_size: u64,
-        ) -> Result<(Vec<User>, u64), UserRepositoryError> {
-            let users = self.users.lock().unwrap();
-            let active_users: Vec<User> =
-                users.iter().filter(|u| !u.is_deleted()).cloned().collect();
-            let total = active_users.len() as u64;
-            let offset = (page * page_size) as usize;
-            let paged_users = active_users
-                .into_iter()
-                .skip(offset)
-                .take(page_size as usize)
-                .collect();
-            Ok((paged_users, total))
-        }
-    }
-
-    struct MockTokenRepository {
-        tokens: Mutex<Vec<EmailVerificationToken>>,
-    }
-
-    #[async_trait]
-    impl EmailVerificationTokenRepository for MockTokenRepository {
-        async fn create(&self, token: &EmailVerificationToken) -> Result<(), TokenRepositoryError> {
-            self.tokens.lock().unwrap().push(token.clone());
-            Ok(())
-        }
-        async fn find_by_token(
-            &self,
-            token: &str,
-        ) -> Result<Option<EmailVerificationToken>, TokenRepositoryError> {
-            Ok(self
-                .tokens
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|t| t.token == token)
-                .cloned())
-        }
-        async fn delete_by_token(&self, token: &str) -> Result<(), TokenRepositoryError> {
-            let mut tokens = self.tokens.lock().unwrap();
-            tokens.retain(|t| t.token != token);
-            Ok(())
-        }
-    }
-
-    struct MockPasswordHasher;
-    #[async_trait]
-    impl PasswordHasher for MockPasswordHasher {
-        async fn hash(&self, password: &str) -> Result<String, PasswordHasherError> {
-            Ok(password.to_string())
-        }
-        async fn verify(&self, _password: &str, _hash: &str) -> Result<bool, PasswordHasherError> {
-            Ok(true)
-        }
-    }
-
-    struct MockEmailService;
-    #[async_trait]
-    impl EmailService for MockEmailService {
-        async fn send_verification_email(
-            &self,
-            _to: &str,
-            _token: &str,
-            _first_name: &str,
-            _last_name: &str,
-        ) -> Result<(), EmailError> {
-            Ok(())
-        }
-
-        async fn send_password_reset_email(
-            &self,
-            _to: &str,
-            _token: &str,
-            _first_name: &str,
-        ) -> Result<(), EmailError> {
-            Ok(())
-        }
-    }
-
-    #[tokio::test]
-    async fn test_signup_success() {
-        let user_repo = Arc::new(MockUserRepository {
-            users: Mutex::new(vec![]),
-        });
-        let token_repo = Arc::new(MockTokenRepository {
-            tokens: Mutex::new(vec![]),
-        });
-        let hasher = Arc::new(MockPasswordHasher);
-        let email_service = Arc::new(MockEmailService);
-        let use_case =
-            SignupUseCase::new(user_repo.clone(), token_repo.clone(), hasher, email_service);
-
-        let input = SignupInput {
-            first_name: "John".to_string(),
-            last_name: "Doe".to_string(),
-            email: "john@example.com".to_string(),
-            phone_number: None,
-            password: "password123".to_string(),
-            birth_date: None,
-        };
-
-        let user = use_case.execute(input).await.unwrap();
-
-        assert_eq!(user.email, "john@example.com");
-        assert!(!user.is_email_verified);
-        assert_eq!(user.role, domain_users::models::user::UserRole::User);
-
-        let tokens = token_repo.tokens.lock().unwrap();
-        assert_eq!(tokens.len(), 1);
-        assert_eq!(tokens[0].user_id, user.id);
-    }
-}
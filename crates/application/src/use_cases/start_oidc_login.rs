@@ -0,0 +1,103 @@
+use crate::error_code::ErrorCode;
+use crate::ports::oidc_client::{OidcClient, OidcError};
+use crate::ports::oidc_flow_store::{OidcFlowStore, OidcFlowStoreError, PendingOidcLogin};
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+/// A started login. The caller binds `state` to the browser, so only the browser that
+/// started the login can complete it.
+#[derive(Debug)]
+pub struct OidcLoginStarted {
+    pub authorization_url: String,
+    pub state: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Starts a "Sign in with ..." login and returns the provider URL to send the browser
+/// to. The flow's secrets stay on the server until the provider redirects back.
+pub struct StartOidcLoginUseCase {
+    oidc_client: Arc<dyn OidcClient>,
+    flow_store: Arc<dyn OidcFlowStore>,
+    ttl: Duration,
+}
+
+impl StartOidcLoginUseCase {
+    pub fn new(
+        oidc_client: Arc<dyn OidcClient>,
+        flow_store: Arc<dyn OidcFlowStore>,
+        ttl: Duration,
+    ) -> Self {
+        Self {
+            oidc_client,
+            flow_store,
+            ttl,
+        }
+    }
+
+    pub async fn execute(&self, provider: &str) -> Result<OidcLoginStarted, StartOidcLoginError> {
+        let authorization = self.oidc_client.authorize(provider).await?;
+        let expires_at = Utc::now() + self.ttl;
+
+        self.flow_store
+            .save_login(&PendingOidcLogin {
+                state: authorization.state.clone(),
+                provider: provider.to_string(),
+                nonce: authorization.nonce,
+                code_verifier: authorization.code_verifier,
+                expires_at,
+            })
+            .await?;
+
+        Ok(OidcLoginStarted {
+            authorization_url: authorization.authorization_url,
+            state: authorization.state,
+            expires_at,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum StartOidcLoginError {
+    UnknownProvider,
+    ProviderUnavailable(String),
+    RepositoryError(String),
+}
+
+impl From<OidcError> for StartOidcLoginError {
+    fn from(err: OidcError) -> Self {
+        match err {
+            OidcError::UnknownProvider => StartOidcLoginError::UnknownProvider,
+            OidcError::ProviderUnavailable(msg) | OidcError::Rejected(msg) => {
+                StartOidcLoginError::ProviderUnavailable(msg)
+            }
+        }
+    }
+}
+
+impl From<OidcFlowStoreError> for StartOidcLoginError {
+    fn from(err: OidcFlowStoreError) -> Self {
+        StartOidcLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl StartOidcLoginError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::UnknownProvider => ErrorCode::NotFound,
+            Self::ProviderUnavailable(_) => ErrorCode::UpstreamUnavailable,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for StartOidcLoginError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownProvider => write!(f, "Unknown identity provider"),
+            Self::ProviderUnavailable(msg) => write!(f, "Identity provider unavailable: {}", msg),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for StartOidcLoginError {}
@@ -0,0 +1,87 @@
+use crate::error_code::ErrorCode;
+use crate::ports::passkey_ceremony_store::{
+    PasskeyCeremony, PasskeyCeremonyKind, PasskeyCeremonyStore, PasskeyCeremonyStoreError,
+};
+use crate::ports::webauthn_service::WebauthnService;
+use crate::use_cases::start_passkey_registration::PasskeyOptions;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Everything `navigator.credentials.get()` needs, plus the ceremony to finish.
+#[derive(Debug)]
+pub struct PasskeyLoginOptions {
+    pub ceremony_id: Uuid,
+    pub challenge: Vec<u8>,
+    pub rp_id: String,
+    pub timeout: Duration,
+}
+
+/// Starts a passkey login. No email is asked for: the browser offers the discoverable
+/// passkeys it holds for this site, so nothing reveals whether an account exists.
+pub struct StartPasskeyLoginUseCase {
+    ceremony_store: Arc<dyn PasskeyCeremonyStore>,
+    webauthn_service: Arc<dyn WebauthnService>,
+    options: PasskeyOptions,
+}
+
+impl StartPasskeyLoginUseCase {
+    pub fn new(
+        ceremony_store: Arc<dyn PasskeyCeremonyStore>,
+        webauthn_service: Arc<dyn WebauthnService>,
+        options: PasskeyOptions,
+    ) -> Self {
+        Self {
+            ceremony_store,
+            webauthn_service,
+            options,
+        }
+    }
+
+    pub async fn execute(&self) -> Result<PasskeyLoginOptions, StartPasskeyLoginError> {
+        let ceremony = PasskeyCeremony {
+            id: Uuid::new_v4(),
+            kind: PasskeyCeremonyKind::Authentication,
+            user_id: None,
+            challenge: self.webauthn_service.generate_challenge(),
+            expires_at: Utc::now() + self.options.timeout,
+        };
+        self.ceremony_store.save(&ceremony).await?;
+
+        Ok(PasskeyLoginOptions {
+            ceremony_id: ceremony.id,
+            challenge: ceremony.challenge,
+            rp_id: self.options.rp_id.clone(),
+            timeout: self.options.timeout,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum StartPasskeyLoginError {
+    RepositoryError(String),
+}
+
+impl From<PasskeyCeremonyStoreError> for StartPasskeyLoginError {
+    fn from(err: PasskeyCeremonyStoreError) -> Self {
+        StartPasskeyLoginError::RepositoryError(err.to_string())
+    }
+}
+
+impl StartPasskeyLoginError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for StartPasskeyLoginError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for StartPasskeyLoginError {}
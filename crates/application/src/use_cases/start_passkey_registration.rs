@@ -0,0 +1,148 @@
+use crate::error_code::ErrorCode;
+use crate::ports::passkey_ceremony_store::{
+    PasskeyCeremony, PasskeyCeremonyKind, PasskeyCeremonyStore, PasskeyCeremonyStoreError,
+};
+use crate::ports::passkey_repository::{PasskeyRepository, PasskeyRepositoryError};
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use crate::ports::webauthn_service::WebauthnService;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Relying party details sent to the browser with every ceremony.
+#[derive(Debug, Clone)]
+pub struct PasskeyOptions {
+    pub rp_id: String,
+    pub rp_name: String,
+    /// How long the user has to answer a ceremony.
+    pub timeout: Duration,
+}
+
+/// Everything `navigator.credentials.create()` needs, plus the ceremony to finish.
+#[derive(Debug)]
+pub struct PasskeyRegistrationOptions {
+    pub ceremony_id: Uuid,
+    pub challenge: Vec<u8>,
+    pub rp_id: String,
+    pub rp_name: String,
+    /// The user handle stored on the authenticator: the user id's bytes.
+    pub user_handle: Vec<u8>,
+    pub user_name: String,
+    pub user_display_name: String,
+    pub algorithms: Vec<i64>,
+    /// Credentials the user already registered, so an authenticator is not added twice.
+    pub exclude_credentials: Vec<Vec<u8>>,
+    pub timeout: Duration,
+}
+
+pub struct StartPasskeyRegistrationUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    passkey_repo: Arc<dyn PasskeyRepository>,
+    ceremony_store: Arc<dyn PasskeyCeremonyStore>,
+    webauthn_service: Arc<dyn WebauthnService>,
+    options: PasskeyOptions,
+}
+
+impl StartPasskeyRegistrationUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        passkey_repo: Arc<dyn PasskeyRepository>,
+        ceremony_store: Arc<dyn PasskeyCeremonyStore>,
+        webauthn_service: Arc<dyn WebauthnService>,
+        options: PasskeyOptions,
+    ) -> Self {
+        Self {
+            user_repo,
+            passkey_repo,
+            ceremony_store,
+            webauthn_service,
+            options,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+    ) -> Result<PasskeyRegistrationOptions, StartPasskeyRegistrationError> {
+        let user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .filter(|user| !user.is_deleted())
+            .ok_or(StartPasskeyRegistrationError::UserNotFound)?;
+
+        let exclude_credentials = self
+            .passkey_repo
+            .find_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .map(|passkey| passkey.credential_id)
+            .collect();
+
+        let ceremony = PasskeyCeremony {
+            id: Uuid::new_v4(),
+            kind: PasskeyCeremonyKind::Registration,
+            user_id: Some(user_id),
+            challenge: self.webauthn_service.generate_challenge(),
+            expires_at: Utc::now() + self.options.timeout,
+        };
+        self.ceremony_store.save(&ceremony).await?;
+
+        Ok(PasskeyRegistrationOptions {
+            ceremony_id: ceremony.id,
+            challenge: ceremony.challenge,
+            rp_id: self.options.rp_id.clone(),
+            rp_name: self.options.rp_name.clone(),
+            user_handle: user.id().as_bytes().to_vec(),
+            user_display_name: format!("{} {}", user.first_name(), user.last_name()),
+            user_name: user.email().to_string(),
+            algorithms: self.webauthn_service.supported_algorithms(),
+            exclude_credentials,
+            timeout: self.options.timeout,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum StartPasskeyRegistrationError {
+    UserNotFound,
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for StartPasskeyRegistrationError {
+    fn from(err: UserRepositoryError) -> Self {
+        StartPasskeyRegistrationError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<PasskeyRepositoryError> for StartPasskeyRegistrationError {
+    fn from(err: PasskeyRepositoryError) -> Self {
+        StartPasskeyRegistrationError::RepositoryError(err.to_string())
+    }
+}
+
+impl From<PasskeyCeremonyStoreError> for StartPasskeyRegistrationError {
+    fn from(err: PasskeyCeremonyStoreError) -> Self {
+        StartPasskeyRegistrationError::RepositoryError(err.to_string())
+    }
+}
+
+impl StartPasskeyRegistrationError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::UserNotFound => ErrorCode::UserNotFound,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for StartPasskeyRegistrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UserNotFound => write!(f, "User not found"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for StartPasskeyRegistrationError {}
@@ -0,0 +1,66 @@
+use crate::error_code::ErrorCode;
+use crate::ports::login_attempt_store::{LoginAttemptStore, LoginAttemptStoreError};
+use crate::ports::one_time_token_service::OneTimeTokenService;
+use std::sync::Arc;
+
+/// Lifts a login lockout via the token mailed when the account was locked.
+pub struct UnlockAccountUseCase {
+    attempt_store: Arc<dyn LoginAttemptStore>,
+    token_service: Arc<dyn OneTimeTokenService>,
+}
+
+impl UnlockAccountUseCase {
+    pub fn new(
+        attempt_store: Arc<dyn LoginAttemptStore>,
+        token_service: Arc<dyn OneTimeTokenService>,
+    ) -> Self {
+        Self {
+            attempt_store,
+            token_service,
+        }
+    }
+
+    pub async fn execute(&self, token: &str) -> Result<(), UnlockAccountError> {
+        let attempts = self
+            .attempt_store
+            .find_by_unlock_token_hash(&self.token_service.hash(token.trim()))
+            .await?
+            .ok_or(UnlockAccountError::InvalidToken)?;
+
+        self.attempt_store.clear(&attempts.subject).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum UnlockAccountError {
+    InvalidToken,
+    RepositoryError(String),
+}
+
+impl From<LoginAttemptStoreError> for UnlockAccountError {
+    fn from(err: LoginAttemptStoreError) -> Self {
+        UnlockAccountError::RepositoryError(err.to_string())
+    }
+}
+
+impl UnlockAccountError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidToken => ErrorCode::InvalidToken,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl std::fmt::Display for UnlockAccountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidToken => write!(f, "Invalid or already used unlock token"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for UnlockAccountError {}
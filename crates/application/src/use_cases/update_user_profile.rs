@@ -0,0 +1,307 @@
+use crate::error_code::ErrorCode;
+use crate::ports::audit_log::{AuditEntry, AuditLogError, FieldChange};
+use crate::ports::event_outbox::EventOutboxError;
+use crate::ports::event_publisher::EventPublisher;
+use crate::ports::unit_of_work::{UnitOfWork, UnitOfWorkError};
+use crate::ports::user_repository::{UserRepository, UserRepositoryError};
+use crate::use_cases::user_fields::{FieldError, parse_name, parse_phone_number};
+use chrono::{NaiveDate, Utc};
+use domain_users::{PersonName, PhoneNumber, User};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct UpdateUserProfileUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    event_publisher: Arc<dyn EventPublisher>,
+}
+
+impl UpdateUserProfileUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        unit_of_work: Arc<dyn UnitOfWork>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            user_repo,
+            unit_of_work,
+            event_publisher,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: UpdateUserProfileInput,
+    ) -> Result<User, UpdateUserProfileError> {
+        let user_id = input.user_id;
+        let actor_id = input.actor_id;
+        let expected_version = input.expected_version;
+        let update = input.parse().map_err(UpdateUserProfileError::Validation)?;
+
+        let mut user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .filter(|u| !u.is_deleted())
+            .ok_or(UpdateUserProfileError::NotFound(user_id))?;
+
+        if let Some(expected_version) = expected_version
+            && expected_version != user.version()
+        {
+            return Err(UpdateUserProfileError::PreconditionFailed {
+                current_version: user.version(),
+            });
+        }
+
+        let changes = apply_changes(&mut user, update);
+        if changes.is_empty() {
+            return Ok(user);
+        }
+
+        let entry = AuditEntry::new(
+            actor_id,
+            "user.profile_updated",
+            "user",
+            Some(user.id()),
+            changes,
+        );
+        // A change without its audit entry would go unaccounted for, so both are
+        // stored or neither.
+        let events = user.take_events();
+        let transaction = self.unit_of_work.begin().await?;
+        transaction.users().update(&user).await?;
+        transaction.audit_log().record(&entry).await?;
+        transaction.event_outbox().append(&events).await?;
+        transaction.commit().await?;
+        // The repository bumps the stored version on every successful update.
+        user.increment_version();
+        self.event_publisher.publish(&events).await;
+
+        Ok(user)
+    }
+}
+
+/// The validated counterpart of [`UpdateUserProfileInput`].
+struct ProfileUpdate {
+    first_name: Option<PersonName>,
+    last_name: Option<PersonName>,
+    phone_number: Option<Option<PhoneNumber>>,
+    birth_date: Option<Option<NaiveDate>>,
+    locale: Option<Option<String>>,
+}
+
+fn apply_changes(user: &mut User, update: ProfileUpdate) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+
+    if let Some(first_name) = update.first_name
+        && first_name != *user.first_name()
+    {
+        changes.push(change(
+            "first_name",
+            Some(user.first_name()),
+            Some(&first_name),
+        ));
+        user.change_first_name(first_name);
+    }
+
+    if let Some(last_name) = update.last_name
+        && last_name != *user.last_name()
+    {
+        changes.push(change(
+            "last_name",
+            Some(user.last_name()),
+            Some(&last_name),
+        ));
+        user.change_last_name(last_name);
+    }
+
+    if let Some(phone_number) = update.phone_number
+        && phone_number.as_ref() != user.phone_number()
+    {
+        changes.push(change(
+            "phone_number",
+            user.phone_number().map(PhoneNumber::as_str),
+            phone_number.as_deref(),
+        ));
+        user.change_phone_number(phone_number);
+    }
+
+    if let Some(birth_date) = update.birth_date
+        && birth_date != user.birth_date()
+    {
+        changes.push(FieldChange {
+            field: "birth_date".to_string(),
+            old_value: user.birth_date().map(|d| d.to_string()),
+            new_value: birth_date.map(|d| d.to_string()),
+        });
+        user.change_birth_date(birth_date);
+    }
+
+    if let Some(locale) = update.locale
+        && locale.as_deref() != user.locale()
+    {
+        changes.push(change("locale", user.locale(), locale.as_deref()));
+        user.change_locale(locale);
+    }
+
+    changes
+}
+
+fn change(field: &str, old_value: Option<&str>, new_value: Option<&str>) -> FieldChange {
+    FieldChange {
+        field: field.to_string(),
+        old_value: old_value.map(str::to_string),
+        new_value: new_value.map(str::to_string),
+    }
+}
+
+/// Partial profile update. `None` leaves a field untouched; for nullable fields
+/// `Some(None)` clears the stored value.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateUserProfileInput {
+    pub user_id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub expected_version: Option<i32>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub phone_number: Option<Option<String>>,
+    pub birth_date: Option<Option<NaiveDate>>,
+    pub locale: Option<Option<String>>,
+}
+
+impl UpdateUserProfileInput {
+    fn parse(self) -> Result<ProfileUpdate, Vec<FieldError>> {
+        let mut errors = Vec::new();
+
+        let first_name = self
+            .first_name
+            .map(|v| parse_name("first_name", &v, &mut errors));
+        let last_name = self
+            .last_name
+            .map(|v| parse_name("last_name", &v, &mut errors));
+        let phone_number = self
+            .phone_number
+            .map(|v| v.map(|p| parse_phone_number("phone_number", &p, &mut errors)));
+        if let Some(Some(birth_date)) = &self.birth_date {
+            let earliest = NaiveDate::from_ymd_opt(1900, 1, 1).unwrap_or(NaiveDate::MIN);
+            if *birth_date > Utc::now().date_naive() {
+                errors.push(FieldError::new(
+                    "birth_date",
+                    "future_date",
+                    "must not be in the future",
+                ));
+            } else if *birth_date < earliest {
+                errors.push(
+                    FieldError::new("birth_date", "range", "must not be before 1900-01-01")
+                        .with_param("min", &earliest.to_string()),
+                );
+            }
+        }
+        if let Some(Some(locale)) = &self.locale
+            && !is_valid_locale(locale)
+        {
+            errors.push(FieldError::new(
+                "locale",
+                "locale",
+                "must be a language tag like \"de\" or \"de-DE\"",
+            ));
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        // Every present value parsed, so the inner `None`s below are never parse failures.
+        Ok(ProfileUpdate {
+            first_name: first_name.flatten(),
+            last_name: last_name.flatten(),
+            phone_number: phone_number.map(Option::flatten),
+            birth_date: self.birth_date,
+            locale: self.locale,
+        })
+    }
+}
+
+fn is_valid_locale(value: &str) -> bool {
+    let mut parts = value.split('-');
+    let language = parts.next().unwrap_or_default();
+    let region = parts.next();
+    if parts.next().is_some() {
+        return false;
+    }
+    let language_ok =
+        (2..=3).contains(&language.len()) && language.chars().all(|c| c.is_ascii_lowercase());
+    let region_ok = region
+        .map(|r| r.len() == 2 && r.chars().all(|c| c.is_ascii_uppercase()))
+        .unwrap_or(true);
+    language_ok && region_ok
+}
+
+#[derive(Debug)]
+pub enum UpdateUserProfileError {
+    NotFound(Uuid),
+    Validation(Vec<FieldError>),
+    PreconditionFailed { current_version: i32 },
+    Conflict,
+    RepositoryError(String),
+}
+
+impl From<UserRepositoryError> for UpdateUserProfileError {
+    fn from(err: UserRepositoryError) -> Self {
+        match err {
+            UserRepositoryError::VersionConflict(_) => Self::Conflict,
+            other => Self::RepositoryError(other.to_string()),
+        }
+    }
+}
+
+impl From<AuditLogError> for UpdateUserProfileError {
+    fn from(err: AuditLogError) -> Self {
+        Self::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UnitOfWorkError> for UpdateUserProfileError {
+    fn from(err: UnitOfWorkError) -> Self {
+        Self::RepositoryError(err.to_string())
+    }
+}
+
+impl UpdateUserProfileError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound(_) => ErrorCode::UserNotFound,
+            Self::Validation(_) => ErrorCode::ValidationFailed,
+            Self::PreconditionFailed { .. } => ErrorCode::PreconditionFailed,
+            Self::Conflict => ErrorCode::ConcurrentModification,
+            Self::RepositoryError(_) => ErrorCode::InternalServerError,
+        }
+    }
+}
+
+impl From<EventOutboxError> for UpdateUserProfileError {
+    fn from(err: EventOutboxError) -> Self {
+        UpdateUserProfileError::RepositoryError(err.to_string())
+    }
+}
+
+impl std::fmt::Display for UpdateUserProfileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound(id) => write!(f, "User with ID {} not found", id),
+            Self::Validation(errors) => {
+                let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+                write!(f, "Validation failed: {}", messages.join("; "))
+            }
+            Self::PreconditionFailed { current_version } => write!(
+                f,
+                "User has been modified (current version {})",
+                current_version
+            ),
+            Self::Conflict => write!(f, "User was modified concurrently"),
+            Self::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for UpdateUserProfileError {}
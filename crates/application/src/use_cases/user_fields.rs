@@ -0,0 +1,113 @@
+use domain_users::value_objects::PersonNameError;
+use domain_users::{EmailAddress, PersonName, PhoneNumber};
+
+/// A rejected input field. `code` names the violated rule and `params` carries the
+/// rule's limits so callers can render their own (localized) message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    pub field: String,
+    pub code: String,
+    pub message: String,
+    pub params: Vec<(String, String)>,
+}
+
+impl FieldError {
+    pub fn new(field: &str, code: &str, message: &str) -> Self {
+        Self {
+            field: field.to_string(),
+            code: code.to_string(),
+            message: message.to_string(),
+            params: Vec::new(),
+        }
+    }
+
+    pub fn with_param(mut self, name: &str, value: &str) -> Self {
+        self.params.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+impl std::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+pub(crate) fn parse_name(
+    field: &str,
+    value: &str,
+    errors: &mut Vec<FieldError>,
+) -> Option<PersonName> {
+    PersonName::parse(value)
+        .map_err(|err| {
+            let code = match err {
+                PersonNameError::Empty => "required",
+                PersonNameError::TooLong => "length",
+                PersonNameError::InvalidCharacters => "characters",
+            };
+            let mut error = FieldError::new(field, code, &err.to_string());
+            if err == PersonNameError::TooLong {
+                error = error.with_param("max", &PersonName::MAX_LENGTH.to_string());
+            }
+            errors.push(error);
+        })
+        .ok()
+}
+
+pub(crate) fn parse_phone_number(
+    field: &str,
+    value: &str,
+    errors: &mut Vec<FieldError>,
+) -> Option<PhoneNumber> {
+    PhoneNumber::parse(value)
+        .map_err(|err| errors.push(FieldError::new(field, "phone_number", &err.to_string())))
+        .ok()
+}
+
+pub(crate) fn parse_email(
+    field: &str,
+    value: &str,
+    errors: &mut Vec<FieldError>,
+) -> Option<EmailAddress> {
+    EmailAddress::parse(value)
+        .map_err(|err| errors.push(FieldError::new(field, "email", &err.to_string())))
+        .ok()
+}
+
+/// The validated identity fields of a user about to be registered.
+pub(crate) struct NewUserFields {
+    pub first_name: PersonName,
+    pub last_name: PersonName,
+    pub email: EmailAddress,
+    pub phone_number: Option<PhoneNumber>,
+}
+
+impl NewUserFields {
+    pub fn parse(
+        first_name: &str,
+        last_name: &str,
+        email: &str,
+        phone_number: Option<&str>,
+    ) -> Result<Self, Vec<FieldError>> {
+        let mut errors = Vec::new();
+        let first_name = parse_name("first_name", first_name, &mut errors);
+        let last_name = parse_name("last_name", last_name, &mut errors);
+        let email = parse_email("email", email, &mut errors);
+        let phone_number = phone_number.map(|p| parse_phone_number("phone_number", p, &mut errors));
+
+        match (first_name, last_name, email, phone_number) {
+            (
+                Some(first_name),
+                Some(last_name),
+                Some(email),
+                phone_number @ (None | Some(Some(_))),
+            ) if errors.is_empty() => Ok(Self {
+                first_name,
+                last_name,
+                email,
+                phone_number: phone_number.flatten(),
+            }),
+            _ => Err(errors),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::ports::event_outbox::{OutboxConsumer, OutboxEvent};
+use crate::ports::event_publisher::EventHandlerError;
+use crate::ports::webhook_repository::{WebhookDelivery, WebhookRepository};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+/// Turns each outbox event into a pending delivery for every subscription that wants
+/// it. Sending is left to [`DeliverWebhooksUseCase`](super::DeliverWebhooksUseCase),
+/// so a slow endpoint never holds up the outbox.
+pub struct WebhookFanOut {
+    webhook_repo: Arc<dyn WebhookRepository>,
+}
+
+impl WebhookFanOut {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepository>) -> Self {
+        Self { webhook_repo }
+    }
+}
+
+#[async_trait]
+impl OutboxConsumer for WebhookFanOut {
+    fn name(&self) -> &str {
+        "webhooks"
+    }
+
+    async fn consume(&self, event: &OutboxEvent) -> Result<(), EventHandlerError> {
+        let now = Utc::now();
+        let deliveries: Vec<WebhookDelivery> = self
+            .webhook_repo
+            .list_subscriptions()
+            .await
+            .map_err(|e| EventHandlerError(e.to_string()))?
+            .into_iter()
+            .filter(|subscription| subscription.accepts(&event.name))
+            .map(|subscription| WebhookDelivery::new(subscription.id, event.clone(), now))
+            .collect();
+
+        if deliveries.is_empty() {
+            return Ok(());
+        }
+
+        self.webhook_repo
+            .create_deliveries(&deliveries)
+            .await
+            .map_err(|e| EventHandlerError(e.to_string()))
+    }
+}
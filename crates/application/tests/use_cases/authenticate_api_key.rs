@@ -0,0 +1,93 @@
+use application::ports::api_key_repository::ApiKeyScope;
+use application::ports::user_repository::UserRepository;
+use application::use_cases::{
+    API_KEY_PREFIX, AuthenticateApiKeyError, AuthenticateApiKeyUseCase, CreateApiKeyInput,
+    CreateApiKeyUseCase,
+};
+use chrono::Duration;
+use domain_users::UserRole;
+use std::sync::Arc;
+use test_support::fixtures::user_with_email;
+use test_support::{FakeOneTimeTokenService, InMemoryPorts};
+use uuid::Uuid;
+
+fn ports(role: UserRole) -> (InMemoryPorts, Uuid) {
+    let mut user = user_with_email("jane@example.com");
+    user.change_role(role);
+    let user_id = user.id();
+    (InMemoryPorts::new().with_users([user]), user_id)
+}
+
+fn create(p: &InMemoryPorts) -> CreateApiKeyUseCase {
+    CreateApiKeyUseCase::new(
+        p.users.clone(),
+        p.api_keys.clone(),
+        Arc::new(FakeOneTimeTokenService),
+        p.audit_log.clone(),
+    )
+}
+
+fn authenticate(p: &InMemoryPorts) -> AuthenticateApiKeyUseCase {
+    AuthenticateApiKeyUseCase::new(
+        p.api_keys.clone(),
+        p.users.clone(),
+        Arc::new(FakeOneTimeTokenService),
+    )
+}
+
+fn input(user_id: Uuid, expires_in: Duration) -> CreateApiKeyInput {
+    CreateApiKeyInput {
+        user_id,
+        name: " Moodle sync ".to_string(),
+        scopes: vec![ApiKeyScope::ProfileRead, ApiKeyScope::ProfileRead],
+        expires_in,
+    }
+}
+
+#[tokio::test]
+async fn test_created_key_authenticates_its_owner() {
+    let (p, user_id) = ports(UserRole::Creator);
+
+    let created = create(&p)
+        .execute(input(user_id, Duration::days(30)))
+        .await
+        .unwrap();
+    let principal = authenticate(&p).execute(&created.key).await.unwrap();
+
+    assert!(created.key.starts_with(API_KEY_PREFIX));
+    assert!(created.key.starts_with(&created.api_key.prefix));
+    assert_ne!(created.api_key.key_hash, created.key);
+    assert_eq!(created.api_key.name, "Moodle sync");
+    assert_eq!(principal.user_id, user_id);
+    assert_eq!(principal.scopes, vec![ApiKeyScope::ProfileRead]);
+    assert!(p.api_keys.keys()[0].last_used_at.is_some());
+}
+
+#[tokio::test]
+async fn test_expired_key_is_rejected() {
+    let (p, user_id) = ports(UserRole::Admin);
+    let created = create(&p)
+        .execute(input(user_id, Duration::seconds(-1)))
+        .await
+        .unwrap();
+
+    let result = authenticate(&p).execute(&created.key).await;
+
+    assert!(matches!(result, Err(AuthenticateApiKeyError::InvalidKey)));
+}
+
+#[tokio::test]
+async fn test_key_stops_working_when_owner_is_demoted() {
+    let (p, user_id) = ports(UserRole::Creator);
+    let created = create(&p)
+        .execute(input(user_id, Duration::days(30)))
+        .await
+        .unwrap();
+
+    let mut user = p.users.find_by_id(user_id).await.unwrap().unwrap();
+    user.change_role(UserRole::VerifiedUser);
+    p.users.update(&user).await.unwrap();
+    let result = authenticate(&p).execute(&created.key).await;
+
+    assert!(matches!(result, Err(AuthenticateApiKeyError::InvalidKey)));
+}
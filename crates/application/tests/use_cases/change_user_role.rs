@@ -0,0 +1,89 @@
+use application::ports::user_repository::UserRepository;
+use application::use_cases::{ChangeUserRoleError, ChangeUserRoleUseCase};
+use domain_users::UserRole;
+use test_support::InMemoryPorts;
+use test_support::fixtures::{active_session, user_with_email};
+use uuid::Uuid;
+
+/// A user with an open session.
+fn ports() -> (InMemoryPorts, Uuid) {
+    let user = user_with_email("jane@example.com");
+    let user_id = user.id();
+    let p = InMemoryPorts::new()
+        .with_users([user])
+        .with_sessions([active_session(user_id)]);
+    (p, user_id)
+}
+
+fn use_case(p: &InMemoryPorts) -> ChangeUserRoleUseCase {
+    ChangeUserRoleUseCase::new(p.users.clone(), p.unit_of_work(), p.events.clone())
+}
+
+#[tokio::test]
+async fn test_role_change_is_audited_and_signs_the_user_out() {
+    let (p, user_id) = ports();
+    let admin_id = Uuid::new_v4();
+
+    let user = use_case(&p)
+        .execute(admin_id, user_id, UserRole::Creator)
+        .await
+        .unwrap();
+
+    assert_eq!(*user.role(), UserRole::Creator);
+    let stored = p.users.find_by_id(user_id).await.unwrap().unwrap();
+    assert_eq!(*stored.role(), UserRole::Creator);
+    assert!(p.sessions.sessions()[0].revoked_at.is_some());
+
+    let entries = p.audit_log.entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].actor_id, Some(admin_id));
+    assert_eq!(entries[0].action, "user.role_changed");
+    assert_eq!(entries[0].changes[0].old_value.as_deref(), Some("user"));
+    assert_eq!(entries[0].changes[0].new_value.as_deref(), Some("creator"));
+    assert_eq!(p.event_outbox.names(), ["user.role_changed"]);
+    assert_eq!(p.events.events().len(), 1);
+}
+
+#[tokio::test]
+async fn test_failed_audit_write_keeps_the_role_and_raises_no_event() {
+    let (p, user_id) = ports();
+    p.audit_log.fail_writes();
+
+    let result = use_case(&p)
+        .execute(Uuid::new_v4(), user_id, UserRole::Creator)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ChangeUserRoleError::RepositoryError(_))
+    ));
+    let stored = p.users.find_by_id(user_id).await.unwrap().unwrap();
+    assert_eq!(*stored.role(), UserRole::User);
+    assert!(p.sessions.sessions()[0].revoked_at.is_none());
+    assert!(p.event_outbox.events().is_empty());
+    assert!(p.events.events().is_empty());
+}
+
+#[tokio::test]
+async fn test_unchanged_role_is_not_audited() {
+    let (p, user_id) = ports();
+
+    use_case(&p)
+        .execute(Uuid::new_v4(), user_id, UserRole::User)
+        .await
+        .unwrap();
+
+    assert!(p.audit_log.entries().is_empty());
+    assert!(p.sessions.sessions()[0].revoked_at.is_none());
+}
+
+#[tokio::test]
+async fn test_admins_cannot_change_their_own_role() {
+    let (p, user_id) = ports();
+
+    let result = use_case(&p)
+        .execute(user_id, user_id, UserRole::Admin)
+        .await;
+
+    assert!(matches!(result, Err(ChangeUserRoleError::OwnRole)));
+}
@@ -0,0 +1,56 @@
+use application::ports::session_repository::{Session, SessionRepository};
+use application::use_cases::{CheckSessionError, CheckSessionUseCase};
+use chrono::{Duration, Utc};
+use test_support::InMemoryPorts;
+use test_support::fixtures::active_session;
+use uuid::Uuid;
+
+fn last_seen(user_id: Uuid, ago: Duration) -> Session {
+    let mut session = active_session(user_id);
+    session.last_seen_at -= ago;
+    session
+}
+
+#[tokio::test]
+async fn test_revoked_session_is_rejected() {
+    let user_id = Uuid::new_v4();
+    let session = active_session(user_id);
+    let p = InMemoryPorts::new().with_sessions([session.clone()]);
+    let use_case = CheckSessionUseCase::new(p.sessions.clone());
+
+    use_case.execute(user_id, session.id).await.unwrap();
+    p.sessions
+        .revoke(user_id, session.id, Utc::now())
+        .await
+        .unwrap();
+    let result = use_case.execute(user_id, session.id).await;
+
+    assert!(matches!(result, Err(CheckSessionError::Revoked)));
+}
+
+#[tokio::test]
+async fn test_session_of_another_user_is_rejected() {
+    let session = active_session(Uuid::new_v4());
+    let p = InMemoryPorts::new().with_sessions([session.clone()]);
+    let use_case = CheckSessionUseCase::new(p.sessions.clone());
+
+    let result = use_case.execute(Uuid::new_v4(), session.id).await;
+
+    assert!(matches!(result, Err(CheckSessionError::Revoked)));
+}
+
+#[tokio::test]
+async fn test_last_seen_is_updated_at_most_once_a_minute() {
+    let user_id = Uuid::new_v4();
+    let recent = last_seen(user_id, Duration::seconds(10));
+    let stale = last_seen(user_id, Duration::minutes(5));
+    let p = InMemoryPorts::new().with_sessions([recent.clone(), stale.clone()]);
+    let use_case = CheckSessionUseCase::new(p.sessions.clone());
+
+    use_case.execute(user_id, recent.id).await.unwrap();
+    use_case.execute(user_id, stale.id).await.unwrap();
+
+    let sessions = p.sessions.sessions();
+    assert_eq!(sessions[0].last_seen_at, recent.last_seen_at);
+    assert!(sessions[1].last_seen_at > stale.last_seen_at);
+}
@@ -0,0 +1,83 @@
+use application::ports::login_attempt_store::{AttemptSubject, LoginAttempts};
+use application::use_cases::{ClearLoginLockoutError, ClearLoginLockoutUseCase};
+use chrono::{Duration, Utc};
+use test_support::InMemoryPorts;
+use uuid::Uuid;
+
+fn locked(subject: AttemptSubject) -> LoginAttempts {
+    let now = Utc::now();
+    LoginAttempts {
+        subject,
+        failed_attempts: 5,
+        last_failed_at: now,
+        blocked_until: None,
+        locked_until: Some(now + Duration::minutes(15)),
+        unlock_token_hash: None,
+    }
+}
+
+/// An account and an IP, both locked out.
+fn ports() -> InMemoryPorts {
+    InMemoryPorts::new().with_login_attempts([
+        locked(AttemptSubject::account("john@example.com")),
+        locked(AttemptSubject::Ip("203.0.113.7".parse().unwrap())),
+    ])
+}
+
+fn use_case(p: &InMemoryPorts) -> ClearLoginLockoutUseCase {
+    ClearLoginLockoutUseCase::new(p.login_attempts.clone(), p.audit_log.clone())
+}
+
+#[tokio::test]
+async fn test_clear_lifts_only_that_lockout_and_is_audited() {
+    let p = ports();
+    let admin_id = Uuid::new_v4();
+
+    use_case(&p)
+        .execute(admin_id, &AttemptSubject::account("John@Example.com"))
+        .await
+        .unwrap();
+
+    let remaining = p.login_attempts.attempts();
+    assert_eq!(remaining.len(), 1);
+    assert!(matches!(remaining[0].subject, AttemptSubject::Ip(_)));
+
+    let entries = p.audit_log.entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].actor_id, Some(admin_id));
+    assert_eq!(entries[0].action, "login_lockout.cleared");
+    assert_eq!(entries[0].target_type, "login_lockout");
+    assert_eq!(entries[0].target_id, None);
+    assert_eq!(entries[0].changes[0].field, "subject");
+    assert_eq!(
+        entries[0].changes[0].old_value.as_deref(),
+        Some("account:john@example.com")
+    );
+}
+
+#[tokio::test]
+async fn test_ip_lockout_can_be_cleared() {
+    let p = ports();
+    let ip = AttemptSubject::Ip("203.0.113.7".parse().unwrap());
+
+    use_case(&p).execute(Uuid::new_v4(), &ip).await.unwrap();
+
+    assert!(p.login_attempts.attempts().iter().all(|a| a.subject != ip));
+    assert_eq!(
+        p.audit_log.entries()[0].changes[0].old_value.as_deref(),
+        Some("ip:203.0.113.7")
+    );
+}
+
+#[tokio::test]
+async fn test_subject_without_failures_is_not_found() {
+    let p = ports();
+
+    let result = use_case(&p)
+        .execute(Uuid::new_v4(), &AttemptSubject::account("jane@example.com"))
+        .await;
+
+    assert!(matches!(result, Err(ClearLoginLockoutError::NotFound)));
+    assert_eq!(p.login_attempts.attempts().len(), 2);
+    assert!(p.audit_log.entries().is_empty());
+}
@@ -0,0 +1,165 @@
+use application::ports::access_token_service::AccessTokenScope;
+use application::ports::linked_identity_repository::{LinkedIdentity, LinkedIdentityRepository};
+use application::ports::oidc_client::OidcIdentity;
+use application::ports::oidc_flow_store::PendingOidcLogin;
+use application::ports::user_repository::UserRepository;
+use application::use_cases::{
+    ClientInfo, CompleteOidcLoginError, CompleteOidcLoginUseCase, OidcLoginOutcome,
+};
+use chrono::{Duration, Utc};
+use domain_users::User;
+use std::sync::Arc;
+use test_support::fixtures::user_with_email;
+use test_support::{FakeOidcClient, FakeOneTimeTokenService, FastPasswordHasher, InMemoryPorts};
+use uuid::Uuid;
+
+fn identity(email_verified: bool) -> OidcIdentity {
+    OidcIdentity {
+        subject: "google-123".to_string(),
+        email: Some("jane@example.com".to_string()),
+        email_verified,
+        given_name: Some("Jane".to_string()),
+        family_name: Some("Doe".to_string()),
+    }
+}
+
+/// A login started at google, the provider [`FakeOidcClient`] stands in for.
+fn ports() -> InMemoryPorts {
+    InMemoryPorts::new().with_oidc_logins([PendingOidcLogin {
+        state: "state".to_string(),
+        provider: "google".to_string(),
+        nonce: "nonce".to_string(),
+        code_verifier: "verifier".to_string(),
+        expires_at: Utc::now() + Duration::minutes(10),
+    }])
+}
+
+fn use_case(p: &InMemoryPorts, identity: OidcIdentity) -> CompleteOidcLoginUseCase {
+    CompleteOidcLoginUseCase::new(
+        p.users.clone(),
+        p.unit_of_work(),
+        p.linked_identities.clone(),
+        p.oidc_flows.clone(),
+        Arc::new(FakeOidcClient::new(identity)),
+        Arc::new(FakeOneTimeTokenService),
+        Arc::new(FastPasswordHasher),
+        p.two_factor.clone(),
+        p.session_tokens(),
+        Duration::minutes(15),
+        p.events.clone(),
+    )
+}
+
+async fn existing_user(p: &InMemoryPorts) -> User {
+    let user = user_with_email("jane@example.com");
+    p.users.create(&user).await.unwrap();
+    user
+}
+
+#[tokio::test]
+async fn test_new_identity_signs_up_verified_user() {
+    let p = ports();
+
+    let outcome = use_case(&p, identity(true))
+        .execute("google", "code", "state", &ClientInfo::default())
+        .await
+        .unwrap();
+
+    let OidcLoginOutcome::LoggedIn(output) = outcome else {
+        panic!("expected a login");
+    };
+    assert_eq!(output.scope, AccessTokenScope::Full);
+    assert!(output.user.is_email_verified());
+    assert_eq!(output.user.first_name(), "Jane");
+    let identities = p.linked_identities.identities();
+    assert_eq!(identities.len(), 1);
+    assert_eq!(identities[0].user_id, output.user.id());
+    assert_eq!(
+        p.event_outbox.names(),
+        ["user.signed_up", "user.email_verified"]
+    );
+}
+
+#[tokio::test]
+async fn test_linked_identity_logs_in_its_user() {
+    let p = ports();
+    let user = existing_user(&p).await;
+    p.linked_identities
+        .create(&LinkedIdentity {
+            id: Uuid::new_v4(),
+            user_id: user.id(),
+            provider: "google".to_string(),
+            subject: "google-123".to_string(),
+            email: None,
+            created_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+
+    let outcome = use_case(&p, identity(false))
+        .execute("google", "code", "state", &ClientInfo::default())
+        .await
+        .unwrap();
+
+    assert!(matches!(outcome, OidcLoginOutcome::LoggedIn(output) if output.user.id() == user.id()));
+}
+
+#[tokio::test]
+async fn test_matching_email_requires_confirmation_instead_of_linking() {
+    let p = ports();
+    let user = existing_user(&p).await;
+
+    let outcome = use_case(&p, identity(true))
+        .execute("google", "code", "state", &ClientInfo::default())
+        .await
+        .unwrap();
+
+    let OidcLoginOutcome::LinkRequired(link) = outcome else {
+        panic!("expected a pending link");
+    };
+    assert!(p.linked_identities.identities().is_empty());
+    let links = p.oidc_flows.links();
+    assert_eq!(links[0].user_id, user.id());
+    assert_eq!(
+        links[0].token_hash,
+        FakeOneTimeTokenService::hash_of(&link.link_token)
+    );
+}
+
+#[tokio::test]
+async fn test_state_is_single_use_and_bound_to_provider() {
+    let p = ports();
+    let use_case = use_case(&p, identity(true));
+
+    let wrong_provider = use_case
+        .execute("apple", "code", "state", &ClientInfo::default())
+        .await;
+    let replayed = use_case
+        .execute("google", "code", "state", &ClientInfo::default())
+        .await;
+
+    assert!(matches!(
+        wrong_provider,
+        Err(CompleteOidcLoginError::InvalidState)
+    ));
+    assert!(matches!(
+        replayed,
+        Err(CompleteOidcLoginError::InvalidState)
+    ));
+}
+
+#[tokio::test]
+async fn test_unverified_email_is_not_matched_or_signed_up() {
+    let p = ports();
+    existing_user(&p).await;
+
+    let result = use_case(&p, identity(false))
+        .execute("google", "code", "state", &ClientInfo::default())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(CompleteOidcLoginError::EmailNotVerified)
+    ));
+    assert!(p.oidc_flows.links().is_empty());
+}
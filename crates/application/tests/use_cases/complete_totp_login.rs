@@ -0,0 +1,108 @@
+use application::ports::access_token_service::{AccessTokenScope, AccessTokenService};
+use application::ports::login_attempt_store::AttemptSubject;
+use application::ports::two_factor_repository::TwoFactorRepository;
+use application::use_cases::{
+    ClientInfo, CompleteTotpLoginUseCase, LoginError, LoginThrottlePolicy,
+};
+use chrono::Duration;
+use std::sync::Arc;
+use test_support::fixtures::{confirmed_totp, user_with_email};
+use test_support::{FakeTotpService, InMemoryPorts};
+use uuid::Uuid;
+
+/// A user with a confirmed enrollment and the recovery code `abcde-fghij`.
+async fn ports() -> (InMemoryPorts, Uuid) {
+    let user = user_with_email("john@example.com");
+    let user_id = user.id();
+    let p = InMemoryPorts::new()
+        .with_users([user])
+        .with_totp_enrollments([confirmed_totp(user_id)]);
+    p.two_factor
+        .replace_recovery_codes(user_id, &["hashed_abcde-fghij".to_string()])
+        .await
+        .unwrap();
+    (p, user_id)
+}
+
+fn use_case(p: &InMemoryPorts) -> CompleteTotpLoginUseCase {
+    CompleteTotpLoginUseCase::new(
+        p.users.clone(),
+        p.two_factor.clone(),
+        Arc::new(FakeTotpService),
+        p.session_tokens(),
+        p.login_throttle(LoginThrottlePolicy {
+            base_delay: Duration::zero(),
+            max_delay: Duration::zero(),
+            ..LoginThrottlePolicy::default()
+        }),
+    )
+}
+
+#[tokio::test]
+async fn test_valid_code_issues_full_token() {
+    let (p, user_id) = ports().await;
+
+    let output = use_case(&p)
+        .execute(user_id, "123456", &ClientInfo::default())
+        .await
+        .unwrap();
+
+    assert_eq!(output.scope, AccessTokenScope::Full);
+    let claims = p.access_tokens.verify(&output.access_token.token).unwrap();
+    assert_eq!(claims.user_id, user_id);
+    assert_eq!(claims.scope, AccessTokenScope::Full);
+}
+
+#[tokio::test]
+async fn test_code_cannot_be_replayed() {
+    let (p, user_id) = ports().await;
+    let use_case = use_case(&p);
+
+    use_case
+        .execute(user_id, "654321", &ClientInfo::default())
+        .await
+        .unwrap();
+    let replay = use_case
+        .execute(user_id, "654321", &ClientInfo::default())
+        .await;
+    let older = use_case
+        .execute(user_id, "123456", &ClientInfo::default())
+        .await;
+
+    assert!(matches!(replay, Err(LoginError::InvalidTwoFactorCode)));
+    assert!(matches!(older, Err(LoginError::InvalidTwoFactorCode)));
+}
+
+#[tokio::test]
+async fn test_recovery_code_is_single_use() {
+    let (p, user_id) = ports().await;
+    let use_case = use_case(&p);
+
+    use_case
+        .execute(user_id, " ABCDE-FGHIJ ", &ClientInfo::default())
+        .await
+        .unwrap();
+    let again = use_case
+        .execute(user_id, "abcde-fghij", &ClientInfo::default())
+        .await;
+
+    assert!(matches!(again, Err(LoginError::InvalidTwoFactorCode)));
+    assert!(p.two_factor.unused_recovery_codes(user_id).is_empty());
+}
+
+#[tokio::test]
+async fn test_wrong_code_counts_as_failed_login() {
+    let (p, user_id) = ports().await;
+
+    let result = use_case(&p)
+        .execute(user_id, "000000", &ClientInfo::default())
+        .await;
+
+    assert!(matches!(result, Err(LoginError::InvalidTwoFactorCode)));
+    let attempts = p.login_attempts.attempts();
+    let account = attempts
+        .iter()
+        .find(|a| a.subject == AttemptSubject::account("john@example.com"))
+        .unwrap();
+    assert_eq!(account.failed_attempts, 1);
+}
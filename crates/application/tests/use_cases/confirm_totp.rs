@@ -0,0 +1,61 @@
+use application::ports::access_token_service::AccessTokenService;
+use application::use_cases::{ClientInfo, ConfirmTotpError, ConfirmTotpUseCase};
+use domain_users::UserRole;
+use std::sync::Arc;
+use test_support::fixtures::{confirmed_totp, user_with_email};
+use test_support::{FakeTotpService, InMemoryPorts};
+use uuid::Uuid;
+
+/// An admin who started but has not confirmed an enrollment.
+fn ports() -> (InMemoryPorts, Uuid) {
+    let mut user = user_with_email("jane@example.com");
+    user.change_role(UserRole::Admin);
+    let user_id = user.id();
+    let mut enrollment = confirmed_totp(user_id);
+    enrollment.confirmed_at = None;
+    let p = InMemoryPorts::new()
+        .with_users([user])
+        .with_totp_enrollments([enrollment]);
+    (p, user_id)
+}
+
+fn use_case(p: &InMemoryPorts) -> ConfirmTotpUseCase {
+    ConfirmTotpUseCase::new(
+        p.users.clone(),
+        p.two_factor.clone(),
+        Arc::new(FakeTotpService),
+        p.session_tokens(),
+    )
+}
+
+#[tokio::test]
+async fn test_confirm_activates_enrollment_and_stores_hashed_recovery_codes() {
+    let (p, user_id) = ports();
+
+    let output = use_case(&p)
+        .execute(user_id, "123456", &ClientInfo::default())
+        .await
+        .unwrap();
+
+    assert_eq!(output.recovery_codes.len(), 10);
+    let claims = p.access_tokens.verify(&output.access_token.token).unwrap();
+    assert_eq!(claims.user_id, user_id);
+    let enrollment = p.two_factor.enrollments()[0].clone();
+    assert!(enrollment.is_confirmed());
+    assert_eq!(enrollment.last_used_step, Some(1));
+    let hashes = p.two_factor.unused_recovery_codes(user_id);
+    assert_eq!(hashes[0], "hashed_code-0");
+    assert!(!hashes.contains(&output.recovery_codes[0]));
+}
+
+#[tokio::test]
+async fn test_confirm_rejects_wrong_code() {
+    let (p, user_id) = ports();
+
+    let result = use_case(&p)
+        .execute(user_id, "000000", &ClientInfo::default())
+        .await;
+
+    assert!(matches!(result, Err(ConfirmTotpError::InvalidCode)));
+    assert!(!p.two_factor.enrollments()[0].is_confirmed());
+}
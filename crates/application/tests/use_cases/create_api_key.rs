@@ -0,0 +1,119 @@
+use application::ports::api_key_repository::ApiKeyScope;
+use application::ports::user_repository::UserRepository;
+use application::use_cases::{CreateApiKeyError, CreateApiKeyInput, CreateApiKeyUseCase};
+use chrono::Duration;
+use domain_users::UserRole;
+use std::sync::Arc;
+use test_support::fixtures::user_with_email;
+use test_support::{FakeOneTimeTokenService, InMemoryPorts};
+use uuid::Uuid;
+
+fn ports(role: UserRole) -> (InMemoryPorts, Uuid) {
+    let mut user = user_with_email("jane@example.com");
+    user.change_role(role);
+    let user_id = user.id();
+    (InMemoryPorts::new().with_users([user]), user_id)
+}
+
+fn use_case(p: &InMemoryPorts) -> CreateApiKeyUseCase {
+    CreateApiKeyUseCase::new(
+        p.users.clone(),
+        p.api_keys.clone(),
+        Arc::new(FakeOneTimeTokenService),
+        p.audit_log.clone(),
+    )
+}
+
+fn input(user_id: Uuid, scopes: Vec<ApiKeyScope>) -> CreateApiKeyInput {
+    CreateApiKeyInput {
+        user_id,
+        name: "Moodle sync".to_string(),
+        scopes,
+        expires_in: Duration::days(30),
+    }
+}
+
+#[tokio::test]
+async fn test_create_stores_only_the_hash_and_audits_the_scopes() {
+    let (p, user_id) = ports(UserRole::Creator);
+
+    let created = use_case(&p)
+        .execute(input(
+            user_id,
+            vec![
+                ApiKeyScope::ProfileWrite,
+                ApiKeyScope::ProfileRead,
+                ApiKeyScope::ProfileWrite,
+            ],
+        ))
+        .await
+        .unwrap();
+
+    let stored = p.api_keys.keys();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].id, created.api_key.id);
+    assert_eq!(stored[0].user_id, user_id);
+    assert_eq!(
+        stored[0].key_hash,
+        FakeOneTimeTokenService::hash_of(&created.key)
+    );
+    assert_eq!(
+        stored[0].scopes,
+        vec![ApiKeyScope::ProfileRead, ApiKeyScope::ProfileWrite]
+    );
+
+    let entries = p.audit_log.entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].actor_id, Some(user_id));
+    assert_eq!(entries[0].action, "api_key.created");
+    assert_eq!(entries[0].target_type, "api_key");
+    assert_eq!(entries[0].target_id, Some(created.api_key.id));
+    assert_eq!(entries[0].changes[0].field, "scopes");
+    assert_eq!(
+        entries[0].changes[0].new_value.as_deref(),
+        Some("profile:read profile:write")
+    );
+}
+
+#[tokio::test]
+async fn test_regular_user_is_forbidden() {
+    let (p, user_id) = ports(UserRole::VerifiedUser);
+
+    let result = use_case(&p)
+        .execute(input(user_id, vec![ApiKeyScope::ProfileRead]))
+        .await;
+
+    assert!(matches!(result, Err(CreateApiKeyError::NotAllowed)));
+    assert!(p.api_keys.keys().is_empty());
+    assert!(p.audit_log.entries().is_empty());
+}
+
+#[tokio::test]
+async fn test_unknown_or_deleted_user_is_not_found() {
+    let (p, user_id) = ports(UserRole::Creator);
+    let unknown = use_case(&p)
+        .execute(input(Uuid::new_v4(), vec![ApiKeyScope::ProfileRead]))
+        .await;
+
+    let mut user = p.users.find_by_id(user_id).await.unwrap().unwrap();
+    user.delete();
+    p.users.update(&user).await.unwrap();
+    let deleted = use_case(&p)
+        .execute(input(user_id, vec![ApiKeyScope::ProfileRead]))
+        .await;
+
+    assert!(matches!(unknown, Err(CreateApiKeyError::UserNotFound)));
+    assert!(matches!(deleted, Err(CreateApiKeyError::UserNotFound)));
+    assert!(p.api_keys.keys().is_empty());
+    assert!(p.audit_log.entries().is_empty());
+}
+
+#[tokio::test]
+async fn test_key_without_scopes_is_rejected() {
+    let (p, user_id) = ports(UserRole::Admin);
+
+    let result = use_case(&p).execute(input(user_id, Vec::new())).await;
+
+    assert!(matches!(result, Err(CreateApiKeyError::NoScopes)));
+    assert!(p.api_keys.keys().is_empty());
+}
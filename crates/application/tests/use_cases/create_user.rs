@@ -0,0 +1,58 @@
+use application::ports::user_repository::UserRepository;
+use application::use_cases::{CreateUserError, CreateUserInput, CreateUserUseCase};
+use std::sync::Arc;
+use test_support::{FastPasswordHasher, InMemoryPorts};
+
+fn use_case(p: &InMemoryPorts) -> CreateUserUseCase {
+    CreateUserUseCase::new(
+        p.users.clone(),
+        p.unit_of_work(),
+        Arc::new(FastPasswordHasher),
+        p.events.clone(),
+    )
+}
+
+fn input(email: &str) -> CreateUserInput {
+    CreateUserInput {
+        first_name: "Anna".to_string(),
+        last_name: "Schmidt".to_string(),
+        email: email.to_string(),
+        phone_number: None,
+        password: "password".to_string(),
+        birth_date: None,
+    }
+}
+
+#[tokio::test]
+async fn test_create_user_after_soft_delete() {
+    let p = InMemoryPorts::new();
+    let use_case = use_case(&p);
+
+    let first = use_case.execute(input("test@example.com")).await.unwrap();
+    let mut deleted = first.clone();
+    deleted.delete();
+    p.users.update(&deleted).await.unwrap();
+
+    let second = use_case.execute(input("test@example.com")).await.unwrap();
+
+    assert_ne!(first.id(), second.id());
+    assert_eq!(first.email(), second.email());
+    assert!(!second.is_deleted());
+}
+
+#[tokio::test]
+async fn test_create_user_treats_case_variants_as_the_same_email() {
+    let use_case = use_case(&InMemoryPorts::new());
+
+    let user = use_case.execute(input(" Anna@Example.DE")).await.unwrap();
+    assert_eq!(user.email(), "anna@example.de");
+
+    assert!(matches!(
+        use_case.execute(input("anna@example.de")).await,
+        Err(CreateUserError::AlreadyExists(email)) if email == "anna@example.de"
+    ));
+    assert!(matches!(
+        use_case.execute(input("anna.example.de")).await,
+        Err(CreateUserError::Validation(_))
+    ));
+}
@@ -0,0 +1,94 @@
+use application::use_cases::{
+    CreateWebhookSubscriptionError, CreateWebhookSubscriptionInput,
+    CreateWebhookSubscriptionUseCase, WEBHOOK_SECRET_PREFIX,
+};
+use std::sync::Arc;
+use test_support::{FakeOneTimeTokenService, InMemoryPorts};
+use uuid::Uuid;
+
+fn use_case(p: &InMemoryPorts, allow_insecure_urls: bool) -> CreateWebhookSubscriptionUseCase {
+    CreateWebhookSubscriptionUseCase::new(
+        p.webhooks.clone(),
+        Arc::new(FakeOneTimeTokenService),
+        p.audit_log.clone(),
+        allow_insecure_urls,
+    )
+}
+
+#[tokio::test]
+async fn test_create_stores_subscription_with_secret() {
+    let p = InMemoryPorts::new();
+
+    let subscription = use_case(&p, false)
+        .execute(CreateWebhookSubscriptionInput {
+            admin_id: Uuid::new_v4(),
+            url: " https://partner.example.com/hooks ".to_string(),
+            events: vec![
+                "user.deleted".to_string(),
+                "user.signed_up".to_string(),
+                "user.deleted".to_string(),
+            ],
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(subscription.url, "https://partner.example.com/hooks");
+    assert_eq!(subscription.events, vec!["user.deleted", "user.signed_up"]);
+    assert!(subscription.secret.starts_with(WEBHOOK_SECRET_PREFIX));
+    assert_eq!(p.webhooks.subscriptions()[0].id, subscription.id);
+    assert_eq!(p.audit_log.entries()[0].action, "webhook.created");
+}
+
+#[tokio::test]
+async fn test_create_rejects_unknown_events() {
+    let p = InMemoryPorts::new();
+
+    let result = use_case(&p, false)
+        .execute(CreateWebhookSubscriptionInput {
+            admin_id: Uuid::new_v4(),
+            url: "https://partner.example.com/hooks".to_string(),
+            events: vec!["page.published".to_string()],
+        })
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(CreateWebhookSubscriptionError::UnknownEvent(name)) if name == "page.published"
+    ));
+    assert!(p.webhooks.subscriptions().is_empty());
+}
+
+#[tokio::test]
+async fn test_create_requires_https() {
+    let p = InMemoryPorts::new();
+
+    let result = use_case(&p, false)
+        .execute(CreateWebhookSubscriptionInput {
+            admin_id: Uuid::new_v4(),
+            url: "http://partner.example.com/hooks".to_string(),
+            events: vec!["user.deleted".to_string()],
+        })
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(CreateWebhookSubscriptionError::InvalidUrl)
+    ));
+    assert!(p.webhooks.subscriptions().is_empty());
+}
+
+#[tokio::test]
+async fn test_create_accepts_http_when_insecure_urls_are_allowed() {
+    let p = InMemoryPorts::new();
+
+    let subscription = use_case(&p, true)
+        .execute(CreateWebhookSubscriptionInput {
+            admin_id: Uuid::new_v4(),
+            url: "http://localhost:9000/hooks".to_string(),
+            events: vec!["user.deleted".to_string()],
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(subscription.url, "http://localhost:9000/hooks");
+}
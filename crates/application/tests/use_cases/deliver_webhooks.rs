@@ -0,0 +1,136 @@
+use application::ports::event_outbox::OutboxEvent;
+use application::ports::webhook_repository::{
+    WebhookDelivery, WebhookDeliveryStatus, WebhookRepository,
+};
+use application::ports::webhook_sender::{WebhookSendError, WebhookSender};
+use application::use_cases::{DeliverWebhooksUseCase, WebhookRetryPolicy};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use std::sync::{Arc, Mutex};
+use test_support::InMemoryWebhookRepository;
+use test_support::fixtures::{outbox_event, webhook_subscription};
+
+/// Answers with the given results in order, then with 200.
+struct MockSender {
+    results: Mutex<Vec<Result<u16, WebhookSendError>>>,
+    sent: Mutex<Vec<(String, String)>>,
+}
+
+#[async_trait]
+impl WebhookSender for MockSender {
+    async fn send(
+        &self,
+        url: &str,
+        secret: &str,
+        _event: &OutboxEvent,
+    ) -> Result<u16, WebhookSendError> {
+        self.sent
+            .lock()
+            .unwrap()
+            .push((url.to_string(), secret.to_string()));
+        let mut results = self.results.lock().unwrap();
+        if results.is_empty() {
+            Ok(200)
+        } else {
+            results.remove(0)
+        }
+    }
+}
+
+const POLICY: WebhookRetryPolicy = WebhookRetryPolicy {
+    max_attempts: 3,
+    base_delay: Duration::seconds(30),
+    max_delay: Duration::minutes(10),
+};
+
+async fn setup(
+    results: Vec<Result<u16, WebhookSendError>>,
+) -> (
+    DeliverWebhooksUseCase,
+    Arc<InMemoryWebhookRepository>,
+    Arc<MockSender>,
+) {
+    let subscription = webhook_subscription(&["user.deleted"]);
+    let delivery = WebhookDelivery::new(subscription.id, outbox_event("user.deleted"), Utc::now());
+    let repo = Arc::new(InMemoryWebhookRepository::with_subscriptions([
+        subscription,
+    ]));
+    repo.create_deliveries(&[delivery]).await.unwrap();
+    let sender = Arc::new(MockSender {
+        results: Mutex::new(results),
+        sent: Mutex::new(Vec::new()),
+    });
+    let use_case = DeliverWebhooksUseCase::new(repo.clone(), sender.clone(), POLICY);
+    (use_case, repo, sender)
+}
+
+async fn make_due(repo: &InMemoryWebhookRepository) {
+    for mut delivery in repo.deliveries() {
+        if delivery.next_attempt_at.is_some() {
+            delivery.next_attempt_at = Some(Utc::now() - Duration::seconds(1));
+            repo.update_delivery(&delivery).await.unwrap();
+        }
+    }
+}
+
+#[test]
+fn test_delay_doubles_up_to_the_maximum() {
+    assert_eq!(POLICY.delay_after(1), Duration::seconds(30));
+    assert_eq!(POLICY.delay_after(2), Duration::seconds(60));
+    assert_eq!(POLICY.delay_after(3), Duration::seconds(120));
+    assert_eq!(POLICY.delay_after(40), Duration::minutes(10));
+}
+
+#[tokio::test]
+async fn test_execute_marks_successful_delivery() {
+    let (use_case, repo, sender) = setup(Vec::new()).await;
+
+    assert_eq!(use_case.execute().await.unwrap(), 1);
+
+    let delivery = repo.deliveries()[0].clone();
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Succeeded);
+    assert_eq!(delivery.attempts, 1);
+    assert_eq!(delivery.response_status, Some(200));
+    assert_eq!(delivery.next_attempt_at, None);
+    assert_eq!(
+        sender.sent.lock().unwrap()[0],
+        (
+            "https://partner.example.com/hooks".to_string(),
+            "whsec_secret".to_string()
+        )
+    );
+    assert_eq!(use_case.execute().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_execute_retries_with_backoff_and_gives_up() {
+    let (use_case, repo, _) = setup(vec![
+        Ok(500),
+        Err(WebhookSendError::Unreachable("timed out".to_string())),
+        Ok(404),
+    ])
+    .await;
+
+    let before = Utc::now();
+    use_case.execute().await.unwrap();
+    let delivery = repo.deliveries()[0].clone();
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(delivery.response_status, Some(500));
+    assert!(delivery.next_attempt_at.unwrap() >= before + Duration::seconds(30));
+    // Not due yet.
+    assert_eq!(use_case.execute().await.unwrap(), 0);
+
+    make_due(&repo).await;
+    use_case.execute().await.unwrap();
+    let delivery = repo.deliveries()[0].clone();
+    assert_eq!(delivery.attempts, 2);
+    assert_eq!(delivery.response_status, None);
+    assert!(delivery.last_error.unwrap().contains("timed out"));
+
+    make_due(&repo).await;
+    use_case.execute().await.unwrap();
+    let delivery = repo.deliveries()[0].clone();
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Failed);
+    assert_eq!(delivery.attempts, 3);
+    assert_eq!(delivery.next_attempt_at, None);
+}
@@ -0,0 +1,66 @@
+use application::ports::data_export_archive::{DataExportArchive, UserDataSnapshot};
+use application::ports::data_export_repository::{DataExport, DataExportStatus};
+use application::use_cases::EraseDeletedUsersUseCase;
+use chrono::{Duration, Utc};
+use domain_users::User;
+use test_support::InMemoryPorts;
+use test_support::fixtures::user_with_email;
+use uuid::Uuid;
+
+fn deleted_days_ago(email: &str, days: i64) -> User {
+    let mut snapshot = user_with_email(email).snapshot();
+    snapshot.deleted_at = Some(Utc::now() - Duration::days(days));
+    User::from_snapshot(snapshot)
+}
+
+#[tokio::test]
+async fn test_only_accounts_past_the_grace_period_are_erased() {
+    let now = Utc::now();
+    let expired = deleted_days_ago("expired@example.com", 31);
+    let recent = deleted_days_ago("recent@example.com", 1);
+    let expired_id = expired.id();
+    let recent_id = recent.id();
+    let export = DataExport {
+        id: Uuid::new_v4(),
+        user_id: expired_id,
+        requested_by: expired_id,
+        status: DataExportStatus::Ready,
+        download_token_hash: Some("hash".to_string()),
+        created_at: now - Duration::days(32),
+        completed_at: Some(now - Duration::days(32)),
+        expires_at: Some(now - Duration::days(29)),
+    };
+    let p = InMemoryPorts::new()
+        .with_users([expired, recent])
+        .with_data_exports([export.clone()]);
+    let use_case = EraseDeletedUsersUseCase::new(
+        p.users.clone(),
+        p.data_exports.clone(),
+        p.data_export_archive.clone(),
+        p.audit_log.clone(),
+        Duration::days(30),
+    );
+    let snapshot = UserDataSnapshot {
+        user: p.users.users()[0].clone(),
+        sessions: Vec::new(),
+        passkeys: Vec::new(),
+        linked_identities: Vec::new(),
+        api_keys: Vec::new(),
+        audit_entries: Vec::new(),
+    };
+    p.data_export_archive
+        .store(export.id, &snapshot)
+        .await
+        .unwrap();
+
+    assert_eq!(use_case.execute().await.unwrap(), 1);
+
+    let remaining: Vec<_> = p.users.users().iter().map(|user| user.id()).collect();
+    assert_eq!(remaining, [recent_id]);
+    assert!(p.data_export_archive.export_ids().is_empty());
+    let entries = p.audit_log.entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].action, "user.erased");
+    assert_eq!(entries[0].actor_id, None);
+    assert_eq!(entries[0].target_id, Some(expired_id));
+}
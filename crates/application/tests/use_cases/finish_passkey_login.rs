@@ -0,0 +1,124 @@
+use application::ports::access_token_service::AccessTokenScope;
+use application::ports::passkey_ceremony_store::{
+    PasskeyCeremony, PasskeyCeremonyKind, PasskeyCeremonyStore,
+};
+use application::ports::passkey_repository::Passkey;
+use application::ports::webauthn_service::PasskeyAssertionResponse;
+use application::use_cases::{ClientInfo, FinishPasskeyLoginUseCase, PasskeyLoginError};
+use chrono::{Duration, Utc};
+use domain_users::UserRole;
+use std::sync::Arc;
+use test_support::fixtures::user_with_email;
+use test_support::{FakeWebauthnService, InMemoryPorts};
+use uuid::Uuid;
+
+/// An admin with the passkey `cred-1`, whose public key is `key`.
+fn ports() -> (InMemoryPorts, Uuid) {
+    let mut user = user_with_email("jane@example.com");
+    user.change_role(UserRole::Admin);
+    let user_id = user.id();
+    let p = InMemoryPorts::new()
+        .with_users([user])
+        .with_passkeys([Passkey {
+            id: Uuid::new_v4(),
+            user_id,
+            credential_id: b"cred-1".to_vec(),
+            public_key: b"key".to_vec(),
+            sign_count: 4,
+            name: "Phone".to_string(),
+            created_at: Utc::now(),
+            last_used_at: None,
+        }]);
+    (p, user_id)
+}
+
+fn use_case(p: &InMemoryPorts) -> FinishPasskeyLoginUseCase {
+    FinishPasskeyLoginUseCase::new(
+        p.users.clone(),
+        p.passkeys.clone(),
+        p.passkey_ceremonies.clone(),
+        Arc::new(FakeWebauthnService),
+        p.session_tokens(),
+    )
+}
+
+async fn start(p: &InMemoryPorts, kind: PasskeyCeremonyKind) -> Uuid {
+    let ceremony = PasskeyCeremony {
+        id: Uuid::new_v4(),
+        kind,
+        user_id: None,
+        challenge: FakeWebauthnService::CHALLENGE.to_vec(),
+        expires_at: Utc::now() + Duration::minutes(5),
+    };
+    p.passkey_ceremonies.save(&ceremony).await.unwrap();
+    ceremony.id
+}
+
+fn assertion(credential_id: &[u8], user_handle: Option<Uuid>) -> PasskeyAssertionResponse {
+    PasskeyAssertionResponse {
+        credential_id: credential_id.to_vec(),
+        client_data_json: Vec::new(),
+        authenticator_data: Vec::new(),
+        signature: b"challengekey".to_vec(),
+        user_handle: user_handle.map(|id| id.as_bytes().to_vec()),
+    }
+}
+
+#[tokio::test]
+async fn test_passkey_login_issues_full_token_and_records_use() {
+    let (p, user_id) = ports();
+    let ceremony_id = start(&p, PasskeyCeremonyKind::Authentication).await;
+
+    let output = use_case(&p)
+        .execute(
+            ceremony_id,
+            &assertion(b"cred-1", Some(user_id)),
+            &ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+
+    // Staff accounts skip the TOTP step: the passkey already verified the user.
+    assert_eq!(output.scope, AccessTokenScope::Full);
+    assert_eq!(output.user.id(), user_id);
+    let passkeys = p.passkeys.passkeys();
+    assert_eq!(passkeys[0].sign_count, 5);
+    assert!(passkeys[0].last_used_at.is_some());
+}
+
+#[tokio::test]
+async fn test_unknown_credential_or_foreign_user_handle_is_rejected() {
+    let (p, _) = ports();
+
+    let unknown = start(&p, PasskeyCeremonyKind::Authentication).await;
+    let result = use_case(&p)
+        .execute(unknown, &assertion(b"cred-2", None), &ClientInfo::default())
+        .await;
+    assert!(matches!(result, Err(PasskeyLoginError::InvalidCredentials)));
+
+    let foreign = start(&p, PasskeyCeremonyKind::Authentication).await;
+    let result = use_case(&p)
+        .execute(
+            foreign,
+            &assertion(b"cred-1", Some(Uuid::new_v4())),
+            &ClientInfo::default(),
+        )
+        .await;
+    assert!(matches!(result, Err(PasskeyLoginError::InvalidCredentials)));
+}
+
+#[tokio::test]
+async fn test_registration_ceremony_cannot_be_used_to_log_in() {
+    let (p, _) = ports();
+    let ceremony_id = start(&p, PasskeyCeremonyKind::Registration).await;
+
+    let result = use_case(&p)
+        .execute(
+            ceremony_id,
+            &assertion(b"cred-1", None),
+            &ClientInfo::default(),
+        )
+        .await;
+
+    assert!(matches!(result, Err(PasskeyLoginError::InvalidCeremony)));
+}
@@ -0,0 +1,108 @@
+use application::ports::passkey_ceremony_store::{
+    PasskeyCeremony, PasskeyCeremonyKind, PasskeyCeremonyStore,
+};
+use application::ports::webauthn_service::PasskeyRegistrationResponse;
+use application::use_cases::{FinishPasskeyRegistrationError, FinishPasskeyRegistrationUseCase};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use test_support::{FakeWebauthnService, InMemoryPorts};
+use uuid::Uuid;
+
+fn use_case(p: &InMemoryPorts) -> FinishPasskeyRegistrationUseCase {
+    FinishPasskeyRegistrationUseCase::new(
+        p.passkeys.clone(),
+        p.passkey_ceremonies.clone(),
+        Arc::new(FakeWebauthnService),
+    )
+}
+
+async fn start(p: &InMemoryPorts, user_id: Uuid, expires_in: Duration) -> Uuid {
+    let ceremony = PasskeyCeremony {
+        id: Uuid::new_v4(),
+        kind: PasskeyCeremonyKind::Registration,
+        user_id: Some(user_id),
+        challenge: FakeWebauthnService::CHALLENGE.to_vec(),
+        expires_at: Utc::now() + expires_in,
+    };
+    p.passkey_ceremonies.save(&ceremony).await.unwrap();
+    ceremony.id
+}
+
+fn response(credential_id: &[u8]) -> PasskeyRegistrationResponse {
+    PasskeyRegistrationResponse {
+        credential_id: credential_id.to_vec(),
+        client_data_json: Vec::new(),
+        attestation_object: Vec::new(),
+    }
+}
+
+#[tokio::test]
+async fn test_user_can_register_several_passkeys() {
+    let p = InMemoryPorts::new();
+    let user_id = Uuid::new_v4();
+    let use_case = use_case(&p);
+
+    let first = start(&p, user_id, Duration::minutes(5)).await;
+    use_case
+        .execute(user_id, first, " Laptop ", &response(b"cred-1"))
+        .await
+        .unwrap();
+    let second = start(&p, user_id, Duration::minutes(5)).await;
+    let passkey = use_case
+        .execute(user_id, second, "", &response(b"cred-2"))
+        .await
+        .unwrap();
+
+    assert_eq!(passkey.name, "Passkey");
+    let passkeys = p.passkeys.passkeys();
+    assert_eq!(passkeys.len(), 2);
+    assert_eq!(passkeys[0].name, "Laptop");
+    assert!(passkeys.iter().all(|key| key.user_id == user_id));
+}
+
+#[tokio::test]
+async fn test_ceremony_must_be_fresh_and_belong_to_user() {
+    let p = InMemoryPorts::new();
+    let user_id = Uuid::new_v4();
+    let use_case = use_case(&p);
+
+    let expired = start(&p, user_id, Duration::seconds(-1)).await;
+    let foreign = start(&p, Uuid::new_v4(), Duration::minutes(5)).await;
+    let used = start(&p, user_id, Duration::minutes(5)).await;
+    use_case
+        .execute(user_id, used, "Phone", &response(b"cred-1"))
+        .await
+        .unwrap();
+
+    for ceremony_id in [expired, foreign, used] {
+        let result = use_case
+            .execute(user_id, ceremony_id, "Phone", &response(b"cred-2"))
+            .await;
+        assert!(matches!(
+            result,
+            Err(FinishPasskeyRegistrationError::InvalidCeremony)
+        ));
+    }
+}
+
+#[tokio::test]
+async fn test_credential_cannot_be_registered_twice() {
+    let p = InMemoryPorts::new();
+    let user_id = Uuid::new_v4();
+    let use_case = use_case(&p);
+
+    let first = start(&p, user_id, Duration::minutes(5)).await;
+    use_case
+        .execute(user_id, first, "Phone", &response(b"cred-1"))
+        .await
+        .unwrap();
+    let second = start(&p, user_id, Duration::minutes(5)).await;
+    let result = use_case
+        .execute(user_id, second, "Phone", &response(b"cred-1"))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(FinishPasskeyRegistrationError::AlreadyRegistered)
+    ));
+}
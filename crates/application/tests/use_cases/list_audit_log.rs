@@ -0,0 +1,144 @@
+use application::ports::audit_log::{AuditEntry, AuditLog, AuditLogFilter};
+use application::use_cases::ListAuditLogUseCase;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use test_support::InMemoryAuditLog;
+use uuid::Uuid;
+
+async fn record(audit_log: &InMemoryAuditLog, actor_id: Uuid, action: &str, age: Duration) {
+    let mut entry = AuditEntry::new(Some(actor_id), action, "user", None, Vec::new());
+    entry.occurred_at = Utc::now() - age;
+    audit_log.record(&entry).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_lists_matching_entries_newest_first_with_total() {
+    let audit_log = Arc::new(InMemoryAuditLog::new());
+    let admin_id = Uuid::new_v4();
+    record(
+        &audit_log,
+        admin_id,
+        "user.role_changed",
+        Duration::hours(3),
+    )
+    .await;
+    record(
+        &audit_log,
+        admin_id,
+        "user.role_changed",
+        Duration::hours(1),
+    )
+    .await;
+    record(
+        &audit_log,
+        admin_id,
+        "user.role_changed",
+        Duration::hours(2),
+    )
+    .await;
+    record(&audit_log, admin_id, "user.deleted", Duration::zero()).await;
+    record(
+        &audit_log,
+        Uuid::new_v4(),
+        "user.role_changed",
+        Duration::zero(),
+    )
+    .await;
+    let use_case = ListAuditLogUseCase::new(audit_log);
+    let filter = AuditLogFilter {
+        actor_id: Some(admin_id),
+        action: Some("user.role_changed".to_string()),
+        ..AuditLogFilter::default()
+    };
+
+    let (first_page, total) = use_case.execute(&filter, 0, 2).await.unwrap();
+    let (second_page, _) = use_case.execute(&filter, 1, 2).await.unwrap();
+
+    assert_eq!(total, 3);
+    assert_eq!(first_page.len(), 2);
+    assert!(first_page[0].occurred_at > first_page[1].occurred_at);
+    assert_eq!(second_page.len(), 1);
+    assert!(second_page[0].occurred_at < first_page[1].occurred_at);
+    assert!(
+        first_page
+            .iter()
+            .chain(&second_page)
+            .all(|e| e.actor_id == Some(admin_id) && e.action == "user.role_changed")
+    );
+}
+
+#[tokio::test]
+async fn test_time_range_includes_from_and_excludes_to() {
+    let audit_log = Arc::new(InMemoryAuditLog::new());
+    let admin_id = Uuid::new_v4();
+    record(&audit_log, admin_id, "user.deleted", Duration::days(2)).await;
+    record(&audit_log, admin_id, "user.deleted", Duration::hours(1)).await;
+    let from = audit_log.entries()[1].occurred_at;
+    let use_case = ListAuditLogUseCase::new(audit_log);
+
+    let (entries, total) = use_case
+        .execute(
+            &AuditLogFilter {
+                from: Some(from),
+                to: Some(Utc::now()),
+                ..AuditLogFilter::default()
+            },
+            0,
+            20,
+        )
+        .await
+        .unwrap();
+    assert_eq!(total, 1);
+    assert_eq!(entries[0].occurred_at, from);
+
+    let (entries, total) = use_case
+        .execute(
+            &AuditLogFilter {
+                to: Some(from),
+                ..AuditLogFilter::default()
+            },
+            0,
+            20,
+        )
+        .await
+        .unwrap();
+    assert_eq!(total, 1);
+    assert!(entries[0].occurred_at < from);
+}
+
+#[tokio::test]
+async fn test_no_matches_is_an_empty_page() {
+    let audit_log = Arc::new(InMemoryAuditLog::new());
+    record(&audit_log, Uuid::new_v4(), "user.deleted", Duration::zero()).await;
+    let use_case = ListAuditLogUseCase::new(audit_log);
+
+    let (entries, total) = use_case
+        .execute(
+            &AuditLogFilter {
+                target_id: Some(Uuid::new_v4()),
+                ..AuditLogFilter::default()
+            },
+            0,
+            20,
+        )
+        .await
+        .unwrap();
+
+    assert!(entries.is_empty());
+    assert_eq!(total, 0);
+}
+
+#[tokio::test]
+async fn test_page_past_the_end_is_empty_but_keeps_total() {
+    let audit_log = Arc::new(InMemoryAuditLog::new());
+    record(&audit_log, Uuid::new_v4(), "user.deleted", Duration::zero()).await;
+    let use_case = ListAuditLogUseCase::new(audit_log);
+
+    let (entries, total) = use_case
+        .execute(&AuditLogFilter::default(), 5, 20)
+        .await
+        .unwrap();
+
+    assert!(entries.is_empty());
+    assert_eq!(total, 1);
+}
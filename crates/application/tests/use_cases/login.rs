@@ -0,0 +1,279 @@
+use application::ports::access_token_service::{AccessTokenScope, AccessTokenService};
+use application::ports::login_attempt_store::{AttemptSubject, LoginAttempts};
+use application::ports::password_hasher::{PasswordHasher, PasswordHasherError};
+use application::ports::two_factor_repository::TwoFactorRepository;
+use application::ports::user_repository::UserRepository;
+use application::use_cases::{ClientInfo, LoginError, LoginThrottlePolicy, LoginUseCase};
+use async_trait::async_trait;
+use chrono::Duration;
+use domain_users::{PasswordHash, User, UserRole};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use test_support::fixtures::confirmed_totp;
+use test_support::{
+    EmailKind, FakeAccessTokenService, FakeOneTimeTokenService, FastPasswordHasher, InMemoryPorts,
+};
+use uuid::Uuid;
+
+/// [`FastPasswordHasher`] that counts how often it was asked to hash.
+#[derive(Default)]
+struct CountingPasswordHasher {
+    hashes: AtomicUsize,
+}
+
+#[async_trait]
+impl PasswordHasher for CountingPasswordHasher {
+    async fn hash(&self, password: &str) -> Result<String, PasswordHasherError> {
+        self.hashes.fetch_add(1, Ordering::SeqCst);
+        FastPasswordHasher.hash(password).await
+    }
+
+    async fn verify(&self, password: &str, hash: &str) -> Result<bool, PasswordHasherError> {
+        FastPasswordHasher.verify(password, hash).await
+    }
+}
+
+/// John, signing in with `password123`.
+fn ports() -> (InMemoryPorts, Uuid) {
+    let user = User::register(
+        Uuid::new_v4(),
+        "John".parse().unwrap(),
+        "Doe".parse().unwrap(),
+        "john@example.com".parse().unwrap(),
+        None,
+        PasswordHash::new_unchecked(FastPasswordHasher::hash_of("password123")),
+        None,
+    );
+    let user_id = user.id();
+    (InMemoryPorts::new().with_users([user]), user_id)
+}
+
+fn use_case_with(
+    p: &InMemoryPorts,
+    password_hasher: Arc<CountingPasswordHasher>,
+    policy: LoginThrottlePolicy,
+) -> LoginUseCase {
+    LoginUseCase::new(
+        p.users.clone(),
+        password_hasher,
+        p.session_tokens(),
+        p.two_factor.clone(),
+        p.login_throttle(policy),
+    )
+}
+
+/// No backoff, so tests can fail repeatedly without waiting.
+fn use_case(p: &InMemoryPorts) -> LoginUseCase {
+    use_case_with(
+        p,
+        Arc::default(),
+        LoginThrottlePolicy {
+            base_delay: Duration::zero(),
+            max_delay: Duration::zero(),
+            ..LoginThrottlePolicy::default()
+        },
+    )
+}
+
+fn client() -> ClientInfo {
+    ClientInfo {
+        ip: Some("203.0.113.7".parse().unwrap()),
+        user_agent: Some("Mozilla/5.0".to_string()),
+    }
+}
+
+fn account_attempts(p: &InMemoryPorts) -> Option<LoginAttempts> {
+    p.login_attempts
+        .attempts()
+        .into_iter()
+        .find(|a| a.subject == AttemptSubject::account("john@example.com"))
+}
+
+#[tokio::test]
+async fn test_login_success_opens_session_with_client_details() {
+    let (p, user_id) = ports();
+    let use_case = use_case(&p);
+
+    let output = use_case
+        .execute("john@example.com", "password123", &client())
+        .await
+        .unwrap();
+
+    assert_eq!(output.user.id(), user_id);
+    assert_eq!(output.scope, AccessTokenScope::Full);
+
+    let sessions = p.sessions.sessions();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].user_id, user_id);
+    assert_eq!(sessions[0].ip_address, client().ip);
+    assert_eq!(sessions[0].user_agent.as_deref(), Some("Mozilla/5.0"));
+    assert_eq!(
+        sessions[0].expires_at - sessions[0].created_at,
+        Duration::seconds(FakeAccessTokenService::EXPIRES_IN_SECONDS as i64)
+    );
+
+    let claims = p.access_tokens.verify(&output.access_token.token).unwrap();
+    assert_eq!(claims.user_id, user_id);
+    assert_eq!(claims.session_id, Some(sessions[0].id));
+}
+
+#[tokio::test]
+async fn test_login_with_totp_requires_second_step() {
+    let (p, user_id) = ports();
+    let use_case = use_case(&p);
+    p.two_factor.save(&confirmed_totp(user_id)).await.unwrap();
+
+    let _ = use_case
+        .execute("john@example.com", "wrong", &client())
+        .await;
+    let output = use_case
+        .execute("john@example.com", "password123", &client())
+        .await
+        .unwrap();
+
+    assert_eq!(output.scope, AccessTokenScope::TotpChallenge);
+    // Partial tokens open no session.
+    assert!(p.sessions.sessions().is_empty());
+    let claims = p.access_tokens.verify(&output.access_token.token).unwrap();
+    assert_eq!(claims.session_id, None);
+    // The password alone does not reset the failure count.
+    assert!(account_attempts(&p).is_some());
+}
+
+#[tokio::test]
+async fn test_staff_login_without_totp_requires_enrollment() {
+    let (p, user_id) = ports();
+    let use_case = use_case(&p);
+    let mut user = p.users.find_by_id(user_id).await.unwrap().unwrap();
+    user.change_role(UserRole::Admin);
+    p.users.update(&user).await.unwrap();
+
+    let output = use_case
+        .execute("john@example.com", "password123", &client())
+        .await
+        .unwrap();
+
+    assert_eq!(output.scope, AccessTokenScope::TotpEnrollment);
+}
+
+#[tokio::test]
+async fn test_login_wrong_password() {
+    let (p, _) = ports();
+    let use_case = use_case(&p);
+
+    let result = use_case
+        .execute("john@example.com", "wrong", &client())
+        .await;
+    assert!(matches!(result, Err(LoginError::InvalidCredentials)));
+}
+
+#[tokio::test]
+async fn test_login_unknown_email() {
+    let (p, _) = ports();
+    let password_hasher = Arc::new(CountingPasswordHasher::default());
+    let use_case = use_case_with(&p, password_hasher.clone(), LoginThrottlePolicy::default());
+
+    let result = use_case
+        .execute("nobody@example.com", "password123", &client())
+        .await;
+    assert!(matches!(result, Err(LoginError::InvalidCredentials)));
+    assert_eq!(password_hasher.hashes.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_failed_login_applies_backoff() {
+    let (p, _) = ports();
+    let use_case = use_case_with(&p, Arc::default(), LoginThrottlePolicy::default());
+
+    let _ = use_case
+        .execute("john@example.com", "wrong", &client())
+        .await;
+    let result = use_case
+        .execute("john@example.com", "password123", &client())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(LoginError::TooManyAttempts {
+            retry_after_seconds: 1
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_account_is_locked_after_max_failures_and_unlock_mail_sent() {
+    let (p, _) = ports();
+    let use_case = use_case(&p);
+
+    for _ in 0..5 {
+        let result = use_case
+            .execute("John@Example.com", "wrong", &client())
+            .await;
+        assert!(matches!(result, Err(LoginError::InvalidCredentials)));
+    }
+    let result = use_case
+        .execute("john@example.com", "password123", &client())
+        .await;
+
+    assert!(matches!(result, Err(LoginError::AccountLocked { .. })));
+    let emails = p.emails.sent();
+    assert_eq!(emails.len(), 1);
+    assert_eq!(emails[0].kind, EmailKind::AccountUnlock);
+    assert_eq!(emails[0].to, "john@example.com");
+    let account = account_attempts(&p).unwrap();
+    assert_eq!(
+        account.unlock_token_hash,
+        Some(FakeOneTimeTokenService::hash_of(&emails[0].token))
+    );
+}
+
+#[tokio::test]
+async fn test_ip_is_throttled_across_accounts() {
+    let (p, _) = ports();
+    let use_case = use_case_with(
+        &p,
+        Arc::default(),
+        LoginThrottlePolicy {
+            max_ip_failures: 3,
+            base_delay: Duration::zero(),
+            max_delay: Duration::zero(),
+            ..LoginThrottlePolicy::default()
+        },
+    );
+
+    for i in 0..3 {
+        let email = format!("user{}@example.com", i);
+        let _ = use_case.execute(&email, "wrong", &client()).await;
+    }
+    let result = use_case
+        .execute("john@example.com", "password123", &client())
+        .await;
+
+    assert!(matches!(result, Err(LoginError::TooManyAttempts { .. })));
+    assert!(p.emails.sent().is_empty());
+}
+
+#[tokio::test]
+async fn test_successful_login_resets_account_failures() {
+    let (p, _) = ports();
+    let use_case = use_case(&p);
+
+    let _ = use_case
+        .execute("john@example.com", "wrong", &client())
+        .await;
+    use_case
+        .execute("john@example.com", "password123", &client())
+        .await
+        .unwrap();
+
+    assert!(account_attempts(&p).is_none());
+}
+
+#[test]
+fn test_backoff_doubles_up_to_max_delay() {
+    let policy = LoginThrottlePolicy::default();
+
+    assert_eq!(policy.backoff(1), Duration::seconds(1));
+    assert_eq!(policy.backoff(3), Duration::seconds(4));
+    assert_eq!(policy.backoff(100), Duration::minutes(1));
+}
@@ -0,0 +1,81 @@
+use application::ports::access_token_service::{AccessTokenScope, AccessTokenService};
+use application::ports::magic_link_token_repository::{MagicLinkToken, MagicLinkTokenRepository};
+use application::ports::two_factor_repository::TwoFactorRepository;
+use application::use_cases::{ClientInfo, MagicLinkLoginError, MagicLinkLoginUseCase};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use test_support::fixtures::{confirmed_totp, user_with_email};
+use test_support::{FakeOneTimeTokenService, InMemoryPorts};
+use uuid::Uuid;
+
+fn ports() -> (InMemoryPorts, Uuid) {
+    let mut user = user_with_email("john@example.com");
+    user.verify_email();
+    let user_id = user.id();
+    (InMemoryPorts::new().with_users([user]), user_id)
+}
+
+fn use_case(p: &InMemoryPorts) -> MagicLinkLoginUseCase {
+    MagicLinkLoginUseCase::new(
+        p.users.clone(),
+        p.magic_link_tokens.clone(),
+        Arc::new(FakeOneTimeTokenService),
+        p.two_factor.clone(),
+        p.session_tokens(),
+    )
+}
+
+async fn issue_link(p: &InMemoryPorts, user_id: Uuid, token: &str, expires_in: Duration) {
+    let now = Utc::now();
+    p.magic_link_tokens
+        .create(&MagicLinkToken {
+            token_hash: FakeOneTimeTokenService::hash_of(token),
+            user_id,
+            created_at: now,
+            expires_at: now + expires_in,
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_link_logs_in_once() {
+    let (p, user_id) = ports();
+    issue_link(&p, user_id, "abc", Duration::minutes(15)).await;
+
+    let output = use_case(&p)
+        .execute("abc", &ClientInfo::default())
+        .await
+        .unwrap();
+    let again = use_case(&p).execute("abc", &ClientInfo::default()).await;
+
+    assert_eq!(output.scope, AccessTokenScope::Full);
+    let claims = p.access_tokens.verify(&output.access_token.token).unwrap();
+    assert_eq!(claims.user_id, user_id);
+    assert!(matches!(again, Err(MagicLinkLoginError::InvalidToken)));
+}
+
+#[tokio::test]
+async fn test_expired_link_is_rejected_and_removed() {
+    let (p, user_id) = ports();
+    issue_link(&p, user_id, "abc", Duration::seconds(-1)).await;
+
+    let result = use_case(&p).execute("abc", &ClientInfo::default()).await;
+
+    assert!(matches!(result, Err(MagicLinkLoginError::ExpiredToken)));
+    assert!(p.magic_link_tokens.tokens().is_empty());
+}
+
+#[tokio::test]
+async fn test_two_factor_accounts_still_need_their_code() {
+    let (p, user_id) = ports();
+    p.two_factor.save(&confirmed_totp(user_id)).await.unwrap();
+    issue_link(&p, user_id, "abc", Duration::minutes(15)).await;
+
+    let output = use_case(&p)
+        .execute("abc", &ClientInfo::default())
+        .await
+        .unwrap();
+
+    assert_eq!(output.scope, AccessTokenScope::TotpChallenge);
+}
@@ -0,0 +1,36 @@
+//! The use cases against the in-memory adapters from `test-support`, one module per
+//! use case. They live here rather than next to the code because `test-support`
+//! depends on this crate.
+
+mod authenticate_api_key;
+mod change_user_role;
+mod check_session;
+mod clear_login_lockout;
+mod complete_oidc_login;
+mod complete_totp_login;
+mod confirm_totp;
+mod create_api_key;
+mod create_user;
+mod create_webhook_subscription;
+mod deliver_webhooks;
+mod erase_deleted_users;
+mod finish_passkey_login;
+mod finish_passkey_registration;
+mod list_audit_log;
+mod login;
+mod magic_link_login;
+mod process_data_export;
+mod purge_audit_log;
+mod redeliver_webhook;
+mod request_data_export;
+mod request_magic_link;
+mod request_password_reset;
+mod reset_password;
+mod restore_account;
+mod restore_user;
+mod revoke_session;
+mod signup;
+mod unlock_account;
+mod update_user_profile;
+mod verify_email;
+mod webhook_fan_out;
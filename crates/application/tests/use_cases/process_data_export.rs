@@ -0,0 +1,156 @@
+use application::ports::audit_log::{AuditEntry, AuditLog};
+use application::ports::data_export_repository::{DataExportRepository, DataExportStatus};
+use application::ports::user_repository::UserRepository;
+use application::use_cases::{
+    DownloadDataExportError, DownloadDataExportUseCase, ProcessDataExportError,
+    ProcessDataExportUseCase, RequestDataExportUseCase, UserDataCollector,
+};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use test_support::fixtures::user_with_email;
+use test_support::{EmailKind, FakeOneTimeTokenService, InMemoryPorts};
+use uuid::Uuid;
+
+/// Requests, builds and downloads exports over the same ports, like the API and
+/// the worker do.
+struct DataExports {
+    request: RequestDataExportUseCase,
+    process: ProcessDataExportUseCase,
+    download: DownloadDataExportUseCase,
+}
+
+fn ports() -> (InMemoryPorts, Uuid) {
+    let user = user_with_email("jane@example.com");
+    let user_id = user.id();
+    (InMemoryPorts::new().with_users([user]), user_id)
+}
+
+fn use_cases(p: &InMemoryPorts) -> DataExports {
+    let collector = Arc::new(UserDataCollector::new(
+        p.users.clone(),
+        p.sessions.clone(),
+        p.passkeys.clone(),
+        p.linked_identities.clone(),
+        p.api_keys.clone(),
+        p.audit_log.clone(),
+    ));
+    DataExports {
+        request: RequestDataExportUseCase::new(
+            p.users.clone(),
+            p.data_exports.clone(),
+            p.audit_log.clone(),
+        ),
+        process: ProcessDataExportUseCase::new(
+            collector,
+            p.data_exports.clone(),
+            p.data_export_archive.clone(),
+            Arc::new(FakeOneTimeTokenService),
+            p.emails.clone(),
+            Duration::hours(72),
+        ),
+        download: DownloadDataExportUseCase::new(
+            p.data_exports.clone(),
+            p.data_export_archive.clone(),
+            Arc::new(FakeOneTimeTokenService),
+        ),
+    }
+}
+
+fn mailed_token(p: &InMemoryPorts) -> String {
+    let sent = p.emails.sent();
+    assert_eq!(sent[0].kind, EmailKind::DataExportReady);
+    sent[0].token.clone()
+}
+
+#[tokio::test]
+async fn test_export_is_mailed_to_the_data_subject_and_downloadable() {
+    let (p, user_id) = ports();
+    let f = use_cases(&p);
+    let admin_id = Uuid::new_v4();
+    p.audit_log
+        .record(&AuditEntry::new(
+            Some(user_id),
+            "api_key.created",
+            "api_key",
+            Some(Uuid::new_v4()),
+            Vec::new(),
+        ))
+        .await
+        .unwrap();
+
+    let export = f.request.execute(user_id, admin_id).await.unwrap();
+    assert_eq!(export.requested_by, admin_id);
+    assert_eq!(f.process.execute().await.unwrap(), Some(export.id));
+    assert_eq!(f.process.execute().await.unwrap(), None);
+
+    // Both the user's own action and the admin's request about them are included.
+    let snapshot = p.data_export_archive.snapshot(export.id).unwrap();
+    assert_eq!(snapshot.audit_entries.len(), 2);
+
+    assert_eq!(p.emails.sent()[0].to, "jane@example.com");
+    let download = f.download.execute(&mailed_token(&p)).await.unwrap();
+    assert_eq!(download.export.id, export.id);
+    assert!(String::from_utf8_lossy(&download.archive).contains("jane@example.com"));
+    assert!(matches!(
+        f.download.execute("token_guessed").await,
+        Err(DownloadDataExportError::InvalidToken)
+    ));
+}
+
+#[tokio::test]
+async fn test_pending_export_is_returned_instead_of_queueing_another() {
+    let (p, user_id) = ports();
+    let f = use_cases(&p);
+
+    let first = f.request.execute(user_id, user_id).await.unwrap();
+    let second = f.request.execute(user_id, user_id).await.unwrap();
+
+    assert_eq!(first.id, second.id);
+    assert_eq!(p.data_exports.exports().len(), 1);
+}
+
+#[tokio::test]
+async fn test_expired_export_is_removed_and_no_longer_downloadable() {
+    let (p, user_id) = ports();
+    let f = use_cases(&p);
+    let export = f.request.execute(user_id, user_id).await.unwrap();
+    f.process.execute().await.unwrap();
+    let token = mailed_token(&p);
+    p.data_exports
+        .mark_ready(
+            export.id,
+            &FakeOneTimeTokenService::hash_of(&token),
+            Utc::now() - Duration::hours(1),
+            Utc::now() - Duration::minutes(1),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(f.process.remove_expired().await.unwrap(), 1);
+
+    assert!(p.data_export_archive.export_ids().is_empty());
+    assert!(matches!(
+        f.download.execute(&token).await,
+        Err(DownloadDataExportError::ExpiredToken)
+    ));
+}
+
+#[tokio::test]
+async fn test_export_of_deleted_user_fails() {
+    let (p, user_id) = ports();
+    let f = use_cases(&p);
+    let export = f.request.execute(user_id, user_id).await.unwrap();
+    let mut user = p.users.find_by_id(user_id).await.unwrap().unwrap();
+    user.delete();
+    p.users.update(&user).await.unwrap();
+
+    assert!(matches!(
+        f.process.execute().await,
+        Err(ProcessDataExportError::UserNotFound(_))
+    ));
+
+    let exports = p.data_exports.exports();
+    assert_eq!(exports[0].id, export.id);
+    assert_eq!(exports[0].status, DataExportStatus::Failed);
+    assert!(p.emails.sent().is_empty());
+}
@@ -0,0 +1,49 @@
+use application::ports::audit_log::{AuditEntry, AuditLog};
+use application::use_cases::PurgeAuditLogUseCase;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use test_support::InMemoryAuditLog;
+
+async fn record(audit_log: &InMemoryAuditLog, action: &str, age: Duration) {
+    let mut entry = AuditEntry::new(None, action, "user", None, Vec::new());
+    entry.occurred_at = Utc::now() - age;
+    audit_log.record(&entry).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_purge_removes_only_entries_past_retention() {
+    let audit_log = Arc::new(InMemoryAuditLog::new());
+    record(&audit_log, "user.expired", Duration::days(400)).await;
+    record(&audit_log, "user.expired", Duration::days(366)).await;
+    record(&audit_log, "user.kept", Duration::days(364)).await;
+    record(&audit_log, "user.kept", Duration::zero()).await;
+    let use_case = PurgeAuditLogUseCase::new(audit_log.clone(), Duration::days(365));
+
+    let purged = use_case.execute().await.unwrap();
+
+    assert_eq!(purged, 2);
+    let entries = audit_log.entries();
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().all(|e| e.action == "user.kept"));
+}
+
+#[tokio::test]
+async fn test_purge_with_nothing_expired_removes_nothing() {
+    let audit_log = Arc::new(InMemoryAuditLog::new());
+    record(&audit_log, "user.kept", Duration::days(1)).await;
+    let use_case = PurgeAuditLogUseCase::new(audit_log.clone(), Duration::days(365));
+
+    assert_eq!(use_case.execute().await.unwrap(), 0);
+    assert_eq!(audit_log.entries().len(), 1);
+}
+
+#[tokio::test]
+async fn test_purge_surfaces_storage_failures() {
+    let audit_log = Arc::new(InMemoryAuditLog::new());
+    record(&audit_log, "user.expired", Duration::days(400)).await;
+    audit_log.fail_writes();
+    let use_case = PurgeAuditLogUseCase::new(audit_log.clone(), Duration::days(365));
+
+    assert!(use_case.execute().await.is_err());
+    assert_eq!(audit_log.entries().len(), 1);
+}
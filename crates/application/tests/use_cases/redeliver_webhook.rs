@@ -0,0 +1,83 @@
+use application::ports::webhook_repository::{
+    WebhookDelivery, WebhookDeliveryStatus, WebhookRepository,
+};
+use application::use_cases::{RedeliverWebhookError, RedeliverWebhookUseCase};
+use chrono::{Duration, Utc};
+use test_support::InMemoryPorts;
+use test_support::fixtures::outbox_event;
+use uuid::Uuid;
+
+/// A delivery that gave up after exhausting its attempts.
+async fn ports() -> (InMemoryPorts, Uuid) {
+    let mut delivery = WebhookDelivery::new(
+        Uuid::new_v4(),
+        outbox_event("user.deleted"),
+        Utc::now() - Duration::hours(1),
+    );
+    delivery.status = WebhookDeliveryStatus::Failed;
+    delivery.attempts = 5;
+    delivery.next_attempt_at = None;
+    delivery.response_status = Some(503);
+    let p = InMemoryPorts::new();
+    p.webhooks
+        .create_deliveries(std::slice::from_ref(&delivery))
+        .await
+        .unwrap();
+    (p, delivery.id)
+}
+
+fn use_case(p: &InMemoryPorts) -> RedeliverWebhookUseCase {
+    RedeliverWebhookUseCase::new(p.webhooks.clone(), p.audit_log.clone())
+}
+
+#[tokio::test]
+async fn test_redelivery_requeues_with_fresh_attempts_and_is_audited() {
+    let (p, delivery_id) = ports().await;
+    let admin_id = Uuid::new_v4();
+
+    let delivery = use_case(&p).execute(admin_id, delivery_id).await.unwrap();
+
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(delivery.attempts, 0);
+    assert!(delivery.next_attempt_at.is_some_and(|at| at <= Utc::now()));
+    let stored = p
+        .webhooks
+        .find_delivery(delivery_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(stored.attempts, 0);
+
+    let claimed = p
+        .webhooks
+        .claim_due_deliveries(Utc::now(), Duration::minutes(1), 10)
+        .await
+        .unwrap();
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].id, delivery_id);
+
+    let entries = p.audit_log.entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].actor_id, Some(admin_id));
+    assert_eq!(entries[0].action, "webhook.redelivered");
+    assert_eq!(entries[0].target_type, "webhook_delivery");
+    assert_eq!(entries[0].target_id, Some(delivery_id));
+}
+
+#[tokio::test]
+async fn test_unknown_delivery_is_not_found() {
+    let (p, delivery_id) = ports().await;
+
+    let result = use_case(&p).execute(Uuid::new_v4(), Uuid::new_v4()).await;
+
+    assert!(matches!(result, Err(RedeliverWebhookError::NotFound)));
+    assert!(p.audit_log.entries().is_empty());
+    let stored = p
+        .webhooks
+        .find_delivery(delivery_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.status, WebhookDeliveryStatus::Failed);
+}
@@ -0,0 +1,74 @@
+use application::ports::data_export_repository::DataExportStatus;
+use application::ports::user_repository::UserRepository;
+use application::use_cases::{RequestDataExportError, RequestDataExportUseCase};
+use test_support::InMemoryPorts;
+use test_support::fixtures::user_with_email;
+use uuid::Uuid;
+
+fn ports() -> (InMemoryPorts, Uuid) {
+    let user = user_with_email("jane@example.com");
+    let user_id = user.id();
+    (InMemoryPorts::new().with_users([user]), user_id)
+}
+
+fn use_case(p: &InMemoryPorts) -> RequestDataExportUseCase {
+    RequestDataExportUseCase::new(p.users.clone(), p.data_exports.clone(), p.audit_log.clone())
+}
+
+#[tokio::test]
+async fn test_request_queues_a_pending_export_and_audits_it() {
+    let (p, user_id) = ports();
+    let admin_id = Uuid::new_v4();
+
+    let export = use_case(&p).execute(user_id, admin_id).await.unwrap();
+
+    assert_eq!(export.user_id, user_id);
+    assert_eq!(export.requested_by, admin_id);
+    assert_eq!(export.status, DataExportStatus::Pending);
+    assert!(export.download_token_hash.is_none());
+    assert_eq!(p.data_exports.exports()[0].id, export.id);
+
+    let entries = p.audit_log.entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].actor_id, Some(admin_id));
+    assert_eq!(entries[0].action, "user.data_export_requested");
+    assert_eq!(entries[0].target_type, "user");
+    assert_eq!(entries[0].target_id, Some(user_id));
+}
+
+#[tokio::test]
+async fn test_repeated_request_returns_the_open_export_without_auditing_again() {
+    let (p, user_id) = ports();
+
+    let first = use_case(&p).execute(user_id, user_id).await.unwrap();
+    let second = use_case(&p).execute(user_id, user_id).await.unwrap();
+
+    assert_eq!(first.id, second.id);
+    assert_eq!(p.data_exports.exports().len(), 1);
+    assert_eq!(p.audit_log.entries().len(), 1);
+}
+
+#[tokio::test]
+async fn test_unknown_user_is_not_found() {
+    let (p, _) = ports();
+    let unknown = Uuid::new_v4();
+
+    let result = use_case(&p).execute(unknown, unknown).await;
+
+    assert!(matches!(result, Err(RequestDataExportError::NotFound(id)) if id == unknown));
+    assert!(p.data_exports.exports().is_empty());
+    assert!(p.audit_log.entries().is_empty());
+}
+
+#[tokio::test]
+async fn test_deleted_user_is_not_found() {
+    let (p, user_id) = ports();
+    let mut user = p.users.find_by_id(user_id).await.unwrap().unwrap();
+    user.delete();
+    p.users.update(&user).await.unwrap();
+
+    let result = use_case(&p).execute(user_id, user_id).await;
+
+    assert!(matches!(result, Err(RequestDataExportError::NotFound(_))));
+    assert!(p.data_exports.exports().is_empty());
+}
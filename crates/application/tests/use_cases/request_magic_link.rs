@@ -0,0 +1,63 @@
+use application::use_cases::{RequestMagicLinkError, RequestMagicLinkUseCase};
+use chrono::Duration;
+use std::sync::Arc;
+use test_support::fixtures::user_with_email;
+use test_support::{EmailKind, FakeOneTimeTokenService, InMemoryPorts};
+
+fn ports() -> InMemoryPorts {
+    let mut verified = user_with_email("verified@example.com");
+    verified.verify_email();
+    InMemoryPorts::new().with_users([verified, user_with_email("unverified@example.com")])
+}
+
+fn use_case(p: &InMemoryPorts) -> RequestMagicLinkUseCase {
+    RequestMagicLinkUseCase::new(
+        p.users.clone(),
+        p.magic_link_tokens.clone(),
+        Arc::new(FakeOneTimeTokenService),
+        p.emails.clone(),
+        Duration::minutes(15),
+    )
+}
+
+#[tokio::test]
+async fn test_sends_link_and_stores_only_the_hash() {
+    let p = ports();
+    let use_case = use_case(&p);
+
+    use_case.execute("verified@example.com").await.unwrap();
+    use_case.execute("verified@example.com").await.unwrap();
+
+    let sent = p.emails.sent();
+    assert_eq!(sent.len(), 2);
+    assert_eq!(sent[1].kind, EmailKind::MagicLink);
+    assert_eq!(sent[1].to, "verified@example.com");
+    // The first link was replaced by the second one.
+    let tokens = p.magic_link_tokens.tokens();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(
+        tokens[0].token_hash,
+        FakeOneTimeTokenService::hash_of(&sent[1].token)
+    );
+    assert_eq!(
+        tokens[0].expires_at - tokens[0].created_at,
+        Duration::minutes(15)
+    );
+}
+
+#[tokio::test]
+async fn test_unverified_or_unknown_email_gets_no_link() {
+    let p = ports();
+    let use_case = use_case(&p);
+
+    let unverified = use_case.execute("unverified@example.com").await;
+    let unknown = use_case.execute("nobody@example.com").await;
+
+    assert!(matches!(
+        unverified,
+        Err(RequestMagicLinkError::EmailNotVerified)
+    ));
+    assert!(matches!(unknown, Err(RequestMagicLinkError::UserNotFound)));
+    assert!(p.emails.sent().is_empty());
+    assert!(p.magic_link_tokens.tokens().is_empty());
+}
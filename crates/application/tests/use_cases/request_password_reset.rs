@@ -0,0 +1,68 @@
+use application::use_cases::{RequestPasswordResetError, RequestPasswordResetUseCase};
+use domain_users::User;
+use test_support::{EmailKind, InMemoryPorts};
+use uuid::Uuid;
+
+fn user(id: Uuid) -> User {
+    User::register(
+        id,
+        "John".parse().unwrap(),
+        "Doe".parse().unwrap(),
+        "john@example.com".parse().unwrap(),
+        None,
+        "hash".parse().unwrap(),
+        None,
+    )
+}
+
+fn use_case(p: &InMemoryPorts) -> RequestPasswordResetUseCase {
+    RequestPasswordResetUseCase::new(
+        p.users.clone(),
+        p.password_reset_tokens.clone(),
+        p.emails.clone(),
+    )
+}
+
+#[tokio::test]
+async fn test_request_password_reset_success() {
+    let user_id = Uuid::new_v4();
+    let p = InMemoryPorts::new().with_users([user(user_id)]);
+
+    use_case(&p).execute("john@example.com").await.unwrap();
+
+    let tokens = p.password_reset_tokens.tokens();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].user_id, user_id);
+
+    let sent_emails = p.emails.sent();
+    assert_eq!(sent_emails.len(), 1);
+    assert_eq!(sent_emails[0].kind, EmailKind::PasswordReset);
+    assert_eq!(sent_emails[0].to, "john@example.com");
+    assert_eq!(sent_emails[0].token, tokens[0].token);
+    assert_eq!(sent_emails[0].first_name, "John");
+}
+
+#[tokio::test]
+async fn test_request_password_reset_replaces_earlier_tokens() {
+    let p = InMemoryPorts::new().with_users([user(Uuid::new_v4())]);
+    let use_case = use_case(&p);
+
+    use_case.execute("john@example.com").await.unwrap();
+    let first = p.password_reset_tokens.tokens();
+    use_case.execute("john@example.com").await.unwrap();
+
+    let tokens = p.password_reset_tokens.tokens();
+    assert_eq!(tokens.len(), 1);
+    assert_ne!(tokens[0].token, first[0].token);
+}
+
+#[tokio::test]
+async fn test_request_password_reset_user_not_found() {
+    let result = use_case(&InMemoryPorts::new())
+        .execute("nonexistent@example.com")
+        .await;
+    assert!(matches!(
+        result,
+        Err(RequestPasswordResetError::UserNotFound)
+    ));
+}
@@ -0,0 +1,125 @@
+use application::ports::password_reset_token_repository::PasswordResetToken;
+use application::ports::user_repository::UserRepository;
+use application::use_cases::{
+    CheckSessionError, CheckSessionUseCase, ResetPasswordError, ResetPasswordUseCase,
+};
+use chrono::{DateTime, Duration, Utc};
+use domain_users::{User, UserEvent};
+use std::sync::Arc;
+use test_support::fixtures::active_session;
+use test_support::{FastPasswordHasher, InMemoryPorts};
+use uuid::Uuid;
+
+fn user(id: Uuid) -> User {
+    User::register(
+        id,
+        "John".parse().unwrap(),
+        "Doe".parse().unwrap(),
+        "john@example.com".parse().unwrap(),
+        None,
+        "old_hash".parse().unwrap(),
+        None,
+    )
+}
+
+/// A reset token issued at `token_created_at` and a session opened with the old password.
+fn ports(token_created_at: DateTime<Utc>) -> (InMemoryPorts, Uuid) {
+    let user_id = Uuid::new_v4();
+    let p = InMemoryPorts::new()
+        .with_users([user(user_id)])
+        .with_password_reset_tokens([PasswordResetToken {
+            token: "token123".to_string(),
+            user_id,
+            created_at: token_created_at,
+        }])
+        .with_sessions([active_session(user_id)]);
+    (p, user_id)
+}
+
+fn use_case(p: &InMemoryPorts) -> ResetPasswordUseCase {
+    ResetPasswordUseCase::new(
+        p.users.clone(),
+        p.password_reset_tokens.clone(),
+        Arc::new(FastPasswordHasher),
+        p.unit_of_work(),
+        24,
+        p.events.clone(),
+    )
+}
+
+#[tokio::test]
+async fn test_reset_password_success() {
+    let (p, user_id) = ports(Utc::now());
+
+    use_case(&p)
+        .execute("token123", "new_password")
+        .await
+        .unwrap();
+
+    let updated_user = p.users.find_by_id(user_id).await.unwrap().unwrap();
+    assert_eq!(
+        updated_user.password_hash().as_str(),
+        FastPasswordHasher::hash_of("new_password")
+    );
+    assert_eq!(updated_user.version(), 2);
+
+    assert!(p.password_reset_tokens.tokens().is_empty());
+
+    let entries = p.audit_log.entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].action, "user.password_reset");
+    assert_eq!(entries[0].target_id, Some(user_id));
+
+    assert_eq!(
+        p.events.events(),
+        vec![UserEvent::PasswordChanged { user_id }]
+    );
+}
+
+#[tokio::test]
+async fn test_reset_password_signs_out_existing_sessions() {
+    let (p, user_id) = ports(Utc::now());
+    let session_id = p.sessions.sessions()[0].id;
+    let check_session = CheckSessionUseCase::new(p.sessions.clone());
+    check_session.execute(user_id, session_id).await.unwrap();
+
+    use_case(&p)
+        .execute("token123", "new_password")
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        check_session.execute(user_id, session_id).await,
+        Err(CheckSessionError::Revoked)
+    ));
+}
+
+#[tokio::test]
+async fn test_failed_audit_keeps_password_and_sessions() {
+    let (p, user_id) = ports(Utc::now());
+    p.audit_log.fail_writes();
+
+    let result = use_case(&p).execute("token123", "new_password").await;
+
+    assert!(matches!(
+        result,
+        Err(ResetPasswordError::RepositoryError(_))
+    ));
+    let unchanged = p.users.find_by_id(user_id).await.unwrap().unwrap();
+    assert_eq!(unchanged.password_hash().as_str(), "old_hash");
+    assert!(p.sessions.sessions()[0].revoked_at.is_none());
+    assert_eq!(p.password_reset_tokens.tokens().len(), 1);
+}
+
+#[tokio::test]
+async fn test_reset_password_expired_token() {
+    let (p, user_id) = ports(Utc::now() - Duration::hours(25));
+
+    let result = use_case(&p).execute("token123", "new_password").await;
+    assert!(matches!(result, Err(ResetPasswordError::ExpiredToken)));
+
+    assert!(p.password_reset_tokens.tokens().is_empty());
+    let unchanged = p.users.find_by_id(user_id).await.unwrap().unwrap();
+    assert_eq!(unchanged.password_hash().as_str(), "old_hash");
+    assert!(p.sessions.sessions()[0].revoked_at.is_none());
+}
@@ -0,0 +1,143 @@
+use application::ports::user_repository::UserRepository;
+use application::use_cases::{
+    CheckSessionError, CheckSessionUseCase, DeleteUserUseCase, RestoreAccountError,
+    RestoreAccountUseCase,
+};
+use chrono::Duration;
+use domain_users::UserEvent;
+use std::sync::Arc;
+use test_support::fixtures::{active_session, user_with_email};
+use test_support::{EmailKind, FakeOneTimeTokenService, InMemoryPorts};
+use uuid::Uuid;
+
+/// A user with an open session.
+fn ports() -> (InMemoryPorts, Uuid) {
+    let user = user_with_email("jane@example.com");
+    let user_id = user.id();
+    let p = InMemoryPorts::new()
+        .with_users([user])
+        .with_sessions([active_session(user_id)]);
+    (p, user_id)
+}
+
+fn delete(p: &InMemoryPorts, grace_period: Duration) -> DeleteUserUseCase {
+    DeleteUserUseCase::new(
+        p.users.clone(),
+        p.account_restore_tokens.clone(),
+        Arc::new(FakeOneTimeTokenService),
+        p.emails.clone(),
+        p.unit_of_work(),
+        grace_period,
+        p.events.clone(),
+    )
+}
+
+fn restore(p: &InMemoryPorts) -> RestoreAccountUseCase {
+    RestoreAccountUseCase::new(
+        p.users.clone(),
+        p.account_restore_tokens.clone(),
+        Arc::new(FakeOneTimeTokenService),
+        p.unit_of_work(),
+        p.events.clone(),
+    )
+}
+
+async fn is_deleted(p: &InMemoryPorts, user_id: Uuid) -> bool {
+    let user = p.users.find_by_id(user_id).await.unwrap().unwrap();
+    user.is_deleted()
+}
+
+fn mailed_link(p: &InMemoryPorts) -> String {
+    let sent = p.emails.sent();
+    assert_eq!(sent[0].kind, EmailKind::AccountRestore);
+    sent[0].token.clone()
+}
+
+#[tokio::test]
+async fn test_deleted_account_is_restored_with_the_mailed_link() {
+    let (p, user_id) = ports();
+    delete(&p, Duration::days(30))
+        .execute(user_id, Some(user_id))
+        .await
+        .unwrap();
+    assert!(is_deleted(&p, user_id).await);
+
+    let token = mailed_link(&p);
+    let user = restore(&p).execute(&token).await.unwrap();
+
+    assert!(!user.is_deleted());
+    assert!(!is_deleted(&p, user_id).await);
+    assert!(p.account_restore_tokens.tokens().is_empty());
+    let actions: Vec<String> = p
+        .audit_log
+        .entries()
+        .into_iter()
+        .map(|e| e.action)
+        .collect();
+    assert_eq!(actions, ["user.deleted", "user.restored"]);
+    assert_eq!(
+        p.events.events(),
+        vec![
+            UserEvent::Deleted { user_id },
+            UserEvent::Restored { user_id },
+        ]
+    );
+    assert_eq!(p.event_outbox.names(), ["user.deleted", "user.restored"]);
+
+    // The link only works once.
+    assert!(matches!(
+        restore(&p).execute(&token).await,
+        Err(RestoreAccountError::InvalidToken)
+    ));
+}
+
+#[tokio::test]
+async fn test_deletion_signs_out_every_session() {
+    let (p, user_id) = ports();
+    let session_id = p.sessions.sessions()[0].id;
+    let check_session = CheckSessionUseCase::new(p.sessions.clone());
+
+    delete(&p, Duration::days(30))
+        .execute(user_id, Some(user_id))
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        check_session.execute(user_id, session_id).await,
+        Err(CheckSessionError::Revoked)
+    ));
+}
+
+#[tokio::test]
+async fn test_link_expires_with_the_grace_period() {
+    let (p, user_id) = ports();
+    delete(&p, Duration::minutes(-1))
+        .execute(user_id, None)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        restore(&p).execute(&mailed_link(&p)).await,
+        Err(RestoreAccountError::ExpiredToken)
+    ));
+    assert!(is_deleted(&p, user_id).await);
+}
+
+#[tokio::test]
+async fn test_account_is_not_restored_once_its_email_is_taken() {
+    let (p, user_id) = ports();
+    delete(&p, Duration::days(30))
+        .execute(user_id, Some(user_id))
+        .await
+        .unwrap();
+    p.users
+        .create(&user_with_email("jane@example.com"))
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        restore(&p).execute(&mailed_link(&p)).await,
+        Err(RestoreAccountError::EmailTaken(_))
+    ));
+    assert!(is_deleted(&p, user_id).await);
+}
@@ -0,0 +1,132 @@
+use application::ports::user_repository::UserRepository;
+use application::use_cases::{
+    CreateUserError, CreateUserInput, CreateUserUseCase, DeleteUserUseCase, RestoreUserError,
+    RestoreUserUseCase,
+};
+use chrono::Duration;
+use std::sync::Arc;
+use test_support::{FakeOneTimeTokenService, FastPasswordHasher, InMemoryPorts};
+use uuid::Uuid;
+
+/// Creates, deletes and restores users over the same ports.
+struct Accounts {
+    create: CreateUserUseCase,
+    delete: DeleteUserUseCase,
+    restore: RestoreUserUseCase,
+}
+
+fn use_cases(p: &InMemoryPorts) -> Accounts {
+    Accounts {
+        create: CreateUserUseCase::new(
+            p.users.clone(),
+            p.unit_of_work(),
+            Arc::new(FastPasswordHasher),
+            p.events.clone(),
+        ),
+        delete: DeleteUserUseCase::new(
+            p.users.clone(),
+            p.account_restore_tokens.clone(),
+            Arc::new(FakeOneTimeTokenService),
+            p.emails.clone(),
+            p.unit_of_work(),
+            Duration::days(30),
+            p.events.clone(),
+        ),
+        restore: RestoreUserUseCase::new(
+            p.users.clone(),
+            p.account_restore_tokens.clone(),
+            p.unit_of_work(),
+            p.events.clone(),
+        ),
+    }
+}
+
+fn input(first_name: &str) -> CreateUserInput {
+    CreateUserInput {
+        first_name: first_name.to_string(),
+        last_name: "Doe".to_string(),
+        email: "jane@example.com".to_string(),
+        phone_number: None,
+        password: "password".to_string(),
+        birth_date: None,
+    }
+}
+
+#[tokio::test]
+async fn test_email_lifecycle_across_delete_reuse_and_restore() {
+    let p = InMemoryPorts::new();
+    let f = use_cases(&p);
+    let admin_id = Uuid::new_v4();
+
+    // An active account blocks the email.
+    let first = f.create.execute(input("First")).await.unwrap();
+    assert!(matches!(
+        f.create.execute(input("Second")).await,
+        Err(CreateUserError::AlreadyExists(_))
+    ));
+
+    // Deleting it frees the email for a new account.
+    f.delete.execute(first.id(), Some(admin_id)).await.unwrap();
+    let second = f.create.execute(input("Second")).await.unwrap();
+
+    // Which then blocks restoring the old one.
+    assert!(matches!(
+        f.restore.execute(admin_id, first.id()).await,
+        Err(RestoreUserError::EmailTaken(_))
+    ));
+    assert!(
+        p.users
+            .find_by_id(first.id())
+            .await
+            .unwrap()
+            .unwrap()
+            .is_deleted()
+    );
+    assert_eq!(p.account_restore_tokens.tokens().len(), 1);
+
+    // Once the new account is deleted, the old one comes back.
+    f.delete.execute(second.id(), Some(admin_id)).await.unwrap();
+    let restored = f.restore.execute(admin_id, first.id()).await.unwrap();
+    assert!(!restored.is_deleted());
+    assert_eq!(restored.version(), 3);
+    assert!(
+        p.account_restore_tokens
+            .tokens()
+            .iter()
+            .all(|t| t.user_id != first.id())
+    );
+    assert!(matches!(
+        f.create.execute(input("Third")).await,
+        Err(CreateUserError::AlreadyExists(_))
+    ));
+
+    let entries = p.audit_log.entries();
+    let last = entries.last().unwrap();
+    assert_eq!(last.action, "user.restored");
+    assert_eq!(last.actor_id, Some(admin_id));
+    assert_eq!(last.target_id, Some(first.id()));
+}
+
+#[tokio::test]
+async fn test_restoring_an_active_user_changes_nothing() {
+    let p = InMemoryPorts::new();
+    let f = use_cases(&p);
+    let user = f.create.execute(input("First")).await.unwrap();
+
+    let restored = f.restore.execute(Uuid::new_v4(), user.id()).await.unwrap();
+
+    assert_eq!(restored.version(), user.version());
+    assert!(p.audit_log.entries().is_empty());
+}
+
+#[tokio::test]
+async fn test_erased_user_is_not_found() {
+    let p = InMemoryPorts::new();
+    let f = use_cases(&p);
+    let id = Uuid::new_v4();
+
+    assert!(matches!(
+        f.restore.execute(Uuid::new_v4(), id).await,
+        Err(RestoreUserError::NotFound(missing)) if missing == id
+    ));
+}
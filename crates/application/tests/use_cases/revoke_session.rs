@@ -0,0 +1,62 @@
+use application::use_cases::{RevokeSessionError, RevokeSessionUseCase};
+use test_support::InMemoryPorts;
+use test_support::fixtures::active_session;
+use uuid::Uuid;
+
+/// Two sessions of one user; the first is on a lost phone.
+fn ports() -> (InMemoryPorts, Uuid, Uuid) {
+    let user_id = Uuid::new_v4();
+    let lost_phone = active_session(user_id);
+    let session_id = lost_phone.id;
+    let p = InMemoryPorts::new().with_sessions([lost_phone, active_session(user_id)]);
+    (p, user_id, session_id)
+}
+
+fn use_case(p: &InMemoryPorts) -> RevokeSessionUseCase {
+    RevokeSessionUseCase::new(p.sessions.clone(), p.audit_log.clone())
+}
+
+#[tokio::test]
+async fn test_revoke_signs_out_only_that_session_and_is_audited() {
+    let (p, user_id, session_id) = ports();
+    let use_case = use_case(&p);
+
+    use_case.execute(user_id, session_id).await.unwrap();
+
+    let sessions = p.sessions.sessions();
+    assert!(sessions[0].revoked_at.is_some());
+    assert!(sessions[1].revoked_at.is_none());
+
+    let entries = p.audit_log.entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].actor_id, Some(user_id));
+    assert_eq!(entries[0].action, "session.revoked");
+    assert_eq!(entries[0].target_type, "session");
+    assert_eq!(entries[0].target_id, Some(session_id));
+}
+
+#[tokio::test]
+async fn test_another_users_session_is_not_found() {
+    let (p, _, session_id) = ports();
+    let use_case = use_case(&p);
+
+    let result = use_case.execute(Uuid::new_v4(), session_id).await;
+
+    assert!(matches!(result, Err(RevokeSessionError::NotFound)));
+    assert!(p.sessions.sessions()[0].revoked_at.is_none());
+    assert!(p.audit_log.entries().is_empty());
+}
+
+#[tokio::test]
+async fn test_revoked_or_unknown_session_is_not_found() {
+    let (p, user_id, session_id) = ports();
+    let use_case = use_case(&p);
+    use_case.execute(user_id, session_id).await.unwrap();
+
+    let again = use_case.execute(user_id, session_id).await;
+    let unknown = use_case.execute(user_id, Uuid::new_v4()).await;
+
+    assert!(matches!(again, Err(RevokeSessionError::NotFound)));
+    assert!(matches!(unknown, Err(RevokeSessionError::NotFound)));
+    assert_eq!(p.audit_log.entries().len(), 1);
+}
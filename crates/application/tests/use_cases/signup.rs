@@ -0,0 +1,113 @@
+use application::use_cases::{SignupError, SignupInput, SignupUseCase};
+use domain_users::{UserEvent, UserRole};
+use std::sync::Arc;
+use test_support::{EmailKind, FastPasswordHasher, InMemoryPorts};
+
+fn use_case(p: &InMemoryPorts) -> SignupUseCase {
+    SignupUseCase::new(
+        p.users.clone(),
+        p.unit_of_work(),
+        Arc::new(FastPasswordHasher),
+        p.emails.clone(),
+        p.events.clone(),
+    )
+}
+
+fn input() -> SignupInput {
+    SignupInput {
+        first_name: "John".to_string(),
+        last_name: "Doe".to_string(),
+        email: "john@example.com".to_string(),
+        phone_number: None,
+        password: "password123".to_string(),
+        birth_date: None,
+    }
+}
+
+#[tokio::test]
+async fn test_signup_success() {
+    let p = InMemoryPorts::new();
+    let use_case = use_case(&p);
+
+    let user = use_case.execute(input()).await.unwrap();
+
+    assert_eq!(user.email(), "john@example.com");
+    assert!(!user.is_email_verified());
+    assert_eq!(*user.role(), UserRole::User);
+    assert_eq!(
+        user.password_hash().as_str(),
+        FastPasswordHasher::hash_of("password123")
+    );
+    assert_eq!(p.users.users().len(), 1);
+
+    let tokens = p.email_verification_tokens.tokens();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].user_id, user.id());
+
+    let sent = p.emails.sent();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].kind, EmailKind::Verification);
+    assert_eq!(sent[0].to, "john@example.com");
+    assert_eq!(sent[0].token, tokens[0].token);
+
+    assert_eq!(
+        p.events.events(),
+        vec![UserEvent::SignedUp {
+            user_id: user.id(),
+            email: user.email().clone(),
+        }]
+    );
+    assert!(user.events().is_empty());
+    let stored = p.event_outbox.events();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].name, "user.signed_up");
+    assert_eq!(stored[0].aggregate_id, user.id());
+}
+
+#[tokio::test]
+async fn test_signup_rejects_taken_email() {
+    let p = InMemoryPorts::new();
+    let use_case = use_case(&p);
+    use_case.execute(input()).await.unwrap();
+
+    let result = use_case
+        .execute(SignupInput {
+            email: "John@Example.com".to_string(),
+            ..input()
+        })
+        .await;
+
+    assert!(matches!(result, Err(SignupError::AlreadyExists(_))));
+    assert_eq!(p.users.users().len(), 1);
+}
+
+#[tokio::test]
+async fn test_signup_does_not_commit_user_when_token_fails() {
+    let p = InMemoryPorts::new();
+    let use_case = use_case(&p);
+    p.email_verification_tokens.fail_writes();
+
+    let result = use_case.execute(input()).await;
+
+    assert!(matches!(result, Err(SignupError::RepositoryError(_))));
+    assert!(p.users.users().is_empty());
+    assert!(p.email_verification_tokens.tokens().is_empty());
+    assert!(p.emails.sent().is_empty());
+    assert!(p.events.events().is_empty());
+    assert!(p.event_outbox.events().is_empty());
+}
+
+#[tokio::test]
+async fn test_signup_does_not_commit_user_when_outbox_fails() {
+    let p = InMemoryPorts::new();
+    let use_case = use_case(&p);
+    p.event_outbox.fail_writes();
+
+    let result = use_case.execute(input()).await;
+
+    assert!(matches!(result, Err(SignupError::RepositoryError(_))));
+    assert!(p.users.users().is_empty());
+    assert!(p.email_verification_tokens.tokens().is_empty());
+    assert!(p.emails.sent().is_empty());
+    assert!(p.events.events().is_empty());
+}
@@ -0,0 +1,25 @@
+use application::ports::login_attempt_store::{AttemptSubject, LoginAttempts};
+use application::use_cases::{UnlockAccountError, UnlockAccountUseCase};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use test_support::{FakeOneTimeTokenService, InMemoryLoginAttemptStore};
+
+#[tokio::test]
+async fn test_unlock_account_clears_lockout_once() {
+    let now = Utc::now();
+    let store = Arc::new(InMemoryLoginAttemptStore::with_attempts([LoginAttempts {
+        subject: AttemptSubject::account("john@example.com"),
+        failed_attempts: 5,
+        last_failed_at: now,
+        blocked_until: None,
+        locked_until: Some(now + Duration::minutes(15)),
+        unlock_token_hash: Some(FakeOneTimeTokenService::hash_of("unlock123")),
+    }]));
+    let use_case = UnlockAccountUseCase::new(store.clone(), Arc::new(FakeOneTimeTokenService));
+
+    use_case.execute("unlock123").await.unwrap();
+
+    assert!(store.attempts().is_empty());
+    let result = use_case.execute("unlock123").await;
+    assert!(matches!(result, Err(UnlockAccountError::InvalidToken)));
+}
@@ -0,0 +1,203 @@
+use application::ports::user_repository::UserRepository;
+use application::use_cases::{
+    UpdateUserProfileError, UpdateUserProfileInput, UpdateUserProfileUseCase,
+};
+use chrono::NaiveDate;
+use domain_users::User;
+use test_support::InMemoryPorts;
+use uuid::Uuid;
+
+fn john() -> User {
+    User::register(
+        Uuid::new_v4(),
+        "John".parse().unwrap(),
+        "Doe".parse().unwrap(),
+        "john@example.com".parse().unwrap(),
+        None,
+        "hash".parse().unwrap(),
+        None,
+    )
+}
+
+fn use_case(p: &InMemoryPorts) -> UpdateUserProfileUseCase {
+    UpdateUserProfileUseCase::new(p.users.clone(), p.unit_of_work(), p.events.clone())
+}
+
+#[tokio::test]
+async fn test_update_profile_success() {
+    let user = john();
+    let user_id = user.id();
+    let p = InMemoryPorts::new().with_users([user]);
+    let actor_id = Uuid::new_v4();
+
+    let user = use_case(&p)
+        .execute(UpdateUserProfileInput {
+            user_id,
+            actor_id: Some(actor_id),
+            expected_version: Some(1),
+            first_name: Some("  Jane ".to_string()),
+            phone_number: Some(Some("+49 30 1234567".to_string())),
+            locale: Some(Some("de-DE".to_string())),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(user.first_name(), "Jane");
+    assert_eq!(user.last_name(), "Doe");
+    assert_eq!(user.version(), 2);
+
+    let stored = p.users.find_by_id(user_id).await.unwrap().unwrap();
+    assert_eq!(
+        stored.phone_number().map(|p| p.as_str()),
+        Some("+49301234567")
+    );
+    assert_eq!(stored.locale(), Some("de-DE"));
+    assert_eq!(stored.version(), 2);
+
+    let entries = p.audit_log.entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].action, "user.profile_updated");
+    assert_eq!(entries[0].actor_id, Some(actor_id));
+    assert_eq!(entries[0].target_id, Some(user_id));
+    let fields: Vec<&str> = entries[0]
+        .changes
+        .iter()
+        .map(|c| c.field.as_str())
+        .collect();
+    assert_eq!(fields, vec!["first_name", "phone_number", "locale"]);
+    assert_eq!(entries[0].changes[0].old_value.as_deref(), Some("John"));
+}
+
+#[tokio::test]
+async fn test_update_profile_failed_audit_keeps_user_unchanged() {
+    let user = john();
+    let user_id = user.id();
+    let p = InMemoryPorts::new().with_users([user]);
+    p.audit_log.fail_writes();
+
+    let result = use_case(&p)
+        .execute(UpdateUserProfileInput {
+            user_id,
+            first_name: Some("Jane".to_string()),
+            ..Default::default()
+        })
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(UpdateUserProfileError::RepositoryError(_))
+    ));
+    let stored = p.users.find_by_id(user_id).await.unwrap().unwrap();
+    assert_eq!(stored.first_name(), "John");
+    assert_eq!(stored.version(), 1);
+}
+
+#[tokio::test]
+async fn test_update_profile_clears_nullable_field() {
+    let mut user = john();
+    user.change_phone_number(Some("+4930123456".parse().unwrap()));
+    let user_id = user.id();
+    let p = InMemoryPorts::new().with_users([user]);
+
+    let user = use_case(&p)
+        .execute(UpdateUserProfileInput {
+            user_id,
+            phone_number: Some(None),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(user.phone_number(), None);
+}
+
+#[tokio::test]
+async fn test_update_profile_collects_field_errors() {
+    let user = john();
+    let user_id = user.id();
+    let p = InMemoryPorts::new().with_users([user]);
+
+    let result = use_case(&p)
+        .execute(UpdateUserProfileInput {
+            user_id,
+            first_name: Some("   ".to_string()),
+            phone_number: Some(Some("call me".to_string())),
+            birth_date: Some(Some(NaiveDate::from_ymd_opt(1850, 1, 1).unwrap())),
+            locale: Some(Some("german".to_string())),
+            ..Default::default()
+        })
+        .await;
+
+    match result {
+        Err(UpdateUserProfileError::Validation(errors)) => {
+            let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+            assert_eq!(
+                fields,
+                vec!["first_name", "phone_number", "birth_date", "locale"]
+            );
+            assert_eq!(errors[0].code, "required");
+        }
+        other => panic!("expected validation error, got {:?}", other),
+    }
+    assert!(p.audit_log.entries().is_empty());
+}
+
+#[tokio::test]
+async fn test_update_profile_version_mismatch() {
+    let user = john();
+    let user_id = user.id();
+    let p = InMemoryPorts::new().with_users([user]);
+
+    let result = use_case(&p)
+        .execute(UpdateUserProfileInput {
+            user_id,
+            expected_version: Some(7),
+            first_name: Some("Jane".to_string()),
+            ..Default::default()
+        })
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(UpdateUserProfileError::PreconditionFailed { current_version: 1 })
+    ));
+    assert!(p.audit_log.entries().is_empty());
+}
+
+#[tokio::test]
+async fn test_update_profile_without_changes_skips_audit() {
+    let user = john();
+    let user_id = user.id();
+    let p = InMemoryPorts::new().with_users([user]);
+
+    let user = use_case(&p)
+        .execute(UpdateUserProfileInput {
+            user_id,
+            first_name: Some("John".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(user.version(), 1);
+    assert!(p.audit_log.entries().is_empty());
+}
+
+#[tokio::test]
+async fn test_update_profile_deleted_user_not_found() {
+    let mut user = john();
+    user.delete();
+    let user_id = user.id();
+    let p = InMemoryPorts::new().with_users([user]);
+
+    let result = use_case(&p)
+        .execute(UpdateUserProfileInput {
+            user_id,
+            first_name: Some("Jane".to_string()),
+            ..Default::default()
+        })
+        .await;
+
+    assert!(matches!(result, Err(UpdateUserProfileError::NotFound(id)) if id == user_id));
+}
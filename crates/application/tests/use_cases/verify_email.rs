@@ -0,0 +1,36 @@
+use application::ports::email_verification_token_repository::EmailVerificationToken;
+use application::ports::user_repository::UserRepository;
+use application::use_cases::VerifyEmailUseCase;
+use domain_users::{UserEvent, UserRole};
+use test_support::InMemoryPorts;
+use test_support::fixtures::user_with_email;
+
+#[tokio::test]
+async fn test_verify_email_success() {
+    let user = user_with_email("john@example.com");
+    let user_id = user.id();
+    let p = InMemoryPorts::new()
+        .with_users([user])
+        .with_email_verification_tokens([EmailVerificationToken {
+            token: "token123".to_string(),
+            user_id,
+        }]);
+    let use_case = VerifyEmailUseCase::new(
+        p.users.clone(),
+        p.email_verification_tokens.clone(),
+        p.unit_of_work(),
+        p.events.clone(),
+    );
+
+    use_case.execute("token123").await.unwrap();
+
+    let updated = p.users.find_by_id(user_id).await.unwrap().unwrap();
+    assert!(updated.is_email_verified());
+    assert_eq!(*updated.role(), UserRole::VerifiedUser);
+    assert!(p.email_verification_tokens.tokens().is_empty());
+    assert_eq!(
+        p.events.events(),
+        vec![UserEvent::EmailVerified { user_id }]
+    );
+    assert_eq!(p.event_outbox.names(), ["user.email_verified"]);
+}
@@ -0,0 +1,25 @@
+use application::ports::event_outbox::{OutboxConsumer, OutboxEvent};
+use application::use_cases::WebhookFanOut;
+use test_support::InMemoryPorts;
+use test_support::fixtures::{outbox_event, webhook_subscription};
+
+#[tokio::test]
+async fn test_consume_queues_a_delivery_per_matching_subscription() {
+    let deleted = webhook_subscription(&["user.signed_up", "user.deleted"]);
+    let p = InMemoryPorts::new()
+        .with_webhook_subscriptions([deleted.clone(), webhook_subscription(&["user.signed_up"])]);
+    let fan_out = WebhookFanOut::new(p.webhooks.clone());
+
+    fan_out
+        .consume(&OutboxEvent {
+            position: 7,
+            ..outbox_event("user.deleted")
+        })
+        .await
+        .unwrap();
+
+    let deliveries = p.webhooks.deliveries();
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].subscription_id, deleted.id);
+    assert_eq!(deliveries[0].event.position, 7);
+}
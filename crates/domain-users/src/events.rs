@@ -0,0 +1,80 @@
+use crate::models::user::UserRole;
+use crate::value_objects::EmailAddress;
+use uuid::Uuid;
+
+/// Something that happened to a user. Recorded by the [`User`](crate::User) methods
+/// that cause it and taken off the aggregate once the change is stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserEvent {
+    SignedUp {
+        user_id: Uuid,
+        email: EmailAddress,
+    },
+    EmailVerified {
+        user_id: Uuid,
+    },
+    EmailChanged {
+        user_id: Uuid,
+        previous: EmailAddress,
+        current: EmailAddress,
+    },
+    PasswordChanged {
+        user_id: Uuid,
+    },
+    ProfileUpdated {
+        user_id: Uuid,
+        field: &'static str,
+    },
+    RoleChanged {
+        user_id: Uuid,
+        previous: UserRole,
+        current: UserRole,
+    },
+    Deleted {
+        user_id: Uuid,
+    },
+    Restored {
+        user_id: Uuid,
+    },
+}
+
+impl UserEvent {
+    /// Every value [`UserEvent::name`] can return.
+    pub const NAMES: [&'static str; 8] = [
+        "user.signed_up",
+        "user.email_verified",
+        "user.email_changed",
+        "user.password_changed",
+        "user.profile_updated",
+        "user.role_changed",
+        "user.deleted",
+        "user.restored",
+    ];
+
+    pub fn user_id(&self) -> Uuid {
+        match self {
+            Self::SignedUp { user_id, .. }
+            | Self::EmailVerified { user_id }
+            | Self::EmailChanged { user_id, .. }
+            | Self::PasswordChanged { user_id }
+            | Self::ProfileUpdated { user_id, .. }
+            | Self::RoleChanged { user_id, .. }
+            | Self::Deleted { user_id }
+            | Self::Restored { user_id } => *user_id,
+        }
+    }
+
+    /// Stable name, e.g. `user.signed_up`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SignedUp { .. } => "user.signed_up",
+            Self::EmailVerified { .. } => "user.email_verified",
+            Self::EmailChanged { .. } => "user.email_changed",
+            Self::PasswordChanged { .. } => "user.password_changed",
+            Self::ProfileUpdated { .. } => "user.profile_updated",
+            Self::RoleChanged { .. } => "user.role_changed",
+            Self::Deleted { .. } => "user.deleted",
+            Self::Restored { .. } => "user.restored",
+        }
+    }
+}
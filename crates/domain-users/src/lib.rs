@@ -1,7 +1,11 @@
+pub mod events;
 pub mod models;
 pub mod telemetry;
+pub mod value_objects;
 
-pub use models::User;
+pub use events::UserEvent;
+pub use models::{User, UserRole, UserSnapshot};
+pub use value_objects::{EmailAddress, PasswordHash, PersonName, PhoneNumber};
 
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
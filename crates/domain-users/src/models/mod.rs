@@ -1,3 +1,3 @@
 pub mod user;
 
-pub use user::User;
+pub use user::{User, UserRole, UserSnapshot};
@@ -1,9 +1,27 @@
+use crate::events::UserEvent;
+use crate::value_objects::{EmailAddress, PasswordHash, PersonName, PhoneNumber};
 use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UserRole {
     User,
     VerifiedUser,
+    /// Publishes content and may access the API with personal API keys.
+    Creator,
+    Moderator,
+    Admin,
+}
+
+impl UserRole {
+    /// Staff accounts must sign in with a second factor.
+    pub fn requires_two_factor(&self) -> bool {
+        matches!(self, UserRole::Moderator | UserRole::Admin)
+    }
+
+    pub fn can_use_api_keys(&self) -> bool {
+        matches!(self, UserRole::Creator | UserRole::Admin)
+    }
 }
 
 impl std::fmt::Display for UserRole {
@@ -11,6 +29,9 @@ impl std::fmt::Display for UserRole {
         match self {
             UserRole::User => write!(f, "user"),
             UserRole::VerifiedUser => write!(f, "verified_user"),
+            UserRole::Creator => write!(f, "creator"),
+            UserRole::Moderator => write!(f, "moderator"),
+            UserRole::Admin => write!(f, "admin"),
         }
     }
 }
@@ -19,37 +40,66 @@ impl From<String> for UserRole {
     fn from(s: String) -> Self {
         match s.as_str() {
             "verified_user" => UserRole::VerifiedUser,
+            "creator" => UserRole::Creator,
+            "moderator" => UserRole::Moderator,
+            "admin" => UserRole::Admin,
             _ => UserRole::User,
         }
     }
 }
 
+/// A user account. State only changes through the methods below, each of which
+/// records a [`UserEvent`] for [`User::take_events`].
 #[derive(Debug, Clone)]
 pub struct User {
-    pub id: uuid::Uuid,
-    pub first_name: String,
-    pub last_name: String,
-    pub email: String,
-    pub phone_number: Option<String>,
-    pub password_hash: String,
+    id: Uuid,
+    first_name: PersonName,
+    last_name: PersonName,
+    email: EmailAddress,
+    phone_number: Option<PhoneNumber>,
+    password_hash: PasswordHash,
+    birth_date: Option<NaiveDate>,
+    is_email_verified: bool,
+    role: UserRole,
+    locale: Option<String>,
+    version: i32,
+    deleted_at: Option<DateTime<Utc>>,
+    events: Vec<UserEvent>,
+}
+
+/// Every field of a stored user, for repositories to load and save one.
+#[derive(Debug, Clone)]
+pub struct UserSnapshot {
+    pub id: Uuid,
+    pub first_name: PersonName,
+    pub last_name: PersonName,
+    pub email: EmailAddress,
+    pub phone_number: Option<PhoneNumber>,
+    pub password_hash: PasswordHash,
     pub birth_date: Option<NaiveDate>,
     pub is_email_verified: bool,
     pub role: UserRole,
+    pub locale: Option<String>,
+    pub version: i32,
     pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl User {
-    pub fn new(
-        id: uuid::Uuid,
-        first_name: String,
-        last_name: String,
-        email: String,
-        phone_number: Option<String>,
-        password_hash: String,
+    pub fn register(
+        id: Uuid,
+        first_name: PersonName,
+        last_name: PersonName,
+        email: EmailAddress,
+        phone_number: Option<PhoneNumber>,
+        password_hash: PasswordHash,
         birth_date: Option<NaiveDate>,
     ) -> Self {
         Self {
             id,
+            events: vec![UserEvent::SignedUp {
+                user_id: id,
+                email: email.clone(),
+            }],
             first_name,
             last_name,
             email,
@@ -58,22 +108,274 @@ impl User {
             birth_date,
             is_email_verified: false,
             role: UserRole::User,
+            locale: None,
+            version: 1,
             deleted_at: None,
         }
     }
 
+    /// Loads a stored user; nothing happened, so no events are recorded.
+    pub fn from_snapshot(snapshot: UserSnapshot) -> Self {
+        Self {
+            id: snapshot.id,
+            first_name: snapshot.first_name,
+            last_name: snapshot.last_name,
+            email: snapshot.email,
+            phone_number: snapshot.phone_number,
+            password_hash: snapshot.password_hash,
+            birth_date: snapshot.birth_date,
+            is_email_verified: snapshot.is_email_verified,
+            role: snapshot.role,
+            locale: snapshot.locale,
+            version: snapshot.version,
+            deleted_at: snapshot.deleted_at,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn snapshot(&self) -> UserSnapshot {
+        UserSnapshot {
+            id: self.id,
+            first_name: self.first_name.clone(),
+            last_name: self.last_name.clone(),
+            email: self.email.clone(),
+            phone_number: self.phone_number.clone(),
+            password_hash: self.password_hash.clone(),
+            birth_date: self.birth_date,
+            is_email_verified: self.is_email_verified,
+            role: self.role.clone(),
+            locale: self.locale.clone(),
+            version: self.version,
+            deleted_at: self.deleted_at,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn first_name(&self) -> &PersonName {
+        &self.first_name
+    }
+
+    pub fn last_name(&self) -> &PersonName {
+        &self.last_name
+    }
+
+    pub fn email(&self) -> &EmailAddress {
+        &self.email
+    }
+
+    pub fn phone_number(&self) -> Option<&PhoneNumber> {
+        self.phone_number.as_ref()
+    }
+
+    pub fn password_hash(&self) -> &PasswordHash {
+        &self.password_hash
+    }
+
+    pub fn birth_date(&self) -> Option<NaiveDate> {
+        self.birth_date
+    }
+
+    pub fn is_email_verified(&self) -> bool {
+        self.is_email_verified
+    }
+
+    pub fn role(&self) -> &UserRole {
+        &self.role
+    }
+
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    /// Optimistic locking version of the stored row.
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    pub fn deleted_at(&self) -> Option<DateTime<Utc>> {
+        self.deleted_at
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Repositories bump the stored version on every update; this keeps the
+    /// loaded user in step after a successful save.
+    pub fn increment_version(&mut self) {
+        self.version += 1;
+    }
+
+    /// Events recorded since the user was loaded or last drained.
+    pub fn events(&self) -> &[UserEvent] {
+        &self.events
+    }
+
+    pub fn take_events(&mut self) -> Vec<UserEvent> {
+        std::mem::take(&mut self.events)
+    }
+
     pub fn verify_email(&mut self) {
+        if self.is_email_verified {
+            return;
+        }
         self.is_email_verified = true;
-        self.role = UserRole::VerifiedUser;
+        if self.role == UserRole::User {
+            self.role = UserRole::VerifiedUser;
+        }
+        self.events
+            .push(UserEvent::EmailVerified { user_id: self.id });
+    }
+
+    /// A new address has to be verified again.
+    pub fn change_email(&mut self, email: EmailAddress) {
+        if email == self.email {
+            return;
+        }
+        let previous = std::mem::replace(&mut self.email, email);
+        self.is_email_verified = false;
+        self.events.push(UserEvent::EmailChanged {
+            user_id: self.id,
+            previous,
+            current: self.email.clone(),
+        });
+    }
+
+    pub fn change_password(&mut self, password_hash: PasswordHash) {
+        self.password_hash = password_hash;
+        self.events
+            .push(UserEvent::PasswordChanged { user_id: self.id });
+    }
+
+    pub fn change_role(&mut self, role: UserRole) {
+        if role == self.role {
+            return;
+        }
+        let previous = std::mem::replace(&mut self.role, role);
+        self.events.push(UserEvent::RoleChanged {
+            user_id: self.id,
+            previous,
+            current: self.role.clone(),
+        });
+    }
+
+    pub fn change_first_name(&mut self, first_name: PersonName) {
+        if first_name != self.first_name {
+            self.first_name = first_name;
+            self.profile_updated("first_name");
+        }
+    }
+
+    pub fn change_last_name(&mut self, last_name: PersonName) {
+        if last_name != self.last_name {
+            self.last_name = last_name;
+            self.profile_updated("last_name");
+        }
+    }
+
+    pub fn change_phone_number(&mut self, phone_number: Option<PhoneNumber>) {
+        if phone_number != self.phone_number {
+            self.phone_number = phone_number;
+            self.profile_updated("phone_number");
+        }
+    }
+
+    pub fn change_birth_date(&mut self, birth_date: Option<NaiveDate>) {
+        if birth_date != self.birth_date {
+            self.birth_date = birth_date;
+            self.profile_updated("birth_date");
+        }
+    }
+
+    pub fn change_locale(&mut self, locale: Option<String>) {
+        if locale != self.locale {
+            self.locale = locale;
+            self.profile_updated("locale");
+        }
+    }
+
+    fn profile_updated(&mut self, field: &'static str) {
+        self.events.push(UserEvent::ProfileUpdated {
+            user_id: self.id,
+            field,
+        });
     }
 
     pub fn delete(&mut self) {
         if self.deleted_at.is_none() {
             self.deleted_at = Some(Utc::now());
+            self.events.push(UserEvent::Deleted { user_id: self.id });
         }
     }
 
-    pub fn is_deleted(&self) -> bool {
-        self.deleted_at.is_some()
+    /// Undoes a deletion that has not been erased yet.
+    pub fn restore(&mut self) {
+        if self.deleted_at.take().is_some() {
+            self.events.push(UserEvent::Restored { user_id: self.id });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user() -> User {
+        User::register(
+            Uuid::new_v4(),
+            "Anna".parse().unwrap(),
+            "Schmidt".parse().unwrap(),
+            "anna@example.de".parse().unwrap(),
+            None,
+            "hash".parse().unwrap(),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_each_change_records_an_event() {
+        let mut user = user();
+        let id = user.id();
+
+        user.verify_email();
+        user.change_password("new-hash".parse().unwrap());
+        user.change_email("anna@example.com".parse().unwrap());
+        user.change_role(UserRole::Creator);
+        user.delete();
+        user.restore();
+
+        let names: Vec<&str> = user.take_events().iter().map(UserEvent::name).collect();
+        assert_eq!(
+            names,
+            [
+                "user.signed_up",
+                "user.email_verified",
+                "user.password_changed",
+                "user.email_changed",
+                "user.role_changed",
+                "user.deleted",
+                "user.restored",
+            ]
+        );
+        assert!(user.events().is_empty());
+        assert_eq!(user.id(), id);
+        assert!(!user.is_email_verified());
+        assert_eq!(user.password_hash().as_str(), "new-hash");
+    }
+
+    #[test]
+    fn test_changes_that_change_nothing_record_nothing() {
+        let mut user = User::from_snapshot(user().snapshot());
+
+        user.change_email("anna@example.de".parse().unwrap());
+        user.change_role(UserRole::User);
+        user.change_first_name("Anna".parse().unwrap());
+        user.change_locale(None);
+        user.restore();
+
+        assert!(user.events().is_empty());
     }
 }
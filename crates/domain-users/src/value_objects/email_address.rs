@@ -0,0 +1,175 @@
+/// An email address in the one form it is stored and compared in: trimmed,
+/// lowercased, with an internationalized domain encoded as punycode. Two inputs
+/// that reach the same mailbox this way parse to equal values.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmailAddress(String);
+
+const MAX_LENGTH: usize = 254;
+const MAX_LOCAL_PART_LENGTH: usize = 64;
+const MAX_LABEL_LENGTH: usize = 63;
+
+impl EmailAddress {
+    pub fn parse(raw: &str) -> Result<Self, EmailAddressError> {
+        let (local, domain) = raw
+            .trim()
+            .rsplit_once('@')
+            .ok_or(EmailAddressError::MissingAt)?;
+
+        let local = local.to_lowercase();
+        if local.is_empty()
+            || local.len() > MAX_LOCAL_PART_LENGTH
+            || local
+                .chars()
+                .any(|c| c == '@' || c.is_whitespace() || c.is_control())
+        {
+            return Err(EmailAddressError::InvalidLocalPart);
+        }
+
+        // Also lowercases and applies the UTS #46 mapping, e.g. `Bücher.DE` -> `xn--bcher-kva.de`.
+        let domain = idna::domain_to_ascii(domain).map_err(|_| EmailAddressError::InvalidDomain)?;
+        if domain.is_empty() || !domain.split('.').all(is_valid_label) {
+            return Err(EmailAddressError::InvalidDomain);
+        }
+
+        let address = format!("{}@{}", local, domain);
+        if address.len() > MAX_LENGTH {
+            return Err(EmailAddressError::TooLong);
+        }
+
+        Ok(Self(address))
+    }
+
+    /// For addresses read back from storage, which were normalized when written.
+    pub fn new_unchecked(address: String) -> Self {
+        Self(address)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+fn is_valid_label(label: &str) -> bool {
+    !label.is_empty()
+        && label.len() <= MAX_LABEL_LENGTH
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+impl std::fmt::Display for EmailAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for EmailAddress {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for EmailAddress {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for EmailAddress {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl From<EmailAddress> for String {
+    fn from(email: EmailAddress) -> Self {
+        email.0
+    }
+}
+
+impl std::str::FromStr for EmailAddress {
+    type Err = EmailAddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmailAddressError {
+    MissingAt,
+    InvalidLocalPart,
+    InvalidDomain,
+    TooLong,
+}
+
+impl std::fmt::Display for EmailAddressError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingAt => write!(f, "Email address must contain '@'"),
+            Self::InvalidLocalPart => write!(f, "Email address has an invalid local part"),
+            Self::InvalidDomain => write!(f, "Email address has an invalid domain"),
+            Self::TooLong => write!(f, "Email address is longer than {} characters", MAX_LENGTH),
+        }
+    }
+}
+
+impl std::error::Error for EmailAddressError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trims_and_lowercases() {
+        let email = EmailAddress::parse("  Anna@Example.DE ").unwrap();
+        assert_eq!(email.as_str(), "anna@example.de");
+        assert_eq!(email, EmailAddress::parse("anna@example.de").unwrap());
+    }
+
+    #[test]
+    fn test_encodes_internationalized_domain() {
+        let email = EmailAddress::parse("info@Bücher.de").unwrap();
+        assert_eq!(email.as_str(), "info@xn--bcher-kva.de");
+        assert_eq!(email, EmailAddress::parse("info@xn--bcher-kva.de").unwrap());
+    }
+
+    #[test]
+    fn test_rejects_malformed_addresses() {
+        for (raw, error) in [
+            ("anna.example.de", EmailAddressError::MissingAt),
+            ("@example.de", EmailAddressError::InvalidLocalPart),
+            ("an na@example.de", EmailAddressError::InvalidLocalPart),
+            ("a@b@example.de", EmailAddressError::InvalidLocalPart),
+            ("anna@", EmailAddressError::InvalidDomain),
+            ("anna@example..de", EmailAddressError::InvalidDomain),
+            ("anna@-example.de", EmailAddressError::InvalidDomain),
+            ("anna@exa_mple.de", EmailAddressError::InvalidDomain),
+        ] {
+            assert_eq!(EmailAddress::parse(raw), Err(error), "{}", raw);
+        }
+    }
+
+    #[test]
+    fn test_rejects_overlong_addresses() {
+        let local = "a".repeat(64);
+        let domain = format!(
+            "{}.{}.{}.de",
+            "b".repeat(63),
+            "c".repeat(63),
+            "d".repeat(63)
+        );
+        assert_eq!(
+            EmailAddress::parse(&format!("{}@{}", local, domain)),
+            Err(EmailAddressError::TooLong)
+        );
+    }
+}
@@ -0,0 +1,9 @@
+pub mod email_address;
+pub mod password_hash;
+pub mod person_name;
+pub mod phone_number;
+
+pub use email_address::{EmailAddress, EmailAddressError};
+pub use password_hash::{PasswordHash, PasswordHashError};
+pub use person_name::{PersonName, PersonNameError};
+pub use phone_number::{PhoneNumber, PhoneNumberError};
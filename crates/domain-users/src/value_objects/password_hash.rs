@@ -0,0 +1,57 @@
+/// The stored form of a password as produced by the password hasher. The domain
+/// never sees plain passwords, and `Debug` keeps the hash out of logs.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PasswordHash(String);
+
+impl PasswordHash {
+    pub fn new(hash: String) -> Result<Self, PasswordHashError> {
+        if hash.trim().is_empty() {
+            return Err(PasswordHashError::Empty);
+        }
+        Ok(Self(hash))
+    }
+
+    /// For hashes read back from storage.
+    pub fn new_unchecked(hash: String) -> Self {
+        Self(hash)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for PasswordHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PasswordHash(<redacted>)")
+    }
+}
+
+impl From<PasswordHash> for String {
+    fn from(hash: PasswordHash) -> Self {
+        hash.0
+    }
+}
+
+impl std::str::FromStr for PasswordHash {
+    type Err = PasswordHashError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasswordHashError {
+    Empty,
+}
+
+impl std::fmt::Display for PasswordHashError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Password hash must not be empty"),
+        }
+    }
+}
+
+impl std::error::Error for PasswordHashError {}
@@ -0,0 +1,108 @@
+/// A first or last name: trimmed, not empty and at most [`PersonName::MAX_LENGTH`]
+/// characters. Anything else a person may be called is allowed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PersonName(String);
+
+impl PersonName {
+    pub const MAX_LENGTH: usize = 100;
+
+    pub fn parse(raw: &str) -> Result<Self, PersonNameError> {
+        let name = raw.trim();
+        if name.is_empty() {
+            return Err(PersonNameError::Empty);
+        }
+        if name.chars().count() > Self::MAX_LENGTH {
+            return Err(PersonNameError::TooLong);
+        }
+        if name.chars().any(char::is_control) {
+            return Err(PersonNameError::InvalidCharacters);
+        }
+        Ok(Self(name.to_string()))
+    }
+
+    /// For names read back from storage, which may predate these rules.
+    pub fn new_unchecked(name: String) -> Self {
+        Self(name)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for PersonName {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for PersonName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl PartialEq<str> for PersonName {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl From<PersonName> for String {
+    fn from(name: PersonName) -> Self {
+        name.0
+    }
+}
+
+impl std::str::FromStr for PersonName {
+    type Err = PersonNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PersonNameError {
+    Empty,
+    TooLong,
+    InvalidCharacters,
+}
+
+impl std::fmt::Display for PersonNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "must not be empty"),
+            Self::TooLong => write!(f, "must be at most {} characters", PersonName::MAX_LENGTH),
+            Self::InvalidCharacters => write!(f, "must not contain control characters"),
+        }
+    }
+}
+
+impl std::error::Error for PersonNameError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trims_and_keeps_any_script() {
+        assert_eq!(PersonName::parse("  Zoë ").unwrap().as_str(), "Zoë");
+        assert_eq!(PersonName::parse("李").unwrap().as_str(), "李");
+    }
+
+    #[test]
+    fn test_rejects_empty_long_and_control_characters() {
+        assert_eq!(PersonName::parse("   "), Err(PersonNameError::Empty));
+        assert_eq!(
+            PersonName::parse(&"a".repeat(PersonName::MAX_LENGTH + 1)),
+            Err(PersonNameError::TooLong)
+        );
+        assert_eq!(
+            PersonName::parse("Anna\u{0}"),
+            Err(PersonNameError::InvalidCharacters)
+        );
+    }
+}
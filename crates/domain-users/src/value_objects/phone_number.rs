@@ -0,0 +1,134 @@
+/// A phone number in E.164 form, e.g. `+49301234567`. Input may use the usual
+/// separators and a `00` international prefix; a country code is required.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PhoneNumber(String);
+
+const MIN_DIGITS: usize = 6;
+const MAX_DIGITS: usize = 15;
+
+impl PhoneNumber {
+    pub fn parse(raw: &str) -> Result<Self, PhoneNumberError> {
+        let raw = raw.trim();
+        let body = if let Some(body) = raw.strip_prefix('+') {
+            body
+        } else if let Some(body) = raw.strip_prefix("00") {
+            body
+        } else {
+            return Err(PhoneNumberError::MissingCountryCode);
+        };
+
+        let mut digits = String::with_capacity(MAX_DIGITS);
+        for c in body.chars() {
+            match c {
+                '0'..='9' => digits.push(c),
+                ' ' | '-' | '/' | '(' | ')' | '.' => {}
+                _ => return Err(PhoneNumberError::InvalidCharacters),
+            }
+        }
+
+        if digits.starts_with('0') {
+            return Err(PhoneNumberError::MissingCountryCode);
+        }
+        if !(MIN_DIGITS..=MAX_DIGITS).contains(&digits.len()) {
+            return Err(PhoneNumberError::InvalidLength);
+        }
+
+        Ok(Self(format!("+{}", digits)))
+    }
+
+    /// For numbers read back from storage, which may predate these rules.
+    pub fn new_unchecked(number: String) -> Self {
+        Self(number)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for PhoneNumber {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for PhoneNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<PhoneNumber> for String {
+    fn from(number: PhoneNumber) -> Self {
+        number.0
+    }
+}
+
+impl std::str::FromStr for PhoneNumber {
+    type Err = PhoneNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhoneNumberError {
+    MissingCountryCode,
+    InvalidCharacters,
+    InvalidLength,
+}
+
+impl std::fmt::Display for PhoneNumberError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingCountryCode => write!(f, "must start with a country code, e.g. +49"),
+            Self::InvalidCharacters => write!(f, "may only contain digits and separators"),
+            Self::InvalidLength => write!(
+                f,
+                "must have between {} and {} digits",
+                MIN_DIGITS, MAX_DIGITS
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PhoneNumberError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_formats_as_e164() {
+        for raw in ["+49 30 1234567", "0049 (30) 123-4567", " +49/30/1234567 "] {
+            assert_eq!(PhoneNumber::parse(raw).unwrap().as_str(), "+49301234567");
+        }
+    }
+
+    #[test]
+    fn test_rejects_numbers_without_country_code_or_bad_length() {
+        assert_eq!(
+            PhoneNumber::parse("030 1234567"),
+            Err(PhoneNumberError::MissingCountryCode)
+        );
+        assert_eq!(
+            PhoneNumber::parse("+0 30 1234567"),
+            Err(PhoneNumberError::MissingCountryCode)
+        );
+        assert_eq!(
+            PhoneNumber::parse("+49 30 123x"),
+            Err(PhoneNumberError::InvalidCharacters)
+        );
+        assert_eq!(
+            PhoneNumber::parse("+4930"),
+            Err(PhoneNumberError::InvalidLength)
+        );
+        assert_eq!(
+            PhoneNumber::parse("+1234567890123456"),
+            Err(PhoneNumberError::InvalidLength)
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "event_outbox")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub position: i64,
+    #[sea_orm(unique)]
+    pub id: Uuid,
+    pub name: String,
+    pub aggregate_id: Uuid,
+    pub payload: Json,
+    pub occurred_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
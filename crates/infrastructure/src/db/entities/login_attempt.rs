@@ -0,0 +1,21 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "login_attempt")]
+pub struct Model {
+    /// `account:<email>` or `ip:<address>`.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub subject: String,
+    pub failed_attempts: i32,
+    pub last_failed_at: DateTimeWithTimeZone,
+    pub blocked_until: Option<DateTimeWithTimeZone>,
+    pub locked_until: Option<DateTimeWithTimeZone>,
+    #[sea_orm(unique)]
+    pub unlock_token_hash: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
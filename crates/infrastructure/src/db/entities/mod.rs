@@ -1,7 +1,45 @@
+pub mod account_restore_token;
+pub mod api_key;
+pub mod audit_log;
+pub mod data_export;
 pub mod email_verification_token;
+pub mod event_consumer;
+pub mod event_outbox;
+pub mod linked_identity;
+pub mod login_attempt;
+pub mod magic_link_token;
+pub mod oidc_login_request;
+pub mod passkey;
+pub mod passkey_ceremony;
 pub mod password_reset_token;
+pub mod pending_identity_link;
+pub mod totp_recovery_code;
 pub mod user;
+pub mod user_session;
+pub mod user_tombstone;
+pub mod user_totp;
+pub mod webhook_delivery;
+pub mod webhook_subscription;
 
+pub use account_restore_token::Entity as AccountRestoreToken;
+pub use api_key::Entity as ApiKey;
+pub use audit_log::Entity as AuditLog;
+pub use data_export::Entity as DataExport;
 pub use email_verification_token::Entity as EmailVerificationToken;
+pub use event_consumer::Entity as EventConsumer;
+pub use event_outbox::Entity as EventOutbox;
+pub use linked_identity::Entity as LinkedIdentity;
+pub use login_attempt::Entity as LoginAttempt;
+pub use magic_link_token::Entity as MagicLinkToken;
+pub use oidc_login_request::Entity as OidcLoginRequest;
+pub use passkey::Entity as Passkey;
+pub use passkey_ceremony::Entity as PasskeyCeremony;
 pub use password_reset_token::Entity as PasswordResetToken;
+pub use pending_identity_link::Entity as PendingIdentityLink;
+pub use totp_recovery_code::Entity as TotpRecoveryCode;
 pub use user::Entity as User;
+pub use user_session::Entity as UserSession;
+pub use user_tombstone::Entity as UserTombstone;
+pub use user_totp::Entity as UserTotp;
+pub use webhook_delivery::Entity as WebhookDelivery;
+pub use webhook_subscription::Entity as WebhookSubscription;
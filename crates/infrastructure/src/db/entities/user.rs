@@ -14,6 +14,8 @@ pub struct Model {
     pub birth_date: Option<Date>,
     pub is_email_verified: bool,
     pub role: String,
+    pub locale: Option<String>,
+    pub version: i32,
     pub deleted_at: Option<DateTimeWithTimeZone>,
 }
 
@@ -0,0 +1,40 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "webhook_delivery")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    /// `event_outbox.id` of the event sent.
+    pub event_id: Uuid,
+    /// pending | succeeded | failed
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: Option<DateTimeWithTimeZone>,
+    pub last_attempt_at: Option<DateTimeWithTimeZone>,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::db::entities::webhook_subscription::Entity",
+        from = "Column::SubscriptionId",
+        to = "crate::db::entities::webhook_subscription::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    WebhookSubscription,
+}
+
+impl Related<crate::db::entities::webhook_subscription::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WebhookSubscription.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
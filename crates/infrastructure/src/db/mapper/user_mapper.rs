@@ -1,36 +1,44 @@
 use crate::db::entities::user::Model as DbUser;
-use domain_users::models::user::User as DomainUser;
+use domain_users::models::user::{User as DomainUser, UserSnapshot};
+use domain_users::value_objects::{EmailAddress, PasswordHash, PersonName, PhoneNumber};
 
 pub struct UserMapper;
 
 impl UserMapper {
+    /// Stored values are loaded unchecked: they were valid when written, and rows
+    /// predating the value objects must stay readable.
     pub fn to_domain(db_user: DbUser) -> DomainUser {
-        DomainUser {
+        DomainUser::from_snapshot(UserSnapshot {
             id: db_user.id,
-            first_name: db_user.first_name,
-            last_name: db_user.last_name,
-            email: db_user.email,
-            phone_number: db_user.phone_number,
-            password_hash: db_user.password_hash,
+            first_name: PersonName::new_unchecked(db_user.first_name),
+            last_name: PersonName::new_unchecked(db_user.last_name),
+            email: EmailAddress::new_unchecked(db_user.email),
+            phone_number: db_user.phone_number.map(PhoneNumber::new_unchecked),
+            password_hash: PasswordHash::new_unchecked(db_user.password_hash),
             birth_date: db_user.birth_date,
             is_email_verified: db_user.is_email_verified,
             role: db_user.role.into(),
+            locale: db_user.locale,
+            version: db_user.version,
             deleted_at: db_user.deleted_at.map(|dt| dt.into()),
-        }
+        })
     }
 
     pub fn to_db(domain_user: DomainUser) -> DbUser {
+        let snapshot = domain_user.snapshot();
         DbUser {
-            id: domain_user.id,
-            first_name: domain_user.first_name,
-            last_name: domain_user.last_name,
-            email: domain_user.email,
-            phone_number: domain_user.phone_number,
-            password_hash: domain_user.password_hash,
-            birth_date: domain_user.birth_date,
-            is_email_verified: domain_user.is_email_verified,
-            role: domain_user.role.to_string(),
-            deleted_at: domain_user.deleted_at.map(|dt| dt.into()),
+            id: snapshot.id,
+            first_name: snapshot.first_name.into(),
+            last_name: snapshot.last_name.into(),
+            email: snapshot.email.into_string(),
+            phone_number: snapshot.phone_number.map(Into::into),
+            password_hash: snapshot.password_hash.into(),
+            birth_date: snapshot.birth_date,
+            is_email_verified: snapshot.is_email_verified,
+            role: snapshot.role.to_string(),
+            locale: snapshot.locale,
+            version: snapshot.version,
+            deleted_at: snapshot.deleted_at.map(|dt| dt.into()),
         }
     }
 }
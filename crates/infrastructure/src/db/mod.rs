@@ -1,6 +1,9 @@
 pub mod entities;
 pub mod mapper;
 pub mod repos;
+pub mod unit_of_work;
+
+pub use unit_of_work::PostgresUnitOfWork;
 
 use sea_orm::{Database, DatabaseConnection, DbErr};
 
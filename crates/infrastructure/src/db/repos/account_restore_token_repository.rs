@@ -0,0 +1,64 @@
+use crate::db::entities::account_restore_token::{ActiveModel, Column, Entity as TokenEntity};
+use application::ports::TokenRepositoryError;
+use application::ports::account_restore_token_repository::{
+    AccountRestoreToken, AccountRestoreTokenRepository,
+};
+use async_trait::async_trait;
+use sea_orm::*;
+use uuid::Uuid;
+
+pub struct PostgresAccountRestoreTokenRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresAccountRestoreTokenRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AccountRestoreTokenRepository for PostgresAccountRestoreTokenRepository {
+    async fn create(&self, token: &AccountRestoreToken) -> Result<(), TokenRepositoryError> {
+        let active_model = ActiveModel {
+            token_hash: Set(token.token_hash.clone()),
+            user_id: Set(token.user_id),
+            created_at: Set(token.created_at.into()),
+            expires_at: Set(token.expires_at.into()),
+        };
+
+        TokenEntity::insert(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(|e| TokenRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<AccountRestoreToken>, TokenRepositoryError> {
+        let db_token = TokenEntity::find_by_id(token_hash)
+            .one(&self.db)
+            .await
+            .map_err(|e| TokenRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(db_token.map(|t| AccountRestoreToken {
+            token_hash: t.token_hash,
+            user_id: t.user_id,
+            created_at: t.created_at.into(),
+            expires_at: t.expires_at.into(),
+        }))
+    }
+
+    async fn delete_by_user_id(&self, user_id: &Uuid) -> Result<(), TokenRepositoryError> {
+        TokenEntity::delete_many()
+            .filter(Column::UserId.eq(*user_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| TokenRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use crate::db::entities::api_key::{ActiveModel, Column, Entity as ApiKeyEntity, Model};
+use application::ports::api_key_repository::{
+    ApiKey, ApiKeyRepository, ApiKeyRepositoryError, ApiKeyScope,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+use uuid::Uuid;
+
+pub struct PostgresApiKeyRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresApiKeyRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(err: DbErr) -> ApiKeyRepositoryError {
+    ApiKeyRepositoryError::DatabaseError(err.to_string())
+}
+
+fn to_domain(model: Model) -> ApiKey {
+    ApiKey {
+        id: model.id,
+        user_id: model.user_id,
+        name: model.name,
+        prefix: model.prefix,
+        key_hash: model.key_hash,
+        // Scopes that are no longer known grant nothing.
+        scopes: model
+            .scopes
+            .split_whitespace()
+            .filter_map(ApiKeyScope::parse)
+            .collect(),
+        created_at: model.created_at.into(),
+        expires_at: model.expires_at.into(),
+        last_used_at: model.last_used_at.map(Into::into),
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for PostgresApiKeyRepository {
+    async fn create(&self, api_key: &ApiKey) -> Result<(), ApiKeyRepositoryError> {
+        let scopes: Vec<&str> = api_key.scopes.iter().map(ApiKeyScope::as_str).collect();
+
+        let active_model = ActiveModel {
+            id: Set(api_key.id),
+            user_id: Set(api_key.user_id),
+            name: Set(api_key.name.clone()),
+            prefix: Set(api_key.prefix.clone()),
+            key_hash: Set(api_key.key_hash.clone()),
+            scopes: Set(scopes.join(" ")),
+            created_at: Set(api_key.created_at.into()),
+            expires_at: Set(api_key.expires_at.into()),
+            last_used_at: Set(api_key.last_used_at.map(DateTimeWithTimeZone::from)),
+        };
+
+        ApiKeyEntity::insert(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn find_by_key_hash(
+        &self,
+        key_hash: &str,
+    ) -> Result<Option<ApiKey>, ApiKeyRepositoryError> {
+        Ok(ApiKeyEntity::find()
+            .filter(Column::KeyHash.eq(key_hash))
+            .one(&self.db)
+            .await
+            .map_err(database_error)?
+            .map(to_domain))
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<ApiKey>, ApiKeyRepositoryError> {
+        Ok(ApiKeyEntity::find()
+            .filter(Column::UserId.eq(user_id))
+            .order_by_desc(Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(database_error)?
+            .into_iter()
+            .map(to_domain)
+            .collect())
+    }
+
+    async fn record_use(
+        &self,
+        id: Uuid,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), ApiKeyRepositoryError> {
+        ApiKeyEntity::update_many()
+            .col_expr(
+                Column::LastUsedAt,
+                Expr::value(DateTimeWithTimeZone::from(used_at)),
+            )
+            .filter(Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiKeyRepositoryError> {
+        let result = ApiKeyEntity::delete_many()
+            .filter(Column::Id.eq(id))
+            .filter(Column::UserId.eq(user_id))
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
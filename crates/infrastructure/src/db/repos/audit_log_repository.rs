@@ -0,0 +1,127 @@
+use crate::db::entities::audit_log::{ActiveModel, Column, Entity as AuditLogEntity, Model};
+use application::ports::audit_log::{
+    AuditEntry, AuditLog, AuditLogError, AuditLogFilter, FieldChange,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::*;
+use serde_json::json;
+
+pub struct PostgresAuditLog<C = DatabaseConnection> {
+    db: C,
+}
+
+impl<C> PostgresAuditLog<C> {
+    pub fn new(db: C) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(err: DbErr) -> AuditLogError {
+    AuditLogError::DatabaseError(err.to_string())
+}
+
+fn to_change(value: &serde_json::Value) -> Option<FieldChange> {
+    let text = |key: &str| value.get(key).and_then(|v| v.as_str()).map(str::to_string);
+    Some(FieldChange {
+        field: text("field")?,
+        old_value: text("old"),
+        new_value: text("new"),
+    })
+}
+
+fn to_domain(model: Model) -> AuditEntry {
+    AuditEntry {
+        id: model.id,
+        occurred_at: model.occurred_at.into(),
+        actor_id: model.actor_id,
+        action: model.action,
+        target_type: model.target_type,
+        target_id: model.target_id,
+        changes: model
+            .changes
+            .as_array()
+            .map(|changes| changes.iter().filter_map(to_change).collect())
+            .unwrap_or_default(),
+        correlation_id: model.correlation_id,
+        ip_address: model.ip_address.and_then(|ip| ip.parse().ok()),
+    }
+}
+
+fn condition(filter: &AuditLogFilter) -> Condition {
+    let mut condition = Condition::all();
+    if let Some(actor_id) = filter.actor_id {
+        condition = condition.add(Column::ActorId.eq(actor_id));
+    }
+    if let Some(target_id) = filter.target_id {
+        condition = condition.add(Column::TargetId.eq(target_id));
+    }
+    if let Some(action) = &filter.action {
+        condition = condition.add(Column::Action.eq(action.as_str()));
+    }
+    if let Some(from) = filter.from {
+        condition = condition.add(Column::OccurredAt.gte(DateTimeWithTimeZone::from(from)));
+    }
+    if let Some(to) = filter.to {
+        condition = condition.add(Column::OccurredAt.lt(DateTimeWithTimeZone::from(to)));
+    }
+    condition
+}
+
+#[async_trait]
+impl<C: ConnectionTrait + Send> AuditLog for PostgresAuditLog<C> {
+    async fn record(&self, entry: &AuditEntry) -> Result<(), AuditLogError> {
+        let changes: Vec<serde_json::Value> = entry
+            .changes
+            .iter()
+            .map(|c| json!({ "field": c.field, "old": c.old_value, "new": c.new_value }))
+            .collect();
+
+        let active_model = ActiveModel {
+            id: Set(entry.id),
+            occurred_at: Set(entry.occurred_at.into()),
+            actor_id: Set(entry.actor_id),
+            action: Set(entry.action.clone()),
+            target_type: Set(entry.target_type.clone()),
+            target_id: Set(entry.target_id),
+            changes: Set(serde_json::Value::Array(changes)),
+            correlation_id: Set(entry.correlation_id.clone()),
+            ip_address: Set(entry.ip_address.map(|ip| ip.to_string())),
+        };
+
+        AuditLogEntity::insert(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn find(
+        &self,
+        filter: &AuditLogFilter,
+        page: u64,
+        page_size: u64,
+    ) -> Result<(Vec<AuditEntry>, u64), AuditLogError> {
+        let paginator = AuditLogEntity::find()
+            .filter(condition(filter))
+            .order_by_desc(Column::OccurredAt)
+            .paginate(&self.db, page_size);
+
+        let total_items = paginator.num_items().await.map_err(database_error)?;
+        let entries = paginator.fetch_page(page).await.map_err(database_error)?;
+
+        Ok((entries.into_iter().map(to_domain).collect(), total_items))
+    }
+
+    async fn delete_older_than(&self, cutoff: DateTime<Utc>) -> Result<u64, AuditLogError> {
+        let result = AuditLogEntity::delete_many()
+            .filter(Column::OccurredAt.lt(DateTimeWithTimeZone::from(cutoff)))
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,221 @@
+use crate::db::entities::data_export::{ActiveModel, Column, Entity as DataExportEntity, Model};
+use application::ports::data_export_repository::{
+    DataExport, DataExportRepository, DataExportRepositoryError, DataExportStatus,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+use uuid::Uuid;
+
+pub struct PostgresDataExportRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresDataExportRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(err: DbErr) -> DataExportRepositoryError {
+    DataExportRepositoryError::DatabaseError(err.to_string())
+}
+
+fn to_domain(model: Model) -> Result<DataExport, DataExportRepositoryError> {
+    let status = DataExportStatus::parse(&model.status).ok_or_else(|| {
+        DataExportRepositoryError::DatabaseError(format!(
+            "Unknown data export status: {}",
+            model.status
+        ))
+    })?;
+
+    Ok(DataExport {
+        id: model.id,
+        user_id: model.user_id,
+        requested_by: model.requested_by,
+        status,
+        download_token_hash: model.download_token_hash,
+        created_at: model.created_at.into(),
+        completed_at: model.completed_at.map(Into::into),
+        expires_at: model.expires_at.map(Into::into),
+    })
+}
+
+#[async_trait]
+impl DataExportRepository for PostgresDataExportRepository {
+    async fn create(&self, export: &DataExport) -> Result<(), DataExportRepositoryError> {
+        let active_model = ActiveModel {
+            id: Set(export.id),
+            user_id: Set(export.user_id),
+            requested_by: Set(export.requested_by),
+            status: Set(export.status.as_str().to_string()),
+            download_token_hash: Set(export.download_token_hash.clone()),
+            created_at: Set(export.created_at.into()),
+            completed_at: Set(export.completed_at.map(DateTimeWithTimeZone::from)),
+            expires_at: Set(export.expires_at.map(DateTimeWithTimeZone::from)),
+        };
+
+        DataExportEntity::insert(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn find_open_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Option<DataExport>, DataExportRepositoryError> {
+        DataExportEntity::find()
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::Status.is_in([
+                DataExportStatus::Pending.as_str(),
+                DataExportStatus::Processing.as_str(),
+            ]))
+            .order_by_desc(Column::CreatedAt)
+            .one(&self.db)
+            .await
+            .map_err(database_error)?
+            .map(to_domain)
+            .transpose()
+    }
+
+    async fn find_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<DataExport>, DataExportRepositoryError> {
+        DataExportEntity::find()
+            .filter(Column::UserId.eq(user_id))
+            .order_by_desc(Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(database_error)?
+            .into_iter()
+            .map(to_domain)
+            .collect()
+    }
+
+    async fn find_by_download_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<DataExport>, DataExportRepositoryError> {
+        DataExportEntity::find()
+            .filter(Column::DownloadTokenHash.eq(token_hash))
+            .one(&self.db)
+            .await
+            .map_err(database_error)?
+            .map(to_domain)
+            .transpose()
+    }
+
+    async fn claim_next_pending(&self) -> Result<Option<DataExport>, DataExportRepositoryError> {
+        // SKIP LOCKED lets several instances run the worker without claiming the same export.
+        let statement = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"UPDATE data_export SET status = $1
+               WHERE id = (
+                 SELECT id FROM data_export
+                 WHERE status = $2
+                 ORDER BY created_at
+                 LIMIT 1
+                 FOR UPDATE SKIP LOCKED
+               )
+               RETURNING *"#,
+            [
+                DataExportStatus::Processing.as_str().into(),
+                DataExportStatus::Pending.as_str().into(),
+            ],
+        );
+
+        DataExportEntity::find()
+            .from_raw_sql(statement)
+            .one(&self.db)
+            .await
+            .map_err(database_error)?
+            .map(to_domain)
+            .transpose()
+    }
+
+    async fn mark_ready(
+        &self,
+        id: Uuid,
+        download_token_hash: &str,
+        completed_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), DataExportRepositoryError> {
+        DataExportEntity::update_many()
+            .col_expr(
+                Column::Status,
+                Expr::value(DataExportStatus::Ready.as_str()),
+            )
+            .col_expr(
+                Column::DownloadTokenHash,
+                Expr::value(download_token_hash.to_string()),
+            )
+            .col_expr(
+                Column::CompletedAt,
+                Expr::value(DateTimeWithTimeZone::from(completed_at)),
+            )
+            .col_expr(
+                Column::ExpiresAt,
+                Expr::value(DateTimeWithTimeZone::from(expires_at)),
+            )
+            .filter(Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        completed_at: DateTime<Utc>,
+    ) -> Result<(), DataExportRepositoryError> {
+        DataExportEntity::update_many()
+            .col_expr(
+                Column::Status,
+                Expr::value(DataExportStatus::Failed.as_str()),
+            )
+            .col_expr(
+                Column::CompletedAt,
+                Expr::value(DateTimeWithTimeZone::from(completed_at)),
+            )
+            .filter(Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn expire(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<DataExport>, DataExportRepositoryError> {
+        let statement = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"UPDATE data_export SET status = $1
+               WHERE status = $2 AND expires_at <= $3
+               RETURNING *"#,
+            [
+                DataExportStatus::Expired.as_str().into(),
+                DataExportStatus::Ready.as_str().into(),
+                DateTimeWithTimeZone::from(now).into(),
+            ],
+        );
+
+        DataExportEntity::find()
+            .from_raw_sql(statement)
+            .all(&self.db)
+            .await
+            .map_err(database_error)?
+            .into_iter()
+            .map(to_domain)
+            .collect()
+    }
+}
@@ -6,18 +6,20 @@ use application::ports::email_verification_token_repository::{
 use async_trait::async_trait;
 use sea_orm::*;
 
-pub struct PostgresEmailVerificationTokenRepository {
-    db: DatabaseConnection,
+pub struct PostgresEmailVerificationTokenRepository<C = DatabaseConnection> {
+    db: C,
 }
 
-impl PostgresEmailVerificationTokenRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
+impl<C> PostgresEmailVerificationTokenRepository<C> {
+    pub fn new(db: C) -> Self {
         Self { db }
     }
 }
 
 #[async_trait]
-impl EmailVerificationTokenRepository for PostgresEmailVerificationTokenRepository {
+impl<C: ConnectionTrait + Send> EmailVerificationTokenRepository
+    for PostgresEmailVerificationTokenRepository<C>
+{
     async fn create(&self, token: &EmailVerificationToken) -> Result<(), TokenRepositoryError> {
         let active_model = ActiveModel {
             token: Set(token.token.clone()),
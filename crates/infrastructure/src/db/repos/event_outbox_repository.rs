@@ -0,0 +1,177 @@
+use crate::db::entities::event_consumer::{
+    ActiveModel as ConsumerActiveModel, Column as ConsumerColumn, Entity as ConsumerEntity,
+};
+use crate::db::entities::event_outbox::{ActiveModel, Column, Entity as EventOutboxEntity, Model};
+use application::ports::event_outbox::{EventOutbox, EventOutboxError, OutboxEvent};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use domain_users::UserEvent;
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::*;
+use serde_json::json;
+use uuid::Uuid;
+
+/// Positions come from a sequence, so a concurrent insert can commit after a later
+/// position is already visible. Readers stay this far behind so they never skip it.
+const SETTLE_TIME: Duration = Duration::seconds(2);
+
+pub struct PostgresEventOutbox<C = DatabaseConnection> {
+    db: C,
+}
+
+impl<C> PostgresEventOutbox<C> {
+    pub fn new(db: C) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(err: DbErr) -> EventOutboxError {
+    EventOutboxError::DatabaseError(err.to_string())
+}
+
+/// The JSON consumers receive. Keys are part of the public contract of each event.
+fn payload(event: &UserEvent) -> serde_json::Value {
+    match event {
+        UserEvent::SignedUp { user_id, email } => {
+            json!({ "user_id": user_id, "email": email.as_str() })
+        }
+        UserEvent::EmailChanged {
+            user_id,
+            previous,
+            current,
+        } => json!({
+            "user_id": user_id,
+            "previous_email": previous.as_str(),
+            "current_email": current.as_str(),
+        }),
+        UserEvent::ProfileUpdated { user_id, field } => {
+            json!({ "user_id": user_id, "field": field })
+        }
+        UserEvent::RoleChanged {
+            user_id,
+            previous,
+            current,
+        } => json!({
+            "user_id": user_id,
+            "previous_role": previous.to_string(),
+            "current_role": current.to_string(),
+        }),
+        UserEvent::EmailVerified { user_id }
+        | UserEvent::PasswordChanged { user_id }
+        | UserEvent::Deleted { user_id }
+        | UserEvent::Restored { user_id } => json!({ "user_id": user_id }),
+    }
+}
+
+pub(crate) fn to_domain(model: Model) -> OutboxEvent {
+    OutboxEvent {
+        position: model.position,
+        id: model.id,
+        name: model.name,
+        aggregate_id: model.aggregate_id,
+        payload: model.payload.to_string(),
+        occurred_at: model.occurred_at.into(),
+    }
+}
+
+#[async_trait]
+impl<C: ConnectionTrait + Send> EventOutbox for PostgresEventOutbox<C> {
+    async fn append(&self, events: &[UserEvent]) -> Result<(), EventOutboxError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let occurred_at = Utc::now();
+        let active_models = events.iter().map(|event| ActiveModel {
+            position: NotSet,
+            id: Set(Uuid::new_v4()),
+            name: Set(event.name().to_string()),
+            aggregate_id: Set(event.user_id()),
+            payload: Set(payload(event)),
+            occurred_at: Set(occurred_at.into()),
+        });
+
+        EventOutboxEntity::insert_many(active_models)
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn read_after(
+        &self,
+        position: i64,
+        limit: u64,
+    ) -> Result<Vec<OutboxEvent>, EventOutboxError> {
+        Ok(EventOutboxEntity::find()
+            .filter(Column::Position.gt(position))
+            .filter(Column::OccurredAt.lte(DateTimeWithTimeZone::from(Utc::now() - SETTLE_TIME)))
+            .order_by_asc(Column::Position)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(database_error)?
+            .into_iter()
+            .map(to_domain)
+            .collect())
+    }
+
+    async fn consumer_position(&self, consumer: &str) -> Result<i64, EventOutboxError> {
+        Ok(ConsumerEntity::find_by_id(consumer.to_string())
+            .one(&self.db)
+            .await
+            .map_err(database_error)?
+            .map(|c| c.position)
+            .unwrap_or(0))
+    }
+
+    async fn commit_position(&self, consumer: &str, position: i64) -> Result<(), EventOutboxError> {
+        ConsumerEntity::insert(ConsumerActiveModel {
+            name: Set(consumer.to_string()),
+            position: Set(position),
+            updated_at: Set(Utc::now().into()),
+        })
+        .on_conflict(
+            OnConflict::column(ConsumerColumn::Name)
+                .update_columns([ConsumerColumn::Position, ConsumerColumn::UpdatedAt])
+                .to_owned(),
+        )
+        .exec(&self.db)
+        .await
+        .map_err(database_error)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_users::UserRole;
+
+    #[test]
+    fn test_payload_uses_documented_keys() {
+        let user_id = Uuid::new_v4();
+
+        let signed_up = payload(&UserEvent::SignedUp {
+            user_id,
+            email: "anna@example.de".parse().unwrap(),
+        });
+        assert_eq!(
+            signed_up,
+            json!({ "user_id": user_id, "email": "anna@example.de" })
+        );
+
+        let role_changed = payload(&UserEvent::RoleChanged {
+            user_id,
+            previous: UserRole::User,
+            current: UserRole::Creator,
+        });
+        assert_eq!(
+            role_changed,
+            json!({ "user_id": user_id, "previous_role": "user", "current_role": "creator" })
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use crate::db::entities::linked_identity::{
+    ActiveModel, Column, Entity as LinkedIdentityEntity, Model,
+};
+use application::ports::linked_identity_repository::{
+    LinkedIdentity, LinkedIdentityRepository, LinkedIdentityRepositoryError,
+};
+use async_trait::async_trait;
+use sea_orm::*;
+use uuid::Uuid;
+
+pub struct PostgresLinkedIdentityRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresLinkedIdentityRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(err: DbErr) -> LinkedIdentityRepositoryError {
+    LinkedIdentityRepositoryError::DatabaseError(err.to_string())
+}
+
+fn to_domain(model: Model) -> LinkedIdentity {
+    LinkedIdentity {
+        id: model.id,
+        user_id: model.user_id,
+        provider: model.provider,
+        subject: model.subject,
+        email: model.email,
+        created_at: model.created_at.into(),
+    }
+}
+
+#[async_trait]
+impl LinkedIdentityRepository for PostgresLinkedIdentityRepository {
+    async fn create(&self, identity: &LinkedIdentity) -> Result<(), LinkedIdentityRepositoryError> {
+        let active_model = ActiveModel {
+            id: Set(identity.id),
+            user_id: Set(identity.user_id),
+            provider: Set(identity.provider.clone()),
+            subject: Set(identity.subject.clone()),
+            email: Set(identity.email.clone()),
+            created_at: Set(identity.created_at.into()),
+        };
+
+        LinkedIdentityEntity::insert(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(|err| match err.sql_err() {
+                Some(SqlErr::UniqueConstraintViolation(_)) => {
+                    LinkedIdentityRepositoryError::AlreadyLinked
+                }
+                _ => database_error(err),
+            })?;
+
+        Ok(())
+    }
+
+    async fn find_by_subject(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> Result<Option<LinkedIdentity>, LinkedIdentityRepositoryError> {
+        Ok(LinkedIdentityEntity::find()
+            .filter(Column::Provider.eq(provider))
+            .filter(Column::Subject.eq(subject))
+            .one(&self.db)
+            .await
+            .map_err(database_error)?
+            .map(to_domain))
+    }
+
+    async fn find_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<LinkedIdentity>, LinkedIdentityRepositoryError> {
+        Ok(LinkedIdentityEntity::find()
+            .filter(Column::UserId.eq(user_id))
+            .order_by_asc(Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(database_error)?
+            .into_iter()
+            .map(to_domain)
+            .collect())
+    }
+}
@@ -0,0 +1,148 @@
+use crate::db::entities::login_attempt::{Column, Entity as LoginAttemptEntity, Model};
+use application::ports::login_attempt_store::{
+    AttemptSubject, LoginAttemptStore, LoginAttemptStoreError, LoginAttempts,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+
+pub struct PostgresLoginAttemptStore {
+    db: DatabaseConnection,
+}
+
+impl PostgresLoginAttemptStore {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn storage_error(err: DbErr) -> LoginAttemptStoreError {
+    LoginAttemptStoreError::StorageError(err.to_string())
+}
+
+fn to_domain(model: Model) -> Result<LoginAttempts, LoginAttemptStoreError> {
+    let subject = AttemptSubject::from_key(&model.subject).ok_or_else(|| {
+        LoginAttemptStoreError::StorageError(format!("Invalid subject key: {}", model.subject))
+    })?;
+
+    Ok(LoginAttempts {
+        subject,
+        failed_attempts: model.failed_attempts.max(0) as u32,
+        last_failed_at: model.last_failed_at.into(),
+        blocked_until: model.blocked_until.map(Into::into),
+        locked_until: model.locked_until.map(Into::into),
+        unlock_token_hash: model.unlock_token_hash,
+    })
+}
+
+#[async_trait]
+impl LoginAttemptStore for PostgresLoginAttemptStore {
+    async fn find(
+        &self,
+        subject: &AttemptSubject,
+    ) -> Result<Option<LoginAttempts>, LoginAttemptStoreError> {
+        LoginAttemptEntity::find_by_id(subject.key())
+            .one(&self.db)
+            .await
+            .map_err(storage_error)?
+            .map(to_domain)
+            .transpose()
+    }
+
+    async fn record_failure(
+        &self,
+        subject: &AttemptSubject,
+        at: DateTime<Utc>,
+        window_start: DateTime<Utc>,
+    ) -> Result<LoginAttempts, LoginAttemptStoreError> {
+        // A single upsert so concurrent failures cannot overwrite each other's increments.
+        let statement = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"INSERT INTO login_attempt (subject, failed_attempts, last_failed_at)
+               VALUES ($1, 1, $2)
+               ON CONFLICT (subject) DO UPDATE SET
+                 failed_attempts = CASE
+                   WHEN login_attempt.last_failed_at < $3 THEN 1
+                   ELSE login_attempt.failed_attempts + 1
+                 END,
+                 last_failed_at = EXCLUDED.last_failed_at
+               RETURNING *"#,
+            [subject.key().into(), at.into(), window_start.into()],
+        );
+
+        let model = LoginAttemptEntity::find()
+            .from_raw_sql(statement)
+            .one(&self.db)
+            .await
+            .map_err(storage_error)?
+            .ok_or_else(|| {
+                LoginAttemptStoreError::StorageError("Upsert returned no row".to_string())
+            })?;
+
+        to_domain(model)
+    }
+
+    async fn save(&self, attempts: &LoginAttempts) -> Result<(), LoginAttemptStoreError> {
+        LoginAttemptEntity::update_many()
+            .col_expr(
+                Column::BlockedUntil,
+                Expr::value(attempts.blocked_until.map(DateTimeWithTimeZone::from)),
+            )
+            .col_expr(
+                Column::LockedUntil,
+                Expr::value(attempts.locked_until.map(DateTimeWithTimeZone::from)),
+            )
+            .col_expr(
+                Column::UnlockTokenHash,
+                Expr::value(attempts.unlock_token_hash.clone()),
+            )
+            .filter(Column::Subject.eq(attempts.subject.key()))
+            .exec(&self.db)
+            .await
+            .map_err(storage_error)?;
+
+        Ok(())
+    }
+
+    async fn clear(&self, subject: &AttemptSubject) -> Result<bool, LoginAttemptStoreError> {
+        let result = LoginAttemptEntity::delete_by_id(subject.key())
+            .exec(&self.db)
+            .await
+            .map_err(storage_error)?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn find_by_unlock_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<LoginAttempts>, LoginAttemptStoreError> {
+        LoginAttemptEntity::find()
+            .filter(Column::UnlockTokenHash.eq(token_hash))
+            .one(&self.db)
+            .await
+            .map_err(storage_error)?
+            .map(to_domain)
+            .transpose()
+    }
+
+    async fn find_restricted(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<LoginAttempts>, LoginAttemptStoreError> {
+        LoginAttemptEntity::find()
+            .filter(
+                Condition::any()
+                    .add(Column::BlockedUntil.gt(now))
+                    .add(Column::LockedUntil.gt(now)),
+            )
+            .all(&self.db)
+            .await
+            .map_err(storage_error)?
+            .into_iter()
+            .map(to_domain)
+            .collect()
+    }
+}
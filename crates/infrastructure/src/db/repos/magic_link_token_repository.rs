@@ -0,0 +1,71 @@
+use crate::db::entities::magic_link_token::{ActiveModel, Column, Entity as TokenEntity};
+use application::ports::TokenRepositoryError;
+use application::ports::magic_link_token_repository::{MagicLinkToken, MagicLinkTokenRepository};
+use async_trait::async_trait;
+use sea_orm::*;
+use uuid::Uuid;
+
+pub struct PostgresMagicLinkTokenRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresMagicLinkTokenRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl MagicLinkTokenRepository for PostgresMagicLinkTokenRepository {
+    async fn create(&self, token: &MagicLinkToken) -> Result<(), TokenRepositoryError> {
+        let active_model = ActiveModel {
+            token_hash: Set(token.token_hash.clone()),
+            user_id: Set(token.user_id),
+            created_at: Set(token.created_at.into()),
+            expires_at: Set(token.expires_at.into()),
+        };
+
+        TokenEntity::insert(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(|e| TokenRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<MagicLinkToken>, TokenRepositoryError> {
+        let db_token = TokenEntity::find_by_id(token_hash)
+            .one(&self.db)
+            .await
+            .map_err(|e| TokenRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(db_token.map(|t| MagicLinkToken {
+            token_hash: t.token_hash,
+            user_id: t.user_id,
+            created_at: t.created_at.into(),
+            expires_at: t.expires_at.into(),
+        }))
+    }
+
+    async fn delete_by_token_hash(&self, token_hash: &str) -> Result<bool, TokenRepositoryError> {
+        let result = TokenEntity::delete_by_id(token_hash)
+            .exec(&self.db)
+            .await
+            .map_err(|e| TokenRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn delete_by_user_id(&self, user_id: &Uuid) -> Result<(), TokenRepositoryError> {
+        TokenEntity::delete_many()
+            .filter(Column::UserId.eq(*user_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| TokenRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
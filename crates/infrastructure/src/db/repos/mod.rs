@@ -1,9 +1,39 @@
 // Repository implementations using SeaORM
 
+pub mod account_restore_token_repository;
+pub mod api_key_repository;
+pub mod audit_log_repository;
+pub mod data_export_repository;
 pub mod email_verification_token_repository;
+pub mod event_outbox_repository;
+pub mod linked_identity_repository;
+pub mod login_attempt_store;
+pub mod magic_link_token_repository;
+pub mod oidc_flow_store;
+pub mod passkey_ceremony_store;
+pub mod passkey_repository;
 pub mod password_reset_token_repository;
+pub mod session_repository;
+pub mod two_factor_repository;
+pub mod user_erasure_repository;
 pub mod user_repository;
+pub mod webhook_repository;
 
+pub use account_restore_token_repository::PostgresAccountRestoreTokenRepository;
+pub use api_key_repository::PostgresApiKeyRepository;
+pub use audit_log_repository::PostgresAuditLog;
+pub use data_export_repository::PostgresDataExportRepository;
 pub use email_verification_token_repository::PostgresEmailVerificationTokenRepository;
+pub use event_outbox_repository::PostgresEventOutbox;
+pub use linked_identity_repository::PostgresLinkedIdentityRepository;
+pub use login_attempt_store::PostgresLoginAttemptStore;
+pub use magic_link_token_repository::PostgresMagicLinkTokenRepository;
+pub use oidc_flow_store::PostgresOidcFlowStore;
+pub use passkey_ceremony_store::PostgresPasskeyCeremonyStore;
+pub use passkey_repository::PostgresPasskeyRepository;
 pub use password_reset_token_repository::PostgresPasswordResetTokenRepository;
+pub use session_repository::PostgresSessionRepository;
+pub use two_factor_repository::PostgresTwoFactorRepository;
+pub use user_erasure_repository::PostgresUserErasureRepository;
 pub use user_repository::PostgresUserRepository;
+pub use webhook_repository::PostgresWebhookRepository;
@@ -0,0 +1,137 @@
+use crate::db::entities::oidc_login_request::{
+    self, ActiveModel as LoginActiveModel, Entity as LoginRequestEntity,
+};
+use crate::db::entities::pending_identity_link::{
+    self, ActiveModel as LinkActiveModel, Entity as PendingLinkEntity,
+};
+use application::ports::oidc_flow_store::{
+    OidcFlowStore, OidcFlowStoreError, PendingIdentityLink, PendingOidcLogin,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::*;
+
+pub struct PostgresOidcFlowStore {
+    db: DatabaseConnection,
+}
+
+impl PostgresOidcFlowStore {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn storage_error(err: DbErr) -> OidcFlowStoreError {
+    OidcFlowStoreError::StorageError(err.to_string())
+}
+
+#[async_trait]
+impl OidcFlowStore for PostgresOidcFlowStore {
+    async fn save_login(&self, login: &PendingOidcLogin) -> Result<(), OidcFlowStoreError> {
+        // Abandoned logins are swept whenever a new one starts.
+        LoginRequestEntity::delete_many()
+            .filter(oidc_login_request::Column::ExpiresAt.lt(Utc::now()))
+            .exec(&self.db)
+            .await
+            .map_err(storage_error)?;
+
+        let active_model = LoginActiveModel {
+            state: Set(login.state.clone()),
+            provider: Set(login.provider.clone()),
+            nonce: Set(login.nonce.clone()),
+            code_verifier: Set(login.code_verifier.clone()),
+            expires_at: Set(login.expires_at.into()),
+        };
+
+        LoginRequestEntity::insert(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(storage_error)?;
+
+        Ok(())
+    }
+
+    async fn take_login(
+        &self,
+        state: &str,
+    ) -> Result<Option<PendingOidcLogin>, OidcFlowStoreError> {
+        let Some(model) = LoginRequestEntity::find_by_id(state)
+            .one(&self.db)
+            .await
+            .map_err(storage_error)?
+        else {
+            return Ok(None);
+        };
+
+        // Only the request that actually deletes the row may redeem the state.
+        let result = LoginRequestEntity::delete_by_id(state)
+            .exec(&self.db)
+            .await
+            .map_err(storage_error)?;
+        if result.rows_affected == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(PendingOidcLogin {
+            state: model.state,
+            provider: model.provider,
+            nonce: model.nonce,
+            code_verifier: model.code_verifier,
+            expires_at: model.expires_at.into(),
+        }))
+    }
+
+    async fn save_link(&self, link: &PendingIdentityLink) -> Result<(), OidcFlowStoreError> {
+        PendingLinkEntity::delete_many()
+            .filter(pending_identity_link::Column::ExpiresAt.lt(Utc::now()))
+            .exec(&self.db)
+            .await
+            .map_err(storage_error)?;
+
+        let active_model = LinkActiveModel {
+            token_hash: Set(link.token_hash.clone()),
+            user_id: Set(link.user_id),
+            provider: Set(link.provider.clone()),
+            subject: Set(link.subject.clone()),
+            email: Set(link.email.clone()),
+            expires_at: Set(link.expires_at.into()),
+        };
+
+        PendingLinkEntity::insert(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(storage_error)?;
+
+        Ok(())
+    }
+
+    async fn take_link(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PendingIdentityLink>, OidcFlowStoreError> {
+        let Some(model) = PendingLinkEntity::find_by_id(token_hash)
+            .one(&self.db)
+            .await
+            .map_err(storage_error)?
+        else {
+            return Ok(None);
+        };
+
+        let result = PendingLinkEntity::delete_by_id(token_hash)
+            .exec(&self.db)
+            .await
+            .map_err(storage_error)?;
+        if result.rows_affected == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(PendingIdentityLink {
+            token_hash: model.token_hash,
+            user_id: model.user_id,
+            provider: model.provider,
+            subject: model.subject,
+            email: model.email,
+            expires_at: model.expires_at.into(),
+        }))
+    }
+}
@@ -0,0 +1,86 @@
+use crate::db::entities::passkey_ceremony::{
+    ActiveModel, Column, Entity as PasskeyCeremonyEntity, Model,
+};
+use application::ports::passkey_ceremony_store::{
+    PasskeyCeremony, PasskeyCeremonyKind, PasskeyCeremonyStore, PasskeyCeremonyStoreError,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::*;
+use uuid::Uuid;
+
+pub struct PostgresPasskeyCeremonyStore {
+    db: DatabaseConnection,
+}
+
+impl PostgresPasskeyCeremonyStore {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn storage_error(err: DbErr) -> PasskeyCeremonyStoreError {
+    PasskeyCeremonyStoreError::StorageError(err.to_string())
+}
+
+fn to_domain(model: Model) -> Result<PasskeyCeremony, PasskeyCeremonyStoreError> {
+    let kind = PasskeyCeremonyKind::parse(&model.kind).ok_or_else(|| {
+        PasskeyCeremonyStoreError::StorageError(format!("Invalid ceremony kind: {}", model.kind))
+    })?;
+
+    Ok(PasskeyCeremony {
+        id: model.id,
+        kind,
+        user_id: model.user_id,
+        challenge: model.challenge,
+        expires_at: model.expires_at.into(),
+    })
+}
+
+#[async_trait]
+impl PasskeyCeremonyStore for PostgresPasskeyCeremonyStore {
+    async fn save(&self, ceremony: &PasskeyCeremony) -> Result<(), PasskeyCeremonyStoreError> {
+        // Abandoned ceremonies are swept whenever a new one starts.
+        PasskeyCeremonyEntity::delete_many()
+            .filter(Column::ExpiresAt.lt(Utc::now()))
+            .exec(&self.db)
+            .await
+            .map_err(storage_error)?;
+
+        let active_model = ActiveModel {
+            id: Set(ceremony.id),
+            kind: Set(ceremony.kind.as_str().to_string()),
+            user_id: Set(ceremony.user_id),
+            challenge: Set(ceremony.challenge.clone()),
+            expires_at: Set(ceremony.expires_at.into()),
+        };
+
+        PasskeyCeremonyEntity::insert(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(storage_error)?;
+
+        Ok(())
+    }
+
+    async fn take(&self, id: Uuid) -> Result<Option<PasskeyCeremony>, PasskeyCeremonyStoreError> {
+        let Some(model) = PasskeyCeremonyEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(storage_error)?
+        else {
+            return Ok(None);
+        };
+
+        // Only the request that actually deletes the row may use the challenge.
+        let result = PasskeyCeremonyEntity::delete_by_id(id)
+            .exec(&self.db)
+            .await
+            .map_err(storage_error)?;
+        if result.rows_affected == 0 {
+            return Ok(None);
+        }
+
+        to_domain(model).map(Some)
+    }
+}
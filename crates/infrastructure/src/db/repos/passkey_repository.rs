@@ -0,0 +1,118 @@
+use crate::db::entities::passkey::{ActiveModel, Column, Entity as PasskeyEntity, Model};
+use application::ports::passkey_repository::{Passkey, PasskeyRepository, PasskeyRepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+use uuid::Uuid;
+
+pub struct PostgresPasskeyRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresPasskeyRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(err: DbErr) -> PasskeyRepositoryError {
+    PasskeyRepositoryError::DatabaseError(err.to_string())
+}
+
+fn to_domain(model: Model) -> Passkey {
+    Passkey {
+        id: model.id,
+        user_id: model.user_id,
+        credential_id: model.credential_id,
+        public_key: model.public_key,
+        sign_count: model.sign_count.clamp(0, u32::MAX as i64) as u32,
+        name: model.name,
+        created_at: model.created_at.into(),
+        last_used_at: model.last_used_at.map(Into::into),
+    }
+}
+
+#[async_trait]
+impl PasskeyRepository for PostgresPasskeyRepository {
+    async fn create(&self, passkey: &Passkey) -> Result<(), PasskeyRepositoryError> {
+        let active_model = ActiveModel {
+            id: Set(passkey.id),
+            user_id: Set(passkey.user_id),
+            credential_id: Set(passkey.credential_id.clone()),
+            public_key: Set(passkey.public_key.clone()),
+            sign_count: Set(passkey.sign_count as i64),
+            name: Set(passkey.name.clone()),
+            created_at: Set(passkey.created_at.into()),
+            last_used_at: Set(passkey.last_used_at.map(DateTimeWithTimeZone::from)),
+        };
+
+        PasskeyEntity::insert(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(|err| match err.sql_err() {
+                Some(SqlErr::UniqueConstraintViolation(_)) => {
+                    PasskeyRepositoryError::DuplicateCredential
+                }
+                _ => database_error(err),
+            })?;
+
+        Ok(())
+    }
+
+    async fn find_by_credential_id(
+        &self,
+        credential_id: &[u8],
+    ) -> Result<Option<Passkey>, PasskeyRepositoryError> {
+        Ok(PasskeyEntity::find()
+            .filter(Column::CredentialId.eq(credential_id.to_vec()))
+            .one(&self.db)
+            .await
+            .map_err(database_error)?
+            .map(to_domain))
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Passkey>, PasskeyRepositoryError> {
+        Ok(PasskeyEntity::find()
+            .filter(Column::UserId.eq(user_id))
+            .order_by_asc(Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(database_error)?
+            .into_iter()
+            .map(to_domain)
+            .collect())
+    }
+
+    async fn record_use(
+        &self,
+        id: Uuid,
+        sign_count: u32,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), PasskeyRepositoryError> {
+        PasskeyEntity::update_many()
+            .col_expr(Column::SignCount, Expr::value(sign_count as i64))
+            .col_expr(
+                Column::LastUsedAt,
+                Expr::value(DateTimeWithTimeZone::from(used_at)),
+            )
+            .filter(Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, PasskeyRepositoryError> {
+        let result = PasskeyEntity::delete_many()
+            .filter(Column::Id.eq(id))
+            .filter(Column::UserId.eq(user_id))
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
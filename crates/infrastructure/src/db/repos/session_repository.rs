@@ -0,0 +1,167 @@
+use crate::db::entities::user_session::{ActiveModel, Column, Entity as SessionEntity, Model};
+use application::ports::session_repository::{Session, SessionRepository, SessionRepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+use uuid::Uuid;
+
+/// User agents are client-controlled; longer ones are cut rather than stored whole.
+const MAX_USER_AGENT_LENGTH: usize = 512;
+
+pub struct PostgresSessionRepository<C = DatabaseConnection> {
+    db: C,
+}
+
+impl<C> PostgresSessionRepository<C> {
+    pub fn new(db: C) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(err: DbErr) -> SessionRepositoryError {
+    SessionRepositoryError::DatabaseError(err.to_string())
+}
+
+fn to_domain(model: Model) -> Session {
+    Session {
+        id: model.id,
+        user_id: model.user_id,
+        user_agent: model.user_agent,
+        ip_address: model.ip_address.and_then(|ip| ip.parse().ok()),
+        created_at: model.created_at.into(),
+        last_seen_at: model.last_seen_at.into(),
+        expires_at: model.expires_at.into(),
+        revoked_at: model.revoked_at.map(Into::into),
+    }
+}
+
+fn active(user_id: Uuid, now: DateTime<Utc>) -> Condition {
+    Condition::all()
+        .add(Column::UserId.eq(user_id))
+        .add(Column::RevokedAt.is_null())
+        .add(Column::ExpiresAt.gt(DateTimeWithTimeZone::from(now)))
+}
+
+#[async_trait]
+impl<C: ConnectionTrait + Send> SessionRepository for PostgresSessionRepository<C> {
+    async fn create(&self, session: &Session) -> Result<(), SessionRepositoryError> {
+        // Expired sessions are only kept until the user's next login.
+        SessionEntity::delete_many()
+            .filter(Column::UserId.eq(session.user_id))
+            .filter(Column::ExpiresAt.lte(DateTimeWithTimeZone::from(session.created_at)))
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        let active_model = ActiveModel {
+            id: Set(session.id),
+            user_id: Set(session.user_id),
+            user_agent: Set(session
+                .user_agent
+                .as_ref()
+                .map(|ua| ua.chars().take(MAX_USER_AGENT_LENGTH).collect())),
+            ip_address: Set(session.ip_address.map(|ip| ip.to_string())),
+            created_at: Set(session.created_at.into()),
+            last_seen_at: Set(session.last_seen_at.into()),
+            expires_at: Set(session.expires_at.into()),
+            revoked_at: Set(session.revoked_at.map(DateTimeWithTimeZone::from)),
+        };
+
+        SessionEntity::insert(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Session>, SessionRepositoryError> {
+        Ok(SessionEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(database_error)?
+            .map(to_domain))
+    }
+
+    async fn find_active_by_user_id(
+        &self,
+        user_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Session>, SessionRepositoryError> {
+        Ok(SessionEntity::find()
+            .filter(active(user_id, now))
+            .order_by_desc(Column::LastSeenAt)
+            .all(&self.db)
+            .await
+            .map_err(database_error)?
+            .into_iter()
+            .map(to_domain)
+            .collect())
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Session>, SessionRepositoryError> {
+        Ok(SessionEntity::find()
+            .filter(Column::UserId.eq(user_id))
+            .order_by_desc(Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(database_error)?
+            .into_iter()
+            .map(to_domain)
+            .collect())
+    }
+
+    async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>) -> Result<(), SessionRepositoryError> {
+        SessionEntity::update_many()
+            .col_expr(
+                Column::LastSeenAt,
+                Expr::value(DateTimeWithTimeZone::from(seen_at)),
+            )
+            .filter(Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn revoke(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<bool, SessionRepositoryError> {
+        let result = SessionEntity::update_many()
+            .col_expr(
+                Column::RevokedAt,
+                Expr::value(DateTimeWithTimeZone::from(now)),
+            )
+            .filter(active(user_id, now))
+            .filter(Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn revoke_all(
+        &self,
+        user_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<u64, SessionRepositoryError> {
+        let result = SessionEntity::update_many()
+            .col_expr(
+                Column::RevokedAt,
+                Expr::value(DateTimeWithTimeZone::from(now)),
+            )
+            .filter(active(user_id, now))
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(result.rows_affected)
+    }
+}
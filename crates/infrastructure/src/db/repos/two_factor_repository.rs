@@ -0,0 +1,146 @@
+use crate::db::entities::totp_recovery_code::{
+    ActiveModel as RecoveryCodeActiveModel, Column as RecoveryCodeColumn,
+    Entity as RecoveryCodeEntity,
+};
+use crate::db::entities::user_totp::{ActiveModel, Column, Entity as UserTotpEntity, Model};
+use application::ports::two_factor_repository::{
+    TotpEnrollment, TwoFactorRepository, TwoFactorRepositoryError,
+};
+use async_trait::async_trait;
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::*;
+use uuid::Uuid;
+
+pub struct PostgresTwoFactorRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresTwoFactorRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(err: DbErr) -> TwoFactorRepositoryError {
+    TwoFactorRepositoryError::DatabaseError(err.to_string())
+}
+
+fn to_domain(model: Model) -> TotpEnrollment {
+    TotpEnrollment {
+        user_id: model.user_id,
+        secret: model.secret,
+        confirmed_at: model.confirmed_at.map(Into::into),
+        last_used_step: model.last_used_step.map(|step| step.max(0) as u64),
+        created_at: model.created_at.into(),
+    }
+}
+
+#[async_trait]
+impl TwoFactorRepository for PostgresTwoFactorRepository {
+    async fn find(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Option<TotpEnrollment>, TwoFactorRepositoryError> {
+        Ok(UserTotpEntity::find_by_id(user_id)
+            .one(&self.db)
+            .await
+            .map_err(database_error)?
+            .map(to_domain))
+    }
+
+    async fn save(&self, enrollment: &TotpEnrollment) -> Result<(), TwoFactorRepositoryError> {
+        let active_model = ActiveModel {
+            user_id: Set(enrollment.user_id),
+            secret: Set(enrollment.secret.clone()),
+            confirmed_at: Set(enrollment.confirmed_at.map(DateTimeWithTimeZone::from)),
+            last_used_step: Set(enrollment.last_used_step.map(|step| step as i64)),
+            created_at: Set(enrollment.created_at.into()),
+        };
+
+        UserTotpEntity::insert(active_model)
+            .on_conflict(
+                OnConflict::column(Column::UserId)
+                    .update_columns([
+                        Column::Secret,
+                        Column::ConfirmedAt,
+                        Column::LastUsedStep,
+                        Column::CreatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn mark_step_used(
+        &self,
+        user_id: Uuid,
+        step: u64,
+    ) -> Result<bool, TwoFactorRepositoryError> {
+        // Conditional update, so two requests with the same code cannot both succeed.
+        let result = UserTotpEntity::update_many()
+            .col_expr(Column::LastUsedStep, Expr::value(step as i64))
+            .filter(Column::UserId.eq(user_id))
+            .filter(
+                Condition::any()
+                    .add(Column::LastUsedStep.is_null())
+                    .add(Column::LastUsedStep.lt(step as i64)),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    async fn replace_recovery_codes(
+        &self,
+        user_id: Uuid,
+        code_hashes: &[String],
+    ) -> Result<(), TwoFactorRepositoryError> {
+        let txn = self.db.begin().await.map_err(database_error)?;
+
+        RecoveryCodeEntity::delete_many()
+            .filter(RecoveryCodeColumn::UserId.eq(user_id))
+            .exec(&txn)
+            .await
+            .map_err(database_error)?;
+
+        if !code_hashes.is_empty() {
+            let models = code_hashes.iter().map(|hash| RecoveryCodeActiveModel {
+                id: Set(Uuid::new_v4()),
+                user_id: Set(user_id),
+                code_hash: Set(hash.clone()),
+                used_at: Set(None),
+            });
+            RecoveryCodeEntity::insert_many(models)
+                .exec(&txn)
+                .await
+                .map_err(database_error)?;
+        }
+
+        txn.commit().await.map_err(database_error)
+    }
+
+    async fn use_recovery_code(
+        &self,
+        user_id: Uuid,
+        code_hash: &str,
+    ) -> Result<bool, TwoFactorRepositoryError> {
+        let now: DateTimeWithTimeZone = chrono::Utc::now().into();
+        let result = RecoveryCodeEntity::update_many()
+            .col_expr(RecoveryCodeColumn::UsedAt, Expr::value(now))
+            .filter(RecoveryCodeColumn::UserId.eq(user_id))
+            .filter(RecoveryCodeColumn::CodeHash.eq(code_hash))
+            .filter(RecoveryCodeColumn::UsedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,120 @@
+use crate::db::entities::user::{Column as UserColumn, Entity as UserEntity};
+use crate::db::entities::user_tombstone::{
+    ActiveModel as TombstoneActiveModel, Entity as TombstoneEntity,
+};
+use application::ports::user_erasure_repository::{
+    UserErasureRepository, UserErasureRepositoryError,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::*;
+use uuid::Uuid;
+
+/// Keeps who did what to whom, but drops what was changed and where from. The
+/// audit_log trigger permits exactly this update and nothing else.
+const REDACT_AUDIT_LOG: &str = r#"
+UPDATE audit_log
+SET changes = CASE WHEN target_id = $1 THEN audit_log_redacted_changes(changes) ELSE changes END,
+    ip_address = CASE WHEN actor_id = $1 THEN NULL ELSE ip_address END
+WHERE target_id = $1 OR actor_id = $1
+"#;
+
+/// Outbox events outlive the user; only the addresses they carry are removed.
+const REDACT_EVENT_OUTBOX: &str = r#"
+UPDATE event_outbox
+SET payload = payload - 'email' - 'previous_email' - 'current_email'
+WHERE aggregate_id = $1
+"#;
+
+pub struct PostgresUserErasureRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresUserErasureRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(err: DbErr) -> UserErasureRepositoryError {
+    UserErasureRepositoryError::DatabaseError(err.to_string())
+}
+
+#[async_trait]
+impl UserErasureRepository for PostgresUserErasureRepository {
+    async fn find_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<Uuid>, UserErasureRepositoryError> {
+        UserEntity::find()
+            .select_only()
+            .column(UserColumn::Id)
+            .filter(UserColumn::DeletedAt.lt(DateTimeWithTimeZone::from(cutoff)))
+            .order_by_asc(UserColumn::DeletedAt)
+            .limit(limit)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(database_error)
+    }
+
+    async fn erase(
+        &self,
+        user_id: Uuid,
+        deleted_before: DateTime<Utc>,
+        erased_at: DateTime<Utc>,
+    ) -> Result<bool, UserErasureRepositoryError> {
+        let txn = self.db.begin().await.map_err(database_error)?;
+
+        // The row lock keeps a concurrent restore from racing the erasure.
+        let Some(user) = UserEntity::find_by_id(user_id)
+            .filter(UserColumn::DeletedAt.lt(DateTimeWithTimeZone::from(deleted_before)))
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(database_error)?
+        else {
+            return Ok(false);
+        };
+        let Some(deleted_at) = user.deleted_at else {
+            return Ok(false);
+        };
+
+        TombstoneEntity::insert(TombstoneActiveModel {
+            user_id: Set(user_id),
+            deleted_at: Set(deleted_at),
+            erased_at: Set(erased_at.into()),
+        })
+        .exec(&txn)
+        .await
+        .map_err(database_error)?;
+
+        txn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            REDACT_AUDIT_LOG,
+            [user_id.into()],
+        ))
+        .await
+        .map_err(database_error)?;
+
+        txn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            REDACT_EVENT_OUTBOX,
+            [user_id.into()],
+        ))
+        .await
+        .map_err(database_error)?;
+
+        // Tokens, sessions, passkeys, identities, API keys and exports cascade.
+        UserEntity::delete_by_id(user_id)
+            .exec(&txn)
+            .await
+            .map_err(database_error)?;
+
+        txn.commit().await.map_err(database_error)?;
+
+        Ok(true)
+    }
+}
@@ -1,72 +1,97 @@
-use crate::db::entities::user::{ActiveModel as UserActiveModel, Entity as UserEntity};
+use crate::db::entities::user::{
+    ActiveModel as UserActiveModel, Column as UserColumn, Entity as UserEntity,
+};
 use crate::db::mapper::UserMapper;
 use application::ports::user_repository::{UserRepository, UserRepositoryError};
 use async_trait::async_trait;
 use domain_users::User;
+use sea_orm::sea_query::{Expr, Func, SimpleExpr};
 use sea_orm::*;
 use uuid::Uuid;
 
-pub struct PostgresUserRepository {
-    db: DatabaseConnection,
+/// Works on a pooled connection by default, or inside a transaction of a
+/// [`PostgresUnitOfWork`](crate::db::PostgresUnitOfWork).
+pub struct PostgresUserRepository<C = DatabaseConnection> {
+    db: C,
 }
 
-impl PostgresUserRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
+impl<C> PostgresUserRepository<C> {
+    pub fn new(db: C) -> Self {
         Self { db }
     }
 }
 
+fn to_active_model(user: &User, version: i32) -> UserActiveModel {
+    let user = user.snapshot();
+    UserActiveModel {
+        id: Set(user.id),
+        first_name: Set(user.first_name.into()),
+        last_name: Set(user.last_name.into()),
+        email: Set(user.email.into_string()),
+        phone_number: Set(user.phone_number.map(Into::into)),
+        password_hash: Set(user.password_hash.into()),
+        birth_date: Set(user.birth_date),
+        is_email_verified: Set(user.is_email_verified),
+        role: Set(user.role.to_string()),
+        locale: Set(user.locale),
+        version: Set(version),
+        deleted_at: Set(user.deleted_at.map(|dt| dt.into())),
+    }
+}
+
+/// Emails are unique among users that are not deleted, so both inserting a user
+/// and restoring one can hit the index.
+fn map_write_error(err: DbErr, user: &User) -> UserRepositoryError {
+    let err_msg = err.to_string();
+    if err_msg.contains("duplicate key value") || err_msg.contains("UNIQUE constraint failed") {
+        UserRepositoryError::AlreadyExists(user.email().to_string())
+    } else {
+        UserRepositoryError::DatabaseError(err_msg)
+    }
+}
+
+/// Compares like the `lower(email)` unique index, so lookups can use it.
+fn email_matches(email: &str) -> SimpleExpr {
+    Expr::expr(Func::lower(Expr::col(UserColumn::Email))).eq(email.to_lowercase())
+}
+
 #[async_trait]
-impl UserRepository for PostgresUserRepository {
+impl<C: ConnectionTrait + Send> UserRepository for PostgresUserRepository<C> {
     async fn create(&self, user: &User) -> Result<(), UserRepositoryError> {
-        let active_model = UserActiveModel {
-            id: Set(user.id),
-            first_name: Set(user.first_name.clone()),
-            last_name: Set(user.last_name.clone()),
-            email: Set(user.email.clone()),
-            phone_number: Set(user.phone_number.clone()),
-            password_hash: Set(user.password_hash.clone()),
-            birth_date: Set(user.birth_date),
-            is_email_verified: Set(user.is_email_verified),
-            role: Set(user.role.to_string()),
-            deleted_at: Set(user.deleted_at.map(|dt| dt.into())),
-        };
+        let active_model = to_active_model(user, user.version());
 
         UserEntity::insert(active_model)
             .exec(&self.db)
             .await
-            .map_err(|e| {
-                let err_msg = e.to_string();
-                if err_msg.contains("duplicate key value")
-                    || err_msg.contains("UNIQUE constraint failed")
-                {
-                    UserRepositoryError::AlreadyExists(user.email.clone())
-                } else {
-                    UserRepositoryError::DatabaseError(err_msg)
-                }
-            })?;
+            .map_err(|e| map_write_error(e, user))?;
 
         Ok(())
     }
 
     async fn update(&self, user: &User) -> Result<(), UserRepositoryError> {
-        let active_model = UserActiveModel {
-            id: Set(user.id),
-            first_name: Set(user.first_name.clone()),
-            last_name: Set(user.last_name.clone()),
-            email: Set(user.email.clone()),
-            phone_number: Set(user.phone_number.clone()),
-            password_hash: Set(user.password_hash.clone()),
-            birth_date: Set(user.birth_date),
-            is_email_verified: Set(user.is_email_verified),
-            role: Set(user.role.to_string()),
-            deleted_at: Set(user.deleted_at.map(|dt| dt.into())),
-        };
-
-        active_model
-            .update(&self.db)
+        let active_model = to_active_model(user, user.version() + 1);
+
+        // Optimistic locking: only write if nobody else bumped the version since we read it.
+        let result = UserEntity::update_many()
+            .set(active_model)
+            .filter(UserColumn::Id.eq(user.id()))
+            .filter(UserColumn::Version.eq(user.version()))
+            .exec(&self.db)
             .await
-            .map_err(|e| UserRepositoryError::DatabaseError(e.to_string()))?;
+            .map_err(|e| map_write_error(e, user))?;
+
+        if result.rows_affected == 0 {
+            let exists = UserEntity::find_by_id(user.id())
+                .one(&self.db)
+                .await
+                .map_err(|e| UserRepositoryError::DatabaseError(e.to_string()))?
+                .is_some();
+            return Err(if exists {
+                UserRepositoryError::VersionConflict(user.id().to_string())
+            } else {
+                UserRepositoryError::NotFound(user.id().to_string())
+            });
+        }
 
         Ok(())
     }
@@ -81,8 +106,10 @@ impl UserRepository for PostgresUserRepository {
     }
 
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, UserRepositoryError> {
+        // Deleted accounts may share the email; NULLs sort first, so the active one wins.
         let db_user = UserEntity::find()
-            .filter(crate::db::entities::user::Column::Email.eq(email))
+            .filter(email_matches(email))
+            .order_by_desc(crate::db::entities::user::Column::DeletedAt)
             .one(&self.db)
             .await
             .map_err(|e| UserRepositoryError::DatabaseError(e.to_string()))?;
@@ -92,7 +119,7 @@ impl UserRepository for PostgresUserRepository {
 
     async fn find_active_by_email(&self, email: &str) -> Result<Option<User>, UserRepositoryError> {
         let db_user = UserEntity::find()
-            .filter(crate::db::entities::user::Column::Email.eq(email))
+            .filter(email_matches(email))
             .filter(crate::db::entities::user::Column::DeletedAt.is_null())
             .one(&self.db)
             .await
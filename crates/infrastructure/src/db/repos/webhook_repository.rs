@@ -0,0 +1,277 @@
+use crate::db::entities::event_outbox::{Column as EventColumn, Entity as EventOutboxEntity};
+use crate::db::entities::webhook_delivery::{
+    ActiveModel as DeliveryActiveModel, Column as DeliveryColumn, Entity as WebhookDeliveryEntity,
+    Model as DeliveryModel,
+};
+use crate::db::entities::webhook_subscription::{
+    ActiveModel as SubscriptionActiveModel, Column as SubscriptionColumn,
+    Entity as WebhookSubscriptionEntity, Model as SubscriptionModel,
+};
+use crate::db::repos::event_outbox_repository;
+use application::ports::event_outbox::OutboxEvent;
+use application::ports::webhook_repository::{
+    WebhookDelivery, WebhookDeliveryStatus, WebhookRepository, WebhookRepositoryError,
+    WebhookSubscription,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::*;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub struct PostgresWebhookRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresWebhookRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Attaches the outbox event each delivery sends.
+    async fn with_events(
+        &self,
+        models: Vec<DeliveryModel>,
+    ) -> Result<Vec<WebhookDelivery>, WebhookRepositoryError> {
+        let event_ids: Vec<Uuid> = models.iter().map(|model| model.event_id).collect();
+        let mut events: HashMap<Uuid, OutboxEvent> = EventOutboxEntity::find()
+            .filter(EventColumn::Id.is_in(event_ids))
+            .all(&self.db)
+            .await
+            .map_err(database_error)?
+            .into_iter()
+            .map(|model| (model.id, event_outbox_repository::to_domain(model)))
+            .collect();
+
+        models
+            .into_iter()
+            .filter_map(|model| {
+                // Removed events take their deliveries with them.
+                let event = events.remove(&model.event_id)?;
+                Some(delivery_to_domain(model, event))
+            })
+            .collect()
+    }
+}
+
+fn database_error(err: DbErr) -> WebhookRepositoryError {
+    WebhookRepositoryError::DatabaseError(err.to_string())
+}
+
+fn subscription_to_domain(model: SubscriptionModel) -> WebhookSubscription {
+    WebhookSubscription {
+        id: model.id,
+        url: model.url,
+        events: model
+            .events
+            .split_whitespace()
+            .map(str::to_string)
+            .collect(),
+        secret: model.secret,
+        created_by: model.created_by,
+        created_at: model.created_at.into(),
+    }
+}
+
+fn delivery_to_domain(
+    model: DeliveryModel,
+    event: OutboxEvent,
+) -> Result<WebhookDelivery, WebhookRepositoryError> {
+    let status = WebhookDeliveryStatus::parse(&model.status).ok_or_else(|| {
+        WebhookRepositoryError::DatabaseError(format!(
+            "Unknown webhook delivery status: {}",
+            model.status
+        ))
+    })?;
+
+    Ok(WebhookDelivery {
+        id: model.id,
+        subscription_id: model.subscription_id,
+        event,
+        status,
+        attempts: model.attempts.max(0) as u32,
+        next_attempt_at: model.next_attempt_at.map(Into::into),
+        last_attempt_at: model.last_attempt_at.map(Into::into),
+        response_status: model.response_status.map(|status| status as u16),
+        last_error: model.last_error,
+        created_at: model.created_at.into(),
+    })
+}
+
+/// The columns that change over the life of a delivery.
+fn delivery_progress(delivery: &WebhookDelivery) -> DeliveryActiveModel {
+    DeliveryActiveModel {
+        status: Set(delivery.status.as_str().to_string()),
+        attempts: Set(delivery.attempts as i32),
+        next_attempt_at: Set(delivery.next_attempt_at.map(DateTimeWithTimeZone::from)),
+        last_attempt_at: Set(delivery.last_attempt_at.map(DateTimeWithTimeZone::from)),
+        response_status: Set(delivery.response_status.map(i32::from)),
+        last_error: Set(delivery.last_error.clone()),
+        ..Default::default()
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for PostgresWebhookRepository {
+    async fn create_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), WebhookRepositoryError> {
+        let active_model = SubscriptionActiveModel {
+            id: Set(subscription.id),
+            url: Set(subscription.url.clone()),
+            events: Set(subscription.events.join(" ")),
+            secret: Set(subscription.secret.clone()),
+            created_by: Set(subscription.created_by),
+            created_at: Set(subscription.created_at.into()),
+        };
+
+        WebhookSubscriptionEntity::insert(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn find_subscription(
+        &self,
+        id: Uuid,
+    ) -> Result<Option<WebhookSubscription>, WebhookRepositoryError> {
+        Ok(WebhookSubscriptionEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(database_error)?
+            .map(subscription_to_domain))
+    }
+
+    async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, WebhookRepositoryError> {
+        Ok(WebhookSubscriptionEntity::find()
+            .order_by_asc(SubscriptionColumn::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(database_error)?
+            .into_iter()
+            .map(subscription_to_domain)
+            .collect())
+    }
+
+    async fn delete_subscription(&self, id: Uuid) -> Result<bool, WebhookRepositoryError> {
+        let result = WebhookSubscriptionEntity::delete_by_id(id)
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn create_deliveries(
+        &self,
+        deliveries: &[WebhookDelivery],
+    ) -> Result<(), WebhookRepositoryError> {
+        if deliveries.is_empty() {
+            return Ok(());
+        }
+
+        let active_models = deliveries.iter().map(|delivery| DeliveryActiveModel {
+            id: Set(delivery.id),
+            subscription_id: Set(delivery.subscription_id),
+            event_id: Set(delivery.event.id),
+            created_at: Set(delivery.created_at.into()),
+            ..delivery_progress(delivery)
+        });
+
+        WebhookDeliveryEntity::insert_many(active_models)
+            .on_conflict(
+                OnConflict::columns([DeliveryColumn::SubscriptionId, DeliveryColumn::EventId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease: Duration,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, WebhookRepositoryError> {
+        // SKIP LOCKED lets several instances run the worker without claiming the same delivery.
+        let statement = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"UPDATE webhook_delivery SET next_attempt_at = $1
+               WHERE id IN (
+                 SELECT id FROM webhook_delivery
+                 WHERE status = $2 AND next_attempt_at <= $3
+                 ORDER BY next_attempt_at
+                 LIMIT $4
+                 FOR UPDATE SKIP LOCKED
+               )
+               RETURNING *"#,
+            [
+                DateTimeWithTimeZone::from(now + lease).into(),
+                WebhookDeliveryStatus::Pending.as_str().into(),
+                DateTimeWithTimeZone::from(now).into(),
+                (limit as i64).into(),
+            ],
+        );
+
+        let models = WebhookDeliveryEntity::find()
+            .from_raw_sql(statement)
+            .all(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        self.with_events(models).await
+    }
+
+    async fn find_delivery(
+        &self,
+        id: Uuid,
+    ) -> Result<Option<WebhookDelivery>, WebhookRepositoryError> {
+        let models = WebhookDeliveryEntity::find_by_id(id)
+            .all(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(self.with_events(models).await?.pop())
+    }
+
+    async fn list_deliveries(
+        &self,
+        subscription_id: Uuid,
+        page: u64,
+        page_size: u64,
+    ) -> Result<(Vec<WebhookDelivery>, u64), WebhookRepositoryError> {
+        let paginator = WebhookDeliveryEntity::find()
+            .filter(DeliveryColumn::SubscriptionId.eq(subscription_id))
+            .order_by_desc(DeliveryColumn::CreatedAt)
+            .order_by_desc(DeliveryColumn::Id)
+            .paginate(&self.db, page_size);
+
+        let total_items = paginator.num_items().await.map_err(database_error)?;
+        let models = paginator.fetch_page(page).await.map_err(database_error)?;
+
+        Ok((self.with_events(models).await?, total_items))
+    }
+
+    async fn update_delivery(
+        &self,
+        delivery: &WebhookDelivery,
+    ) -> Result<(), WebhookRepositoryError> {
+        WebhookDeliveryEntity::update_many()
+            .set(delivery_progress(delivery))
+            .filter(DeliveryColumn::Id.eq(delivery.id))
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+}
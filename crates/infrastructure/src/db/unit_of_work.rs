@@ -0,0 +1,285 @@
+use crate::db::repos::{
+    PostgresAuditLog, PostgresEmailVerificationTokenRepository, PostgresEventOutbox,
+    PostgresSessionRepository, PostgresUserRepository,
+};
+use application::ports::audit_log::AuditLog;
+use application::ports::email_verification_token_repository::EmailVerificationTokenRepository;
+use application::ports::event_outbox::EventOutbox;
+use application::ports::session_repository::SessionRepository;
+use application::ports::unit_of_work::{Transaction, UnitOfWork, UnitOfWorkError};
+use application::ports::user_repository::UserRepository;
+use async_trait::async_trait;
+use sea_orm::*;
+use std::sync::Arc;
+
+/// Starts database transactions that the repositories of a [`Transaction`] share.
+pub struct PostgresUnitOfWork {
+    db: DatabaseConnection,
+}
+
+impl PostgresUnitOfWork {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(err: DbErr) -> UnitOfWorkError {
+    UnitOfWorkError::DatabaseError(err.to_string())
+}
+
+#[async_trait]
+impl UnitOfWork for PostgresUnitOfWork {
+    async fn begin(&self) -> Result<Box<dyn Transaction>, UnitOfWorkError> {
+        let txn = Arc::new(self.db.begin().await.map_err(database_error)?);
+        Ok(Box::new(PostgresTransaction {
+            users: PostgresUserRepository::new(SharedTransaction(txn.clone())),
+            email_verification_tokens: PostgresEmailVerificationTokenRepository::new(
+                SharedTransaction(txn.clone()),
+            ),
+            audit_log: PostgresAuditLog::new(SharedTransaction(txn.clone())),
+            sessions: PostgresSessionRepository::new(SharedTransaction(txn.clone())),
+            event_outbox: PostgresEventOutbox::new(SharedTransaction(txn.clone())),
+            txn,
+        }))
+    }
+}
+
+/// A [`DatabaseTransaction`] that several repositories run their statements on.
+/// Dropping the last handle without committing rolls the transaction back.
+struct SharedTransaction(Arc<DatabaseTransaction>);
+
+#[async_trait]
+impl ConnectionTrait for SharedTransaction {
+    fn get_database_backend(&self) -> DbBackend {
+        self.0.get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.0.execute(stmt).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.0.execute_unprepared(sql).await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.0.query_one(stmt).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.0.query_all(stmt).await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.0.support_returning()
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.0.is_mock_connection()
+    }
+}
+
+struct PostgresTransaction {
+    users: PostgresUserRepository<SharedTransaction>,
+    email_verification_tokens: PostgresEmailVerificationTokenRepository<SharedTransaction>,
+    audit_log: PostgresAuditLog<SharedTransaction>,
+    sessions: PostgresSessionRepository<SharedTransaction>,
+    event_outbox: PostgresEventOutbox<SharedTransaction>,
+    txn: Arc<DatabaseTransaction>,
+}
+
+impl PostgresTransaction {
+    /// Drops the repositories' handles, so the transaction can be finished.
+    fn into_inner(self) -> Result<DatabaseTransaction, UnitOfWorkError> {
+        let Self {
+            users,
+            email_verification_tokens,
+            audit_log,
+            sessions,
+            event_outbox,
+            txn,
+        } = self;
+        drop(users);
+        drop(email_verification_tokens);
+        drop(audit_log);
+        drop(sessions);
+        drop(event_outbox);
+        Arc::try_unwrap(txn)
+            .map_err(|_| UnitOfWorkError::DatabaseError("Transaction is still in use".to_string()))
+    }
+}
+
+#[async_trait]
+impl Transaction for PostgresTransaction {
+    fn users(&self) -> &dyn UserRepository {
+        &self.users
+    }
+
+    fn email_verification_tokens(&self) -> &dyn EmailVerificationTokenRepository {
+        &self.email_verification_tokens
+    }
+
+    fn audit_log(&self) -> &dyn AuditLog {
+        &self.audit_log
+    }
+
+    fn sessions(&self) -> &dyn SessionRepository {
+        &self.sessions
+    }
+
+    fn event_outbox(&self) -> &dyn EventOutbox {
+        &self.event_outbox
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), UnitOfWorkError> {
+        self.into_inner()?.commit().await.map_err(database_error)
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), UnitOfWorkError> {
+        self.into_inner()?.rollback().await.map_err(database_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use application::ports::email_verification_token_repository::EmailVerificationToken;
+    use domain_users::{EmailAddress, PasswordHash, PersonName, User};
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    /// Records the statements and transaction commands it receives and fails the
+    /// statements containing `fail_on`.
+    #[derive(Debug)]
+    struct RecordingDatabase {
+        log: Arc<Mutex<Vec<String>>>,
+        fail_on: &'static str,
+    }
+
+    impl RecordingDatabase {
+        fn record(&self, entry: &str) {
+            self.log.lock().unwrap().push(entry.to_string());
+        }
+    }
+
+    #[async_trait]
+    impl ProxyDatabaseTrait for RecordingDatabase {
+        async fn query(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr> {
+            self.record(&statement.sql);
+            if statement.sql.contains(self.fail_on) {
+                return Err(DbErr::Custom("connection reset".to_string()));
+            }
+            Ok(vec![ProxyRow::new(BTreeMap::from([(
+                "id".to_string(),
+                Value::from(Uuid::new_v4()),
+            )]))])
+        }
+
+        async fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr> {
+            self.record(&statement.sql);
+            if statement.sql.contains(self.fail_on) {
+                return Err(DbErr::Custom("connection reset".to_string()));
+            }
+            Ok(ProxyExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            })
+        }
+
+        async fn begin(&self) {
+            self.record("BEGIN");
+        }
+
+        async fn commit(&self) {
+            self.record("COMMIT");
+        }
+
+        async fn rollback(&self) {
+            self.record("ROLLBACK");
+        }
+
+        fn start_rollback(&self) {
+            self.record("ROLLBACK");
+        }
+    }
+
+    async fn unit_of_work(fail_on: &'static str) -> (PostgresUnitOfWork, Arc<Mutex<Vec<String>>>) {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let db = Database::connect_proxy(
+            DbBackend::Postgres,
+            Arc::new(Box::new(RecordingDatabase {
+                log: log.clone(),
+                fail_on,
+            })),
+        )
+        .await
+        .unwrap();
+        (PostgresUnitOfWork::new(db), log)
+    }
+
+    fn user() -> User {
+        User::register(
+            Uuid::new_v4(),
+            PersonName::new_unchecked("Anna".to_string()),
+            PersonName::new_unchecked("Schmidt".to_string()),
+            EmailAddress::new_unchecked("anna@example.de".to_string()),
+            None,
+            PasswordHash::new_unchecked("hash".to_string()),
+            None,
+        )
+    }
+
+    async fn store_user_and_token(
+        transaction: &dyn Transaction,
+        user: &User,
+    ) -> Result<(), String> {
+        transaction
+            .users()
+            .create(user)
+            .await
+            .map_err(|e| e.to_string())?;
+        transaction
+            .email_verification_tokens()
+            .create(&EmailVerificationToken {
+                token: "token".to_string(),
+                user_id: user.id(),
+            })
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_commit_finishes_the_transaction() {
+        let (unit_of_work, log) = unit_of_work("never").await;
+
+        let transaction = unit_of_work.begin().await.unwrap();
+        store_user_and_token(transaction.as_ref(), &user())
+            .await
+            .unwrap();
+        transaction.commit().await.unwrap();
+
+        let log = log.lock().unwrap();
+        assert_eq!(log.len(), 4);
+        assert_eq!(log[0], "BEGIN");
+        assert!(log[1].starts_with(r#"INSERT INTO "user""#));
+        assert!(log[2].starts_with(r#"INSERT INTO "email_verification_token""#));
+        assert_eq!(log[3], "COMMIT");
+    }
+
+    #[tokio::test]
+    async fn test_failed_write_rolls_back_earlier_writes() {
+        let (unit_of_work, log) = unit_of_work("email_verification_token").await;
+
+        let transaction = unit_of_work.begin().await.unwrap();
+        let result = store_user_and_token(transaction.as_ref(), &user()).await;
+        assert!(result.is_err());
+        drop(transaction);
+
+        let log = log.lock().unwrap();
+        assert_eq!(log.len(), 4);
+        assert!(log[1].starts_with(r#"INSERT INTO "user""#));
+        assert_eq!(log[3], "ROLLBACK");
+        assert!(!log.contains(&"COMMIT".to_string()));
+    }
+}
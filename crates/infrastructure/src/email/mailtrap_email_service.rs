@@ -38,24 +38,13 @@ struct MailtrapSendRequest {
     template_variables: HashMap<String, String>,
 }
 
-#[async_trait]
-impl EmailService for MailtrapEmailService {
-    async fn send_verification_email(
+impl MailtrapEmailService {
+    async fn send_template(
         &self,
         to: &str,
-        token: &str,
-        first_name: &str,
-        last_name: &str,
+        template_uuid: &str,
+        template_variables: HashMap<String, String>,
     ) -> Result<(), EmailError> {
-        let mut template_variables = HashMap::new();
-        // The user asked to remove non-existing fields instead of dummy values.
-        // We now include the user's name as they are available during signup.
-        template_variables.insert("first_name".to_string(), first_name.to_string());
-        template_variables.insert("name".to_string(), format!("{} {}", first_name, last_name));
-
-        let confirmation_link = format!("{}?id={}", self.settings.verification_base_url, token);
-        template_variables.insert("confirmation_link".to_string(), confirmation_link);
-
         let request_body = MailtrapSendRequest {
             from: MailtrapSender {
                 email: self.settings.sender_email.clone(),
@@ -64,7 +53,7 @@ impl EmailService for MailtrapEmailService {
             to: vec![MailtrapRecipient {
                 email: to.to_string(),
             }],
-            template_uuid: self.settings.verification_template_uuid.clone(),
+            template_uuid: template_uuid.to_string(),
             template_variables,
         };
 
@@ -93,6 +82,33 @@ impl EmailService for MailtrapEmailService {
 
         Ok(())
     }
+}
+
+#[async_trait]
+impl EmailService for MailtrapEmailService {
+    async fn send_verification_email(
+        &self,
+        to: &str,
+        token: &str,
+        first_name: &str,
+        last_name: &str,
+    ) -> Result<(), EmailError> {
+        let mut template_variables = HashMap::new();
+        // The user asked to remove non-existing fields instead of dummy values.
+        // We now include the user's name as they are available during signup.
+        template_variables.insert("first_name".to_string(), first_name.to_string());
+        template_variables.insert("name".to_string(), format!("{} {}", first_name, last_name));
+
+        let confirmation_link = format!("{}?id={}", self.settings.verification_base_url, token);
+        template_variables.insert("confirmation_link".to_string(), confirmation_link);
+
+        self.send_template(
+            to,
+            &self.settings.verification_template_uuid,
+            template_variables,
+        )
+        .await
+    }
 
     async fn send_password_reset_email(
         &self,
@@ -107,41 +123,91 @@ impl EmailService for MailtrapEmailService {
             format!("{}?token={}", self.settings.password_reset_base_url, token);
         template_variables.insert("password_reset_link".to_string(), password_reset_link);
 
-        let request_body = MailtrapSendRequest {
-            from: MailtrapSender {
-                email: self.settings.sender_email.clone(),
-                name: self.settings.sender_name.clone(),
-            },
-            to: vec![MailtrapRecipient {
-                email: to.to_string(),
-            }],
-            template_uuid: self.settings.password_reset_template_uuid.clone(),
+        self.send_template(
+            to,
+            &self.settings.password_reset_template_uuid,
             template_variables,
-        };
+        )
+        .await
+    }
 
-        let response = self
-            .client
-            .post("https://send.api.mailtrap.io/api/send")
-            .header(
-                "Authorization",
-                format!("Bearer {}", self.settings.api_token),
-            )
-            .json(&request_body)
-            .send()
-            .await
-            .map_err(|e| EmailError::SendError(e.to_string()))?;
+    async fn send_account_unlock_email(
+        &self,
+        to: &str,
+        token: &str,
+        first_name: &str,
+    ) -> Result<(), EmailError> {
+        let mut template_variables = HashMap::new();
+        template_variables.insert("first_name".to_string(), first_name.to_string());
 
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(EmailError::SendError(format!(
-                "Mailtrap API returned error: {}",
-                error_text
-            )));
-        }
+        let unlock_link = format!("{}?token={}", self.settings.account_unlock_base_url, token);
+        template_variables.insert("unlock_link".to_string(), unlock_link);
 
-        Ok(())
+        self.send_template(
+            to,
+            &self.settings.account_unlock_template_uuid,
+            template_variables,
+        )
+        .await
+    }
+
+    async fn send_magic_link_email(
+        &self,
+        to: &str,
+        token: &str,
+        first_name: &str,
+    ) -> Result<(), EmailError> {
+        let mut template_variables = HashMap::new();
+        template_variables.insert("first_name".to_string(), first_name.to_string());
+
+        let login_link = format!("{}?token={}", self.settings.magic_link_base_url, token);
+        template_variables.insert("login_link".to_string(), login_link);
+
+        self.send_template(
+            to,
+            &self.settings.magic_link_template_uuid,
+            template_variables,
+        )
+        .await
+    }
+
+    async fn send_account_restore_email(
+        &self,
+        to: &str,
+        token: &str,
+        first_name: &str,
+    ) -> Result<(), EmailError> {
+        let mut template_variables = HashMap::new();
+        template_variables.insert("first_name".to_string(), first_name.to_string());
+
+        let restore_link = format!("{}?token={}", self.settings.account_restore_base_url, token);
+        template_variables.insert("restore_link".to_string(), restore_link);
+
+        self.send_template(
+            to,
+            &self.settings.account_restore_template_uuid,
+            template_variables,
+        )
+        .await
+    }
+
+    async fn send_data_export_ready_email(
+        &self,
+        to: &str,
+        token: &str,
+        first_name: &str,
+    ) -> Result<(), EmailError> {
+        let mut template_variables = HashMap::new();
+        template_variables.insert("first_name".to_string(), first_name.to_string());
+
+        let download_link = format!("{}?token={}", self.settings.data_export_base_url, token);
+        template_variables.insert("download_link".to_string(), download_link);
+
+        self.send_template(
+            to,
+            &self.settings.data_export_template_uuid,
+            template_variables,
+        )
+        .await
     }
 }
@@ -0,0 +1,3 @@
+pub mod tracing_event_handler;
+
+pub use tracing_event_handler::TracingEventHandler;
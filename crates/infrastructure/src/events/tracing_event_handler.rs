@@ -0,0 +1,14 @@
+use application::ports::event_publisher::{EventHandler, EventHandlerError};
+use async_trait::async_trait;
+use domain_users::UserEvent;
+
+/// Logs every domain event, so the log shows what a request changed.
+pub struct TracingEventHandler;
+
+#[async_trait]
+impl EventHandler for TracingEventHandler {
+    async fn handle(&self, event: &UserEvent) -> Result<(), EventHandlerError> {
+        tracing::info!(event = event.name(), user_id = %event.user_id(), "domain event");
+        Ok(())
+    }
+}
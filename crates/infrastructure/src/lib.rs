@@ -1,3 +1,8 @@
 pub mod db;
 pub mod email;
+pub mod events;
+pub mod memory;
+pub mod oidc;
 pub mod security;
+pub mod storage;
+pub mod webhooks;
@@ -0,0 +1,134 @@
+use application::ports::login_attempt_store::{
+    AttemptSubject, LoginAttemptStore, LoginAttemptStoreError, LoginAttempts,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Keeps failed login counters in process memory. Counters are lost on restart and
+/// not shared between instances.
+#[derive(Default)]
+pub struct InMemoryLoginAttemptStore {
+    entries: Mutex<HashMap<AttemptSubject, LoginAttempts>>,
+}
+
+impl InMemoryLoginAttemptStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn entries(
+        &self,
+    ) -> Result<
+        std::sync::MutexGuard<'_, HashMap<AttemptSubject, LoginAttempts>>,
+        LoginAttemptStoreError,
+    > {
+        self.entries
+            .lock()
+            .map_err(|_| LoginAttemptStoreError::StorageError("Lock poisoned".to_string()))
+    }
+}
+
+#[async_trait]
+impl LoginAttemptStore for InMemoryLoginAttemptStore {
+    async fn find(
+        &self,
+        subject: &AttemptSubject,
+    ) -> Result<Option<LoginAttempts>, LoginAttemptStoreError> {
+        Ok(self.entries()?.get(subject).cloned())
+    }
+
+    async fn record_failure(
+        &self,
+        subject: &AttemptSubject,
+        at: DateTime<Utc>,
+        window_start: DateTime<Utc>,
+    ) -> Result<LoginAttempts, LoginAttemptStoreError> {
+        let mut entries = self.entries()?;
+        let entry = entries
+            .entry(subject.clone())
+            .or_insert_with(|| LoginAttempts {
+                subject: subject.clone(),
+                failed_attempts: 0,
+                last_failed_at: at,
+                blocked_until: None,
+                locked_until: None,
+                unlock_token_hash: None,
+            });
+
+        if entry.last_failed_at < window_start {
+            entry.failed_attempts = 0;
+        }
+        entry.failed_attempts += 1;
+        entry.last_failed_at = at;
+
+        Ok(entry.clone())
+    }
+
+    async fn save(&self, attempts: &LoginAttempts) -> Result<(), LoginAttemptStoreError> {
+        if let Some(entry) = self.entries()?.get_mut(&attempts.subject) {
+            entry.blocked_until = attempts.blocked_until;
+            entry.locked_until = attempts.locked_until;
+            entry.unlock_token_hash = attempts.unlock_token_hash.clone();
+        }
+        Ok(())
+    }
+
+    async fn clear(&self, subject: &AttemptSubject) -> Result<bool, LoginAttemptStoreError> {
+        Ok(self.entries()?.remove(subject).is_some())
+    }
+
+    async fn find_by_unlock_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<LoginAttempts>, LoginAttemptStoreError> {
+        Ok(self
+            .entries()?
+            .values()
+            .find(|e| e.unlock_token_hash.as_deref() == Some(token_hash))
+            .cloned())
+    }
+
+    async fn find_restricted(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<LoginAttempts>, LoginAttemptStoreError> {
+        Ok(self
+            .entries()?
+            .values()
+            .filter(|e| e.retry_after(now).is_some())
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_failures_reset_after_window() {
+        let store = InMemoryLoginAttemptStore::new();
+        let subject = AttemptSubject::account("john@example.com");
+        let start = Utc::now();
+
+        store
+            .record_failure(&subject, start, start - Duration::minutes(15))
+            .await
+            .unwrap();
+        let second = store
+            .record_failure(&subject, start, start - Duration::minutes(15))
+            .await
+            .unwrap();
+        assert_eq!(second.failed_attempts, 2);
+
+        let later = start + Duration::minutes(20);
+        let after_window = store
+            .record_failure(&subject, later, later - Duration::minutes(15))
+            .await
+            .unwrap();
+        assert_eq!(after_window.failed_attempts, 1);
+    }
+}
@@ -0,0 +1,5 @@
+// Process-local adapters for single-instance deployments and tests
+
+pub mod login_attempt_store;
+
+pub use login_attempt_store::InMemoryLoginAttemptStore;
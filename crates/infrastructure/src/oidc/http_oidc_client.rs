@@ -0,0 +1,563 @@
+use application::ports::oidc_client::{OidcAuthorization, OidcClient, OidcError, OidcIdentity};
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use rand::RngCore;
+use reqwest::{Client, Url};
+use serde::{Deserialize, Deserializer};
+use sha2::{Digest, Sha256};
+use shared::config::{OidcProviderSettings, OidcSettings};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+const DISCOVERY_PATH: &str = "/.well-known/openid-configuration";
+const RANDOM_BYTES: usize = 32;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const CLOCK_SKEW_SECONDS: u64 = 60;
+/// Tokens naming an unknown key trigger a refetch at most this often, so forged
+/// tokens cannot make us hammer the provider.
+const KEY_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+/// What Google and Apple sign with; `none` and HMAC are never accepted.
+const SUPPORTED_ALGORITHMS: [Algorithm; 2] = [Algorithm::RS256, Algorithm::ES256];
+
+#[derive(Debug, Deserialize)]
+struct ProviderMetadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+#[derive(Deserialize)]
+struct IdTokenClaims {
+    sub: String,
+    nonce: Option<String>,
+    email: Option<String>,
+    #[serde(default, deserialize_with = "lenient_bool")]
+    email_verified: bool,
+    given_name: Option<String>,
+    family_name: Option<String>,
+}
+
+/// Apple sends `email_verified` as the string `"true"`.
+fn lenient_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Bool(value) => value,
+        serde_json::Value::String(value) => value == "true",
+        _ => false,
+    })
+}
+
+struct Provider {
+    settings: OidcProviderSettings,
+    metadata: Mutex<Option<Arc<ProviderMetadata>>>,
+    keys: Mutex<Option<Arc<JwkSet>>>,
+    keys_refreshed_at: Mutex<Option<Instant>>,
+}
+
+impl Provider {
+    /// Claims the next refetch of the signing keys, unless the last one was too recent.
+    fn claim_key_refresh(&self) -> bool {
+        let mut refreshed_at = self
+            .keys_refreshed_at
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if refreshed_at.is_some_and(|at| at.elapsed() < KEY_REFRESH_INTERVAL) {
+            return false;
+        }
+        *refreshed_at = Some(Instant::now());
+        true
+    }
+}
+
+/// Authorization code flow with PKCE against providers found via discovery. Metadata
+/// and signing keys are fetched on first use; keys are refetched when a token names
+/// one we do not know, which is how providers rotate them, but at most once per
+/// [`KEY_REFRESH_INTERVAL`].
+pub struct HttpOidcClient {
+    http: Client,
+    providers: HashMap<String, Provider>,
+}
+
+impl HttpOidcClient {
+    pub fn new(settings: &OidcSettings) -> Self {
+        let providers = settings
+            .providers
+            .iter()
+            .map(|settings| {
+                (
+                    settings.name.clone(),
+                    Provider {
+                        settings: settings.clone(),
+                        metadata: Mutex::new(None),
+                        keys: Mutex::new(None),
+                        keys_refreshed_at: Mutex::new(None),
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            http: Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+            providers,
+        }
+    }
+
+    fn provider(&self, name: &str) -> Result<&Provider, OidcError> {
+        self.providers.get(name).ok_or(OidcError::UnknownProvider)
+    }
+
+    async fn get_json<T: for<'de> Deserialize<'de>>(&self, url: &str) -> Result<T, OidcError> {
+        self.http
+            .get(url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| OidcError::ProviderUnavailable(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| OidcError::ProviderUnavailable(e.to_string()))
+    }
+
+    async fn metadata(&self, provider: &Provider) -> Result<Arc<ProviderMetadata>, OidcError> {
+        if let Some(metadata) = provider
+            .metadata
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+        {
+            return Ok(metadata);
+        }
+
+        let url = format!(
+            "{}{}",
+            provider.settings.issuer.trim_end_matches('/'),
+            DISCOVERY_PATH
+        );
+        let metadata: ProviderMetadata = self.get_json(&url).await?;
+        if metadata.issuer != provider.settings.issuer {
+            return Err(OidcError::ProviderUnavailable(format!(
+                "Discovery document is for issuer {}",
+                metadata.issuer
+            )));
+        }
+
+        let metadata = Arc::new(metadata);
+        *provider
+            .metadata
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(metadata.clone());
+        Ok(metadata)
+    }
+
+    async fn keys(
+        &self,
+        provider: &Provider,
+        metadata: &ProviderMetadata,
+        refresh: bool,
+    ) -> Result<Arc<JwkSet>, OidcError> {
+        if !refresh
+            && let Some(keys) = provider
+                .keys
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone()
+        {
+            return Ok(keys);
+        }
+
+        let keys: Arc<JwkSet> = Arc::new(self.get_json(&metadata.jwks_uri).await?);
+        *provider.keys.lock().unwrap_or_else(PoisonError::into_inner) = Some(keys.clone());
+        Ok(keys)
+    }
+
+    async fn decoding_key(
+        &self,
+        provider: &Provider,
+        metadata: &ProviderMetadata,
+        kid: &str,
+    ) -> Result<DecodingKey, OidcError> {
+        let mut keys = self.keys(provider, metadata, false).await?;
+        if keys.find(kid).is_none() && provider.claim_key_refresh() {
+            keys = self.keys(provider, metadata, true).await?;
+        }
+
+        let jwk = keys
+            .find(kid)
+            .ok_or_else(|| OidcError::Rejected(format!("Unknown signing key {}", kid)))?;
+        DecodingKey::from_jwk(jwk).map_err(|e| OidcError::Rejected(e.to_string()))
+    }
+
+    async fn validate_id_token(
+        &self,
+        provider: &Provider,
+        metadata: &ProviderMetadata,
+        id_token: &str,
+        nonce: &str,
+    ) -> Result<IdTokenClaims, OidcError> {
+        let header = jsonwebtoken::decode_header(id_token)
+            .map_err(|e| OidcError::Rejected(e.to_string()))?;
+        if !SUPPORTED_ALGORITHMS.contains(&header.alg) {
+            return Err(OidcError::Rejected(format!(
+                "Unsupported signing algorithm {:?}",
+                header.alg
+            )));
+        }
+        let kid = header
+            .kid
+            .ok_or_else(|| OidcError::Rejected("ID token names no signing key".to_string()))?;
+        let key = self.decoding_key(provider, metadata, &kid).await?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_audience(&[&provider.settings.client_id]);
+        validation.set_issuer(&[&metadata.issuer]);
+        validation.set_required_spec_claims(&["exp", "iat", "iss", "aud", "sub"]);
+        validation.leeway = CLOCK_SKEW_SECONDS;
+
+        let claims = jsonwebtoken::decode::<IdTokenClaims>(id_token, &key, &validation)
+            .map_err(|e| OidcError::Rejected(e.to_string()))?
+            .claims;
+
+        // Binds the token to the login that asked for it, so it cannot be replayed.
+        if claims.nonce.as_deref() != Some(nonce) {
+            return Err(OidcError::Rejected("Nonce mismatch".to_string()));
+        }
+
+        Ok(claims)
+    }
+}
+
+fn random_token() -> String {
+    let mut bytes = [0u8; RANDOM_BYTES];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+#[async_trait]
+impl OidcClient for HttpOidcClient {
+    async fn authorize(&self, provider: &str) -> Result<OidcAuthorization, OidcError> {
+        let provider = self.provider(provider)?;
+        let metadata = self.metadata(provider).await?;
+
+        let state = random_token();
+        let nonce = random_token();
+        let code_verifier = random_token();
+        let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+
+        let mut url = Url::parse(&metadata.authorization_endpoint)
+            .map_err(|e| OidcError::ProviderUnavailable(e.to_string()))?;
+        {
+            let mut query = url.query_pairs_mut();
+            query
+                .append_pair("response_type", "code")
+                .append_pair("client_id", &provider.settings.client_id)
+                .append_pair("redirect_uri", &provider.settings.redirect_uri)
+                .append_pair("scope", &provider.settings.scopes.join(" "))
+                .append_pair("state", &state)
+                .append_pair("nonce", &nonce)
+                .append_pair("code_challenge", &code_challenge)
+                .append_pair("code_challenge_method", "S256");
+            if let Some(response_mode) = &provider.settings.response_mode {
+                query.append_pair("response_mode", response_mode);
+            }
+        }
+
+        Ok(OidcAuthorization {
+            authorization_url: url.into(),
+            state,
+            nonce,
+            code_verifier,
+        })
+    }
+
+    async fn exchange_code(
+        &self,
+        provider: &str,
+        code: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> Result<OidcIdentity, OidcError> {
+        let provider = self.provider(provider)?;
+        let metadata = self.metadata(provider).await?;
+
+        let response = self
+            .http
+            .post(&metadata.token_endpoint)
+            .form(&[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", &provider.settings.redirect_uri),
+                ("client_id", &provider.settings.client_id),
+                ("client_secret", &provider.settings.client_secret),
+                ("code_verifier", code_verifier),
+            ])
+            .send()
+            .await
+            .map_err(|e| OidcError::ProviderUnavailable(e.to_string()))?;
+
+        let status = response.status();
+        if status.is_client_error() {
+            return Err(OidcError::Rejected(format!(
+                "Token endpoint answered {}",
+                status
+            )));
+        }
+        let tokens: TokenResponse = response
+            .error_for_status()
+            .map_err(|e| OidcError::ProviderUnavailable(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| OidcError::ProviderUnavailable(e.to_string()))?;
+
+        let claims = self
+            .validate_id_token(provider, &metadata, &tokens.id_token, nonce)
+            .await?;
+
+        Ok(OidcIdentity {
+            subject: claims.sub,
+            email: claims.email,
+            email_verified: claims.email_verified,
+            given_name: claims.given_name,
+            family_name: claims.family_name,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::{EncodingKey, Header};
+    use p256::ecdsa::SigningKey;
+    use p256::elliptic_curve::rand_core::OsRng;
+    use p256::pkcs8::EncodePrivateKey;
+    use serde_json::json;
+    use wiremock::matchers::{body_string_contains, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const CLIENT_ID: &str = "client-123";
+    const KEY_ID: &str = "test-key";
+
+    /// A local identity provider: discovery, a JWKS with one ES256 key and a token
+    /// endpoint answering with whatever ID token the test signs.
+    struct MockProvider {
+        server: MockServer,
+        signing_key: SigningKey,
+    }
+
+    impl MockProvider {
+        async fn start() -> Self {
+            let server = MockServer::start().await;
+            let signing_key = SigningKey::random(&mut OsRng);
+            let point = signing_key.verifying_key().to_encoded_point(false);
+
+            Mock::given(method("GET"))
+                .and(path(DISCOVERY_PATH))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "issuer": server.uri(),
+                    "authorization_endpoint": format!("{}/authorize", server.uri()),
+                    "token_endpoint": format!("{}/token", server.uri()),
+                    "jwks_uri": format!("{}/jwks", server.uri()),
+                })))
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path("/jwks"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "keys": [{
+                        "kty": "EC",
+                        "crv": "P-256",
+                        "x": URL_SAFE_NO_PAD.encode(point.x().unwrap()),
+                        "y": URL_SAFE_NO_PAD.encode(point.y().unwrap()),
+                        "kid": KEY_ID,
+                        "alg": "ES256",
+                        "use": "sig",
+                    }]
+                })))
+                .mount(&server)
+                .await;
+
+            Self {
+                server,
+                signing_key,
+            }
+        }
+
+        fn client(&self) -> HttpOidcClient {
+            HttpOidcClient::new(&OidcSettings {
+                providers: vec![OidcProviderSettings {
+                    name: "mock".to_string(),
+                    issuer: self.server.uri(),
+                    client_id: CLIENT_ID.to_string(),
+                    client_secret: "secret".to_string(),
+                    redirect_uri: "http://localhost:3030/callback".to_string(),
+                    scopes: vec!["openid".to_string(), "email".to_string()],
+                    response_mode: None,
+                }],
+                ..OidcSettings::default()
+            })
+        }
+
+        fn id_token(&self, claims: serde_json::Value) -> String {
+            self.id_token_with_key_id(KEY_ID, claims)
+        }
+
+        fn id_token_with_key_id(&self, kid: &str, claims: serde_json::Value) -> String {
+            let der = self.signing_key.to_pkcs8_der().unwrap();
+            let mut header = Header::new(Algorithm::ES256);
+            header.kid = Some(kid.to_string());
+            jsonwebtoken::encode(&header, &claims, &EncodingKey::from_ec_der(der.as_bytes()))
+                .unwrap()
+        }
+
+        fn claims(&self, nonce: &str) -> serde_json::Value {
+            let now = chrono::Utc::now().timestamp();
+            json!({
+                "iss": self.server.uri(),
+                "aud": CLIENT_ID,
+                "sub": "user-42",
+                "iat": now,
+                "exp": now + 300,
+                "nonce": nonce,
+                "email": "jane@example.com",
+                "email_verified": "true",
+                "given_name": "Jane",
+            })
+        }
+
+        async fn issue_on_code(&self, code_verifier: &str, id_token: String) {
+            Mock::given(method("POST"))
+                .and(path("/token"))
+                .and(body_string_contains("code=the-code"))
+                .and(body_string_contains(format!(
+                    "code_verifier={}",
+                    code_verifier
+                )))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(json!({ "id_token": id_token })),
+                )
+                .mount(&self.server)
+                .await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_authorization_url_carries_pkce_challenge_state_and_nonce() {
+        let provider = MockProvider::start().await;
+
+        let authorization = provider.client().authorize("mock").await.unwrap();
+
+        let url = Url::parse(&authorization.authorization_url).unwrap();
+        let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        assert_eq!(url.path(), "/authorize");
+        assert_eq!(query["client_id"], CLIENT_ID);
+        assert_eq!(query["state"], authorization.state);
+        assert_eq!(query["nonce"], authorization.nonce);
+        assert_eq!(query["code_challenge_method"], "S256");
+        assert_eq!(
+            query["code_challenge"],
+            URL_SAFE_NO_PAD.encode(Sha256::digest(authorization.code_verifier.as_bytes()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_code_exchange_returns_validated_identity() {
+        let provider = MockProvider::start().await;
+        let client = provider.client();
+        let id_token = provider.id_token(provider.claims("nonce-1"));
+        provider.issue_on_code("verifier-1", id_token).await;
+
+        let identity = client
+            .exchange_code("mock", "the-code", "verifier-1", "nonce-1")
+            .await
+            .unwrap();
+
+        assert_eq!(identity.subject, "user-42");
+        assert_eq!(identity.email.as_deref(), Some("jane@example.com"));
+        assert!(identity.email_verified);
+        assert_eq!(identity.given_name.as_deref(), Some("Jane"));
+    }
+
+    #[tokio::test]
+    async fn test_id_token_for_other_login_or_client_is_rejected() {
+        let provider = MockProvider::start().await;
+        let client = provider.client();
+        let mut claims = provider.claims("nonce-1");
+        claims["aud"] = json!("someone-else");
+        provider
+            .issue_on_code("other-audience", provider.id_token(claims))
+            .await;
+        provider
+            .issue_on_code("other-nonce", provider.id_token(provider.claims("nonce-2")))
+            .await;
+
+        let wrong_audience = client
+            .exchange_code("mock", "the-code", "other-audience", "nonce-1")
+            .await;
+        let wrong_nonce = client
+            .exchange_code("mock", "the-code", "other-nonce", "nonce-1")
+            .await;
+
+        assert!(matches!(wrong_audience, Err(OidcError::Rejected(_))));
+        assert!(matches!(wrong_nonce, Err(OidcError::Rejected(_))));
+    }
+
+    #[tokio::test]
+    async fn test_refused_code_and_unknown_provider() {
+        let provider = MockProvider::start().await;
+        let client = provider.client();
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .respond_with(
+                ResponseTemplate::new(400).set_body_json(json!({ "error": "invalid_grant" })),
+            )
+            .mount(&provider.server)
+            .await;
+
+        let refused = client
+            .exchange_code("mock", "stale-code", "verifier", "nonce")
+            .await;
+        let unknown = client.authorize("other").await;
+
+        assert!(matches!(refused, Err(OidcError::Rejected(_))));
+        assert!(matches!(unknown, Err(OidcError::UnknownProvider)));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_signing_key_refetches_keys_at_most_once_per_interval() {
+        let provider = MockProvider::start().await;
+        let client = provider.client();
+        let forged = provider.id_token_with_key_id("unknown-key", provider.claims("nonce-1"));
+        provider.issue_on_code("verifier-1", forged).await;
+
+        for _ in 0..3 {
+            let result = client
+                .exchange_code("mock", "the-code", "verifier-1", "nonce-1")
+                .await;
+            assert!(matches!(result, Err(OidcError::Rejected(_))));
+        }
+
+        let key_fetches = provider
+            .server
+            .received_requests()
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|request| request.url.path() == "/jwks")
+            .count();
+        assert_eq!(key_fetches, 2);
+    }
+}
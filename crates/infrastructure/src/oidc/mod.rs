@@ -0,0 +1,3 @@
+pub mod http_oidc_client;
+
+pub use http_oidc_client::HttpOidcClient;
@@ -0,0 +1,153 @@
+use application::ports::access_token_service::{
+    AccessTokenClaims, AccessTokenError, AccessTokenScope, AccessTokenService, IssuedAccessToken,
+};
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use serde::{Deserialize, Serialize};
+use shared::config::AuthSettings;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JwtClaims {
+    sub: Uuid,
+    role: String,
+    /// Absent in tokens issued before scopes existed, which were all full tokens.
+    #[serde(default = "full_scope")]
+    scope: String,
+    /// Session of a full token; absent in partial tokens and in tokens issued before
+    /// sessions existed, which stay valid until they expire.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sid: Option<Uuid>,
+    iat: i64,
+    exp: i64,
+}
+
+fn full_scope() -> String {
+    AccessTokenScope::Full.as_str().to_string()
+}
+
+pub struct JwtAccessTokenService {
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    ttl_seconds: u64,
+    two_factor_ttl_seconds: u64,
+}
+
+impl JwtAccessTokenService {
+    pub fn new(settings: &AuthSettings) -> Self {
+        Self {
+            encoding_key: EncodingKey::from_secret(settings.jwt_secret.as_bytes()),
+            decoding_key: DecodingKey::from_secret(settings.jwt_secret.as_bytes()),
+            ttl_seconds: settings.access_token_ttl_minutes * 60,
+            two_factor_ttl_seconds: settings.two_factor_token_ttl_minutes * 60,
+        }
+    }
+}
+
+impl AccessTokenService for JwtAccessTokenService {
+    fn issue(&self, claims: &AccessTokenClaims) -> Result<IssuedAccessToken, AccessTokenError> {
+        let now = chrono::Utc::now().timestamp();
+        let ttl_seconds = match claims.scope {
+            AccessTokenScope::Full => self.ttl_seconds,
+            _ => self.two_factor_ttl_seconds,
+        };
+        let jwt_claims = JwtClaims {
+            sub: claims.user_id,
+            role: claims.role.to_string(),
+            scope: claims.scope.as_str().to_string(),
+            sid: claims.session_id,
+            iat: now,
+            exp: now + ttl_seconds as i64,
+        };
+
+        let token = encode(&Header::default(), &jwt_claims, &self.encoding_key)
+            .map_err(|e| AccessTokenError::IssueError(e.to_string()))?;
+
+        Ok(IssuedAccessToken {
+            token,
+            expires_in_seconds: ttl_seconds,
+        })
+    }
+
+    fn verify(&self, token: &str) -> Result<AccessTokenClaims, AccessTokenError> {
+        let data = decode::<JwtClaims>(token, &self.decoding_key, &Validation::default())
+            .map_err(|_| AccessTokenError::InvalidToken)?;
+
+        Ok(AccessTokenClaims {
+            user_id: data.claims.sub,
+            role: data.claims.role.into(),
+            scope: AccessTokenScope::parse(&data.claims.scope)
+                .ok_or(AccessTokenError::InvalidToken)?,
+            session_id: data.claims.sid,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_users::models::user::UserRole;
+
+    #[test]
+    fn test_issue_and_verify_roundtrip() {
+        let service = JwtAccessTokenService::new(&AuthSettings::default());
+        let user_id = Uuid::new_v4();
+        let session_id = Uuid::new_v4();
+
+        let issued = service
+            .issue(&AccessTokenClaims {
+                user_id,
+                role: UserRole::VerifiedUser,
+                scope: AccessTokenScope::Full,
+                session_id: Some(session_id),
+            })
+            .expect("Issuing failed");
+        assert_eq!(issued.expires_in_seconds, 15 * 60);
+
+        let claims = service.verify(&issued.token).expect("Verification failed");
+        assert_eq!(claims.user_id, user_id);
+        assert_eq!(claims.role, UserRole::VerifiedUser);
+        assert_eq!(claims.scope, AccessTokenScope::Full);
+        assert_eq!(claims.session_id, Some(session_id));
+    }
+
+    #[test]
+    fn test_two_factor_tokens_are_short_lived_and_keep_their_scope() {
+        let service = JwtAccessTokenService::new(&AuthSettings::default());
+
+        let issued = service
+            .issue(&AccessTokenClaims {
+                user_id: Uuid::new_v4(),
+                role: UserRole::Admin,
+                scope: AccessTokenScope::TotpChallenge,
+                session_id: None,
+            })
+            .unwrap();
+        assert_eq!(issued.expires_in_seconds, 5 * 60);
+
+        let claims = service.verify(&issued.token).unwrap();
+        assert_eq!(claims.scope, AccessTokenScope::TotpChallenge);
+    }
+
+    #[test]
+    fn test_verify_rejects_foreign_signature() {
+        let service = JwtAccessTokenService::new(&AuthSettings::default());
+        let other = JwtAccessTokenService::new(&AuthSettings {
+            jwt_secret: "another-secret".to_string(),
+            ..AuthSettings::default()
+        });
+
+        let issued = other
+            .issue(&AccessTokenClaims {
+                user_id: Uuid::new_v4(),
+                role: UserRole::User,
+                scope: AccessTokenScope::Full,
+                session_id: None,
+            })
+            .unwrap();
+
+        assert!(matches!(
+            service.verify(&issued.token),
+            Err(AccessTokenError::InvalidToken)
+        ));
+    }
+}
@@ -1,3 +1,11 @@
 pub mod argon2_hasher;
+pub mod jwt_access_token_service;
+pub mod sha256_one_time_token_service;
+pub mod totp_rs_service;
+pub mod webauthn_relying_party;
 
 pub use argon2_hasher::Argon2Hasher;
+pub use jwt_access_token_service::JwtAccessTokenService;
+pub use sha256_one_time_token_service::Sha256OneTimeTokenService;
+pub use totp_rs_service::TotpRsService;
+pub use webauthn_relying_party::WebauthnRelyingParty;
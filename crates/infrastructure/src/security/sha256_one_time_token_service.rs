@@ -0,0 +1,39 @@
+use application::ports::one_time_token_service::OneTimeTokenService;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+const TOKEN_BYTES: usize = 32;
+
+/// 256 random bits, URL-safe so tokens can be embedded in links. A plain SHA-256 is
+/// enough for lookups as the tokens are not guessable, unlike passwords.
+pub struct Sha256OneTimeTokenService;
+
+impl OneTimeTokenService for Sha256OneTimeTokenService {
+    fn generate(&self) -> String {
+        let mut bytes = [0u8; TOKEN_BYTES];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    fn hash(&self, token: &str) -> String {
+        hex::encode(Sha256::digest(token.as_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens_are_unique_and_hashes_stable() {
+        let service = Sha256OneTimeTokenService;
+        let token = service.generate();
+
+        assert_eq!(token.len(), 43);
+        assert_ne!(token, service.generate());
+        assert_eq!(service.hash(&token), service.hash(&token));
+        assert_ne!(service.hash(&token), token);
+    }
+}
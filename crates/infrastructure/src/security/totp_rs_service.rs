@@ -0,0 +1,145 @@
+use application::ports::totp_service::{TotpError, TotpService};
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use totp_rs::{Algorithm, Secret, TOTP};
+
+const DIGITS: usize = 6;
+const STEP_SECONDS: u64 = 30;
+/// Without look-alikes such as `0`/`o` and `1`/`l`, as recovery codes are typed by hand.
+const RECOVERY_CODE_ALPHABET: &[u8] = b"23456789abcdefghjkmnpqrstuvwxyz";
+
+/// RFC 6238 codes as understood by common authenticator apps: SHA-1, six digits, 30 seconds.
+pub struct TotpRsService {
+    issuer: String,
+}
+
+impl TotpRsService {
+    pub fn new(issuer: impl Into<String>) -> Self {
+        Self {
+            issuer: issuer.into(),
+        }
+    }
+
+    fn totp(&self, secret: &str, account_name: &str) -> Result<TOTP, TotpError> {
+        let bytes = Secret::Encoded(secret.to_string())
+            .to_bytes()
+            .map_err(|e| TotpError::InvalidSecret(e.to_string()))?;
+
+        TOTP::new(
+            Algorithm::SHA1,
+            DIGITS,
+            0,
+            STEP_SECONDS,
+            bytes,
+            Some(self.issuer.clone()),
+            account_name.to_string(),
+        )
+        .map_err(|e| TotpError::InvalidSecret(e.to_string()))
+    }
+}
+
+impl TotpService for TotpRsService {
+    fn generate_secret(&self) -> String {
+        Secret::generate_secret().to_encoded().to_string()
+    }
+
+    fn provisioning_uri(&self, secret: &str, account_name: &str) -> Result<String, TotpError> {
+        Ok(self.totp(secret, account_name)?.get_url())
+    }
+
+    fn verify(
+        &self,
+        secret: &str,
+        code: &str,
+        at: DateTime<Utc>,
+    ) -> Result<Option<u64>, TotpError> {
+        let totp = self.totp(secret, "")?;
+        let current_step = at.timestamp().max(0) as u64 / STEP_SECONDS;
+
+        // Newest step first, so a code valid in two windows is recorded as late as possible.
+        Ok([
+            current_step + 1,
+            current_step,
+            current_step.saturating_sub(1),
+        ]
+        .into_iter()
+        .find(|step| totp.check(code, step * STEP_SECONDS)))
+    }
+
+    fn generate_recovery_codes(&self, count: usize) -> Vec<String> {
+        let mut rng = rand::thread_rng();
+        (0..count)
+            .map(|_| {
+                let chars: String = (0..10)
+                    .map(|_| {
+                        RECOVERY_CODE_ALPHABET[rng.gen_range(0..RECOVERY_CODE_ALPHABET.len())]
+                            as char
+                    })
+                    .collect();
+                format!("{}-{}", &chars[..5], &chars[5..])
+            })
+            .collect()
+    }
+
+    fn hash_recovery_code(&self, code: &str) -> String {
+        hex::encode(Sha256::digest(code.as_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_returns_step_within_skew() {
+        let service = TotpRsService::new("Test");
+        let secret = service.generate_secret();
+        let totp = service.totp(&secret, "").unwrap();
+        let at = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let step = 1_700_000_000 / STEP_SECONDS;
+
+        let code = totp.generate(step * STEP_SECONDS);
+        assert_eq!(service.verify(&secret, &code, at).unwrap(), Some(step));
+
+        let previous = totp.generate((step - 1) * STEP_SECONDS);
+        assert_eq!(
+            service.verify(&secret, &previous, at).unwrap(),
+            Some(step - 1)
+        );
+
+        let stale = totp.generate((step - 2) * STEP_SECONDS);
+        assert_eq!(service.verify(&secret, &stale, at).unwrap(), None);
+    }
+
+    #[test]
+    fn test_provisioning_uri_names_issuer_and_account() {
+        let service = TotpRsService::new("Ausmalbilder");
+        let secret = service.generate_secret();
+
+        let uri = service
+            .provisioning_uri(&secret, "admin@example.com")
+            .unwrap();
+
+        assert!(uri.starts_with("otpauth://totp/Ausmalbilder:admin%40example.com?"));
+        assert!(uri.contains(&format!("secret={}", secret)));
+    }
+
+    #[test]
+    fn test_recovery_codes_are_unique_and_hashed() {
+        let service = TotpRsService::new("Test");
+
+        let codes = service.generate_recovery_codes(10);
+        let mut unique = codes.clone();
+        unique.sort();
+        unique.dedup();
+
+        assert_eq!(unique.len(), 10);
+        assert_eq!(codes[0].len(), 11);
+        assert_ne!(service.hash_recovery_code(&codes[0]), codes[0]);
+        assert_eq!(
+            service.hash_recovery_code(&codes[0]),
+            service.hash_recovery_code(&codes[0])
+        );
+    }
+}
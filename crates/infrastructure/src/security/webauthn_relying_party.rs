@@ -0,0 +1,499 @@
+use application::ports::webauthn_service::{
+    PasskeyAssertionResponse, PasskeyRegistrationResponse, VerifiedPasskey, WebauthnError,
+    WebauthnService,
+};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use ciborium::value::{Integer, Value};
+use p256::ecdsa::signature::Verifier;
+use p256::ecdsa::{Signature, VerifyingKey};
+use rand::RngCore;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+const CHALLENGE_BYTES: usize = 32;
+/// COSE algorithm ES256: ECDSA over P-256 with SHA-256.
+const COSE_ALG_ES256: i64 = -7;
+const COSE_KTY_EC2: i64 = 2;
+const COSE_CRV_P256: i64 = 1;
+
+const FLAG_USER_PRESENT: u8 = 0x01;
+const FLAG_USER_VERIFIED: u8 = 0x04;
+const FLAG_ATTESTED_CREDENTIAL_DATA: u8 = 0x40;
+
+/// A WebAuthn Level 2 relying party for passkeys.
+///
+/// Only ES256 keys are accepted, which every platform authenticator supports.
+/// Attestation is not supported: we ask for none, and any attestation statement an
+/// authenticator sends anyway is ignored, so nothing is known about its make or model.
+/// User verification is required in both ceremonies, which makes a passkey a complete
+/// second factor. Ceremonies run from inside a cross-origin iframe are refused.
+/// Public keys are stored as uncompressed SEC1 points.
+pub struct WebauthnRelyingParty {
+    rp_id_hash: [u8; 32],
+    origins: Vec<String>,
+}
+
+impl WebauthnRelyingParty {
+    pub fn new(rp_id: &str, origins: Vec<String>) -> Self {
+        Self {
+            rp_id_hash: Sha256::digest(rp_id.as_bytes()).into(),
+            origins,
+        }
+    }
+
+    fn verify_client_data(
+        &self,
+        client_data_json: &[u8],
+        expected_type: &str,
+        challenge: &[u8],
+    ) -> Result<(), WebauthnError> {
+        let client_data: CollectedClientData = serde_json::from_slice(client_data_json)
+            .map_err(|e| rejected(format!("invalid client data: {}", e)))?;
+
+        if client_data.kind != expected_type {
+            return Err(rejected(format!(
+                "unexpected ceremony type {}",
+                client_data.kind
+            )));
+        }
+        let answered = URL_SAFE_NO_PAD
+            .decode(client_data.challenge.trim_end_matches('='))
+            .map_err(|_| rejected("invalid challenge encoding"))?;
+        if answered != challenge {
+            return Err(rejected("challenge does not match"));
+        }
+        if !self.origins.contains(&client_data.origin) {
+            return Err(rejected(format!(
+                "origin {} is not allowed",
+                client_data.origin
+            )));
+        }
+        if client_data.cross_origin || client_data.top_origin.is_some() {
+            return Err(rejected("cross-origin ceremonies are not allowed"));
+        }
+        Ok(())
+    }
+
+    fn verify_authenticator_data(&self, data: &AuthenticatorData<'_>) -> Result<(), WebauthnError> {
+        if data.rp_id_hash != self.rp_id_hash {
+            return Err(rejected("credential is scoped to another relying party"));
+        }
+        if data.flags & FLAG_USER_PRESENT == 0 {
+            return Err(rejected("user presence was not confirmed"));
+        }
+        if data.flags & FLAG_USER_VERIFIED == 0 {
+            return Err(rejected("user was not verified"));
+        }
+        Ok(())
+    }
+}
+
+impl WebauthnService for WebauthnRelyingParty {
+    fn generate_challenge(&self) -> Vec<u8> {
+        let mut challenge = vec![0u8; CHALLENGE_BYTES];
+        rand::thread_rng().fill_bytes(&mut challenge);
+        challenge
+    }
+
+    fn supported_algorithms(&self) -> Vec<i64> {
+        vec![COSE_ALG_ES256]
+    }
+
+    fn verify_registration(
+        &self,
+        response: &PasskeyRegistrationResponse,
+        challenge: &[u8],
+    ) -> Result<VerifiedPasskey, WebauthnError> {
+        self.verify_client_data(&response.client_data_json, "webauthn.create", challenge)?;
+
+        let attestation: Value = ciborium::from_reader(response.attestation_object.as_slice())
+            .map_err(|e| rejected(format!("invalid attestation object: {}", e)))?;
+        let auth_data_bytes = map_entry(&attestation, |key| key.as_text() == Some("authData"))
+            .and_then(Value::as_bytes)
+            .ok_or_else(|| rejected("attestation object has no authenticator data"))?;
+
+        let auth_data = AuthenticatorData::parse(auth_data_bytes)?;
+        self.verify_authenticator_data(&auth_data)?;
+
+        let (credential_id, cose_key) = auth_data
+            .attested_credential()?
+            .ok_or_else(|| rejected("no credential was attested"))?;
+        if credential_id != response.credential_id.as_slice() {
+            return Err(rejected("credential id does not match"));
+        }
+
+        Ok(VerifiedPasskey {
+            credential_id: credential_id.to_vec(),
+            public_key: es256_public_key(&cose_key)?,
+            sign_count: auth_data.sign_count,
+        })
+    }
+
+    fn verify_assertion(
+        &self,
+        response: &PasskeyAssertionResponse,
+        challenge: &[u8],
+        public_key: &[u8],
+        stored_sign_count: u32,
+    ) -> Result<u32, WebauthnError> {
+        self.verify_client_data(&response.client_data_json, "webauthn.get", challenge)?;
+
+        let auth_data = AuthenticatorData::parse(&response.authenticator_data)?;
+        self.verify_authenticator_data(&auth_data)?;
+
+        let key = VerifyingKey::from_sec1_bytes(public_key)
+            .map_err(|_| rejected("stored public key is invalid"))?;
+        let signature =
+            Signature::from_der(&response.signature).map_err(|_| rejected("invalid signature"))?;
+        let signed = [
+            response.authenticator_data.as_slice(),
+            &Sha256::digest(&response.client_data_json),
+        ]
+        .concat();
+        key.verify(&signed, &signature)
+            .map_err(|_| rejected("signature does not verify"))?;
+
+        // Authenticators without a counter always report zero; a synced passkey may too.
+        let counts = auth_data.sign_count != 0 || stored_sign_count != 0;
+        if counts && auth_data.sign_count <= stored_sign_count {
+            return Err(WebauthnError::CounterRegression);
+        }
+
+        Ok(auth_data.sign_count)
+    }
+}
+
+#[derive(Deserialize)]
+struct CollectedClientData {
+    #[serde(rename = "type")]
+    kind: String,
+    challenge: String,
+    origin: String,
+    #[serde(rename = "crossOrigin", default)]
+    cross_origin: bool,
+    #[serde(rename = "topOrigin")]
+    top_origin: Option<String>,
+}
+
+/// The authenticator data layout from the WebAuthn spec, section 6.1.
+struct AuthenticatorData<'a> {
+    rp_id_hash: &'a [u8],
+    flags: u8,
+    sign_count: u32,
+    rest: &'a [u8],
+}
+
+impl<'a> AuthenticatorData<'a> {
+    fn parse(bytes: &'a [u8]) -> Result<Self, WebauthnError> {
+        if bytes.len() < 37 {
+            return Err(rejected("authenticator data is too short"));
+        }
+        let (rp_id_hash, rest) = bytes.split_at(32);
+        let flags = rest[0];
+        let sign_count = u32::from_be_bytes([rest[1], rest[2], rest[3], rest[4]]);
+        Ok(Self {
+            rp_id_hash,
+            flags,
+            sign_count,
+            rest: &rest[5..],
+        })
+    }
+
+    /// Credential id and COSE public key, when the authenticator attested a new credential.
+    fn attested_credential(&self) -> Result<Option<(&'a [u8], Value)>, WebauthnError> {
+        if self.flags & FLAG_ATTESTED_CREDENTIAL_DATA == 0 {
+            return Ok(None);
+        }
+        // 16 bytes AAGUID, then the big-endian credential id length.
+        let truncated = || rejected("attested credential data is truncated");
+        let length_bytes = self.rest.get(16..18).ok_or_else(truncated)?;
+        let length = u16::from_be_bytes([length_bytes[0], length_bytes[1]]) as usize;
+        let credential_id = self.rest.get(18..18 + length).ok_or_else(truncated)?;
+        let cose_key: Value = ciborium::from_reader(&self.rest[18 + length..])
+            .map_err(|e| rejected(format!("invalid credential public key: {}", e)))?;
+        Ok(Some((credential_id, cose_key)))
+    }
+}
+
+fn rejected(reason: impl Into<String>) -> WebauthnError {
+    WebauthnError::Rejected(reason.into())
+}
+
+fn map_entry(map: &Value, matches: impl Fn(&Value) -> bool) -> Option<&Value> {
+    map.as_map()?
+        .iter()
+        .find(|(key, _)| matches(key))
+        .map(|(_, value)| value)
+}
+
+fn cose_entry(key: &Value, label: i64) -> Option<&Value> {
+    map_entry(key, |k| k.as_integer() == Some(Integer::from(label)))
+}
+
+fn cose_int(key: &Value, label: i64) -> Option<i64> {
+    cose_entry(key, label)
+        .and_then(Value::as_integer)
+        .and_then(|value| i64::try_from(value).ok())
+}
+
+/// Converts a COSE_Key (RFC 9053) to an uncompressed SEC1 point, rejecting anything but
+/// ES256 keys on P-256.
+fn es256_public_key(cose_key: &Value) -> Result<Vec<u8>, WebauthnError> {
+    if cose_int(cose_key, 1) != Some(COSE_KTY_EC2)
+        || cose_int(cose_key, 3) != Some(COSE_ALG_ES256)
+        || cose_int(cose_key, -1) != Some(COSE_CRV_P256)
+    {
+        return Err(rejected("only ES256 passkeys are supported"));
+    }
+    let coordinate = |label| {
+        cose_entry(cose_key, label)
+            .and_then(Value::as_bytes)
+            .filter(|bytes| bytes.len() == 32)
+            .ok_or_else(|| rejected("invalid public key coordinates"))
+    };
+
+    let point = [&[0x04][..], coordinate(-2)?, coordinate(-3)?].concat();
+    VerifyingKey::from_sec1_bytes(&point).map_err(|_| rejected("public key is not on P-256"))?;
+    Ok(point)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::signature::Signer;
+    use p256::ecdsa::{DerSignature, SigningKey};
+    use rand::rngs::OsRng;
+
+    const RP_ID: &str = "example.com";
+    const ORIGIN: &str = "https://example.com";
+
+    /// A software authenticator holding a single ES256 passkey.
+    struct SoftwareAuthenticator {
+        credential_id: Vec<u8>,
+        key: SigningKey,
+        sign_count: u32,
+    }
+
+    impl SoftwareAuthenticator {
+        fn new() -> Self {
+            Self {
+                credential_id: b"software-credential".to_vec(),
+                key: SigningKey::random(&mut OsRng),
+                sign_count: 0,
+            }
+        }
+
+        fn client_data(kind: &str, challenge: &[u8], origin: &str) -> Vec<u8> {
+            Self::client_data_with(kind, challenge, origin, serde_json::json!({}))
+        }
+
+        /// Client data with `extra` members added, e.g. those of an embedded ceremony.
+        fn client_data_with(
+            kind: &str,
+            challenge: &[u8],
+            origin: &str,
+            extra: serde_json::Value,
+        ) -> Vec<u8> {
+            let mut client_data = serde_json::json!({
+                "type": kind,
+                "challenge": URL_SAFE_NO_PAD.encode(challenge),
+                "origin": origin,
+                "crossOrigin": false,
+            });
+            if let (Some(client_data), Some(extra)) =
+                (client_data.as_object_mut(), extra.as_object())
+            {
+                client_data.extend(extra.clone());
+            }
+            client_data.to_string().into_bytes()
+        }
+
+        fn authenticator_data(&self, rp_id: &str, flags: u8, attested: Option<Vec<u8>>) -> Vec<u8> {
+            let mut data = Sha256::digest(rp_id.as_bytes()).to_vec();
+            data.push(
+                flags
+                    | if attested.is_some() {
+                        FLAG_ATTESTED_CREDENTIAL_DATA
+                    } else {
+                        0
+                    },
+            );
+            data.extend_from_slice(&self.sign_count.to_be_bytes());
+            if let Some(attested) = attested {
+                data.extend_from_slice(&attested);
+            }
+            data
+        }
+
+        fn cose_key(&self) -> Vec<u8> {
+            let point = self.key.verifying_key().to_encoded_point(false);
+            let entry = |label: i64, value: Value| (Value::Integer(label.into()), value);
+            let key = Value::Map(vec![
+                entry(1, Value::Integer(COSE_KTY_EC2.into())),
+                entry(3, Value::Integer(COSE_ALG_ES256.into())),
+                entry(-1, Value::Integer(COSE_CRV_P256.into())),
+                entry(-2, Value::Bytes(point.x().unwrap().to_vec())),
+                entry(-3, Value::Bytes(point.y().unwrap().to_vec())),
+            ]);
+            let mut bytes = Vec::new();
+            ciborium::into_writer(&key, &mut bytes).unwrap();
+            bytes
+        }
+
+        fn create(&self, challenge: &[u8], flags: u8) -> PasskeyRegistrationResponse {
+            let mut attested = vec![0u8; 16];
+            attested.extend_from_slice(&(self.credential_id.len() as u16).to_be_bytes());
+            attested.extend_from_slice(&self.credential_id);
+            attested.extend_from_slice(&self.cose_key());
+
+            let attestation = Value::Map(vec![
+                (Value::Text("fmt".into()), Value::Text("none".into())),
+                (Value::Text("attStmt".into()), Value::Map(Vec::new())),
+                (
+                    Value::Text("authData".into()),
+                    Value::Bytes(self.authenticator_data(RP_ID, flags, Some(attested))),
+                ),
+            ]);
+            let mut attestation_object = Vec::new();
+            ciborium::into_writer(&attestation, &mut attestation_object).unwrap();
+
+            PasskeyRegistrationResponse {
+                credential_id: self.credential_id.clone(),
+                client_data_json: Self::client_data("webauthn.create", challenge, ORIGIN),
+                attestation_object,
+            }
+        }
+
+        fn get(&mut self, challenge: &[u8], origin: &str) -> PasskeyAssertionResponse {
+            self.sign_count += 1;
+            let client_data_json = Self::client_data("webauthn.get", challenge, origin);
+            let authenticator_data =
+                self.authenticator_data(RP_ID, FLAG_USER_PRESENT | FLAG_USER_VERIFIED, None);
+            let signed = [
+                authenticator_data.as_slice(),
+                &Sha256::digest(&client_data_json),
+            ]
+            .concat();
+            let signature: DerSignature = self.key.sign(&signed);
+
+            PasskeyAssertionResponse {
+                credential_id: self.credential_id.clone(),
+                client_data_json,
+                authenticator_data,
+                signature: signature.as_bytes().to_vec(),
+                user_handle: None,
+            }
+        }
+    }
+
+    fn relying_party() -> WebauthnRelyingParty {
+        WebauthnRelyingParty::new(RP_ID, vec![ORIGIN.to_string()])
+    }
+
+    #[test]
+    fn test_software_passkey_registers_and_logs_in() {
+        let rp = relying_party();
+        let mut authenticator = SoftwareAuthenticator::new();
+
+        let challenge = rp.generate_challenge();
+        let passkey = rp
+            .verify_registration(
+                &authenticator.create(&challenge, FLAG_USER_PRESENT | FLAG_USER_VERIFIED),
+                &challenge,
+            )
+            .unwrap();
+        assert_eq!(passkey.credential_id, authenticator.credential_id);
+
+        let challenge = rp.generate_challenge();
+        let assertion = authenticator.get(&challenge, ORIGIN);
+        let sign_count = rp
+            .verify_assertion(
+                &assertion,
+                &challenge,
+                &passkey.public_key,
+                passkey.sign_count,
+            )
+            .unwrap();
+        assert_eq!(sign_count, 1);
+
+        // Replaying the assertion fails: the counter did not move.
+        assert!(matches!(
+            rp.verify_assertion(&assertion, &challenge, &passkey.public_key, sign_count),
+            Err(WebauthnError::CounterRegression)
+        ));
+    }
+
+    #[test]
+    fn test_registration_requires_user_verification_and_matching_challenge() {
+        let rp = relying_party();
+        let authenticator = SoftwareAuthenticator::new();
+        let challenge = rp.generate_challenge();
+
+        let unverified = authenticator.create(&challenge, FLAG_USER_PRESENT);
+        assert!(rp.verify_registration(&unverified, &challenge).is_err());
+
+        let verified = authenticator.create(&challenge, FLAG_USER_PRESENT | FLAG_USER_VERIFIED);
+        assert!(
+            rp.verify_registration(&verified, &rp.generate_challenge())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_assertion_from_foreign_origin_or_other_key_is_rejected() {
+        let rp = relying_party();
+        let mut authenticator = SoftwareAuthenticator::new();
+        let challenge = rp.generate_challenge();
+        let passkey = rp
+            .verify_registration(
+                &authenticator.create(&challenge, FLAG_USER_PRESENT | FLAG_USER_VERIFIED),
+                &challenge,
+            )
+            .unwrap();
+
+        let phished = authenticator.get(&challenge, "https://examp1e.com");
+        assert!(
+            rp.verify_assertion(&phished, &challenge, &passkey.public_key, 0)
+                .is_err()
+        );
+
+        let other_key = SoftwareAuthenticator::new()
+            .key
+            .verifying_key()
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec();
+        let assertion = authenticator.get(&challenge, ORIGIN);
+        assert!(
+            rp.verify_assertion(&assertion, &challenge, &other_key, 0)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cross_origin_ceremonies_are_rejected() {
+        let rp = relying_party();
+        let authenticator = SoftwareAuthenticator::new();
+        let challenge = rp.generate_challenge();
+        let embedded = [
+            serde_json::json!({ "crossOrigin": true }),
+            serde_json::json!({ "topOrigin": "https://evil.example.net" }),
+        ];
+
+        for extra in embedded {
+            let mut response =
+                authenticator.create(&challenge, FLAG_USER_PRESENT | FLAG_USER_VERIFIED);
+            response.client_data_json = SoftwareAuthenticator::client_data_with(
+                "webauthn.create",
+                &challenge,
+                ORIGIN,
+                extra,
+            );
+            assert!(matches!(
+                rp.verify_registration(&response, &challenge),
+                Err(WebauthnError::Rejected(reason)) if reason.contains("cross-origin")
+            ));
+        }
+    }
+}
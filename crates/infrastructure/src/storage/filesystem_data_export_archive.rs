@@ -0,0 +1,352 @@
+use application::ports::data_export_archive::{
+    DataExportArchive, DataExportArchiveError, UserDataSnapshot,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use std::io::{Cursor, ErrorKind, Write};
+use std::path::PathBuf;
+use uuid::Uuid;
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+const README: &str = "\
+This archive contains the personal data stored about you, as JSON files:
+
+profile.json            your account
+sessions.json           devices you signed in on
+passkeys.json           registered passkeys
+linked_identities.json  accounts of other providers you sign in with
+api_keys.json           personal API keys
+audit_log.json          security-relevant actions taken by or about you
+
+Actions someone else took on your account, such as an admin, are listed without
+who took them and from which IP address.
+
+Passwords, recovery codes and API keys are only stored in a form that cannot be
+reversed and are therefore not included. Authenticator app secrets and passkey
+keys are left out as well: they only serve to sign in, and would let anyone who
+gets hold of this archive sign in as you.
+";
+
+/// Keeps each export as a ZIP file named after its id in a local directory.
+pub struct FilesystemDataExportArchive {
+    dir: PathBuf,
+}
+
+impl FilesystemDataExportArchive {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, export_id: Uuid) -> PathBuf {
+        self.dir.join(format!("{}.zip", export_id))
+    }
+}
+
+fn storage_error(err: impl std::fmt::Display) -> DataExportArchiveError {
+    DataExportArchiveError::StorageError(err.to_string())
+}
+
+#[derive(Serialize)]
+struct ProfileFile<'a> {
+    id: Uuid,
+    first_name: &'a str,
+    last_name: &'a str,
+    email: &'a str,
+    is_email_verified: bool,
+    phone_number: Option<&'a str>,
+    birth_date: Option<NaiveDate>,
+    locale: Option<&'a str>,
+    role: String,
+}
+
+#[derive(Serialize)]
+struct SessionFile<'a> {
+    id: Uuid,
+    user_agent: Option<&'a str>,
+    ip_address: Option<String>,
+    created_at: DateTime<Utc>,
+    last_seen_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize)]
+struct PasskeyFile<'a> {
+    id: Uuid,
+    name: &'a str,
+    created_at: DateTime<Utc>,
+    last_used_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize)]
+struct LinkedIdentityFile<'a> {
+    provider: &'a str,
+    subject: &'a str,
+    email: Option<&'a str>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+struct ApiKeyFile<'a> {
+    id: Uuid,
+    name: &'a str,
+    prefix: &'a str,
+    scopes: Vec<&'static str>,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    last_used_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize)]
+struct AuditEntryFile<'a> {
+    occurred_at: DateTime<Utc>,
+    action: &'a str,
+    actor_id: Option<Uuid>,
+    target_type: &'a str,
+    target_id: Option<Uuid>,
+    changes: Vec<FieldChangeFile<'a>>,
+    ip_address: Option<String>,
+}
+
+#[derive(Serialize)]
+struct FieldChangeFile<'a> {
+    field: &'a str,
+    old_value: Option<&'a str>,
+    new_value: Option<&'a str>,
+}
+
+fn render(snapshot: &UserDataSnapshot) -> Result<Vec<u8>, DataExportArchiveError> {
+    let user = &snapshot.user;
+    let profile = ProfileFile {
+        id: user.id(),
+        first_name: user.first_name(),
+        last_name: user.last_name(),
+        email: user.email(),
+        is_email_verified: user.is_email_verified(),
+        phone_number: user.phone_number().map(|p| p.as_str()),
+        birth_date: user.birth_date(),
+        locale: user.locale(),
+        role: user.role().to_string(),
+    };
+    let sessions: Vec<SessionFile> = snapshot
+        .sessions
+        .iter()
+        .map(|s| SessionFile {
+            id: s.id,
+            user_agent: s.user_agent.as_deref(),
+            ip_address: s.ip_address.map(|ip| ip.to_string()),
+            created_at: s.created_at,
+            last_seen_at: s.last_seen_at,
+            expires_at: s.expires_at,
+            revoked_at: s.revoked_at,
+        })
+        .collect();
+    let passkeys: Vec<PasskeyFile> = snapshot
+        .passkeys
+        .iter()
+        .map(|p| PasskeyFile {
+            id: p.id,
+            name: &p.name,
+            created_at: p.created_at,
+            last_used_at: p.last_used_at,
+        })
+        .collect();
+    let linked_identities: Vec<LinkedIdentityFile> = snapshot
+        .linked_identities
+        .iter()
+        .map(|i| LinkedIdentityFile {
+            provider: &i.provider,
+            subject: &i.subject,
+            email: i.email.as_deref(),
+            created_at: i.created_at,
+        })
+        .collect();
+    let api_keys: Vec<ApiKeyFile> = snapshot
+        .api_keys
+        .iter()
+        .map(|k| ApiKeyFile {
+            id: k.id,
+            name: &k.name,
+            prefix: &k.prefix,
+            scopes: k.scopes.iter().map(|s| s.as_str()).collect(),
+            created_at: k.created_at,
+            expires_at: k.expires_at,
+            last_used_at: k.last_used_at,
+        })
+        .collect();
+    // Who acted on the user, and from where, is personal data of the actor.
+    let audit_log: Vec<AuditEntryFile> = snapshot
+        .audit_entries
+        .iter()
+        .map(|e| (e, e.actor_id == Some(user.id())))
+        .map(|(e, own)| AuditEntryFile {
+            occurred_at: e.occurred_at,
+            action: &e.action,
+            actor_id: e.actor_id.filter(|_| own),
+            target_type: &e.target_type,
+            target_id: e.target_id,
+            changes: e
+                .changes
+                .iter()
+                .map(|c| FieldChangeFile {
+                    field: &c.field,
+                    old_value: c.old_value.as_deref(),
+                    new_value: c.new_value.as_deref(),
+                })
+                .collect(),
+            ip_address: e.ip_address.filter(|_| own).map(|ip| ip.to_string()),
+        })
+        .collect();
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default();
+    zip.start_file("README.txt", options)
+        .map_err(storage_error)?;
+    zip.write_all(README.as_bytes()).map_err(storage_error)?;
+    for (name, json) in [
+        ("profile.json", serde_json::to_vec_pretty(&profile)),
+        ("sessions.json", serde_json::to_vec_pretty(&sessions)),
+        ("passkeys.json", serde_json::to_vec_pretty(&passkeys)),
+        (
+            "linked_identities.json",
+            serde_json::to_vec_pretty(&linked_identities),
+        ),
+        ("api_keys.json", serde_json::to_vec_pretty(&api_keys)),
+        ("audit_log.json", serde_json::to_vec_pretty(&audit_log)),
+    ] {
+        zip.start_file(name, options).map_err(storage_error)?;
+        zip.write_all(&json.map_err(storage_error)?)
+            .map_err(storage_error)?;
+    }
+
+    Ok(zip.finish().map_err(storage_error)?.into_inner())
+}
+
+#[async_trait]
+impl DataExportArchive for FilesystemDataExportArchive {
+    async fn store(
+        &self,
+        export_id: Uuid,
+        snapshot: &UserDataSnapshot,
+    ) -> Result<(), DataExportArchiveError> {
+        let archive = render(snapshot)?;
+
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(storage_error)?;
+        // Written under a temporary name so a download never sees a partial file.
+        let partial = self.dir.join(format!("{}.zip.partial", export_id));
+        tokio::fs::write(&partial, archive)
+            .await
+            .map_err(storage_error)?;
+        tokio::fs::rename(&partial, self.path(export_id))
+            .await
+            .map_err(storage_error)
+    }
+
+    async fn open(&self, export_id: Uuid) -> Result<Option<Vec<u8>>, DataExportArchiveError> {
+        match tokio::fs::read(self.path(export_id)).await {
+            Ok(archive) => Ok(Some(archive)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(storage_error(err)),
+        }
+    }
+
+    async fn delete(&self, export_id: Uuid) -> Result<(), DataExportArchiveError> {
+        match tokio::fs::remove_file(self.path(export_id)).await {
+            Err(err) if err.kind() != ErrorKind::NotFound => Err(storage_error(err)),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use application::ports::audit_log::AuditEntry;
+    use domain_users::User;
+    use std::io::Read;
+    use zip::ZipArchive;
+
+    fn snapshot() -> UserDataSnapshot {
+        UserDataSnapshot {
+            user: User::register(
+                Uuid::new_v4(),
+                "Jane".parse().unwrap(),
+                "Doe".parse().unwrap(),
+                "jane@example.com".parse().unwrap(),
+                None,
+                "$argon2id$secret".parse().unwrap(),
+                None,
+            ),
+            sessions: Vec::new(),
+            passkeys: Vec::new(),
+            linked_identities: Vec::new(),
+            api_keys: Vec::new(),
+            audit_entries: Vec::new(),
+        }
+    }
+
+    fn read_file(archive: Vec<u8>, name: &str) -> String {
+        let mut zip = ZipArchive::new(Cursor::new(archive)).unwrap();
+        let mut contents = String::new();
+        zip.by_name(name)
+            .unwrap()
+            .read_to_string(&mut contents)
+            .unwrap();
+        contents
+    }
+
+    #[tokio::test]
+    async fn test_archive_round_trip_leaves_out_secrets() {
+        let dir = std::env::temp_dir().join(format!("data-export-test-{}", Uuid::new_v4()));
+        let archive = FilesystemDataExportArchive::new(&dir);
+        let export_id = Uuid::new_v4();
+        let snapshot = snapshot();
+
+        archive.store(export_id, &snapshot).await.unwrap();
+        let bytes = archive.open(export_id).await.unwrap().unwrap();
+
+        assert_eq!(ZipArchive::new(Cursor::new(&bytes)).unwrap().len(), 7);
+        let profile = read_file(bytes, "profile.json");
+        assert!(profile.contains("jane@example.com"));
+        assert!(!profile.contains("secret"));
+
+        archive.delete(export_id).await.unwrap();
+        assert!(archive.open(export_id).await.unwrap().is_none());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_audit_entries_by_others_leave_out_actor_and_ip_address() {
+        let mut snapshot = snapshot();
+        let user_id = snapshot.user.id();
+        let admin_id = Uuid::new_v4();
+        let mut own = AuditEntry::new(
+            Some(user_id),
+            "user.profile_updated",
+            "user",
+            Some(user_id),
+            Vec::new(),
+        );
+        own.ip_address = Some("192.0.2.1".parse().unwrap());
+        let mut by_admin = AuditEntry::new(
+            Some(admin_id),
+            "user.role_changed",
+            "user",
+            Some(user_id),
+            Vec::new(),
+        );
+        by_admin.ip_address = Some("198.51.100.9".parse().unwrap());
+        snapshot.audit_entries = vec![own, by_admin];
+
+        let audit_log = read_file(render(&snapshot).unwrap(), "audit_log.json");
+
+        assert!(audit_log.contains("192.0.2.1"));
+        assert!(audit_log.contains("user.role_changed"));
+        assert!(!audit_log.contains(&admin_id.to_string()));
+        assert!(!audit_log.contains("198.51.100.9"));
+    }
+}
@@ -0,0 +1,5 @@
+// File storage adapters
+
+pub mod filesystem_data_export_archive;
+
+pub use filesystem_data_export_archive::FilesystemDataExportArchive;
@@ -0,0 +1,178 @@
+use application::ports::event_outbox::OutboxEvent;
+use application::ports::webhook_sender::{WebhookSendError, WebhookSender};
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::Client;
+use serde_json::json;
+use sha2::Sha256;
+use std::time::Duration;
+
+/// The event id; stays the same across retries so receivers can drop duplicates.
+pub const ID_HEADER: &str = "Webhook-Id";
+pub const EVENT_HEADER: &str = "Webhook-Event";
+/// Unix seconds at sending, covered by the signature so receivers can reject replays.
+pub const TIMESTAMP_HEADER: &str = "Webhook-Timestamp";
+/// `v1=` followed by the hex HMAC-SHA256 of `{timestamp}.{body}`, keyed with the
+/// subscription's secret.
+pub const SIGNATURE_HEADER: &str = "Webhook-Signature";
+
+/// Posts events as JSON: `{"id", "type", "occurred_at", "data"}`, where `data` is the
+/// event payload from the outbox.
+pub struct HttpWebhookSender {
+    http: Client,
+}
+
+impl HttpWebhookSender {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            http: Client::builder()
+                .timeout(timeout)
+                // A redirect would send the signed payload somewhere nobody subscribed.
+                .redirect(reqwest::redirect::Policy::none())
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+fn body(event: &OutboxEvent) -> String {
+    let data: serde_json::Value =
+        serde_json::from_str(&event.payload).unwrap_or(serde_json::Value::Null);
+    json!({
+        "id": event.id,
+        "type": event.name,
+        "occurred_at": event.occurred_at,
+        "data": data,
+    })
+    .to_string()
+}
+
+fn signature(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+    format!("v1={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(
+        &self,
+        url: &str,
+        secret: &str,
+        event: &OutboxEvent,
+    ) -> Result<u16, WebhookSendError> {
+        let body = body(event);
+        let timestamp = Utc::now().timestamp();
+
+        let response = self
+            .http
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(ID_HEADER, event.id.to_string())
+            .header(EVENT_HEADER, &event.name)
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .header(SIGNATURE_HEADER, signature(secret, timestamp, &body))
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| WebhookSendError::Unreachable(e.to_string()))?;
+
+        Ok(response.status().as_u16())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+    use wiremock::matchers::{header_exists, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const SECRET: &str = "whsec_test";
+
+    fn event() -> OutboxEvent {
+        OutboxEvent {
+            position: 1,
+            id: Uuid::new_v4(),
+            name: "user.deleted".to_string(),
+            aggregate_id: Uuid::new_v4(),
+            payload: r#"{"user_id":"9b2f0a4e-55a3-4c1e-9a61-0d5f7c3e2b11"}"#.to_string(),
+            occurred_at: Utc::now(),
+        }
+    }
+
+    /// A local receiver that answers every signed POST to `/hooks` with `status`.
+    async fn receiver(status: u16) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/hooks"))
+            .and(header_exists(SIGNATURE_HEADER))
+            .and(header_exists(TIMESTAMP_HEADER))
+            .respond_with(ResponseTemplate::new(status))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_send_posts_signed_event() {
+        let server = receiver(204).await;
+        let event = event();
+
+        let status = HttpWebhookSender::new(Duration::from_secs(5))
+            .send(&format!("{}/hooks", server.uri()), SECRET, &event)
+            .await
+            .unwrap();
+
+        assert_eq!(status, 204);
+        let requests = server.received_requests().await.unwrap();
+        let request = &requests[0];
+        let header = |name: &str| request.headers.get(name).unwrap().to_str().unwrap();
+        let body = String::from_utf8(request.body.clone()).unwrap();
+        let timestamp: i64 = header(TIMESTAMP_HEADER).parse().unwrap();
+
+        assert_eq!(
+            header(SIGNATURE_HEADER),
+            signature(SECRET, timestamp, &body)
+        );
+        assert_ne!(
+            header(SIGNATURE_HEADER),
+            signature("whsec_other", timestamp, &body)
+        );
+        assert_eq!(header(ID_HEADER), event.id.to_string());
+        assert_eq!(header(EVENT_HEADER), "user.deleted");
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["type"], "user.deleted");
+        assert_eq!(json["id"], event.id.to_string());
+        assert_eq!(
+            json["data"]["user_id"],
+            "9b2f0a4e-55a3-4c1e-9a61-0d5f7c3e2b11"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_reports_error_statuses_and_unreachable_endpoints() {
+        let server = receiver(503).await;
+        let sender = HttpWebhookSender::new(Duration::from_secs(5));
+
+        let status = sender
+            .send(&format!("{}/hooks", server.uri()), SECRET, &event())
+            .await
+            .unwrap();
+        assert_eq!(status, 503);
+
+        // Nothing listens on a port that was just released.
+        let address = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let result = sender
+            .send(&format!("http://{}/hooks", address), SECRET, &event())
+            .await;
+        assert!(matches!(result, Err(WebhookSendError::Unreachable(_))));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod http_webhook_sender;
+
+pub use http_webhook_sender::HttpWebhookSender;
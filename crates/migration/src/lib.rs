@@ -7,6 +7,22 @@ mod m20251221_144201_split_user_name_and_add_birth_date;
 mod m20251221_145926_add_verification_and_role_to_user;
 mod m20251221_145937_create_email_verification_token_table;
 mod m20251227_000001_create_password_reset_token_table;
+mod m20261018_000001_add_locale_and_version_to_user;
+mod m20261018_000002_create_audit_log_table;
+mod m20261018_000003_create_login_attempt_table;
+mod m20261018_000004_create_two_factor_tables;
+mod m20261018_000005_create_passkey_tables;
+mod m20261018_000006_create_magic_link_token_table;
+mod m20261018_000007_create_oidc_tables;
+mod m20261018_000008_create_user_session_table;
+mod m20261018_000009_create_api_key_table;
+mod m20261018_000010_expand_audit_log;
+mod m20261018_000011_create_data_export_table;
+mod m20261018_000012_create_account_deletion_tables;
+mod m20261018_000014_normalize_user_emails;
+mod m20261018_000015_create_event_outbox_tables;
+mod m20261018_000016_create_webhook_tables;
+mod m20261018_000017_hash_login_unlock_tokens;
 
 pub struct Migrator;
 
@@ -21,6 +37,22 @@ impl MigratorTrait for Migrator {
             Box::new(m20251221_145926_add_verification_and_role_to_user::Migration),
             Box::new(m20251221_145937_create_email_verification_token_table::Migration),
             Box::new(m20251227_000001_create_password_reset_token_table::Migration),
+            Box::new(m20261018_000001_add_locale_and_version_to_user::Migration),
+            Box::new(m20261018_000002_create_audit_log_table::Migration),
+            Box::new(m20261018_000003_create_login_attempt_table::Migration),
+            Box::new(m20261018_000004_create_two_factor_tables::Migration),
+            Box::new(m20261018_000005_create_passkey_tables::Migration),
+            Box::new(m20261018_000006_create_magic_link_token_table::Migration),
+            Box::new(m20261018_000007_create_oidc_tables::Migration),
+            Box::new(m20261018_000008_create_user_session_table::Migration),
+            Box::new(m20261018_000009_create_api_key_table::Migration),
+            Box::new(m20261018_000010_expand_audit_log::Migration),
+            Box::new(m20261018_000011_create_data_export_table::Migration),
+            Box::new(m20261018_000012_create_account_deletion_tables::Migration),
+            Box::new(m20261018_000014_normalize_user_emails::Migration),
+            Box::new(m20261018_000015_create_event_outbox_tables::Migration),
+            Box::new(m20261018_000016_create_webhook_tables::Migration),
+            Box::new(m20261018_000017_hash_login_unlock_tokens::Migration),
         ]
     }
 }
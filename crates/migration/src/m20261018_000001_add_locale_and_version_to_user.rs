@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(Iden)]
+pub enum User {
+    Table,
+    Locale,
+    Version,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .add_column(ColumnDef::new(User::Locale).string())
+                    .add_column(
+                        ColumnDef::new(User::Version)
+                            .integer()
+                            .not_null()
+                            .default(1),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .drop_column(User::Locale)
+                    .drop_column(User::Version)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,58 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(Iden)]
+pub enum AuditLog {
+    Table,
+    Id,
+    OccurredAt,
+    ActorId,
+    Action,
+    TargetType,
+    TargetId,
+    Changes,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuditLog::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(AuditLog::Id).uuid().not_null().primary_key())
+                    .col(
+                        ColumnDef::new(AuditLog::OccurredAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(AuditLog::ActorId).uuid())
+                    .col(ColumnDef::new(AuditLog::Action).string().not_null())
+                    .col(ColumnDef::new(AuditLog::TargetType).string().not_null())
+                    .col(ColumnDef::new(AuditLog::TargetId).uuid())
+                    .col(ColumnDef::new(AuditLog::Changes).json_binary().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-audit_log-target_id")
+                    .table(AuditLog::Table)
+                    .col(AuditLog::TargetId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuditLog::Table).to_owned())
+            .await
+    }
+}
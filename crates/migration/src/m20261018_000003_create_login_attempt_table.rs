@@ -0,0 +1,68 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(Iden)]
+pub enum LoginAttempt {
+    Table,
+    Subject,
+    FailedAttempts,
+    LastFailedAt,
+    BlockedUntil,
+    LockedUntil,
+    UnlockToken,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(LoginAttempt::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(LoginAttempt::Subject)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(LoginAttempt::FailedAttempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(LoginAttempt::LastFailedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(LoginAttempt::BlockedUntil)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(LoginAttempt::LockedUntil)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(LoginAttempt::UnlockToken)
+                            .string()
+                            .null()
+                            .unique_key(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(LoginAttempt::Table).to_owned())
+            .await
+    }
+}
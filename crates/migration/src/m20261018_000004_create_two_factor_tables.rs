@@ -0,0 +1,113 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(Iden)]
+pub enum UserTotp {
+    Table,
+    UserId,
+    Secret,
+    ConfirmedAt,
+    LastUsedStep,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+pub enum TotpRecoveryCode {
+    Table,
+    Id,
+    UserId,
+    CodeHash,
+    UsedAt,
+}
+
+#[derive(Iden)]
+pub enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserTotp::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserTotp::UserId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(UserTotp::Secret).string().not_null())
+                    .col(ColumnDef::new(UserTotp::ConfirmedAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(UserTotp::LastUsedStep).big_integer())
+                    .col(
+                        ColumnDef::new(UserTotp::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-user_totp-user_id")
+                            .from(UserTotp::Table, UserTotp::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TotpRecoveryCode::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TotpRecoveryCode::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TotpRecoveryCode::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(TotpRecoveryCode::CodeHash)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TotpRecoveryCode::UsedAt).timestamp_with_time_zone())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-totp_recovery_code-user_id")
+                            .from(TotpRecoveryCode::Table, TotpRecoveryCode::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-totp_recovery_code-user_id")
+                    .table(TotpRecoveryCode::Table)
+                    .col(TotpRecoveryCode::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TotpRecoveryCode::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(UserTotp::Table).to_owned())
+            .await
+    }
+}
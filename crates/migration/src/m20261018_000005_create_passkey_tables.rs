@@ -0,0 +1,130 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(Iden)]
+pub enum Passkey {
+    Table,
+    Id,
+    UserId,
+    CredentialId,
+    PublicKey,
+    SignCount,
+    Name,
+    CreatedAt,
+    LastUsedAt,
+}
+
+#[derive(Iden)]
+pub enum PasskeyCeremony {
+    Table,
+    Id,
+    Kind,
+    UserId,
+    Challenge,
+    ExpiresAt,
+}
+
+#[derive(Iden)]
+pub enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Passkey::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Passkey::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(Passkey::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(Passkey::CredentialId)
+                            .binary()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(Passkey::PublicKey).binary().not_null())
+                    .col(
+                        ColumnDef::new(Passkey::SignCount)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(Passkey::Name).string().not_null())
+                    .col(
+                        ColumnDef::new(Passkey::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(Passkey::LastUsedAt).timestamp_with_time_zone())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-passkey-user_id")
+                            .from(Passkey::Table, Passkey::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-passkey-user_id")
+                    .table(Passkey::Table)
+                    .col(Passkey::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(PasskeyCeremony::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PasskeyCeremony::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(PasskeyCeremony::Kind).string().not_null())
+                    .col(ColumnDef::new(PasskeyCeremony::UserId).uuid())
+                    .col(
+                        ColumnDef::new(PasskeyCeremony::Challenge)
+                            .binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PasskeyCeremony::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-passkey_ceremony-user_id")
+                            .from(PasskeyCeremony::Table, PasskeyCeremony::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PasskeyCeremony::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Passkey::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,74 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(Iden)]
+pub enum MagicLinkToken {
+    Table,
+    TokenHash,
+    UserId,
+    CreatedAt,
+    ExpiresAt,
+}
+
+#[derive(Iden)]
+pub enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(MagicLinkToken::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(MagicLinkToken::TokenHash)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(MagicLinkToken::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(MagicLinkToken::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(MagicLinkToken::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-magic_link_token-user_id")
+                            .from(MagicLinkToken::Table, MagicLinkToken::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-magic_link_token-user_id")
+                    .table(MagicLinkToken::Table)
+                    .col(MagicLinkToken::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MagicLinkToken::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,191 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(Iden)]
+pub enum LinkedIdentity {
+    Table,
+    Id,
+    UserId,
+    Provider,
+    Subject,
+    Email,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+pub enum OidcLoginRequest {
+    Table,
+    State,
+    Provider,
+    Nonce,
+    CodeVerifier,
+    ExpiresAt,
+}
+
+#[derive(Iden)]
+pub enum PendingIdentityLink {
+    Table,
+    TokenHash,
+    UserId,
+    Provider,
+    Subject,
+    Email,
+    ExpiresAt,
+}
+
+#[derive(Iden)]
+pub enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(LinkedIdentity::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(LinkedIdentity::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(LinkedIdentity::UserId).uuid().not_null())
+                    .col(ColumnDef::new(LinkedIdentity::Provider).string().not_null())
+                    .col(ColumnDef::new(LinkedIdentity::Subject).string().not_null())
+                    .col(ColumnDef::new(LinkedIdentity::Email).string())
+                    .col(
+                        ColumnDef::new(LinkedIdentity::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-linked_identity-user_id")
+                            .from(LinkedIdentity::Table, LinkedIdentity::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-linked_identity-provider-subject")
+                    .table(LinkedIdentity::Table)
+                    .col(LinkedIdentity::Provider)
+                    .col(LinkedIdentity::Subject)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-linked_identity-user_id")
+                    .table(LinkedIdentity::Table)
+                    .col(LinkedIdentity::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(OidcLoginRequest::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(OidcLoginRequest::State)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(OidcLoginRequest::Provider)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(OidcLoginRequest::Nonce).string().not_null())
+                    .col(
+                        ColumnDef::new(OidcLoginRequest::CodeVerifier)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OidcLoginRequest::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(PendingIdentityLink::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PendingIdentityLink::TokenHash)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(PendingIdentityLink::UserId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PendingIdentityLink::Provider)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PendingIdentityLink::Subject)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PendingIdentityLink::Email)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PendingIdentityLink::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-pending_identity_link-user_id")
+                            .from(PendingIdentityLink::Table, PendingIdentityLink::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PendingIdentityLink::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(OidcLoginRequest::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(LinkedIdentity::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,95 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(Iden)]
+pub enum UserSession {
+    Table,
+    Id,
+    UserId,
+    UserAgent,
+    IpAddress,
+    CreatedAt,
+    LastSeenAt,
+    ExpiresAt,
+    RevokedAt,
+}
+
+#[derive(Iden)]
+pub enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserSession::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserSession::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(UserSession::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(UserSession::UserAgent)
+                            .string_len(512)
+                            .null(),
+                    )
+                    .col(ColumnDef::new(UserSession::IpAddress).string_len(45).null())
+                    .col(
+                        ColumnDef::new(UserSession::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(UserSession::LastSeenAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(UserSession::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserSession::RevokedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-user_session-user_id")
+                            .from(UserSession::Table, UserSession::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-user_session-user_id")
+                    .table(UserSession::Table)
+                    .col(UserSession::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserSession::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,97 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(Iden)]
+pub enum AuditLog {
+    Table,
+    OccurredAt,
+    ActorId,
+    Action,
+    CorrelationId,
+    IpAddress,
+}
+
+/// Rejects any change to a recorded entry. Deletes stay possible for retention.
+const APPEND_ONLY_TRIGGER: &str = r#"
+CREATE OR REPLACE FUNCTION audit_log_reject_update() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_log entries cannot be modified';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_log_append_only
+    BEFORE UPDATE ON audit_log
+    FOR EACH ROW EXECUTE FUNCTION audit_log_reject_update();
+"#;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuditLog::Table)
+                    .add_column(ColumnDef::new(AuditLog::CorrelationId).string_len(128))
+                    .add_column(ColumnDef::new(AuditLog::IpAddress).string_len(45))
+                    .to_owned(),
+            )
+            .await?;
+
+        for (name, column) in [
+            ("idx-audit_log-occurred_at", AuditLog::OccurredAt),
+            ("idx-audit_log-actor_id", AuditLog::ActorId),
+            ("idx-audit_log-action", AuditLog::Action),
+        ] {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(name)
+                        .table(AuditLog::Table)
+                        .col(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .get_connection()
+            .execute_unprepared(APPEND_ONLY_TRIGGER)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "DROP TRIGGER IF EXISTS audit_log_append_only ON audit_log; \
+                 DROP FUNCTION IF EXISTS audit_log_reject_update();",
+            )
+            .await?;
+
+        for name in [
+            "idx-audit_log-occurred_at",
+            "idx-audit_log-actor_id",
+            "idx-audit_log-action",
+        ] {
+            manager
+                .drop_index(Index::drop().name(name).table(AuditLog::Table).to_owned())
+                .await?;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuditLog::Table)
+                    .drop_column(AuditLog::CorrelationId)
+                    .drop_column(AuditLog::IpAddress)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
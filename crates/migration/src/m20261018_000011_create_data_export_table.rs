@@ -0,0 +1,103 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(Iden)]
+pub enum DataExport {
+    Table,
+    Id,
+    UserId,
+    RequestedBy,
+    Status,
+    DownloadTokenHash,
+    CreatedAt,
+    CompletedAt,
+    ExpiresAt,
+}
+
+#[derive(Iden)]
+pub enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DataExport::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(DataExport::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(DataExport::UserId).uuid().not_null())
+                    // No foreign key: the export outlives the admin who requested it.
+                    .col(ColumnDef::new(DataExport::RequestedBy).uuid().not_null())
+                    .col(ColumnDef::new(DataExport::Status).string_len(16).not_null())
+                    .col(
+                        ColumnDef::new(DataExport::DownloadTokenHash)
+                            .string_len(64)
+                            .null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(DataExport::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(DataExport::CompletedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(DataExport::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-data_export-user_id")
+                            .from(DataExport::Table, DataExport::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-data_export-user_id")
+                    .table(DataExport::Table)
+                    .col(DataExport::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-data_export-status-created_at")
+                    .table(DataExport::Table)
+                    .col(DataExport::Status)
+                    .col(DataExport::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DataExport::Table).to_owned())
+            .await
+    }
+}
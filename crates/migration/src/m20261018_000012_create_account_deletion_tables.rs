@@ -0,0 +1,188 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(Iden)]
+pub enum AccountRestoreToken {
+    Table,
+    TokenHash,
+    UserId,
+    CreatedAt,
+    ExpiresAt,
+}
+
+#[derive(Iden)]
+pub enum UserTombstone {
+    Table,
+    UserId,
+    DeletedAt,
+    ErasedAt,
+}
+
+#[derive(Iden)]
+pub enum User {
+    Table,
+    Id,
+    DeletedAt,
+}
+
+/// Erasing a user keeps their audit trail but blanks the values they changed and
+/// the addresses they acted from. The trigger still rejects every other update.
+const ALLOW_REDACTION: &str = r#"
+CREATE OR REPLACE FUNCTION audit_log_redacted_changes(changes jsonb) RETURNS jsonb AS $$
+    SELECT CASE WHEN jsonb_typeof(changes) = 'array' THEN COALESCE(
+        (SELECT jsonb_agg(jsonb_build_object('field', c -> 'field', 'old', NULL, 'new', NULL))
+         FROM jsonb_array_elements(changes) AS c),
+        '[]'::jsonb
+    ) ELSE changes END;
+$$ LANGUAGE sql IMMUTABLE;
+
+CREATE OR REPLACE FUNCTION audit_log_reject_update() RETURNS trigger AS $$
+BEGIN
+    IF NEW.id = OLD.id
+        AND NEW.occurred_at = OLD.occurred_at
+        AND NEW.actor_id IS NOT DISTINCT FROM OLD.actor_id
+        AND NEW.action = OLD.action
+        AND NEW.target_type = OLD.target_type
+        AND NEW.target_id IS NOT DISTINCT FROM OLD.target_id
+        AND NEW.correlation_id IS NOT DISTINCT FROM OLD.correlation_id
+        AND (NEW.ip_address IS NULL OR NEW.ip_address = OLD.ip_address)
+        AND (NEW.changes = OLD.changes OR NEW.changes = audit_log_redacted_changes(OLD.changes))
+    THEN
+        RETURN NEW;
+    END IF;
+    RAISE EXCEPTION 'audit_log entries cannot be modified';
+END;
+$$ LANGUAGE plpgsql;
+"#;
+
+const REJECT_ALL_UPDATES: &str = r#"
+CREATE OR REPLACE FUNCTION audit_log_reject_update() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_log entries cannot be modified';
+END;
+$$ LANGUAGE plpgsql;
+
+DROP FUNCTION IF EXISTS audit_log_redacted_changes(jsonb);
+"#;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AccountRestoreToken::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AccountRestoreToken::TokenHash)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AccountRestoreToken::UserId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AccountRestoreToken::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(AccountRestoreToken::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-account_restore_token-user_id")
+                            .from(AccountRestoreToken::Table, AccountRestoreToken::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-account_restore_token-user_id")
+                    .table(AccountRestoreToken::Table)
+                    .col(AccountRestoreToken::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // No foreign key: the tombstone is all that is left once the user row is gone.
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserTombstone::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserTombstone::UserId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(UserTombstone::DeletedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserTombstone::ErasedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-user-deleted_at")
+                    .table(User::Table)
+                    .col(User::DeletedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(ALLOW_REDACTION)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(REJECT_ALL_UPDATES)
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-user-deleted_at")
+                    .table(User::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(UserTombstone::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(AccountRestoreToken::Table).to_owned())
+            .await
+    }
+}
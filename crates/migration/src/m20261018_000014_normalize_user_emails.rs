@@ -0,0 +1,69 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, Statement};
+
+/// Active accounts whose emails only differ in case or surrounding whitespace.
+const FIND_DUPLICATES: &str = r#"
+SELECT lower(btrim(email)) AS email, string_agg(id::text, ', ' ORDER BY id) AS ids
+FROM "user"
+WHERE deleted_at IS NULL
+GROUP BY lower(btrim(email))
+HAVING count(*) > 1
+ORDER BY 1
+"#;
+
+/// Punycode for internationalized domains is applied by the application on the next
+/// write; SQL has no IDNA implementation.
+const NORMALIZE: &str = r#"
+UPDATE "user" SET email = lower(btrim(email)) WHERE email <> lower(btrim(email));
+DROP INDEX IF EXISTS "idx-user-email-active";
+CREATE UNIQUE INDEX "idx-user-email-active" ON "user" (lower(email)) WHERE deleted_at IS NULL;
+"#;
+
+const CASE_SENSITIVE: &str = r#"
+DROP INDEX IF EXISTS "idx-user-email-active";
+CREATE UNIQUE INDEX "idx-user-email-active" ON "user" (email) WHERE deleted_at IS NULL;
+"#;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        let rows = db
+            .query_all(Statement::from_string(
+                manager.get_database_backend(),
+                FIND_DUPLICATES,
+            ))
+            .await?;
+        if !rows.is_empty() {
+            let mut duplicates = Vec::with_capacity(rows.len());
+            for row in rows {
+                let email: String = row.try_get("", "email")?;
+                let ids: String = row.try_get("", "ids")?;
+                duplicates.push(format!("  {}: {}", email, ids));
+            }
+            return Err(DbErr::Migration(format!(
+                "{} emails are used by more than one active account once case is ignored. \
+                 Merge or delete all but one account per email, then run the migration again:\n{}",
+                duplicates.len(),
+                duplicates.join("\n")
+            )));
+        }
+
+        db.execute_unprepared(NORMALIZE).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(CASE_SENSITIVE)
+            .await?;
+
+        Ok(())
+    }
+}
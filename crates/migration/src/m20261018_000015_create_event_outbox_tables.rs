@@ -0,0 +1,109 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(Iden)]
+pub enum EventOutbox {
+    Table,
+    Position,
+    Id,
+    Name,
+    AggregateId,
+    Payload,
+    OccurredAt,
+}
+
+#[derive(Iden)]
+pub enum EventConsumer {
+    Table,
+    Name,
+    Position,
+    UpdatedAt,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventOutbox::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EventOutbox::Position)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(EventOutbox::Id)
+                            .uuid()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(EventOutbox::Name).string_len(64).not_null())
+                    // No foreign key: events outlive the erased users they mention.
+                    .col(ColumnDef::new(EventOutbox::AggregateId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(EventOutbox::Payload)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventOutbox::OccurredAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-event_outbox-aggregate_id")
+                    .table(EventOutbox::Table)
+                    .col(EventOutbox::AggregateId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventConsumer::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EventConsumer::Name)
+                            .string_len(64)
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(EventConsumer::Position)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EventConsumer::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(EventConsumer::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(EventOutbox::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,184 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(Iden)]
+pub enum WebhookSubscription {
+    Table,
+    Id,
+    Url,
+    Events,
+    Secret,
+    CreatedBy,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+pub enum WebhookDelivery {
+    Table,
+    Id,
+    SubscriptionId,
+    EventId,
+    Status,
+    Attempts,
+    NextAttemptAt,
+    LastAttemptAt,
+    ResponseStatus,
+    LastError,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+pub enum EventOutbox {
+    Table,
+    Id,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookSubscription::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WebhookSubscription::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookSubscription::Url)
+                            .string_len(2048)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookSubscription::Events)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookSubscription::Secret)
+                            .string_len(128)
+                            .not_null(),
+                    )
+                    // No foreign key: the subscription outlives the admin who created it.
+                    .col(
+                        ColumnDef::new(WebhookSubscription::CreatedBy)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookSubscription::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookDelivery::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WebhookDelivery::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDelivery::SubscriptionId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(WebhookDelivery::EventId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(WebhookDelivery::Status)
+                            .string_len(16)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDelivery::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDelivery::NextAttemptAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDelivery::LastAttemptAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDelivery::ResponseStatus)
+                            .integer()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(WebhookDelivery::LastError).text().null())
+                    .col(
+                        ColumnDef::new(WebhookDelivery::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-webhook_delivery-subscription_id")
+                            .from(WebhookDelivery::Table, WebhookDelivery::SubscriptionId)
+                            .to(WebhookSubscription::Table, WebhookSubscription::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-webhook_delivery-event_id")
+                            .from(WebhookDelivery::Table, WebhookDelivery::EventId)
+                            .to(EventOutbox::Table, EventOutbox::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Makes fanning out an event that is relayed twice a no-op.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-webhook_delivery-subscription_id-event_id")
+                    .table(WebhookDelivery::Table)
+                    .col(WebhookDelivery::SubscriptionId)
+                    .col(WebhookDelivery::EventId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-webhook_delivery-status-next_attempt_at")
+                    .table(WebhookDelivery::Table)
+                    .col(WebhookDelivery::Status)
+                    .col(WebhookDelivery::NextAttemptAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WebhookDelivery::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(WebhookSubscription::Table).to_owned())
+            .await
+    }
+}
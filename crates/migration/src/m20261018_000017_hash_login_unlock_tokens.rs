@@ -0,0 +1,31 @@
+use sea_orm_migration::prelude::*;
+
+/// Unlock tokens are stored as hashes from now on. Tokens mailed before cannot be
+/// hashed after the fact, so they stop working; their lockouts expire on their own.
+const HASHED: &str = r#"
+UPDATE login_attempt SET unlock_token = NULL;
+ALTER TABLE login_attempt RENAME COLUMN unlock_token TO unlock_token_hash;
+"#;
+
+const PLAIN: &str = r#"
+UPDATE login_attempt SET unlock_token_hash = NULL;
+ALTER TABLE login_attempt RENAME COLUMN unlock_token_hash TO unlock_token;
+"#;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.get_connection().execute_unprepared(HASHED).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.get_connection().execute_unprepared(PLAIN).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountDeletionSettings {
+    /// How long a deleted account can be restored before it is erased.
+    #[serde(default = "default_grace_period_days")]
+    pub grace_period_days: u32,
+
+    /// How often accounts past the grace period are looked for.
+    #[serde(default = "default_erase_interval_minutes")]
+    pub erase_interval_minutes: u64,
+}
+
+fn default_grace_period_days() -> u32 {
+    30
+}
+
+fn default_erase_interval_minutes() -> u64 {
+    60
+}
+
+impl Default for AccountDeletionSettings {
+    fn default() -> Self {
+        Self {
+            grace_period_days: default_grace_period_days(),
+            erase_interval_minutes: default_erase_interval_minutes(),
+        }
+    }
+}
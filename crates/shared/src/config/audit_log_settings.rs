@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuditLogSettings {
+    /// Entries older than this are removed.
+    #[serde(default = "default_retention_days")]
+    pub retention_days: u32,
+
+    /// How often expired entries are looked for.
+    #[serde(default = "default_purge_interval_minutes")]
+    pub purge_interval_minutes: u64,
+}
+
+fn default_retention_days() -> u32 {
+    365
+}
+
+fn default_purge_interval_minutes() -> u64 {
+    60
+}
+
+impl Default for AuditLogSettings {
+    fn default() -> Self {
+        Self {
+            retention_days: default_retention_days(),
+            purge_interval_minutes: default_purge_interval_minutes(),
+        }
+    }
+}
@@ -1,19 +1,99 @@
+use crate::config::login_throttle_settings::LoginThrottleSettings;
 use serde::Deserialize;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Clone, Deserialize)]
 pub struct AuthSettings {
     #[serde(default = "default_password_reset_token_expiry_hours")]
     pub password_reset_token_expiry_hours: u64,
+
+    #[serde(default = "default_jwt_secret")]
+    pub jwt_secret: String,
+
+    #[serde(default = "default_access_token_ttl_minutes")]
+    pub access_token_ttl_minutes: u64,
+
+    /// Lifetime of the partial token that only allows the TOTP login step or enrollment.
+    #[serde(default = "default_two_factor_token_ttl_minutes")]
+    pub two_factor_token_ttl_minutes: u64,
+
+    /// Issuer shown next to the account in authenticator apps.
+    #[serde(default = "default_totp_issuer")]
+    pub totp_issuer: String,
+
+    /// Lifetime of a passwordless login link sent by email.
+    #[serde(default = "default_magic_link_ttl_minutes")]
+    pub magic_link_ttl_minutes: u64,
+
+    #[serde(default)]
+    pub login_throttle: LoginThrottleSettings,
 }
 
 fn default_password_reset_token_expiry_hours() -> u64 {
     24
 }
 
+/// Shortest `jwt_secret` accepted in production; HS256 keys should be at least 256 bits.
+pub const MIN_JWT_SECRET_BYTES: usize = 32;
+
+const DEFAULT_JWT_SECRET: &str = "change-me-in-production";
+
+fn default_jwt_secret() -> String {
+    DEFAULT_JWT_SECRET.to_string()
+}
+
+fn default_access_token_ttl_minutes() -> u64 {
+    15
+}
+
+fn default_two_factor_token_ttl_minutes() -> u64 {
+    5
+}
+
+fn default_totp_issuer() -> String {
+    "mein-ausmalbild.de".to_string()
+}
+
+fn default_magic_link_ttl_minutes() -> u64 {
+    15
+}
+
+impl AuthSettings {
+    /// Whether `jwt_secret` is the shipped default or too short to sign tokens safely.
+    pub fn has_weak_jwt_secret(&self) -> bool {
+        self.jwt_secret == DEFAULT_JWT_SECRET || self.jwt_secret.len() < MIN_JWT_SECRET_BYTES
+    }
+}
+
+impl std::fmt::Debug for AuthSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthSettings")
+            .field(
+                "password_reset_token_expiry_hours",
+                &self.password_reset_token_expiry_hours,
+            )
+            .field("jwt_secret", &"********")
+            .field("access_token_ttl_minutes", &self.access_token_ttl_minutes)
+            .field(
+                "two_factor_token_ttl_minutes",
+                &self.two_factor_token_ttl_minutes,
+            )
+            .field("totp_issuer", &self.totp_issuer)
+            .field("magic_link_ttl_minutes", &self.magic_link_ttl_minutes)
+            .field("login_throttle", &self.login_throttle)
+            .finish()
+    }
+}
+
 impl Default for AuthSettings {
     fn default() -> Self {
         Self {
             password_reset_token_expiry_hours: default_password_reset_token_expiry_hours(),
+            jwt_secret: default_jwt_secret(),
+            access_token_ttl_minutes: default_access_token_ttl_minutes(),
+            two_factor_token_ttl_minutes: default_two_factor_token_ttl_minutes(),
+            totp_issuer: default_totp_issuer(),
+            magic_link_ttl_minutes: default_magic_link_ttl_minutes(),
+            login_throttle: LoginThrottleSettings::default(),
         }
     }
 }
@@ -0,0 +1,38 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DataExportSettings {
+    /// How long the emailed download link works; the archive is removed afterwards.
+    #[serde(default = "default_download_ttl_hours")]
+    pub download_ttl_hours: u64,
+
+    /// Where finished archives are kept until they expire.
+    #[serde(default = "default_storage_dir")]
+    pub storage_dir: String,
+
+    /// How often the worker looks for pending exports.
+    #[serde(default = "default_poll_interval_seconds")]
+    pub poll_interval_seconds: u64,
+}
+
+fn default_download_ttl_hours() -> u64 {
+    72
+}
+
+fn default_storage_dir() -> String {
+    "data/exports".to_string()
+}
+
+fn default_poll_interval_seconds() -> u64 {
+    30
+}
+
+impl Default for DataExportSettings {
+    fn default() -> Self {
+        Self {
+            download_ttl_hours: default_download_ttl_hours(),
+            storage_dir: default_storage_dir(),
+            poll_interval_seconds: default_poll_interval_seconds(),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use serde::Deserialize;
+
+/// Where failed login counters are kept. `memory` is per process and lost on restart,
+/// so it only suits single-instance deployments and tests.
+#[derive(Debug, Clone, Copy, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LoginAttemptStoreKind {
+    #[default]
+    Postgres,
+    Memory,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LoginThrottleSettings {
+    #[serde(default)]
+    pub store: LoginAttemptStoreKind,
+
+    #[serde(default = "default_max_account_failures")]
+    pub max_account_failures: u32,
+
+    #[serde(default = "default_max_ip_failures")]
+    pub max_ip_failures: u32,
+
+    #[serde(default = "default_failure_window_minutes")]
+    pub failure_window_minutes: u64,
+
+    #[serde(default = "default_base_delay_seconds")]
+    pub base_delay_seconds: u64,
+
+    #[serde(default = "default_max_delay_seconds")]
+    pub max_delay_seconds: u64,
+
+    #[serde(default = "default_lockout_minutes")]
+    pub lockout_minutes: u64,
+}
+
+fn default_max_account_failures() -> u32 {
+    5
+}
+
+fn default_max_ip_failures() -> u32 {
+    20
+}
+
+fn default_failure_window_minutes() -> u64 {
+    15
+}
+
+fn default_base_delay_seconds() -> u64 {
+    1
+}
+
+fn default_max_delay_seconds() -> u64 {
+    60
+}
+
+fn default_lockout_minutes() -> u64 {
+    15
+}
+
+impl Default for LoginThrottleSettings {
+    fn default() -> Self {
+        Self {
+            store: LoginAttemptStoreKind::default(),
+            max_account_failures: default_max_account_failures(),
+            max_ip_failures: default_max_ip_failures(),
+            failure_window_minutes: default_failure_window_minutes(),
+            base_delay_seconds: default_base_delay_seconds(),
+            max_delay_seconds: default_max_delay_seconds(),
+            lockout_minutes: default_lockout_minutes(),
+        }
+    }
+}
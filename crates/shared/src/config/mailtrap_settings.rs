@@ -9,6 +9,38 @@ pub struct MailtrapSettings {
     pub verification_base_url: String,
     pub password_reset_template_uuid: String,
     pub password_reset_base_url: String,
+    #[serde(default)]
+    pub account_unlock_template_uuid: String,
+    #[serde(default = "default_account_unlock_base_url")]
+    pub account_unlock_base_url: String,
+    #[serde(default)]
+    pub magic_link_template_uuid: String,
+    #[serde(default = "default_magic_link_base_url")]
+    pub magic_link_base_url: String,
+    #[serde(default)]
+    pub account_restore_template_uuid: String,
+    #[serde(default = "default_account_restore_base_url")]
+    pub account_restore_base_url: String,
+    #[serde(default)]
+    pub data_export_template_uuid: String,
+    #[serde(default = "default_data_export_base_url")]
+    pub data_export_base_url: String,
+}
+
+fn default_account_unlock_base_url() -> String {
+    "http://localhost:3030/user/unlock-account.html".to_string()
+}
+
+fn default_magic_link_base_url() -> String {
+    "http://localhost:3030/user/magic-link.html".to_string()
+}
+
+fn default_account_restore_base_url() -> String {
+    "http://localhost:3030/user/restore-account.html".to_string()
+}
+
+fn default_data_export_base_url() -> String {
+    "http://localhost:8081/data-export/download".to_string()
 }
 
 impl Default for MailtrapSettings {
@@ -21,6 +53,14 @@ impl Default for MailtrapSettings {
             verification_base_url: "http://localhost:3030/user/mailverfication.html".to_string(),
             password_reset_template_uuid: "23b0bba6-f54e-45fa-85a5-f219c89df3e0".to_string(),
             password_reset_base_url: "http://localhost:3030/user/reset-password.html".to_string(),
+            account_unlock_template_uuid: String::new(),
+            account_unlock_base_url: default_account_unlock_base_url(),
+            magic_link_template_uuid: String::new(),
+            magic_link_base_url: default_magic_link_base_url(),
+            account_restore_template_uuid: String::new(),
+            account_restore_base_url: default_account_restore_base_url(),
+            data_export_template_uuid: String::new(),
+            data_export_base_url: default_data_export_base_url(),
         }
     }
 }
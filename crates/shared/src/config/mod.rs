@@ -1,13 +1,31 @@
+pub mod account_deletion_settings;
+pub mod audit_log_settings;
 pub mod auth_settings;
+pub mod data_export_settings;
 pub mod database_settings;
 pub mod logging_settings;
+pub mod login_throttle_settings;
 pub mod mailtrap_settings;
+pub mod oidc_settings;
+pub mod passkey_settings;
+pub mod rate_limit_settings;
 pub mod server_settings;
 pub mod settings;
+pub mod webhook_settings;
 
+pub use crate::config::account_deletion_settings::AccountDeletionSettings;
+pub use crate::config::audit_log_settings::AuditLogSettings;
 pub use crate::config::auth_settings::AuthSettings;
+pub use crate::config::data_export_settings::DataExportSettings;
 pub use crate::config::database_settings::DatabaseSettings;
 pub use crate::config::logging_settings::LoggingSettings;
+pub use crate::config::login_throttle_settings::{LoginAttemptStoreKind, LoginThrottleSettings};
 pub use crate::config::mailtrap_settings::MailtrapSettings;
+pub use crate::config::oidc_settings::{OidcProviderSettings, OidcSettings};
+pub use crate::config::passkey_settings::PasskeySettings;
+pub use crate::config::rate_limit_settings::{
+    ApiKeyRateLimitSettings, RateLimitKey, RateLimitPolicySettings, RateLimitSettings,
+};
 pub use crate::config::server_settings::ServerSettings;
 pub use crate::config::settings::Settings;
+pub use crate::config::webhook_settings::WebhookSettings;
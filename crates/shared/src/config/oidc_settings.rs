@@ -0,0 +1,82 @@
+use serde::Deserialize;
+
+/// "Sign in with ..." providers. Anything speaking OpenID Connect discovery works,
+/// e.g. Google (`https://accounts.google.com`) or Apple (`https://appleid.apple.com`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcSettings {
+    #[serde(default)]
+    pub providers: Vec<OidcProviderSettings>,
+
+    /// How long the provider may take to redirect back after a login started.
+    #[serde(default = "default_login_timeout_seconds")]
+    pub login_timeout_seconds: u64,
+
+    /// How long a user has to confirm linking a provider account to their account.
+    #[serde(default = "default_link_ttl_minutes")]
+    pub link_ttl_minutes: u64,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct OidcProviderSettings {
+    /// Used in the API paths, e.g. `google` for `/auth/oidc/google/authorize`.
+    pub name: String,
+
+    /// Must match the `issuer` of the discovery document and ID tokens.
+    pub issuer: String,
+
+    pub client_id: String,
+
+    /// For Apple this is the signed client secret JWT.
+    #[serde(default)]
+    pub client_secret: String,
+
+    /// Frontend page the provider redirects to with `code` and `state`.
+    pub redirect_uri: String,
+
+    #[serde(default = "default_scopes")]
+    pub scopes: Vec<String>,
+
+    /// E.g. `form_post`, which Apple requires when asking for the email scope.
+    #[serde(default)]
+    pub response_mode: Option<String>,
+}
+
+impl std::fmt::Debug for OidcProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OidcProviderSettings")
+            .field("name", &self.name)
+            .field("issuer", &self.issuer)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"********")
+            .field("redirect_uri", &self.redirect_uri)
+            .field("scopes", &self.scopes)
+            .field("response_mode", &self.response_mode)
+            .finish()
+    }
+}
+
+fn default_login_timeout_seconds() -> u64 {
+    600
+}
+
+fn default_link_ttl_minutes() -> u64 {
+    15
+}
+
+fn default_scopes() -> Vec<String> {
+    vec![
+        "openid".to_string(),
+        "email".to_string(),
+        "profile".to_string(),
+    ]
+}
+
+impl Default for OidcSettings {
+    fn default() -> Self {
+        Self {
+            providers: Vec::new(),
+            login_timeout_seconds: default_login_timeout_seconds(),
+            link_ttl_minutes: default_link_ttl_minutes(),
+        }
+    }
+}
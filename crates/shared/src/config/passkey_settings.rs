@@ -0,0 +1,48 @@
+use serde::Deserialize;
+
+/// WebAuthn relying party. Passkeys are bound to `rp_id`, so changing it later
+/// invalidates every registered passkey.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PasskeySettings {
+    /// Registrable domain of the frontend, e.g. `mein-ausmalbild.de`.
+    #[serde(default = "default_rp_id")]
+    pub rp_id: String,
+
+    #[serde(default = "default_rp_name")]
+    pub rp_name: String,
+
+    /// Origins the browser may report, e.g. `https://mein-ausmalbild.de`.
+    #[serde(default = "default_origins")]
+    pub origins: Vec<String>,
+
+    /// How long a registration or login ceremony stays valid.
+    #[serde(default = "default_ceremony_timeout_seconds")]
+    pub ceremony_timeout_seconds: u64,
+}
+
+fn default_rp_id() -> String {
+    "localhost".to_string()
+}
+
+fn default_rp_name() -> String {
+    "mein-ausmalbild.de".to_string()
+}
+
+fn default_origins() -> Vec<String> {
+    vec!["http://localhost:3030".to_string()]
+}
+
+fn default_ceremony_timeout_seconds() -> u64 {
+    300
+}
+
+impl Default for PasskeySettings {
+    fn default() -> Self {
+        Self {
+            rp_id: default_rp_id(),
+            rp_name: default_rp_name(),
+            origins: default_origins(),
+            ceremony_timeout_seconds: default_ceremony_timeout_seconds(),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use serde::Deserialize;
+
+/// What a rate limit bucket is keyed by.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RateLimitKey {
+    Ip,
+    /// The authenticated user; anonymous requests fall back to the client IP.
+    User,
+}
+
+/// A token bucket per key: up to `capacity` requests in a burst, refilled by one
+/// request every `refill_every_seconds`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RateLimitPolicySettings {
+    pub name: String,
+    /// Route patterns as registered in the router, e.g. `/auth/signup` or `/users/{id}`.
+    /// `*` matches every route not claimed by an earlier policy.
+    pub routes: Vec<String>,
+    pub capacity: u32,
+    pub refill_every_seconds: u64,
+    pub key: RateLimitKey,
+}
+
+/// One bucket per API key, used instead of the route policies for requests made with a
+/// valid key to a route that accepts keys.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiKeyRateLimitSettings {
+    pub capacity: u32,
+    pub refill_every_seconds: u64,
+}
+
+impl Default for ApiKeyRateLimitSettings {
+    fn default() -> Self {
+        Self {
+            capacity: 60,
+            refill_every_seconds: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RateLimitSettings {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+
+    /// Evaluated in order; the first policy listing the route applies.
+    #[serde(default = "default_policies")]
+    pub policies: Vec<RateLimitPolicySettings>,
+
+    #[serde(default)]
+    pub api_keys: ApiKeyRateLimitSettings,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_policies() -> Vec<RateLimitPolicySettings> {
+    vec![
+        RateLimitPolicySettings {
+            name: "email".to_string(),
+            routes: vec![
+                "/auth/signup".to_string(),
+                "/auth/forgot-password".to_string(),
+                "/auth/magic-link".to_string(),
+                "/me/data-export".to_string(),
+            ],
+            capacity: 3,
+            refill_every_seconds: 300,
+            key: RateLimitKey::Ip,
+        },
+        RateLimitPolicySettings {
+            name: "auth".to_string(),
+            routes: vec![
+                "/auth/login".to_string(),
+                "/auth/login/totp".to_string(),
+                "/auth/passkeys/options".to_string(),
+                "/auth/passkeys/login".to_string(),
+                "/auth/magic-link/login".to_string(),
+                "/auth/oidc/{provider}/authorize".to_string(),
+                "/auth/oidc/{provider}/callback".to_string(),
+                "/auth/unlock".to_string(),
+                "/auth/verify-email".to_string(),
+                "/auth/reset-password".to_string(),
+                "/data-export/download".to_string(),
+                "/auth/restore-account".to_string(),
+            ],
+            capacity: 10,
+            refill_every_seconds: 6,
+            key: RateLimitKey::Ip,
+        },
+        RateLimitPolicySettings {
+            name: "api".to_string(),
+            routes: vec!["*".to_string()],
+            capacity: 100,
+            refill_every_seconds: 1,
+            key: RateLimitKey::User,
+        },
+    ]
+}
+
+impl Default for RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            policies: default_policies(),
+            api_keys: ApiKeyRateLimitSettings::default(),
+        }
+    }
+}
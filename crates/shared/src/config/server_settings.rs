@@ -7,6 +7,10 @@ pub struct ServerSettings {
 
     #[serde(default = "default_port")]
     pub port: u16,
+
+    /// Reverse proxies whose `X-Forwarded-For` header is trusted, as IPs or CIDR ranges.
+    #[serde(default)]
+    pub trusted_proxies: Vec<String>,
 }
 
 impl Default for ServerSettings {
@@ -14,6 +18,7 @@ impl Default for ServerSettings {
         Self {
             host: default_host(),
             port: default_port(),
+            trusted_proxies: Vec::new(),
         }
     }
 }
@@ -1,8 +1,15 @@
-use crate::config::auth_settings::AuthSettings;
+use crate::config::account_deletion_settings::AccountDeletionSettings;
+use crate::config::audit_log_settings::AuditLogSettings;
+use crate::config::auth_settings::{AuthSettings, MIN_JWT_SECRET_BYTES};
+use crate::config::data_export_settings::DataExportSettings;
 use crate::config::database_settings::DatabaseSettings;
 use crate::config::logging_settings::LoggingSettings;
 use crate::config::mailtrap_settings::MailtrapSettings;
+use crate::config::oidc_settings::OidcSettings;
+use crate::config::passkey_settings::PasskeySettings;
+use crate::config::rate_limit_settings::RateLimitSettings;
 use crate::config::server_settings::ServerSettings;
+use crate::config::webhook_settings::WebhookSettings;
 use serde::Deserialize;
 use std::net::{IpAddr, SocketAddr};
 
@@ -25,6 +32,27 @@ pub struct Settings {
 
     #[serde(default)]
     pub auth: AuthSettings,
+
+    #[serde(default)]
+    pub rate_limit: RateLimitSettings,
+
+    #[serde(default)]
+    pub passkeys: PasskeySettings,
+
+    #[serde(default)]
+    pub oidc: OidcSettings,
+
+    #[serde(default)]
+    pub audit_log: AuditLogSettings,
+
+    #[serde(default)]
+    pub account_deletion: AccountDeletionSettings,
+
+    #[serde(default)]
+    pub data_export: DataExportSettings,
+
+    #[serde(default)]
+    pub webhooks: WebhookSettings,
 }
 
 impl Default for Settings {
@@ -36,6 +64,13 @@ impl Default for Settings {
             database: DatabaseSettings::default(),
             mailtrap: MailtrapSettings::default(),
             auth: AuthSettings::default(),
+            rate_limit: RateLimitSettings::default(),
+            passkeys: PasskeySettings::default(),
+            oidc: OidcSettings::default(),
+            audit_log: AuditLogSettings::default(),
+            account_deletion: AccountDeletionSettings::default(),
+            data_export: DataExportSettings::default(),
+            webhooks: WebhookSettings::default(),
         }
     }
 }
@@ -93,11 +128,78 @@ impl Settings {
             settings.mailtrap.verification_base_url = base_url;
         }
 
+        if let Some(secret) = std::env::var("JWT_SECRET")
+            .ok()
+            .filter(|s| !s.trim().is_empty())
+        {
+            settings.auth.jwt_secret = secret;
+        }
+
+        settings.validate()?;
         Ok(settings)
     }
 
+    pub fn is_production(&self) -> bool {
+        matches!(
+            self.env.to_ascii_lowercase().as_str(),
+            "prod" | "production"
+        )
+    }
+
+    /// Refuses settings the server must not run with, so a misconfigured deploy
+    /// fails at startup instead of issuing forgeable tokens.
+    pub fn validate(&self) -> Result<(), config::ConfigError> {
+        if self.is_production() && self.auth.has_weak_jwt_secret() {
+            return Err(config::ConfigError::Message(format!(
+                "auth.jwt_secret must be set to a random secret of at least {} bytes in production",
+                MIN_JWT_SECRET_BYTES
+            )));
+        }
+        Ok(())
+    }
+
     pub fn socket_addr(&self) -> Result<SocketAddr, std::net::AddrParseError> {
         let ip: IpAddr = self.server.host.parse()?;
         Ok(SocketAddr::from((ip, self.server.port)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(env: &str, jwt_secret: &str) -> Settings {
+        let mut settings = Settings {
+            env: env.to_string(),
+            ..Settings::default()
+        };
+        settings.auth.jwt_secret = jwt_secret.to_string();
+        settings
+    }
+
+    #[test]
+    fn test_production_refuses_default_jwt_secret() {
+        assert!(Settings::default().validate().is_err());
+        assert!(
+            settings("production", "change-me-in-production")
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_production_refuses_short_jwt_secret() {
+        assert!(settings("prod", &"x".repeat(31)).validate().is_err());
+        assert!(settings("prod", &"x".repeat(32)).validate().is_ok());
+    }
+
+    #[test]
+    fn test_other_environments_accept_any_jwt_secret() {
+        assert!(settings("local", "local-dev-secret").validate().is_ok());
+        assert!(
+            settings("test", "change-me-in-production")
+                .validate()
+                .is_ok()
+        );
+    }
+}
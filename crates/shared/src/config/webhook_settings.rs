@@ -0,0 +1,61 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookSettings {
+    /// How often the worker relays new events and sends due deliveries.
+    #[serde(default = "default_poll_interval_seconds")]
+    pub poll_interval_seconds: u64,
+
+    /// How long an endpoint has to answer before the attempt counts as failed.
+    #[serde(default = "default_timeout_seconds")]
+    pub timeout_seconds: u64,
+
+    /// Attempts before a delivery is given up; admins can still redeliver it.
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+
+    /// Wait after the first failed attempt; doubles with every further one.
+    #[serde(default = "default_retry_base_delay_seconds")]
+    pub retry_base_delay_seconds: u64,
+
+    #[serde(default = "default_retry_max_delay_minutes")]
+    pub retry_max_delay_minutes: u64,
+
+    /// Accepts plain `http://` endpoints. Only meant for local development and tests;
+    /// otherwise payloads and signatures would travel unencrypted.
+    #[serde(default)]
+    pub allow_insecure_urls: bool,
+}
+
+fn default_poll_interval_seconds() -> u64 {
+    10
+}
+
+fn default_timeout_seconds() -> u64 {
+    10
+}
+
+fn default_max_attempts() -> u32 {
+    8
+}
+
+fn default_retry_base_delay_seconds() -> u64 {
+    30
+}
+
+fn default_retry_max_delay_minutes() -> u64 {
+    360
+}
+
+impl Default for WebhookSettings {
+    fn default() -> Self {
+        Self {
+            poll_interval_seconds: default_poll_interval_seconds(),
+            timeout_seconds: default_timeout_seconds(),
+            max_attempts: default_max_attempts(),
+            retry_base_delay_seconds: default_retry_base_delay_seconds(),
+            retry_max_delay_minutes: default_retry_max_delay_minutes(),
+            allow_insecure_urls: false,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use application::ports::access_token_service::{
+    AccessTokenClaims, AccessTokenError, AccessTokenService, IssuedAccessToken,
+};
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use uuid::Uuid;
+
+/// Issues opaque random tokens and remembers their claims, so [`verify`] accepts
+/// exactly the tokens it issued.
+///
+/// [`verify`]: AccessTokenService::verify
+#[derive(Default)]
+pub struct FakeAccessTokenService {
+    issued: Mutex<HashMap<String, AccessTokenClaims>>,
+}
+
+impl FakeAccessTokenService {
+    /// How long every issued token claims to be valid.
+    pub const EXPIRES_IN_SECONDS: u64 = 900;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The claims of every token issued so far, in no particular order.
+    pub fn issued(&self) -> Vec<AccessTokenClaims> {
+        self.issued
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .cloned()
+            .collect()
+    }
+}
+
+impl AccessTokenService for FakeAccessTokenService {
+    fn issue(&self, claims: &AccessTokenClaims) -> Result<IssuedAccessToken, AccessTokenError> {
+        let token = format!("access-{}", Uuid::new_v4());
+        self.issued
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(token.clone(), claims.clone());
+        Ok(IssuedAccessToken {
+            token,
+            expires_in_seconds: Self::EXPIRES_IN_SECONDS,
+        })
+    }
+
+    fn verify(&self, token: &str) -> Result<AccessTokenClaims, AccessTokenError> {
+        self.issued
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(token)
+            .cloned()
+            .ok_or(AccessTokenError::InvalidToken)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::table::Table;
+use application::ports::TokenRepositoryError;
+use application::ports::account_restore_token_repository::{
+    AccountRestoreToken, AccountRestoreTokenRepository,
+};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Account restore tokens kept in memory, by hash like in Postgres.
+pub struct InMemoryAccountRestoreTokenRepository {
+    table: Table<AccountRestoreToken>,
+}
+
+impl InMemoryAccountRestoreTokenRepository {
+    pub fn new() -> Self {
+        Self::with_tokens([])
+    }
+
+    pub fn with_tokens(tokens: impl IntoIterator<Item = AccountRestoreToken>) -> Self {
+        Self {
+            table: Table::new(tokens.into_iter().collect()),
+        }
+    }
+
+    /// Every stored token, in insertion order.
+    pub fn tokens(&self) -> Vec<AccountRestoreToken> {
+        self.table.read()
+    }
+}
+
+impl Default for InMemoryAccountRestoreTokenRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn database_error(msg: &str) -> TokenRepositoryError {
+    TokenRepositoryError::DatabaseError(msg.to_string())
+}
+
+#[async_trait]
+impl AccountRestoreTokenRepository for InMemoryAccountRestoreTokenRepository {
+    async fn create(&self, token: &AccountRestoreToken) -> Result<(), TokenRepositoryError> {
+        let mut tokens = self.table.write().map_err(database_error)?;
+        tokens.rows.push(token.clone());
+        Ok(())
+    }
+
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<AccountRestoreToken>, TokenRepositoryError> {
+        Ok(self
+            .tokens()
+            .into_iter()
+            .find(|token| token.token_hash == token_hash))
+    }
+
+    async fn delete_by_user_id(&self, user_id: &Uuid) -> Result<(), TokenRepositoryError> {
+        let mut tokens = self.table.write().map_err(database_error)?;
+        tokens.rows.retain(|token| token.user_id != *user_id);
+        Ok(())
+    }
+}
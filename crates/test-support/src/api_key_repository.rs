@@ -0,0 +1,98 @@
+use crate::table::Table;
+use application::ports::api_key_repository::{ApiKey, ApiKeyRepository, ApiKeyRepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::cmp::Reverse;
+use uuid::Uuid;
+
+/// API keys kept in memory.
+pub struct InMemoryApiKeyRepository {
+    table: Table<ApiKey>,
+}
+
+impl InMemoryApiKeyRepository {
+    pub fn new() -> Self {
+        Self::with_keys([])
+    }
+
+    pub fn with_keys(keys: impl IntoIterator<Item = ApiKey>) -> Self {
+        Self {
+            table: Table::new(keys.into_iter().collect()),
+        }
+    }
+
+    /// Every stored key, in insertion order.
+    pub fn keys(&self) -> Vec<ApiKey> {
+        self.table.read()
+    }
+
+    /// Makes every later write fail with a database error.
+    pub fn fail_writes(&self) {
+        self.table.fail_writes();
+    }
+}
+
+impl Default for InMemoryApiKeyRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn database_error(msg: &str) -> ApiKeyRepositoryError {
+    ApiKeyRepositoryError::DatabaseError(msg.to_string())
+}
+
+#[async_trait]
+impl ApiKeyRepository for InMemoryApiKeyRepository {
+    async fn create(&self, api_key: &ApiKey) -> Result<(), ApiKeyRepositoryError> {
+        let mut keys = self.table.write().map_err(database_error)?;
+        if keys
+            .rows
+            .iter()
+            .any(|other| other.key_hash == api_key.key_hash)
+        {
+            return Err(database_error(
+                "duplicate key value violates unique constraint",
+            ));
+        }
+        keys.rows.push(api_key.clone());
+        Ok(())
+    }
+
+    async fn find_by_key_hash(
+        &self,
+        key_hash: &str,
+    ) -> Result<Option<ApiKey>, ApiKeyRepositoryError> {
+        Ok(self.keys().into_iter().find(|key| key.key_hash == key_hash))
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<ApiKey>, ApiKeyRepositoryError> {
+        let mut keys: Vec<ApiKey> = self
+            .keys()
+            .into_iter()
+            .filter(|key| key.user_id == user_id)
+            .collect();
+        keys.sort_by_key(|key| Reverse(key.created_at));
+        Ok(keys)
+    }
+
+    async fn record_use(
+        &self,
+        id: Uuid,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), ApiKeyRepositoryError> {
+        let mut keys = self.table.write().map_err(database_error)?;
+        for key in keys.rows.iter_mut().filter(|key| key.id == id) {
+            key.last_used_at = Some(used_at);
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiKeyRepositoryError> {
+        let mut keys = self.table.write().map_err(database_error)?;
+        let before = keys.rows.len();
+        keys.rows
+            .retain(|key| !(key.id == id && key.user_id == user_id));
+        Ok(keys.rows.len() < before)
+    }
+}
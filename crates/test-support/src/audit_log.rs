@@ -0,0 +1,88 @@
+use crate::table::Table;
+use application::ports::audit_log::{AuditEntry, AuditLog, AuditLogError, AuditLogFilter};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Audit entries kept in memory, filtered like the Postgres log: `from` is
+/// inclusive and `to` exclusive.
+pub struct InMemoryAuditLog {
+    pub(crate) table: Table<AuditEntry>,
+}
+
+impl InMemoryAuditLog {
+    pub fn new() -> Self {
+        Self {
+            table: Table::new(Vec::new()),
+        }
+    }
+
+    /// Every recorded entry, oldest first.
+    pub fn entries(&self) -> Vec<AuditEntry> {
+        self.table.read()
+    }
+
+    /// Makes every later write fail with a database error.
+    pub fn fail_writes(&self) {
+        self.table.fail_writes();
+    }
+}
+
+impl Default for InMemoryAuditLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn matches(filter: &AuditLogFilter, entry: &AuditEntry) -> bool {
+    filter.actor_id.is_none_or(|id| entry.actor_id == Some(id))
+        && filter
+            .target_id
+            .is_none_or(|id| entry.target_id == Some(id))
+        && filter
+            .action
+            .as_ref()
+            .is_none_or(|action| &entry.action == action)
+        && filter.from.is_none_or(|from| entry.occurred_at >= from)
+        && filter.to.is_none_or(|to| entry.occurred_at < to)
+}
+
+fn database_error(msg: &str) -> AuditLogError {
+    AuditLogError::DatabaseError(msg.to_string())
+}
+
+#[async_trait]
+impl AuditLog for InMemoryAuditLog {
+    async fn record(&self, entry: &AuditEntry) -> Result<(), AuditLogError> {
+        let mut entries = self.table.write().map_err(database_error)?;
+        entries.rows.push(entry.clone());
+        Ok(())
+    }
+
+    async fn find(
+        &self,
+        filter: &AuditLogFilter,
+        page: u64,
+        page_size: u64,
+    ) -> Result<(Vec<AuditEntry>, u64), AuditLogError> {
+        let mut matching: Vec<AuditEntry> = self
+            .entries()
+            .into_iter()
+            .filter(|entry| matches(filter, entry))
+            .collect();
+        matching.sort_by_key(|entry| std::cmp::Reverse(entry.occurred_at));
+        let total = matching.len() as u64;
+        let entries = matching
+            .into_iter()
+            .skip((page * page_size) as usize)
+            .take(page_size as usize)
+            .collect();
+        Ok((entries, total))
+    }
+
+    async fn delete_older_than(&self, cutoff: DateTime<Utc>) -> Result<u64, AuditLogError> {
+        let mut entries = self.table.write().map_err(database_error)?;
+        let before = entries.rows.len();
+        entries.rows.retain(|entry| entry.occurred_at >= cutoff);
+        Ok((before - entries.rows.len()) as u64)
+    }
+}
@@ -0,0 +1,32 @@
+//! Behaviour every adapter of a port must share, whether it keeps its data in
+//! memory or in Postgres. Each check panics on a violation, so a test only has to
+//! call it with the adapter under test.
+//!
+//! The checks make up unique emails and tokens and never assume an empty store,
+//! so they can run concurrently against one database.
+
+pub mod token_repositories;
+pub mod user_repository;
+
+use domain_users::{EmailAddress, PasswordHash, PersonName, User};
+use uuid::Uuid;
+
+/// An email no other check uses.
+pub fn unique_email() -> String {
+    format!("contract-{}@example.com", Uuid::new_v4())
+}
+
+/// A new user with `email`, as loaded from storage: without pending events.
+pub fn user_with_email(email: &str) -> User {
+    let mut user = User::register(
+        Uuid::new_v4(),
+        PersonName::new_unchecked("Anna".to_string()),
+        PersonName::new_unchecked("Schmidt".to_string()),
+        EmailAddress::new_unchecked(email.to_string()),
+        None,
+        PasswordHash::new_unchecked("hash".to_string()),
+        None,
+    );
+    user.take_events();
+    user
+}
@@ -0,0 +1,86 @@
+//! Tokens reference their user, so every check stores one first through `users`.
+
+use super::{unique_email, user_with_email};
+use application::ports::email_verification_token_repository::{
+    EmailVerificationToken, EmailVerificationTokenRepository,
+};
+use application::ports::password_reset_token_repository::{
+    PasswordResetToken, PasswordResetTokenRepository,
+};
+use application::ports::user_repository::UserRepository;
+use chrono::{SubsecRound, Utc};
+use uuid::Uuid;
+
+async fn stored_user_id(users: &dyn UserRepository) -> Uuid {
+    let user = user_with_email(&unique_email());
+    users.create(&user).await.unwrap();
+    user.id()
+}
+
+pub async fn stores_email_verification_tokens(
+    users: &dyn UserRepository,
+    tokens: &dyn EmailVerificationTokenRepository,
+) {
+    let token = EmailVerificationToken {
+        token: Uuid::new_v4().to_string(),
+        user_id: stored_user_id(users).await,
+    };
+    tokens.create(&token).await.unwrap();
+
+    let found = tokens.find_by_token(&token.token).await.unwrap().unwrap();
+    assert_eq!(found.user_id, token.user_id);
+    assert!(tokens.create(&token).await.is_err());
+
+    tokens.delete_by_token(&token.token).await.unwrap();
+    assert!(tokens.find_by_token(&token.token).await.unwrap().is_none());
+    // Deleting is idempotent.
+    tokens.delete_by_token(&token.token).await.unwrap();
+}
+
+pub async fn stores_password_reset_tokens(
+    users: &dyn UserRepository,
+    tokens: &dyn PasswordResetTokenRepository,
+) {
+    // Postgres keeps microseconds; whole seconds compare equal everywhere.
+    let created_at = Utc::now().trunc_subsecs(0);
+    let token = PasswordResetToken {
+        token: Uuid::new_v4().to_string(),
+        user_id: stored_user_id(users).await,
+        created_at,
+    };
+    tokens.create(&token).await.unwrap();
+
+    let found = tokens.find_by_token(&token.token).await.unwrap().unwrap();
+    assert_eq!(found.user_id, token.user_id);
+    assert_eq!(found.created_at, created_at);
+    assert!(tokens.create(&token).await.is_err());
+
+    tokens.delete_by_token(&token.token).await.unwrap();
+    assert!(tokens.find_by_token(&token.token).await.unwrap().is_none());
+    tokens.delete_by_token(&token.token).await.unwrap();
+}
+
+pub async fn deletes_password_reset_tokens_by_user(
+    users: &dyn UserRepository,
+    tokens: &dyn PasswordResetTokenRepository,
+) {
+    let owner = stored_user_id(users).await;
+    let other = stored_user_id(users).await;
+    let new_token = |user_id| PasswordResetToken {
+        token: Uuid::new_v4().to_string(),
+        user_id,
+        created_at: Utc::now(),
+    };
+    let owned = [new_token(owner), new_token(owner)];
+    let kept = new_token(other);
+    for token in owned.iter().chain([&kept]) {
+        tokens.create(token).await.unwrap();
+    }
+
+    tokens.delete_by_user_id(&owner).await.unwrap();
+
+    for token in &owned {
+        assert!(tokens.find_by_token(&token.token).await.unwrap().is_none());
+    }
+    assert!(tokens.find_by_token(&kept.token).await.unwrap().is_some());
+}
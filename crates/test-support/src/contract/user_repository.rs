@@ -0,0 +1,127 @@
+use super::{unique_email, user_with_email};
+use application::ports::user_repository::{UserRepository, UserRepositoryError};
+use uuid::Uuid;
+
+pub async fn finds_created_users(repo: &dyn UserRepository) {
+    let email = unique_email();
+    let user = user_with_email(&email);
+    repo.create(&user).await.unwrap();
+
+    let by_id = repo.find_by_id(user.id()).await.unwrap().unwrap();
+    assert_eq!(by_id.email(), user.email());
+    assert_eq!(by_id.first_name(), user.first_name());
+    assert_eq!(by_id.password_hash(), user.password_hash());
+    assert_eq!(by_id.role(), user.role());
+    assert_eq!(by_id.version(), user.version());
+    assert!(!by_id.is_deleted());
+    assert!(by_id.events().is_empty());
+
+    let upper = email.to_uppercase();
+    let by_email = repo.find_by_email(&upper).await.unwrap().unwrap();
+    assert_eq!(by_email.id(), user.id());
+    let active = repo.find_active_by_email(&upper).await.unwrap().unwrap();
+    assert_eq!(active.id(), user.id());
+
+    assert!(repo.find_by_id(Uuid::new_v4()).await.unwrap().is_none());
+    assert!(repo.find_by_email(&unique_email()).await.unwrap().is_none());
+}
+
+pub async fn rejects_taken_emails(repo: &dyn UserRepository) {
+    let email = unique_email();
+    repo.create(&user_with_email(&email)).await.unwrap();
+
+    let result = repo.create(&user_with_email(&email.to_uppercase())).await;
+    assert!(matches!(result, Err(UserRepositoryError::AlreadyExists(_))));
+
+    let other = user_with_email(&unique_email());
+    repo.create(&other).await.unwrap();
+    let mut renamed = other.clone();
+    renamed.change_email(user_with_email(&email).email().clone());
+    let result = repo.update(&renamed).await;
+    assert!(matches!(result, Err(UserRepositoryError::AlreadyExists(_))));
+}
+
+pub async fn keeps_deleted_users_out_of_active_queries(repo: &dyn UserRepository) {
+    let email = unique_email();
+    let mut user = user_with_email(&email);
+    repo.create(&user).await.unwrap();
+    user.delete();
+    repo.update(&user).await.unwrap();
+
+    let stored = repo.find_by_id(user.id()).await.unwrap().unwrap();
+    assert!(stored.is_deleted());
+    assert!(repo.find_active_by_email(&email).await.unwrap().is_none());
+    assert!(
+        !repo
+            .find_all_active()
+            .await
+            .unwrap()
+            .iter()
+            .any(|active| active.id() == user.id())
+    );
+    assert_eq!(
+        repo.find_by_email(&email).await.unwrap().map(|u| u.id()),
+        Some(user.id())
+    );
+}
+
+pub async fn frees_the_email_of_deleted_users(repo: &dyn UserRepository) {
+    let email = unique_email();
+    let mut deleted = user_with_email(&email);
+    repo.create(&deleted).await.unwrap();
+    deleted.delete();
+    repo.update(&deleted).await.unwrap();
+    deleted.increment_version();
+
+    let successor = user_with_email(&email);
+    repo.create(&successor).await.unwrap();
+    assert_eq!(
+        repo.find_by_email(&email).await.unwrap().map(|u| u.id()),
+        Some(successor.id())
+    );
+
+    deleted.restore();
+    let result = repo.update(&deleted).await;
+    assert!(matches!(result, Err(UserRepositoryError::AlreadyExists(_))));
+}
+
+pub async fn rejects_stale_updates(repo: &dyn UserRepository) {
+    let user = user_with_email(&unique_email());
+    repo.create(&user).await.unwrap();
+
+    let mut first = repo.find_by_id(user.id()).await.unwrap().unwrap();
+    let mut second = first.clone();
+    first.verify_email();
+    repo.update(&first).await.unwrap();
+
+    let stored = repo.find_by_id(user.id()).await.unwrap().unwrap();
+    assert_eq!(stored.version(), user.version() + 1);
+    assert!(stored.is_email_verified());
+
+    second.delete();
+    let result = repo.update(&second).await;
+    assert!(matches!(
+        result,
+        Err(UserRepositoryError::VersionConflict(_))
+    ));
+
+    let unknown = user_with_email(&unique_email());
+    let result = repo.update(&unknown).await;
+    assert!(matches!(result, Err(UserRepositoryError::NotFound(_))));
+}
+
+pub async fn paginates_active_users(repo: &dyn UserRepository) {
+    for _ in 0..3 {
+        repo.create(&user_with_email(&unique_email()))
+            .await
+            .unwrap();
+    }
+
+    let (first_page, total) = repo.find_all_active_paginated(0, 2).await.unwrap();
+    assert_eq!(first_page.len(), 2);
+    assert!(total >= 3);
+    assert!(first_page.iter().all(|user| !user.is_deleted()));
+
+    let (beyond, _) = repo.find_all_active_paginated(total, 2).await.unwrap();
+    assert!(beyond.is_empty());
+}
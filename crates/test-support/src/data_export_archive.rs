@@ -0,0 +1,70 @@
+use crate::table::Table;
+use application::ports::data_export_archive::{
+    DataExportArchive, DataExportArchiveError, UserDataSnapshot,
+};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Keeps the snapshots instead of rendering them. An opened archive is the debug
+/// rendering of its snapshot, enough for tests to look for values in it.
+pub struct InMemoryDataExportArchive {
+    table: Table<(Uuid, UserDataSnapshot)>,
+}
+
+impl InMemoryDataExportArchive {
+    pub fn new() -> Self {
+        Self {
+            table: Table::new(Vec::new()),
+        }
+    }
+
+    /// The stored snapshot of the export, unless it was deleted.
+    pub fn snapshot(&self, export_id: Uuid) -> Option<UserDataSnapshot> {
+        self.table
+            .read()
+            .into_iter()
+            .find(|(id, _)| *id == export_id)
+            .map(|(_, snapshot)| snapshot)
+    }
+
+    /// Ids of the exports whose archive is still stored.
+    pub fn export_ids(&self) -> Vec<Uuid> {
+        self.table.read().into_iter().map(|(id, _)| id).collect()
+    }
+}
+
+impl Default for InMemoryDataExportArchive {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn storage_error(msg: &str) -> DataExportArchiveError {
+    DataExportArchiveError::StorageError(msg.to_string())
+}
+
+#[async_trait]
+impl DataExportArchive for InMemoryDataExportArchive {
+    async fn store(
+        &self,
+        export_id: Uuid,
+        snapshot: &UserDataSnapshot,
+    ) -> Result<(), DataExportArchiveError> {
+        let mut archives = self.table.write().map_err(storage_error)?;
+        archives.rows.retain(|(id, _)| *id != export_id);
+        archives.rows.push((export_id, snapshot.clone()));
+        Ok(())
+    }
+
+    async fn open(&self, export_id: Uuid) -> Result<Option<Vec<u8>>, DataExportArchiveError> {
+        Ok(self
+            .snapshot(export_id)
+            .map(|snapshot| format!("{:?}", snapshot).into_bytes()))
+    }
+
+    async fn delete(&self, export_id: Uuid) -> Result<(), DataExportArchiveError> {
+        let mut archives = self.table.write().map_err(storage_error)?;
+        archives.rows.retain(|(id, _)| *id != export_id);
+        Ok(())
+    }
+}
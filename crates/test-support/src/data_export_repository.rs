@@ -0,0 +1,152 @@
+use crate::table::Table;
+use application::ports::data_export_repository::{
+    DataExport, DataExportRepository, DataExportRepositoryError, DataExportStatus,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::cmp::Reverse;
+use uuid::Uuid;
+
+/// Data export requests kept in memory, moved through their states like the
+/// Postgres adapter does.
+pub struct InMemoryDataExportRepository {
+    table: Table<DataExport>,
+}
+
+impl InMemoryDataExportRepository {
+    pub fn new() -> Self {
+        Self::with_exports([])
+    }
+
+    pub fn with_exports(exports: impl IntoIterator<Item = DataExport>) -> Self {
+        Self {
+            table: Table::new(exports.into_iter().collect()),
+        }
+    }
+
+    /// Every stored export, in insertion order.
+    pub fn exports(&self) -> Vec<DataExport> {
+        self.table.read()
+    }
+
+    /// Makes every later write fail with a database error.
+    pub fn fail_writes(&self) {
+        self.table.fail_writes();
+    }
+}
+
+impl Default for InMemoryDataExportRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn database_error(msg: &str) -> DataExportRepositoryError {
+    DataExportRepositoryError::DatabaseError(msg.to_string())
+}
+
+fn newest_first(mut exports: Vec<DataExport>) -> Vec<DataExport> {
+    exports.sort_by_key(|export| Reverse(export.created_at));
+    exports
+}
+
+#[async_trait]
+impl DataExportRepository for InMemoryDataExportRepository {
+    async fn create(&self, export: &DataExport) -> Result<(), DataExportRepositoryError> {
+        let mut exports = self.table.write().map_err(database_error)?;
+        exports.rows.push(export.clone());
+        Ok(())
+    }
+
+    async fn find_open_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Option<DataExport>, DataExportRepositoryError> {
+        Ok(self
+            .find_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .find(|export| export.status.is_open()))
+    }
+
+    async fn find_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<DataExport>, DataExportRepositoryError> {
+        Ok(newest_first(
+            self.exports()
+                .into_iter()
+                .filter(|export| export.user_id == user_id)
+                .collect(),
+        ))
+    }
+
+    async fn find_by_download_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<DataExport>, DataExportRepositoryError> {
+        Ok(self
+            .exports()
+            .into_iter()
+            .find(|export| export.download_token_hash.as_deref() == Some(token_hash)))
+    }
+
+    async fn claim_next_pending(&self) -> Result<Option<DataExport>, DataExportRepositoryError> {
+        let mut exports = self.table.write().map_err(database_error)?;
+        let next = exports
+            .rows
+            .iter_mut()
+            .filter(|export| export.status == DataExportStatus::Pending)
+            .min_by_key(|export| export.created_at);
+        Ok(next.map(|export| {
+            export.status = DataExportStatus::Processing;
+            export.clone()
+        }))
+    }
+
+    async fn mark_ready(
+        &self,
+        id: Uuid,
+        download_token_hash: &str,
+        completed_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), DataExportRepositoryError> {
+        let mut exports = self.table.write().map_err(database_error)?;
+        for export in exports.rows.iter_mut().filter(|export| export.id == id) {
+            export.status = DataExportStatus::Ready;
+            export.download_token_hash = Some(download_token_hash.to_string());
+            export.completed_at = Some(completed_at);
+            export.expires_at = Some(expires_at);
+        }
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        completed_at: DateTime<Utc>,
+    ) -> Result<(), DataExportRepositoryError> {
+        let mut exports = self.table.write().map_err(database_error)?;
+        for export in exports.rows.iter_mut().filter(|export| export.id == id) {
+            export.status = DataExportStatus::Failed;
+            export.completed_at = Some(completed_at);
+        }
+        Ok(())
+    }
+
+    async fn expire(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<DataExport>, DataExportRepositoryError> {
+        let mut exports = self.table.write().map_err(database_error)?;
+        let mut expired = Vec::new();
+        for export in exports.rows.iter_mut().filter(|export| {
+            export.status == DataExportStatus::Ready
+                && export.expires_at.is_some_and(|at| at <= now)
+        }) {
+            export.status = DataExportStatus::Expired;
+            expired.push(export.clone());
+        }
+        Ok(expired)
+    }
+}
@@ -0,0 +1,117 @@
+use application::ports::email_service::{EmailError, EmailService};
+use async_trait::async_trait;
+use std::sync::{Mutex, PoisonError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailKind {
+    Verification,
+    PasswordReset,
+    AccountUnlock,
+    MagicLink,
+    AccountRestore,
+    DataExportReady,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SentEmail {
+    pub kind: EmailKind,
+    pub to: String,
+    pub token: String,
+    pub first_name: String,
+}
+
+/// Keeps the emails instead of sending them.
+#[derive(Default)]
+pub struct CapturingEmailService {
+    sent: Mutex<Vec<SentEmail>>,
+}
+
+impl CapturingEmailService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every email sent so far, oldest first.
+    pub fn sent(&self) -> Vec<SentEmail> {
+        self.sent
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn capture(&self, kind: EmailKind, to: &str, token: &str, first_name: &str) {
+        self.sent
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(SentEmail {
+                kind,
+                to: to.to_string(),
+                token: token.to_string(),
+                first_name: first_name.to_string(),
+            });
+    }
+}
+
+#[async_trait]
+impl EmailService for CapturingEmailService {
+    async fn send_verification_email(
+        &self,
+        to: &str,
+        token: &str,
+        first_name: &str,
+        _last_name: &str,
+    ) -> Result<(), EmailError> {
+        self.capture(EmailKind::Verification, to, token, first_name);
+        Ok(())
+    }
+
+    async fn send_password_reset_email(
+        &self,
+        to: &str,
+        token: &str,
+        first_name: &str,
+    ) -> Result<(), EmailError> {
+        self.capture(EmailKind::PasswordReset, to, token, first_name);
+        Ok(())
+    }
+
+    async fn send_account_unlock_email(
+        &self,
+        to: &str,
+        token: &str,
+        first_name: &str,
+    ) -> Result<(), EmailError> {
+        self.capture(EmailKind::AccountUnlock, to, token, first_name);
+        Ok(())
+    }
+
+    async fn send_magic_link_email(
+        &self,
+        to: &str,
+        token: &str,
+        first_name: &str,
+    ) -> Result<(), EmailError> {
+        self.capture(EmailKind::MagicLink, to, token, first_name);
+        Ok(())
+    }
+
+    async fn send_account_restore_email(
+        &self,
+        to: &str,
+        token: &str,
+        first_name: &str,
+    ) -> Result<(), EmailError> {
+        self.capture(EmailKind::AccountRestore, to, token, first_name);
+        Ok(())
+    }
+
+    async fn send_data_export_ready_email(
+        &self,
+        to: &str,
+        token: &str,
+        first_name: &str,
+    ) -> Result<(), EmailError> {
+        self.capture(EmailKind::DataExportReady, to, token, first_name);
+        Ok(())
+    }
+}
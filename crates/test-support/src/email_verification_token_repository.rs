@@ -0,0 +1,71 @@
+use crate::table::Table;
+use application::ports::TokenRepositoryError;
+use application::ports::email_verification_token_repository::{
+    EmailVerificationToken, EmailVerificationTokenRepository,
+};
+use async_trait::async_trait;
+
+/// Email verification tokens kept in memory. Unlike Postgres it does not check
+/// that the token's user exists.
+pub struct InMemoryEmailVerificationTokenRepository {
+    pub(crate) table: Table<EmailVerificationToken>,
+}
+
+impl InMemoryEmailVerificationTokenRepository {
+    pub fn new() -> Self {
+        Self::with_tokens([])
+    }
+
+    pub fn with_tokens(tokens: impl IntoIterator<Item = EmailVerificationToken>) -> Self {
+        Self {
+            table: Table::new(tokens.into_iter().collect()),
+        }
+    }
+
+    /// Every stored token, in insertion order.
+    pub fn tokens(&self) -> Vec<EmailVerificationToken> {
+        self.table.read()
+    }
+
+    /// Makes every later write fail with a database error.
+    pub fn fail_writes(&self) {
+        self.table.fail_writes();
+    }
+}
+
+impl Default for InMemoryEmailVerificationTokenRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn database_error(msg: &str) -> TokenRepositoryError {
+    TokenRepositoryError::DatabaseError(msg.to_string())
+}
+
+#[async_trait]
+impl EmailVerificationTokenRepository for InMemoryEmailVerificationTokenRepository {
+    async fn create(&self, token: &EmailVerificationToken) -> Result<(), TokenRepositoryError> {
+        let mut tokens = self.table.write().map_err(database_error)?;
+        if tokens.rows.iter().any(|other| other.token == token.token) {
+            return Err(database_error(
+                "duplicate key value violates unique constraint",
+            ));
+        }
+        tokens.rows.push(token.clone());
+        Ok(())
+    }
+
+    async fn find_by_token(
+        &self,
+        token: &str,
+    ) -> Result<Option<EmailVerificationToken>, TokenRepositoryError> {
+        Ok(self.tokens().into_iter().find(|other| other.token == token))
+    }
+
+    async fn delete_by_token(&self, token: &str) -> Result<(), TokenRepositoryError> {
+        let mut tokens = self.table.write().map_err(database_error)?;
+        tokens.rows.retain(|other| other.token != token);
+        Ok(())
+    }
+}
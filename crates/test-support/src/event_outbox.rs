@@ -0,0 +1,104 @@
+use crate::table::Table;
+use application::ports::event_outbox::{EventOutbox, EventOutboxError, OutboxEvent};
+use async_trait::async_trait;
+use chrono::Utc;
+use domain_users::UserEvent;
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use uuid::Uuid;
+
+/// Outbox events kept in memory. Positions count up from 1 and, unlike in Postgres,
+/// become readable as soon as they are committed.
+pub struct InMemoryEventOutbox {
+    pub(crate) table: Table<OutboxEvent>,
+    pub(crate) consumer_positions: Mutex<HashMap<String, i64>>,
+}
+
+impl InMemoryEventOutbox {
+    pub fn new() -> Self {
+        Self {
+            table: Table::new(Vec::new()),
+            consumer_positions: Mutex::default(),
+        }
+    }
+
+    /// Every appended event, oldest first.
+    pub fn events(&self) -> Vec<OutboxEvent> {
+        self.table.read()
+    }
+
+    /// The names of every appended event, oldest first.
+    pub fn names(&self) -> Vec<String> {
+        self.events().into_iter().map(|event| event.name).collect()
+    }
+
+    /// Makes every later write fail with a database error.
+    pub fn fail_writes(&self) {
+        self.table.fail_writes();
+    }
+}
+
+impl Default for InMemoryEventOutbox {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn database_error(msg: &str) -> EventOutboxError {
+    EventOutboxError::DatabaseError(msg.to_string())
+}
+
+#[async_trait]
+impl EventOutbox for InMemoryEventOutbox {
+    async fn append(&self, events: &[UserEvent]) -> Result<(), EventOutboxError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let mut stored = self.table.write().map_err(database_error)?;
+        let occurred_at = Utc::now();
+        for event in events {
+            let position = stored.rows.last().map_or(1, |last| last.position + 1);
+            stored.rows.push(OutboxEvent {
+                position,
+                id: Uuid::new_v4(),
+                name: event.name().to_string(),
+                aggregate_id: event.user_id(),
+                payload: format!(r#"{{"user_id":"{}"}}"#, event.user_id()),
+                occurred_at,
+            });
+        }
+        Ok(())
+    }
+
+    async fn read_after(
+        &self,
+        position: i64,
+        limit: u64,
+    ) -> Result<Vec<OutboxEvent>, EventOutboxError> {
+        Ok(self
+            .events()
+            .into_iter()
+            .filter(|event| event.position > position)
+            .take(limit as usize)
+            .collect())
+    }
+
+    async fn consumer_position(&self, consumer: &str) -> Result<i64, EventOutboxError> {
+        Ok(self
+            .consumer_positions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(consumer)
+            .copied()
+            .unwrap_or(0))
+    }
+
+    async fn commit_position(&self, consumer: &str, position: i64) -> Result<(), EventOutboxError> {
+        self.consumer_positions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(consumer.to_string(), position);
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use application::ports::event_publisher::EventPublisher;
+use async_trait::async_trait;
+use domain_users::UserEvent;
+use std::sync::{Mutex, PoisonError};
+
+/// Keeps the published events instead of dispatching them.
+#[derive(Default)]
+pub struct RecordingEventPublisher {
+    events: Mutex<Vec<UserEvent>>,
+}
+
+impl RecordingEventPublisher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every event published so far, oldest first.
+    pub fn events(&self) -> Vec<UserEvent> {
+        self.events
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+#[async_trait]
+impl EventPublisher for RecordingEventPublisher {
+    async fn publish(&self, events: &[UserEvent]) {
+        self.events
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .extend_from_slice(events);
+    }
+}
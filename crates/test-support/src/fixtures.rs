@@ -0,0 +1,61 @@
+//! Rows to seed the in-memory adapters with, for the cases most tests share.
+
+use application::ports::event_outbox::OutboxEvent;
+use application::ports::session_repository::Session;
+use application::ports::two_factor_repository::TotpEnrollment;
+use application::ports::webhook_repository::WebhookSubscription;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+pub use crate::contract::user_with_email;
+
+/// A session just opened by `user_id`, valid for another 15 minutes.
+pub fn active_session(user_id: Uuid) -> Session {
+    let now = Utc::now();
+    Session {
+        id: Uuid::new_v4(),
+        user_id,
+        user_agent: None,
+        ip_address: None,
+        created_at: now,
+        last_seen_at: now,
+        expires_at: now + Duration::minutes(15),
+        revoked_at: None,
+    }
+}
+
+/// A confirmed TOTP enrollment with the secret `SECRET` and no code used yet.
+pub fn confirmed_totp(user_id: Uuid) -> TotpEnrollment {
+    TotpEnrollment {
+        user_id,
+        secret: "SECRET".to_string(),
+        confirmed_at: Some(Utc::now()),
+        last_used_step: None,
+        created_at: Utc::now(),
+    }
+}
+
+/// An event named `name` at the start of the outbox.
+pub fn outbox_event(name: &str) -> OutboxEvent {
+    OutboxEvent {
+        position: 1,
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        aggregate_id: Uuid::new_v4(),
+        payload: "{}".to_string(),
+        occurred_at: Utc::now(),
+    }
+}
+
+/// A subscription of `https://partner.example.com/hooks` to `events`, signed with
+/// `whsec_secret`.
+pub fn webhook_subscription(events: &[&str]) -> WebhookSubscription {
+    WebhookSubscription {
+        id: Uuid::new_v4(),
+        url: "https://partner.example.com/hooks".to_string(),
+        events: events.iter().map(|name| name.to_string()).collect(),
+        secret: "whsec_secret".to_string(),
+        created_by: Uuid::new_v4(),
+        created_at: Utc::now(),
+    }
+}
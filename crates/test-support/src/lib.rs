@@ -0,0 +1,68 @@
+//! In-memory adapters for the application ports, for tests that exercise use
+//! cases without a database, mail server or slow password hashing.
+//!
+//! The repositories behave like their Postgres counterparts: emails are unique
+//! among active users regardless of case, deleted users are left out of the
+//! "active" queries, and updates are checked against the stored version. The
+//! [`contract`] suite runs against both implementations to keep it that way.
+
+pub mod access_token_service;
+pub mod account_restore_token_repository;
+pub mod api_key_repository;
+pub mod audit_log;
+pub mod contract;
+pub mod data_export_archive;
+pub mod data_export_repository;
+pub mod email_service;
+pub mod email_verification_token_repository;
+pub mod event_outbox;
+pub mod event_publisher;
+pub mod fixtures;
+pub mod linked_identity_repository;
+pub mod login_attempt_store;
+pub mod magic_link_token_repository;
+pub mod oidc_client;
+pub mod oidc_flow_store;
+pub mod one_time_token_service;
+pub mod passkey_ceremony_store;
+pub mod passkey_repository;
+pub mod password_hasher;
+pub mod password_reset_token_repository;
+pub mod ports;
+pub mod session_repository;
+mod table;
+pub mod totp_service;
+pub mod two_factor_repository;
+pub mod unit_of_work;
+pub mod user_repository;
+pub mod webauthn_service;
+pub mod webhook_repository;
+
+pub use access_token_service::FakeAccessTokenService;
+pub use account_restore_token_repository::InMemoryAccountRestoreTokenRepository;
+pub use api_key_repository::InMemoryApiKeyRepository;
+pub use audit_log::InMemoryAuditLog;
+pub use data_export_archive::InMemoryDataExportArchive;
+pub use data_export_repository::InMemoryDataExportRepository;
+pub use email_service::{CapturingEmailService, EmailKind, SentEmail};
+pub use email_verification_token_repository::InMemoryEmailVerificationTokenRepository;
+pub use event_outbox::InMemoryEventOutbox;
+pub use event_publisher::RecordingEventPublisher;
+pub use linked_identity_repository::InMemoryLinkedIdentityRepository;
+pub use login_attempt_store::InMemoryLoginAttemptStore;
+pub use magic_link_token_repository::InMemoryMagicLinkTokenRepository;
+pub use oidc_client::FakeOidcClient;
+pub use oidc_flow_store::InMemoryOidcFlowStore;
+pub use one_time_token_service::FakeOneTimeTokenService;
+pub use passkey_ceremony_store::InMemoryPasskeyCeremonyStore;
+pub use passkey_repository::InMemoryPasskeyRepository;
+pub use password_hasher::FastPasswordHasher;
+pub use password_reset_token_repository::InMemoryPasswordResetTokenRepository;
+pub use ports::InMemoryPorts;
+pub use session_repository::InMemorySessionRepository;
+pub use totp_service::FakeTotpService;
+pub use two_factor_repository::InMemoryTwoFactorRepository;
+pub use unit_of_work::InMemoryUnitOfWork;
+pub use user_repository::InMemoryUserRepository;
+pub use webauthn_service::FakeWebauthnService;
+pub use webhook_repository::InMemoryWebhookRepository;
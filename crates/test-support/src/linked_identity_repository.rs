@@ -0,0 +1,79 @@
+use crate::table::Table;
+use application::ports::linked_identity_repository::{
+    LinkedIdentity, LinkedIdentityRepository, LinkedIdentityRepositoryError,
+};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Identities at external providers kept in memory. Like the unique index in
+/// Postgres, a provider subject can be linked to one user only.
+pub struct InMemoryLinkedIdentityRepository {
+    table: Table<LinkedIdentity>,
+}
+
+impl InMemoryLinkedIdentityRepository {
+    pub fn new() -> Self {
+        Self::with_identities([])
+    }
+
+    pub fn with_identities(identities: impl IntoIterator<Item = LinkedIdentity>) -> Self {
+        Self {
+            table: Table::new(identities.into_iter().collect()),
+        }
+    }
+
+    /// Every stored identity, in insertion order.
+    pub fn identities(&self) -> Vec<LinkedIdentity> {
+        self.table.read()
+    }
+}
+
+impl Default for InMemoryLinkedIdentityRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn database_error(msg: &str) -> LinkedIdentityRepositoryError {
+    LinkedIdentityRepositoryError::DatabaseError(msg.to_string())
+}
+
+#[async_trait]
+impl LinkedIdentityRepository for InMemoryLinkedIdentityRepository {
+    async fn create(&self, identity: &LinkedIdentity) -> Result<(), LinkedIdentityRepositoryError> {
+        let mut identities = self.table.write().map_err(database_error)?;
+        if identities
+            .rows
+            .iter()
+            .any(|other| other.provider == identity.provider && other.subject == identity.subject)
+        {
+            return Err(LinkedIdentityRepositoryError::AlreadyLinked);
+        }
+        identities.rows.push(identity.clone());
+        Ok(())
+    }
+
+    async fn find_by_subject(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> Result<Option<LinkedIdentity>, LinkedIdentityRepositoryError> {
+        Ok(self
+            .identities()
+            .into_iter()
+            .find(|identity| identity.provider == provider && identity.subject == subject))
+    }
+
+    async fn find_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<LinkedIdentity>, LinkedIdentityRepositoryError> {
+        let mut identities: Vec<LinkedIdentity> = self
+            .identities()
+            .into_iter()
+            .filter(|identity| identity.user_id == user_id)
+            .collect();
+        identities.sort_by_key(|identity| identity.created_at);
+        Ok(identities)
+    }
+}
@@ -0,0 +1,128 @@
+use crate::table::Table;
+use application::ports::login_attempt_store::{
+    AttemptSubject, LoginAttemptStore, LoginAttemptStoreError, LoginAttempts,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Failed login counters kept in memory.
+pub struct InMemoryLoginAttemptStore {
+    table: Table<LoginAttempts>,
+}
+
+impl InMemoryLoginAttemptStore {
+    pub fn new() -> Self {
+        Self::with_attempts([])
+    }
+
+    pub fn with_attempts(attempts: impl IntoIterator<Item = LoginAttempts>) -> Self {
+        Self {
+            table: Table::new(attempts.into_iter().collect()),
+        }
+    }
+
+    /// The counters of every subject that failed recently.
+    pub fn attempts(&self) -> Vec<LoginAttempts> {
+        self.table.read()
+    }
+}
+
+impl Default for InMemoryLoginAttemptStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn storage_error(msg: &str) -> LoginAttemptStoreError {
+    LoginAttemptStoreError::StorageError(msg.to_string())
+}
+
+#[async_trait]
+impl LoginAttemptStore for InMemoryLoginAttemptStore {
+    async fn find(
+        &self,
+        subject: &AttemptSubject,
+    ) -> Result<Option<LoginAttempts>, LoginAttemptStoreError> {
+        Ok(self
+            .attempts()
+            .into_iter()
+            .find(|attempts| &attempts.subject == subject))
+    }
+
+    async fn record_failure(
+        &self,
+        subject: &AttemptSubject,
+        at: DateTime<Utc>,
+        window_start: DateTime<Utc>,
+    ) -> Result<LoginAttempts, LoginAttemptStoreError> {
+        let mut rows = self.table.write().map_err(storage_error)?;
+        let index = match rows
+            .rows
+            .iter()
+            .position(|attempts| &attempts.subject == subject)
+        {
+            Some(index) => index,
+            None => {
+                rows.rows.push(LoginAttempts {
+                    subject: subject.clone(),
+                    failed_attempts: 0,
+                    last_failed_at: at,
+                    blocked_until: None,
+                    locked_until: None,
+                    unlock_token_hash: None,
+                });
+                rows.rows.len() - 1
+            }
+        };
+
+        let attempts = &mut rows.rows[index];
+        if attempts.last_failed_at < window_start {
+            attempts.failed_attempts = 0;
+        }
+        attempts.failed_attempts += 1;
+        attempts.last_failed_at = at;
+        Ok(attempts.clone())
+    }
+
+    async fn save(&self, attempts: &LoginAttempts) -> Result<(), LoginAttemptStoreError> {
+        let mut rows = self.table.write().map_err(storage_error)?;
+        if let Some(stored) = rows
+            .rows
+            .iter_mut()
+            .find(|stored| stored.subject == attempts.subject)
+        {
+            stored.blocked_until = attempts.blocked_until;
+            stored.locked_until = attempts.locked_until;
+            stored.unlock_token_hash = attempts.unlock_token_hash.clone();
+        }
+        Ok(())
+    }
+
+    async fn clear(&self, subject: &AttemptSubject) -> Result<bool, LoginAttemptStoreError> {
+        let mut rows = self.table.write().map_err(storage_error)?;
+        let before = rows.rows.len();
+        rows.rows.retain(|attempts| &attempts.subject != subject);
+        Ok(rows.rows.len() < before)
+    }
+
+    async fn find_by_unlock_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<LoginAttempts>, LoginAttemptStoreError> {
+        Ok(self
+            .attempts()
+            .into_iter()
+            .find(|attempts| attempts.unlock_token_hash.as_deref() == Some(token_hash)))
+    }
+
+    async fn find_restricted(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<LoginAttempts>, LoginAttemptStoreError> {
+        Ok(self
+            .attempts()
+            .into_iter()
+            .filter(|attempts| attempts.retry_after(now).is_some())
+            .collect())
+    }
+}
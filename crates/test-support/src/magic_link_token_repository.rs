@@ -0,0 +1,69 @@
+use crate::table::Table;
+use application::ports::TokenRepositoryError;
+use application::ports::magic_link_token_repository::{MagicLinkToken, MagicLinkTokenRepository};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Magic link tokens kept in memory, by hash like in Postgres.
+pub struct InMemoryMagicLinkTokenRepository {
+    table: Table<MagicLinkToken>,
+}
+
+impl InMemoryMagicLinkTokenRepository {
+    pub fn new() -> Self {
+        Self::with_tokens([])
+    }
+
+    pub fn with_tokens(tokens: impl IntoIterator<Item = MagicLinkToken>) -> Self {
+        Self {
+            table: Table::new(tokens.into_iter().collect()),
+        }
+    }
+
+    /// Every stored token, in insertion order.
+    pub fn tokens(&self) -> Vec<MagicLinkToken> {
+        self.table.read()
+    }
+}
+
+impl Default for InMemoryMagicLinkTokenRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn database_error(msg: &str) -> TokenRepositoryError {
+    TokenRepositoryError::DatabaseError(msg.to_string())
+}
+
+#[async_trait]
+impl MagicLinkTokenRepository for InMemoryMagicLinkTokenRepository {
+    async fn create(&self, token: &MagicLinkToken) -> Result<(), TokenRepositoryError> {
+        let mut tokens = self.table.write().map_err(database_error)?;
+        tokens.rows.push(token.clone());
+        Ok(())
+    }
+
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<MagicLinkToken>, TokenRepositoryError> {
+        Ok(self
+            .tokens()
+            .into_iter()
+            .find(|token| token.token_hash == token_hash))
+    }
+
+    async fn delete_by_token_hash(&self, token_hash: &str) -> Result<bool, TokenRepositoryError> {
+        let mut tokens = self.table.write().map_err(database_error)?;
+        let before = tokens.rows.len();
+        tokens.rows.retain(|token| token.token_hash != token_hash);
+        Ok(tokens.rows.len() < before)
+    }
+
+    async fn delete_by_user_id(&self, user_id: &Uuid) -> Result<(), TokenRepositoryError> {
+        let mut tokens = self.table.write().map_err(database_error)?;
+        tokens.rows.retain(|token| token.user_id != *user_id);
+        Ok(())
+    }
+}
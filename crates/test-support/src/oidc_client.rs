@@ -0,0 +1,40 @@
+use application::ports::oidc_client::{OidcAuthorization, OidcClient, OidcError, OidcIdentity};
+use async_trait::async_trait;
+
+/// A provider that signs in `identity`. Every login gets the state `state`, and only
+/// the code `code`, passed along with that login's verifier and nonce, is accepted.
+pub struct FakeOidcClient {
+    identity: OidcIdentity,
+}
+
+impl FakeOidcClient {
+    pub fn new(identity: OidcIdentity) -> Self {
+        Self { identity }
+    }
+}
+
+#[async_trait]
+impl OidcClient for FakeOidcClient {
+    async fn authorize(&self, provider: &str) -> Result<OidcAuthorization, OidcError> {
+        Ok(OidcAuthorization {
+            authorization_url: format!("https://{}.example.com/authorize?state=state", provider),
+            state: "state".to_string(),
+            nonce: "nonce".to_string(),
+            code_verifier: "verifier".to_string(),
+        })
+    }
+
+    async fn exchange_code(
+        &self,
+        _provider: &str,
+        code: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> Result<OidcIdentity, OidcError> {
+        if code == "code" && code_verifier == "verifier" && nonce == "nonce" {
+            Ok(self.identity.clone())
+        } else {
+            Err(OidcError::Rejected("bad code".to_string()))
+        }
+    }
+}
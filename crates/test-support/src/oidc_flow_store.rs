@@ -0,0 +1,80 @@
+use crate::table::Table;
+use application::ports::oidc_flow_store::{
+    OidcFlowStore, OidcFlowStoreError, PendingIdentityLink, PendingOidcLogin,
+};
+use async_trait::async_trait;
+
+/// Pending OIDC logins and identity links kept in memory until they are taken.
+pub struct InMemoryOidcFlowStore {
+    logins: Table<PendingOidcLogin>,
+    links: Table<PendingIdentityLink>,
+}
+
+impl InMemoryOidcFlowStore {
+    pub fn new() -> Self {
+        Self::with_logins([])
+    }
+
+    pub fn with_logins(logins: impl IntoIterator<Item = PendingOidcLogin>) -> Self {
+        Self {
+            logins: Table::new(logins.into_iter().collect()),
+            links: Table::new(Vec::new()),
+        }
+    }
+
+    /// Every login not taken yet, in insertion order.
+    pub fn logins(&self) -> Vec<PendingOidcLogin> {
+        self.logins.read()
+    }
+
+    /// Every link not taken yet, in insertion order.
+    pub fn links(&self) -> Vec<PendingIdentityLink> {
+        self.links.read()
+    }
+}
+
+impl Default for InMemoryOidcFlowStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn storage_error(msg: &str) -> OidcFlowStoreError {
+    OidcFlowStoreError::StorageError(msg.to_string())
+}
+
+#[async_trait]
+impl OidcFlowStore for InMemoryOidcFlowStore {
+    async fn save_login(&self, login: &PendingOidcLogin) -> Result<(), OidcFlowStoreError> {
+        let mut logins = self.logins.write().map_err(storage_error)?;
+        logins.rows.push(login.clone());
+        Ok(())
+    }
+
+    async fn take_login(
+        &self,
+        state: &str,
+    ) -> Result<Option<PendingOidcLogin>, OidcFlowStoreError> {
+        let mut logins = self.logins.write().map_err(storage_error)?;
+        let index = logins.rows.iter().position(|login| login.state == state);
+        Ok(index.map(|index| logins.rows.remove(index)))
+    }
+
+    async fn save_link(&self, link: &PendingIdentityLink) -> Result<(), OidcFlowStoreError> {
+        let mut links = self.links.write().map_err(storage_error)?;
+        links.rows.push(link.clone());
+        Ok(())
+    }
+
+    async fn take_link(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PendingIdentityLink>, OidcFlowStoreError> {
+        let mut links = self.links.write().map_err(storage_error)?;
+        let index = links
+            .rows
+            .iter()
+            .position(|link| link.token_hash == token_hash);
+        Ok(index.map(|index| links.rows.remove(index)))
+    }
+}
@@ -0,0 +1,25 @@
+use application::ports::one_time_token_service::OneTimeTokenService;
+use uuid::Uuid;
+
+/// Prefixes tokens instead of hashing them, so tests can compute the stored hash of
+/// a token they know. Generated tokens are still unique.
+pub struct FakeOneTimeTokenService;
+
+impl FakeOneTimeTokenService {
+    const PREFIX: &'static str = "hashed$";
+
+    /// The hash [`OneTimeTokenService::hash`] returns for `token`, for seeding rows.
+    pub fn hash_of(token: &str) -> String {
+        format!("{}{}", Self::PREFIX, token)
+    }
+}
+
+impl OneTimeTokenService for FakeOneTimeTokenService {
+    fn generate(&self) -> String {
+        Uuid::new_v4().simple().to_string()
+    }
+
+    fn hash(&self, token: &str) -> String {
+        Self::hash_of(token)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::table::Table;
+use application::ports::passkey_ceremony_store::{
+    PasskeyCeremony, PasskeyCeremonyStore, PasskeyCeremonyStoreError,
+};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Started passkey ceremonies kept in memory until they are taken.
+pub struct InMemoryPasskeyCeremonyStore {
+    table: Table<PasskeyCeremony>,
+}
+
+impl InMemoryPasskeyCeremonyStore {
+    pub fn new() -> Self {
+        Self::with_ceremonies([])
+    }
+
+    pub fn with_ceremonies(ceremonies: impl IntoIterator<Item = PasskeyCeremony>) -> Self {
+        Self {
+            table: Table::new(ceremonies.into_iter().collect()),
+        }
+    }
+
+    /// Every ceremony not taken yet, in insertion order.
+    pub fn ceremonies(&self) -> Vec<PasskeyCeremony> {
+        self.table.read()
+    }
+}
+
+impl Default for InMemoryPasskeyCeremonyStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn storage_error(msg: &str) -> PasskeyCeremonyStoreError {
+    PasskeyCeremonyStoreError::StorageError(msg.to_string())
+}
+
+#[async_trait]
+impl PasskeyCeremonyStore for InMemoryPasskeyCeremonyStore {
+    async fn save(&self, ceremony: &PasskeyCeremony) -> Result<(), PasskeyCeremonyStoreError> {
+        let mut ceremonies = self.table.write().map_err(storage_error)?;
+        ceremonies.rows.retain(|stored| stored.id != ceremony.id);
+        ceremonies.rows.push(ceremony.clone());
+        Ok(())
+    }
+
+    async fn take(&self, id: Uuid) -> Result<Option<PasskeyCeremony>, PasskeyCeremonyStoreError> {
+        let mut ceremonies = self.table.write().map_err(storage_error)?;
+        let index = ceremonies
+            .rows
+            .iter()
+            .position(|ceremony| ceremony.id == id);
+        Ok(index.map(|index| ceremonies.rows.remove(index)))
+    }
+}
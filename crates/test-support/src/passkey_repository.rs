@@ -0,0 +1,96 @@
+use crate::table::Table;
+use application::ports::passkey_repository::{Passkey, PasskeyRepository, PasskeyRepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Passkeys kept in memory.
+pub struct InMemoryPasskeyRepository {
+    table: Table<Passkey>,
+}
+
+impl InMemoryPasskeyRepository {
+    pub fn new() -> Self {
+        Self::with_passkeys([])
+    }
+
+    pub fn with_passkeys(passkeys: impl IntoIterator<Item = Passkey>) -> Self {
+        Self {
+            table: Table::new(passkeys.into_iter().collect()),
+        }
+    }
+
+    /// Every stored passkey, in insertion order.
+    pub fn passkeys(&self) -> Vec<Passkey> {
+        self.table.read()
+    }
+}
+
+impl Default for InMemoryPasskeyRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn database_error(msg: &str) -> PasskeyRepositoryError {
+    PasskeyRepositoryError::DatabaseError(msg.to_string())
+}
+
+#[async_trait]
+impl PasskeyRepository for InMemoryPasskeyRepository {
+    async fn create(&self, passkey: &Passkey) -> Result<(), PasskeyRepositoryError> {
+        let mut passkeys = self.table.write().map_err(database_error)?;
+        if passkeys
+            .rows
+            .iter()
+            .any(|other| other.credential_id == passkey.credential_id)
+        {
+            return Err(PasskeyRepositoryError::DuplicateCredential);
+        }
+        passkeys.rows.push(passkey.clone());
+        Ok(())
+    }
+
+    async fn find_by_credential_id(
+        &self,
+        credential_id: &[u8],
+    ) -> Result<Option<Passkey>, PasskeyRepositoryError> {
+        Ok(self
+            .passkeys()
+            .into_iter()
+            .find(|passkey| passkey.credential_id == credential_id))
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Passkey>, PasskeyRepositoryError> {
+        let mut passkeys: Vec<Passkey> = self
+            .passkeys()
+            .into_iter()
+            .filter(|passkey| passkey.user_id == user_id)
+            .collect();
+        passkeys.sort_by_key(|passkey| passkey.created_at);
+        Ok(passkeys)
+    }
+
+    async fn record_use(
+        &self,
+        id: Uuid,
+        sign_count: u32,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), PasskeyRepositoryError> {
+        let mut passkeys = self.table.write().map_err(database_error)?;
+        for passkey in passkeys.rows.iter_mut().filter(|passkey| passkey.id == id) {
+            passkey.sign_count = sign_count;
+            passkey.last_used_at = Some(used_at);
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, PasskeyRepositoryError> {
+        let mut passkeys = self.table.write().map_err(database_error)?;
+        let before = passkeys.rows.len();
+        passkeys
+            .rows
+            .retain(|passkey| !(passkey.id == id && passkey.user_id == user_id));
+        Ok(passkeys.rows.len() < before)
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{
+    CapturingEmailService, FakeAccessTokenService, FakeOneTimeTokenService,
+    InMemoryAccountRestoreTokenRepository, InMemoryApiKeyRepository, InMemoryAuditLog,
+    InMemoryDataExportArchive, InMemoryDataExportRepository,
+    InMemoryEmailVerificationTokenRepository, InMemoryEventOutbox,
+    InMemoryLinkedIdentityRepository, InMemoryLoginAttemptStore, InMemoryMagicLinkTokenRepository,
+    InMemoryOidcFlowStore, InMemoryPasskeyCeremonyStore, InMemoryPasskeyRepository,
+    InMemoryPasswordResetTokenRepository, InMemorySessionRepository, InMemoryTwoFactorRepository,
+    InMemoryUnitOfWork, InMemoryUserRepository, InMemoryWebhookRepository, RecordingEventPublisher,
+};
+use application::ports::api_key_repository::ApiKey;
+use application::ports::data_export_repository::DataExport;
+use application::ports::email_verification_token_repository::EmailVerificationToken;
+use application::ports::login_attempt_store::LoginAttempts;
+use application::ports::oidc_flow_store::PendingOidcLogin;
+use application::ports::passkey_repository::Passkey;
+use application::ports::password_reset_token_repository::PasswordResetToken;
+use application::ports::session_repository::Session;
+use application::ports::two_factor_repository::TotpEnrollment;
+use application::ports::webhook_repository::WebhookSubscription;
+use application::use_cases::{LoginThrottle, LoginThrottlePolicy, SessionTokenIssuer};
+use domain_users::User;
+use std::sync::Arc;
+
+/// One in-memory adapter per port, empty unless seeded with a `with_*` method. Use
+/// cases built from the same ports see each other's writes, like they would share a
+/// database, and tests inspect the adapters afterwards.
+#[derive(Default)]
+pub struct InMemoryPorts {
+    pub users: Arc<InMemoryUserRepository>,
+    pub email_verification_tokens: Arc<InMemoryEmailVerificationTokenRepository>,
+    pub password_reset_tokens: Arc<InMemoryPasswordResetTokenRepository>,
+    pub magic_link_tokens: Arc<InMemoryMagicLinkTokenRepository>,
+    pub account_restore_tokens: Arc<InMemoryAccountRestoreTokenRepository>,
+    pub sessions: Arc<InMemorySessionRepository>,
+    pub audit_log: Arc<InMemoryAuditLog>,
+    pub event_outbox: Arc<InMemoryEventOutbox>,
+    pub events: Arc<RecordingEventPublisher>,
+    pub emails: Arc<CapturingEmailService>,
+    pub access_tokens: Arc<FakeAccessTokenService>,
+    pub login_attempts: Arc<InMemoryLoginAttemptStore>,
+    pub two_factor: Arc<InMemoryTwoFactorRepository>,
+    pub passkeys: Arc<InMemoryPasskeyRepository>,
+    pub passkey_ceremonies: Arc<InMemoryPasskeyCeremonyStore>,
+    pub linked_identities: Arc<InMemoryLinkedIdentityRepository>,
+    pub oidc_flows: Arc<InMemoryOidcFlowStore>,
+    pub api_keys: Arc<InMemoryApiKeyRepository>,
+    pub data_exports: Arc<InMemoryDataExportRepository>,
+    pub data_export_archive: Arc<InMemoryDataExportArchive>,
+    pub webhooks: Arc<InMemoryWebhookRepository>,
+}
+
+impl InMemoryPorts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_users(mut self, users: impl IntoIterator<Item = User>) -> Self {
+        self.users = Arc::new(InMemoryUserRepository::with_users(users));
+        self
+    }
+
+    pub fn with_email_verification_tokens(
+        mut self,
+        tokens: impl IntoIterator<Item = EmailVerificationToken>,
+    ) -> Self {
+        self.email_verification_tokens = Arc::new(
+            InMemoryEmailVerificationTokenRepository::with_tokens(tokens),
+        );
+        self
+    }
+
+    pub fn with_password_reset_tokens(
+        mut self,
+        tokens: impl IntoIterator<Item = PasswordResetToken>,
+    ) -> Self {
+        self.password_reset_tokens =
+            Arc::new(InMemoryPasswordResetTokenRepository::with_tokens(tokens));
+        self
+    }
+
+    pub fn with_sessions(mut self, sessions: impl IntoIterator<Item = Session>) -> Self {
+        self.sessions = Arc::new(InMemorySessionRepository::with_sessions(sessions));
+        self
+    }
+
+    pub fn with_login_attempts(
+        mut self,
+        attempts: impl IntoIterator<Item = LoginAttempts>,
+    ) -> Self {
+        self.login_attempts = Arc::new(InMemoryLoginAttemptStore::with_attempts(attempts));
+        self
+    }
+
+    pub fn with_totp_enrollments(
+        mut self,
+        enrollments: impl IntoIterator<Item = TotpEnrollment>,
+    ) -> Self {
+        self.two_factor = Arc::new(InMemoryTwoFactorRepository::with_enrollments(enrollments));
+        self
+    }
+
+    pub fn with_passkeys(mut self, passkeys: impl IntoIterator<Item = Passkey>) -> Self {
+        self.passkeys = Arc::new(InMemoryPasskeyRepository::with_passkeys(passkeys));
+        self
+    }
+
+    pub fn with_oidc_logins(mut self, logins: impl IntoIterator<Item = PendingOidcLogin>) -> Self {
+        self.oidc_flows = Arc::new(InMemoryOidcFlowStore::with_logins(logins));
+        self
+    }
+
+    pub fn with_api_keys(mut self, keys: impl IntoIterator<Item = ApiKey>) -> Self {
+        self.api_keys = Arc::new(InMemoryApiKeyRepository::with_keys(keys));
+        self
+    }
+
+    pub fn with_data_exports(mut self, exports: impl IntoIterator<Item = DataExport>) -> Self {
+        self.data_exports = Arc::new(InMemoryDataExportRepository::with_exports(exports));
+        self
+    }
+
+    pub fn with_webhook_subscriptions(
+        mut self,
+        subscriptions: impl IntoIterator<Item = WebhookSubscription>,
+    ) -> Self {
+        self.webhooks = Arc::new(InMemoryWebhookRepository::with_subscriptions(subscriptions));
+        self
+    }
+
+    /// Transactions over the users, email verification tokens, audit log, sessions and
+    /// outbox.
+    pub fn unit_of_work(&self) -> Arc<InMemoryUnitOfWork> {
+        Arc::new(InMemoryUnitOfWork::new(
+            self.users.clone(),
+            self.email_verification_tokens.clone(),
+            self.audit_log.clone(),
+            self.sessions.clone(),
+            self.event_outbox.clone(),
+        ))
+    }
+
+    /// Opens sessions in [`Self::sessions`] and issues [`Self::access_tokens`].
+    pub fn session_tokens(&self) -> Arc<SessionTokenIssuer> {
+        Arc::new(SessionTokenIssuer::new(
+            self.sessions.clone(),
+            self.access_tokens.clone(),
+        ))
+    }
+
+    /// Counts failures in [`Self::login_attempts`] and mails unlock links to
+    /// [`Self::emails`].
+    pub fn login_throttle(&self, policy: LoginThrottlePolicy) -> Arc<LoginThrottle> {
+        Arc::new(LoginThrottle::new(
+            self.login_attempts.clone(),
+            self.users.clone(),
+            self.emails.clone(),
+            Arc::new(FakeOneTimeTokenService),
+            policy,
+        ))
+    }
+}
@@ -0,0 +1,124 @@
+use crate::table::Table;
+use application::ports::session_repository::{Session, SessionRepository, SessionRepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::cmp::Reverse;
+use uuid::Uuid;
+
+/// Sessions kept in memory, listed in the order the Postgres queries use.
+pub struct InMemorySessionRepository {
+    pub(crate) table: Table<Session>,
+}
+
+impl InMemorySessionRepository {
+    pub fn new() -> Self {
+        Self::with_sessions([])
+    }
+
+    pub fn with_sessions(sessions: impl IntoIterator<Item = Session>) -> Self {
+        Self {
+            table: Table::new(sessions.into_iter().collect()),
+        }
+    }
+
+    /// Every stored session, revoked and expired ones included, in insertion order.
+    pub fn sessions(&self) -> Vec<Session> {
+        self.table.read()
+    }
+
+    /// Makes every later write fail with a database error.
+    pub fn fail_writes(&self) {
+        self.table.fail_writes();
+    }
+}
+
+impl Default for InMemorySessionRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn database_error(msg: &str) -> SessionRepositoryError {
+    SessionRepositoryError::DatabaseError(msg.to_string())
+}
+
+#[async_trait]
+impl SessionRepository for InMemorySessionRepository {
+    async fn create(&self, session: &Session) -> Result<(), SessionRepositoryError> {
+        let mut sessions = self.table.write().map_err(database_error)?;
+        sessions.rows.push(session.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Session>, SessionRepositoryError> {
+        Ok(self.sessions().into_iter().find(|session| session.id == id))
+    }
+
+    async fn find_active_by_user_id(
+        &self,
+        user_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Session>, SessionRepositoryError> {
+        let mut sessions: Vec<Session> = self
+            .sessions()
+            .into_iter()
+            .filter(|session| session.user_id == user_id && session.is_active(now))
+            .collect();
+        sessions.sort_by_key(|session| Reverse(session.last_seen_at));
+        Ok(sessions)
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Session>, SessionRepositoryError> {
+        let mut sessions: Vec<Session> = self
+            .sessions()
+            .into_iter()
+            .filter(|session| session.user_id == user_id)
+            .collect();
+        sessions.sort_by_key(|session| Reverse(session.created_at));
+        Ok(sessions)
+    }
+
+    async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>) -> Result<(), SessionRepositoryError> {
+        let mut sessions = self.table.write().map_err(database_error)?;
+        for session in sessions.rows.iter_mut().filter(|session| session.id == id) {
+            session.last_seen_at = seen_at;
+        }
+        Ok(())
+    }
+
+    async fn revoke(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<bool, SessionRepositoryError> {
+        let mut sessions = self.table.write().map_err(database_error)?;
+        match sessions.rows.iter_mut().find(|session| {
+            session.id == id && session.user_id == user_id && session.is_active(now)
+        }) {
+            Some(session) => {
+                session.revoked_at = Some(now);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn revoke_all(
+        &self,
+        user_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<u64, SessionRepositoryError> {
+        let mut sessions = self.table.write().map_err(database_error)?;
+        let mut revoked = 0;
+        for session in sessions
+            .rows
+            .iter_mut()
+            .filter(|session| session.user_id == user_id && session.is_active(now))
+        {
+            session.revoked_at = Some(now);
+            revoked += 1;
+        }
+        Ok(revoked)
+    }
+}
@@ -0,0 +1,67 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// The rows of one in-memory repository. `generation` grows with every write, so
+/// a transaction can tell whether they changed since it began.
+pub(crate) struct Rows<T> {
+    pub(crate) rows: Vec<T>,
+    pub(crate) generation: u64,
+}
+
+pub(crate) struct Table<T> {
+    rows: Mutex<Rows<T>>,
+    fail_writes: AtomicBool,
+}
+
+pub(crate) const SIMULATED_FAILURE: &str = "simulated write failure";
+
+impl<T: Clone> Table<T> {
+    pub(crate) fn new(rows: Vec<T>) -> Self {
+        Self {
+            rows: Mutex::new(Rows {
+                rows,
+                generation: 0,
+            }),
+            fail_writes: AtomicBool::new(false),
+        }
+    }
+
+    /// A failed assertion in another test must not poison every later call.
+    pub(crate) fn lock(&self) -> MutexGuard<'_, Rows<T>> {
+        self.rows.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(crate) fn read(&self) -> Vec<T> {
+        self.lock().rows.clone()
+    }
+
+    /// Locks the rows for a write, unless writes were made to fail.
+    pub(crate) fn write(&self) -> Result<MutexGuard<'_, Rows<T>>, &'static str> {
+        if self.fail_writes.load(Ordering::SeqCst) {
+            return Err(SIMULATED_FAILURE);
+        }
+        let mut rows = self.lock();
+        rows.generation += 1;
+        Ok(rows)
+    }
+
+    pub(crate) fn fail_writes(&self) {
+        self.fail_writes.store(true, Ordering::SeqCst);
+    }
+
+    /// A copy to stage writes in, with the generation it was taken at.
+    pub(crate) fn fork(&self) -> (Self, u64) {
+        let rows = self.lock();
+        let fork = Self::new(rows.rows.clone());
+        fork.fail_writes
+            .store(self.fail_writes.load(Ordering::SeqCst), Ordering::SeqCst);
+        (fork, rows.generation)
+    }
+
+    pub(crate) fn into_rows(self) -> Vec<T> {
+        self.rows
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+            .rows
+    }
+}
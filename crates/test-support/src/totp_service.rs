@@ -0,0 +1,38 @@
+use application::ports::totp_service::{TotpError, TotpService};
+use chrono::{DateTime, Utc};
+
+/// Hands out the secret `SECRET` and accepts two fixed codes for any secret:
+/// `123456` in time step 1 and `654321` in the later step 2. Recovery codes are
+/// `code-0`, `code-1`, … and hash to `hashed_` followed by the code.
+pub struct FakeTotpService;
+
+impl TotpService for FakeTotpService {
+    fn generate_secret(&self) -> String {
+        "SECRET".to_string()
+    }
+
+    fn provisioning_uri(&self, secret: &str, account_name: &str) -> Result<String, TotpError> {
+        Ok(format!("otpauth://totp/{}?secret={}", account_name, secret))
+    }
+
+    fn verify(
+        &self,
+        _secret: &str,
+        code: &str,
+        _at: DateTime<Utc>,
+    ) -> Result<Option<u64>, TotpError> {
+        Ok(match code {
+            "123456" => Some(1),
+            "654321" => Some(2),
+            _ => None,
+        })
+    }
+
+    fn generate_recovery_codes(&self, count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("code-{}", i)).collect()
+    }
+
+    fn hash_recovery_code(&self, code: &str) -> String {
+        format!("hashed_{}", code)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::table::Table;
+use application::ports::two_factor_repository::{
+    TotpEnrollment, TwoFactorRepository, TwoFactorRepositoryError,
+};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Clone)]
+struct RecoveryCode {
+    user_id: Uuid,
+    code_hash: String,
+    used: bool,
+}
+
+/// TOTP enrollments and recovery codes kept in memory.
+pub struct InMemoryTwoFactorRepository {
+    enrollments: Table<TotpEnrollment>,
+    recovery_codes: Table<RecoveryCode>,
+}
+
+impl InMemoryTwoFactorRepository {
+    pub fn new() -> Self {
+        Self::with_enrollments([])
+    }
+
+    pub fn with_enrollments(enrollments: impl IntoIterator<Item = TotpEnrollment>) -> Self {
+        Self {
+            enrollments: Table::new(enrollments.into_iter().collect()),
+            recovery_codes: Table::new(Vec::new()),
+        }
+    }
+
+    /// Every stored enrollment, in insertion order.
+    pub fn enrollments(&self) -> Vec<TotpEnrollment> {
+        self.enrollments.read()
+    }
+
+    /// Hashes of the recovery codes of the user that are still unused.
+    pub fn unused_recovery_codes(&self, user_id: Uuid) -> Vec<String> {
+        self.recovery_codes
+            .read()
+            .into_iter()
+            .filter(|code| code.user_id == user_id && !code.used)
+            .map(|code| code.code_hash)
+            .collect()
+    }
+}
+
+impl Default for InMemoryTwoFactorRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn database_error(msg: &str) -> TwoFactorRepositoryError {
+    TwoFactorRepositoryError::DatabaseError(msg.to_string())
+}
+
+#[async_trait]
+impl TwoFactorRepository for InMemoryTwoFactorRepository {
+    async fn find(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Option<TotpEnrollment>, TwoFactorRepositoryError> {
+        Ok(self
+            .enrollments()
+            .into_iter()
+            .find(|enrollment| enrollment.user_id == user_id))
+    }
+
+    async fn save(&self, enrollment: &TotpEnrollment) -> Result<(), TwoFactorRepositoryError> {
+        let mut enrollments = self.enrollments.write().map_err(database_error)?;
+        enrollments
+            .rows
+            .retain(|other| other.user_id != enrollment.user_id);
+        enrollments.rows.push(enrollment.clone());
+        Ok(())
+    }
+
+    async fn mark_step_used(
+        &self,
+        user_id: Uuid,
+        step: u64,
+    ) -> Result<bool, TwoFactorRepositoryError> {
+        let mut enrollments = self.enrollments.write().map_err(database_error)?;
+        let Some(enrollment) = enrollments
+            .rows
+            .iter_mut()
+            .find(|enrollment| enrollment.user_id == user_id)
+        else {
+            return Ok(false);
+        };
+        if enrollment.last_used_step.is_some_and(|used| used >= step) {
+            return Ok(false);
+        }
+        enrollment.last_used_step = Some(step);
+        Ok(true)
+    }
+
+    async fn replace_recovery_codes(
+        &self,
+        user_id: Uuid,
+        code_hashes: &[String],
+    ) -> Result<(), TwoFactorRepositoryError> {
+        let mut codes = self.recovery_codes.write().map_err(database_error)?;
+        codes.rows.retain(|code| code.user_id != user_id);
+        codes
+            .rows
+            .extend(code_hashes.iter().map(|code_hash| RecoveryCode {
+                user_id,
+                code_hash: code_hash.clone(),
+                used: false,
+            }));
+        Ok(())
+    }
+
+    async fn use_recovery_code(
+        &self,
+        user_id: Uuid,
+        code_hash: &str,
+    ) -> Result<bool, TwoFactorRepositoryError> {
+        let mut codes = self.recovery_codes.write().map_err(database_error)?;
+        match codes
+            .rows
+            .iter_mut()
+            .find(|code| code.user_id == user_id && code.code_hash == code_hash && !code.used)
+        {
+            Some(code) => {
+                code.used = true;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use crate::audit_log::InMemoryAuditLog;
+use crate::email_verification_token_repository::InMemoryEmailVerificationTokenRepository;
+use crate::event_outbox::InMemoryEventOutbox;
+use crate::session_repository::InMemorySessionRepository;
+use crate::table::{Rows, Table};
+use crate::user_repository::InMemoryUserRepository;
+use application::ports::audit_log::AuditLog;
+use application::ports::email_verification_token_repository::EmailVerificationTokenRepository;
+use application::ports::event_outbox::EventOutbox;
+use application::ports::session_repository::SessionRepository;
+use application::ports::unit_of_work::{Transaction, UnitOfWork, UnitOfWorkError};
+use application::ports::user_repository::UserRepository;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Transactions over in-memory repositories. Writes go to copies that replace the
+/// repositories' rows on commit. Rather than merging, a commit fails if a
+/// repository it wrote to was also written to since the transaction began.
+pub struct InMemoryUnitOfWork {
+    users: Arc<InMemoryUserRepository>,
+    email_verification_tokens: Arc<InMemoryEmailVerificationTokenRepository>,
+    audit_log: Arc<InMemoryAuditLog>,
+    sessions: Arc<InMemorySessionRepository>,
+    event_outbox: Arc<InMemoryEventOutbox>,
+}
+
+impl InMemoryUnitOfWork {
+    pub fn new(
+        users: Arc<InMemoryUserRepository>,
+        email_verification_tokens: Arc<InMemoryEmailVerificationTokenRepository>,
+        audit_log: Arc<InMemoryAuditLog>,
+        sessions: Arc<InMemorySessionRepository>,
+        event_outbox: Arc<InMemoryEventOutbox>,
+    ) -> Self {
+        Self {
+            users,
+            email_verification_tokens,
+            audit_log,
+            sessions,
+            event_outbox,
+        }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for InMemoryUnitOfWork {
+    async fn begin(&self) -> Result<Box<dyn Transaction>, UnitOfWorkError> {
+        let (users, users_generation) = self.users.table.fork();
+        let (tokens, tokens_generation) = self.email_verification_tokens.table.fork();
+        let (audit_entries, audit_generation) = self.audit_log.table.fork();
+        let (sessions, sessions_generation) = self.sessions.table.fork();
+        let (outbox_events, outbox_generation) = self.event_outbox.table.fork();
+        Ok(Box::new(InMemoryTransaction {
+            users: InMemoryUserRepository { table: users },
+            email_verification_tokens: InMemoryEmailVerificationTokenRepository { table: tokens },
+            audit_log: InMemoryAuditLog {
+                table: audit_entries,
+            },
+            sessions: InMemorySessionRepository { table: sessions },
+            event_outbox: InMemoryEventOutbox {
+                table: outbox_events,
+                consumer_positions: Default::default(),
+            },
+            target_users: self.users.clone(),
+            target_tokens: self.email_verification_tokens.clone(),
+            target_audit_log: self.audit_log.clone(),
+            target_sessions: self.sessions.clone(),
+            target_event_outbox: self.event_outbox.clone(),
+            users_generation,
+            tokens_generation,
+            audit_generation,
+            sessions_generation,
+            outbox_generation,
+        }))
+    }
+}
+
+struct InMemoryTransaction {
+    users: InMemoryUserRepository,
+    email_verification_tokens: InMemoryEmailVerificationTokenRepository,
+    audit_log: InMemoryAuditLog,
+    sessions: InMemorySessionRepository,
+    event_outbox: InMemoryEventOutbox,
+    target_users: Arc<InMemoryUserRepository>,
+    target_tokens: Arc<InMemoryEmailVerificationTokenRepository>,
+    target_audit_log: Arc<InMemoryAuditLog>,
+    target_sessions: Arc<InMemorySessionRepository>,
+    target_event_outbox: Arc<InMemoryEventOutbox>,
+    users_generation: u64,
+    tokens_generation: u64,
+    audit_generation: u64,
+    sessions_generation: u64,
+    outbox_generation: u64,
+}
+
+/// Whether the transaction wrote to `staged` while the rows it was forked from changed.
+fn conflicts<T: Clone>(staged: &Table<T>, target: &Rows<T>, forked_at: u64) -> bool {
+    staged.lock().generation > 0 && target.generation != forked_at
+}
+
+/// Replaces the target rows with the staged ones, if the transaction wrote any.
+fn apply<T: Clone>(staged: Table<T>, target: &mut Rows<T>) {
+    if staged.lock().generation == 0 {
+        return;
+    }
+    target.rows = Table::into_rows(staged);
+    target.generation += 1;
+}
+
+#[async_trait]
+impl Transaction for InMemoryTransaction {
+    fn users(&self) -> &dyn UserRepository {
+        &self.users
+    }
+
+    fn email_verification_tokens(&self) -> &dyn EmailVerificationTokenRepository {
+        &self.email_verification_tokens
+    }
+
+    fn audit_log(&self) -> &dyn AuditLog {
+        &self.audit_log
+    }
+
+    fn sessions(&self) -> &dyn SessionRepository {
+        &self.sessions
+    }
+
+    fn event_outbox(&self) -> &dyn EventOutbox {
+        &self.event_outbox
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), UnitOfWorkError> {
+        let Self {
+            users: staged_users,
+            email_verification_tokens: staged_tokens,
+            audit_log: staged_audit_log,
+            sessions: staged_sessions,
+            event_outbox: staged_event_outbox,
+            target_users,
+            target_tokens,
+            target_audit_log,
+            target_sessions,
+            target_event_outbox,
+            users_generation,
+            tokens_generation,
+            audit_generation,
+            sessions_generation,
+            outbox_generation,
+        } = *self;
+        let mut users = target_users.table.lock();
+        let mut tokens = target_tokens.table.lock();
+        let mut audit_entries = target_audit_log.table.lock();
+        let mut sessions = target_sessions.table.lock();
+        let mut outbox_events = target_event_outbox.table.lock();
+        if conflicts(&staged_users.table, &users, users_generation)
+            || conflicts(&staged_tokens.table, &tokens, tokens_generation)
+            || conflicts(&staged_audit_log.table, &audit_entries, audit_generation)
+            || conflicts(&staged_sessions.table, &sessions, sessions_generation)
+            || conflicts(
+                &staged_event_outbox.table,
+                &outbox_events,
+                outbox_generation,
+            )
+        {
+            return Err(UnitOfWorkError::DatabaseError(
+                "could not serialize access due to concurrent update".to_string(),
+            ));
+        }
+
+        apply(staged_users.table, &mut users);
+        apply(staged_tokens.table, &mut tokens);
+        apply(staged_audit_log.table, &mut audit_entries);
+        apply(staged_sessions.table, &mut sessions);
+        apply(staged_event_outbox.table, &mut outbox_events);
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), UnitOfWorkError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::{unique_email, user_with_email};
+    use application::ports::email_verification_token_repository::EmailVerificationToken;
+
+    fn unit_of_work() -> (
+        InMemoryUnitOfWork,
+        Arc<InMemoryUserRepository>,
+        Arc<InMemoryEmailVerificationTokenRepository>,
+    ) {
+        let users = Arc::new(InMemoryUserRepository::new());
+        let tokens = Arc::new(InMemoryEmailVerificationTokenRepository::new());
+        (
+            InMemoryUnitOfWork::new(
+                users.clone(),
+                tokens.clone(),
+                Arc::default(),
+                Arc::default(),
+                Arc::default(),
+            ),
+            users,
+            tokens,
+        )
+    }
+
+    fn token_for(user_id: uuid::Uuid) -> EmailVerificationToken {
+        EmailVerificationToken {
+            token: uuid::Uuid::new_v4().to_string(),
+            user_id,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_writes_are_only_visible_after_commit() {
+        let (unit_of_work, users, tokens) = unit_of_work();
+        let user = user_with_email(&unique_email());
+
+        let transaction = unit_of_work.begin().await.unwrap();
+        transaction.users().create(&user).await.unwrap();
+        transaction
+            .email_verification_tokens()
+            .create(&token_for(user.id()))
+            .await
+            .unwrap();
+        assert!(users.users().is_empty());
+
+        transaction.commit().await.unwrap();
+        assert_eq!(users.users().len(), 1);
+        assert_eq!(tokens.tokens().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dropped_transaction_writes_nothing() {
+        let (unit_of_work, users, tokens) = unit_of_work();
+        tokens.fail_writes();
+        let user = user_with_email(&unique_email());
+
+        let transaction = unit_of_work.begin().await.unwrap();
+        transaction.users().create(&user).await.unwrap();
+        let result = transaction
+            .email_verification_tokens()
+            .create(&token_for(user.id()))
+            .await;
+        assert!(result.is_err());
+        drop(transaction);
+
+        assert!(users.users().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_commit_fails_after_concurrent_write() {
+        let (unit_of_work, users, _) = unit_of_work();
+
+        let transaction = unit_of_work.begin().await.unwrap();
+        transaction
+            .users()
+            .create(&user_with_email(&unique_email()))
+            .await
+            .unwrap();
+        users
+            .create(&user_with_email(&unique_email()))
+            .await
+            .unwrap();
+
+        assert!(transaction.commit().await.is_err());
+        assert_eq!(users.users().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_write_to_an_untouched_repository_does_not_conflict() {
+        let (unit_of_work, users, tokens) = unit_of_work();
+        let user = user_with_email(&unique_email());
+
+        let transaction = unit_of_work.begin().await.unwrap();
+        transaction.users().create(&user).await.unwrap();
+        tokens.create(&token_for(user.id())).await.unwrap();
+
+        transaction.commit().await.unwrap();
+        assert_eq!(users.users().len(), 1);
+        assert_eq!(tokens.tokens().len(), 1);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::table::Table;
+use application::ports::user_erasure_repository::{
+    UserErasureRepository, UserErasureRepositoryError,
+};
+use application::ports::user_repository::{UserRepository, UserRepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain_users::User;
+use uuid::Uuid;
+
+/// Users kept in memory, as they would be stored: without pending events and with
+/// the version bumped on every update.
+pub struct InMemoryUserRepository {
+    pub(crate) table: Table<User>,
+}
+
+impl InMemoryUserRepository {
+    pub fn new() -> Self {
+        Self::with_users([])
+    }
+
+    /// Seeds the repository with users as they are; their versions are not checked.
+    pub fn with_users(users: impl IntoIterator<Item = User>) -> Self {
+        Self {
+            table: Table::new(users.into_iter().map(stored).collect()),
+        }
+    }
+
+    /// Every stored user, deleted ones included, in insertion order.
+    pub fn users(&self) -> Vec<User> {
+        self.table.read()
+    }
+
+    /// Makes every later write fail with a database error.
+    pub fn fail_writes(&self) {
+        self.table.fail_writes();
+    }
+}
+
+impl Default for InMemoryUserRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn stored(user: User) -> User {
+    User::from_snapshot(user.snapshot())
+}
+
+fn same_email(user: &User, email: &str) -> bool {
+    user.email().as_str().to_lowercase() == email.to_lowercase()
+}
+
+/// Mirrors the unique index on `lower(email)` of users that are not deleted.
+fn check_email_is_free(users: &[User], user: &User) -> Result<(), UserRepositoryError> {
+    let taken = !user.is_deleted()
+        && users.iter().any(|other| {
+            other.id() != user.id()
+                && !other.is_deleted()
+                && same_email(other, user.email().as_str())
+        });
+    if taken {
+        return Err(UserRepositoryError::AlreadyExists(user.email().to_string()));
+    }
+    Ok(())
+}
+
+fn database_error(msg: &str) -> UserRepositoryError {
+    UserRepositoryError::DatabaseError(msg.to_string())
+}
+
+#[async_trait]
+impl UserRepository for InMemoryUserRepository {
+    async fn create(&self, user: &User) -> Result<(), UserRepositoryError> {
+        let mut users = self.table.write().map_err(database_error)?;
+        // Postgres reports a taken primary key like a taken email.
+        if users.rows.iter().any(|other| other.id() == user.id()) {
+            return Err(UserRepositoryError::AlreadyExists(user.email().to_string()));
+        }
+        check_email_is_free(&users.rows, user)?;
+        users.rows.push(stored(user.clone()));
+        Ok(())
+    }
+
+    async fn update(&self, user: &User) -> Result<(), UserRepositoryError> {
+        let mut users = self.table.write().map_err(database_error)?;
+        let Some(index) = users.rows.iter().position(|other| other.id() == user.id()) else {
+            return Err(UserRepositoryError::NotFound(user.id().to_string()));
+        };
+        if users.rows[index].version() != user.version() {
+            return Err(UserRepositoryError::VersionConflict(user.id().to_string()));
+        }
+        check_email_is_free(&users.rows, user)?;
+
+        let mut updated = stored(user.clone());
+        updated.increment_version();
+        users.rows[index] = updated;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, UserRepositoryError> {
+        Ok(self.users().into_iter().find(|user| user.id() == id))
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<User>, UserRepositoryError> {
+        // Like the Postgres query: the active user first, then the latest deleted one.
+        Ok(self
+            .users()
+            .into_iter()
+            .filter(|user| same_email(user, email))
+            .max_by_key(|user| (!user.is_deleted(), user.deleted_at())))
+    }
+
+    async fn find_active_by_email(&self, email: &str) -> Result<Option<User>, UserRepositoryError> {
+        Ok(self
+            .users()
+            .into_iter()
+            .find(|user| !user.is_deleted() && same_email(user, email)))
+    }
+
+    async fn find_all_active(&self) -> Result<Vec<User>, UserRepositoryError> {
+        Ok(self
+            .users()
+            .into_iter()
+            .filter(|user| !user.is_deleted())
+            .collect())
+    }
+
+    async fn find_all_active_paginated(
+        &self,
+        page: u64,
+        page_size: u64,
+    ) -> Result<(Vec<User>, u64), UserRepositoryError> {
+        let active = self.find_all_active().await?;
+        let total = active.len() as u64;
+        let users = active
+            .into_iter()
+            .skip((page * page_size) as usize)
+            .take(page_size as usize)
+            .collect();
+        Ok((users, total))
+    }
+}
+
+/// Erasing removes only the user row; the other in-memory repositories keep theirs.
+#[async_trait]
+impl UserErasureRepository for InMemoryUserRepository {
+    async fn find_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<Uuid>, UserErasureRepositoryError> {
+        let mut deleted: Vec<(DateTime<Utc>, Uuid)> = self
+            .users()
+            .into_iter()
+            .filter_map(|user| Some((user.deleted_at()?, user.id())))
+            .filter(|(deleted_at, _)| *deleted_at < cutoff)
+            .collect();
+        deleted.sort();
+        Ok(deleted
+            .into_iter()
+            .take(limit as usize)
+            .map(|(_, id)| id)
+            .collect())
+    }
+
+    async fn erase(
+        &self,
+        user_id: Uuid,
+        deleted_before: DateTime<Utc>,
+        _erased_at: DateTime<Utc>,
+    ) -> Result<bool, UserErasureRepositoryError> {
+        let mut users = self
+            .table
+            .write()
+            .map_err(|msg| UserErasureRepositoryError::DatabaseError(msg.to_string()))?;
+        let before = users.rows.len();
+        users.rows.retain(|user| {
+            user.id() != user_id || user.deleted_at().is_none_or(|at| at >= deleted_before)
+        });
+        Ok(users.rows.len() < before)
+    }
+}
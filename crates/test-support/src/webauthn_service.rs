@@ -0,0 +1,52 @@
+use application::ports::webauthn_service::{
+    PasskeyAssertionResponse, PasskeyRegistrationResponse, VerifiedPasskey, WebauthnError,
+    WebauthnService,
+};
+
+/// Every challenge is `challenge`. A registration answering it is accepted with the
+/// public key `public key`. An assertion is valid when its signature is the
+/// challenge followed by the stored public key, and reports the stored counter
+/// plus one.
+pub struct FakeWebauthnService;
+
+impl FakeWebauthnService {
+    pub const CHALLENGE: &'static [u8] = b"challenge";
+}
+
+impl WebauthnService for FakeWebauthnService {
+    fn generate_challenge(&self) -> Vec<u8> {
+        Self::CHALLENGE.to_vec()
+    }
+
+    fn supported_algorithms(&self) -> Vec<i64> {
+        vec![-7]
+    }
+
+    fn verify_registration(
+        &self,
+        response: &PasskeyRegistrationResponse,
+        challenge: &[u8],
+    ) -> Result<VerifiedPasskey, WebauthnError> {
+        if challenge != Self::CHALLENGE {
+            return Err(WebauthnError::Rejected("challenge mismatch".to_string()));
+        }
+        Ok(VerifiedPasskey {
+            credential_id: response.credential_id.clone(),
+            public_key: b"public key".to_vec(),
+            sign_count: 0,
+        })
+    }
+
+    fn verify_assertion(
+        &self,
+        response: &PasskeyAssertionResponse,
+        challenge: &[u8],
+        public_key: &[u8],
+        stored_sign_count: u32,
+    ) -> Result<u32, WebauthnError> {
+        if response.signature != [challenge, public_key].concat() {
+            return Err(WebauthnError::Rejected("bad signature".to_string()));
+        }
+        Ok(stored_sign_count + 1)
+    }
+}
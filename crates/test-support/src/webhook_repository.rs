@@ -0,0 +1,191 @@
+use crate::table::Table;
+use application::ports::webhook_repository::{
+    WebhookDelivery, WebhookDeliveryStatus, WebhookRepository, WebhookRepositoryError,
+    WebhookSubscription,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use std::cmp::Reverse;
+use uuid::Uuid;
+
+/// Webhook subscriptions and their deliveries kept in memory.
+pub struct InMemoryWebhookRepository {
+    subscriptions: Table<WebhookSubscription>,
+    deliveries: Table<WebhookDelivery>,
+}
+
+impl InMemoryWebhookRepository {
+    pub fn new() -> Self {
+        Self::with_subscriptions([])
+    }
+
+    pub fn with_subscriptions(
+        subscriptions: impl IntoIterator<Item = WebhookSubscription>,
+    ) -> Self {
+        Self {
+            subscriptions: Table::new(subscriptions.into_iter().collect()),
+            deliveries: Table::new(Vec::new()),
+        }
+    }
+
+    /// Every stored subscription, in insertion order.
+    pub fn subscriptions(&self) -> Vec<WebhookSubscription> {
+        self.subscriptions.read()
+    }
+
+    /// Every stored delivery, in insertion order.
+    pub fn deliveries(&self) -> Vec<WebhookDelivery> {
+        self.deliveries.read()
+    }
+
+    /// Makes every later write fail with a database error.
+    pub fn fail_writes(&self) {
+        self.subscriptions.fail_writes();
+        self.deliveries.fail_writes();
+    }
+}
+
+impl Default for InMemoryWebhookRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn database_error(msg: &str) -> WebhookRepositoryError {
+    WebhookRepositoryError::DatabaseError(msg.to_string())
+}
+
+#[async_trait]
+impl WebhookRepository for InMemoryWebhookRepository {
+    async fn create_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), WebhookRepositoryError> {
+        let mut subscriptions = self.subscriptions.write().map_err(database_error)?;
+        subscriptions.rows.push(subscription.clone());
+        Ok(())
+    }
+
+    async fn find_subscription(
+        &self,
+        id: Uuid,
+    ) -> Result<Option<WebhookSubscription>, WebhookRepositoryError> {
+        Ok(self
+            .subscriptions()
+            .into_iter()
+            .find(|subscription| subscription.id == id))
+    }
+
+    async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, WebhookRepositoryError> {
+        let mut subscriptions = self.subscriptions();
+        subscriptions.sort_by_key(|subscription| subscription.created_at);
+        Ok(subscriptions)
+    }
+
+    async fn delete_subscription(&self, id: Uuid) -> Result<bool, WebhookRepositoryError> {
+        let mut subscriptions = self.subscriptions.write().map_err(database_error)?;
+        let mut deliveries = self.deliveries.write().map_err(database_error)?;
+        let before = subscriptions.rows.len();
+        subscriptions
+            .rows
+            .retain(|subscription| subscription.id != id);
+        deliveries
+            .rows
+            .retain(|delivery| delivery.subscription_id != id);
+        Ok(subscriptions.rows.len() < before)
+    }
+
+    async fn create_deliveries(
+        &self,
+        deliveries: &[WebhookDelivery],
+    ) -> Result<(), WebhookRepositoryError> {
+        let mut stored = self.deliveries.write().map_err(database_error)?;
+        for delivery in deliveries {
+            let duplicate = stored.rows.iter().any(|existing| {
+                existing.subscription_id == delivery.subscription_id
+                    && existing.event.id == delivery.event.id
+            });
+            if !duplicate {
+                stored.rows.push(delivery.clone());
+            }
+        }
+        Ok(())
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease: Duration,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, WebhookRepositoryError> {
+        let mut deliveries = self.deliveries.write().map_err(database_error)?;
+        let mut due: Vec<&mut WebhookDelivery> = deliveries
+            .rows
+            .iter_mut()
+            .filter(|delivery| {
+                delivery.status == WebhookDeliveryStatus::Pending
+                    && delivery.next_attempt_at.is_some_and(|at| at <= now)
+            })
+            .collect();
+        due.sort_by_key(|delivery| delivery.created_at);
+        Ok(due
+            .into_iter()
+            .take(limit as usize)
+            .map(|delivery| {
+                delivery.next_attempt_at = Some(now + lease);
+                delivery.clone()
+            })
+            .collect())
+    }
+
+    async fn find_delivery(
+        &self,
+        id: Uuid,
+    ) -> Result<Option<WebhookDelivery>, WebhookRepositoryError> {
+        Ok(self
+            .deliveries()
+            .into_iter()
+            .find(|delivery| delivery.id == id))
+    }
+
+    async fn list_deliveries(
+        &self,
+        subscription_id: Uuid,
+        page: u64,
+        page_size: u64,
+    ) -> Result<(Vec<WebhookDelivery>, u64), WebhookRepositoryError> {
+        let mut deliveries: Vec<WebhookDelivery> = self
+            .deliveries()
+            .into_iter()
+            .filter(|delivery| delivery.subscription_id == subscription_id)
+            .collect();
+        deliveries.sort_by_key(|delivery| Reverse((delivery.created_at, delivery.id)));
+        let total = deliveries.len() as u64;
+        let page = deliveries
+            .into_iter()
+            .skip((page * page_size) as usize)
+            .take(page_size as usize)
+            .collect();
+        Ok((page, total))
+    }
+
+    async fn update_delivery(
+        &self,
+        delivery: &WebhookDelivery,
+    ) -> Result<(), WebhookRepositoryError> {
+        let mut deliveries = self.deliveries.write().map_err(database_error)?;
+        if let Some(stored) = deliveries
+            .rows
+            .iter_mut()
+            .find(|stored| stored.id == delivery.id)
+        {
+            stored.status = delivery.status;
+            stored.attempts = delivery.attempts;
+            stored.next_attempt_at = delivery.next_attempt_at;
+            stored.last_attempt_at = delivery.last_attempt_at;
+            stored.response_status = delivery.response_status;
+            stored.last_error = delivery.last_error.clone();
+        }
+        Ok(())
+    }
+}
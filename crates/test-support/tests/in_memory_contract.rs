@@ -0,0 +1,66 @@
+//! The in-memory adapters against the shared contract; the Postgres ones run it
+//! in `infrastructure/tests/postgres_contract.rs`.
+
+use test_support::contract::{token_repositories, user_repository};
+use test_support::{
+    InMemoryEmailVerificationTokenRepository, InMemoryPasswordResetTokenRepository,
+    InMemoryUserRepository,
+};
+
+#[tokio::test]
+async fn user_repository_finds_created_users() {
+    user_repository::finds_created_users(&InMemoryUserRepository::new()).await;
+}
+
+#[tokio::test]
+async fn user_repository_rejects_taken_emails() {
+    user_repository::rejects_taken_emails(&InMemoryUserRepository::new()).await;
+}
+
+#[tokio::test]
+async fn user_repository_keeps_deleted_users_out_of_active_queries() {
+    user_repository::keeps_deleted_users_out_of_active_queries(&InMemoryUserRepository::new())
+        .await;
+}
+
+#[tokio::test]
+async fn user_repository_frees_the_email_of_deleted_users() {
+    user_repository::frees_the_email_of_deleted_users(&InMemoryUserRepository::new()).await;
+}
+
+#[tokio::test]
+async fn user_repository_rejects_stale_updates() {
+    user_repository::rejects_stale_updates(&InMemoryUserRepository::new()).await;
+}
+
+#[tokio::test]
+async fn user_repository_paginates_active_users() {
+    user_repository::paginates_active_users(&InMemoryUserRepository::new()).await;
+}
+
+#[tokio::test]
+async fn email_verification_token_repository_stores_tokens() {
+    token_repositories::stores_email_verification_tokens(
+        &InMemoryUserRepository::new(),
+        &InMemoryEmailVerificationTokenRepository::new(),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn password_reset_token_repository_stores_tokens() {
+    token_repositories::stores_password_reset_tokens(
+        &InMemoryUserRepository::new(),
+        &InMemoryPasswordResetTokenRepository::new(),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn password_reset_token_repository_deletes_tokens_by_user() {
+    token_repositories::deletes_password_reset_tokens_by_user(
+        &InMemoryUserRepository::new(),
+        &InMemoryPasswordResetTokenRepository::new(),
+    )
+    .await;
+}